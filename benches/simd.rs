@@ -0,0 +1,138 @@
+#![feature(test)]
+#![cfg(feature = "simd")]
+
+extern crate test;
+use test::Bencher;
+
+use ndarray::prelude::*;
+use ndarray::Zip;
+
+const N: usize = 1 << 14;
+
+fn input_f32() -> Array1<f32> {
+    Array::linspace(-1., 1., N).mapv(f32::sin)
+}
+
+fn input_f64() -> Array1<f64> {
+    Array::linspace(-1., 1., N).mapv(f64::sin)
+}
+
+#[bench]
+fn sum_f32_generic(bench: &mut Bencher) {
+    let a = input_f32();
+    bench.iter(|| a.sum());
+}
+
+#[bench]
+fn sum_f32_simd(bench: &mut Bencher) {
+    let a = input_f32();
+    bench.iter(|| a.simd_sum());
+}
+
+#[bench]
+fn sum_f64_generic(bench: &mut Bencher) {
+    let a = input_f64();
+    bench.iter(|| a.sum());
+}
+
+#[bench]
+fn sum_f64_simd(bench: &mut Bencher) {
+    let a = input_f64();
+    bench.iter(|| a.simd_sum());
+}
+
+#[bench]
+fn min_f32_fold(bench: &mut Bencher) {
+    let a = input_f32();
+    bench.iter(|| a.fold(f32::INFINITY, |m, &x| m.min(x)));
+}
+
+#[bench]
+fn min_f32_simd(bench: &mut Bencher) {
+    let a = input_f32();
+    bench.iter(|| a.simd_min());
+}
+
+#[bench]
+fn max_f64_fold(bench: &mut Bencher) {
+    let a = input_f64();
+    bench.iter(|| a.fold(f64::NEG_INFINITY, |m, &x| m.max(x)));
+}
+
+#[bench]
+fn max_f64_simd(bench: &mut Bencher) {
+    let a = input_f64();
+    bench.iter(|| a.simd_max());
+}
+
+#[bench]
+fn max_i32_fold(bench: &mut Bencher) {
+    let a = Array::from_shape_fn(N, |i| (i as i32).wrapping_mul(7919) % 1000);
+    bench.iter(|| a.fold(i32::MIN, |m, &x| m.max(x)));
+}
+
+#[bench]
+fn max_i32_simd(bench: &mut Bencher) {
+    let a = Array::from_shape_fn(N, |i| (i as i32).wrapping_mul(7919) % 1000);
+    bench.iter(|| a.simd_max());
+}
+
+#[bench]
+fn sum_f32_strided_generic(bench: &mut Bencher) {
+    let a = input_f32().into_shape((128, N / 128)).unwrap();
+    let t = a.t();
+    bench.iter(|| t.slice(s![..;2, ..]).sum());
+}
+
+#[bench]
+fn sum_f32_strided_simd(bench: &mut Bencher) {
+    let a = input_f32().into_shape((128, N / 128)).unwrap();
+    let t = a.t();
+    bench.iter(|| t.slice(s![..;2, ..]).simd_sum());
+}
+
+#[bench]
+fn min_f32_strided_fold(bench: &mut Bencher) {
+    let a = input_f32().into_shape((128, N / 128)).unwrap();
+    let t = a.t();
+    bench.iter(|| t.slice(s![..;2, ..]).fold(f32::INFINITY, |m, &x| m.min(x)));
+}
+
+#[bench]
+fn min_f32_strided_simd(bench: &mut Bencher) {
+    let a = input_f32().into_shape((128, N / 128)).unwrap();
+    let t = a.t();
+    bench.iter(|| t.slice(s![..;2, ..]).simd_min());
+}
+
+#[bench]
+fn max_abs_f32_fold(bench: &mut Bencher) {
+    let a = input_f32();
+    bench.iter(|| a.fold(0f32, |m, &x| m.max(x.abs())));
+}
+
+#[bench]
+fn max_abs_f32_simd_fold(bench: &mut Bencher) {
+    use ndarray::simd::Pack;
+    let a = input_f32();
+    bench.iter(|| a.simd_fold(0., |m, x| m.max(x.max(Pack::splat(0.) - x)), f32::max));
+}
+
+#[bench]
+fn clamp_f64_zip(bench: &mut Bencher) {
+    let mut a = input_f64();
+    let b = input_f64().mapv(|x| x / 2.);
+    bench.iter(|| {
+        Zip::from(&mut a).and(&b).for_each(|a, &b| *a = a.max(-b).min(b));
+    });
+}
+
+#[bench]
+fn clamp_f64_simd_zip(bench: &mut Bencher) {
+    use ndarray::simd::Pack;
+    let mut a = input_f64();
+    let b = input_f64().mapv(|x| x / 2.);
+    bench.iter(|| {
+        Zip::from(&mut a).and(&b).simd_for_each(|a, b| *a = a.max(Pack::splat(0.) - b).min(b));
+    });
+}
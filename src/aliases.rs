@@ -68,6 +68,34 @@ pub type Ix4 = Dim<[Ix; 4]>;
 pub type Ix5 = Dim<[Ix; 5]>;
 /// six-dimensional
 pub type Ix6 = Dim<[Ix; 6]>;
+/// `N`-dimensional, for code that is generic over the number of dimensions
+///
+/// `IxN<N>` is the same type as `Ix0` to `Ix6` for `N` from 0 to 6, and it
+/// implements `Dimension` for those. Functions can take arrays of any fixed
+/// dimensionality with a `const N: usize` parameter and an
+/// `IxN<N>: Dimension` bound; the dimensions with one axis less or more,
+/// after [`.index_axis()`](crate::ArrayBase::index_axis) or
+/// [`.insert_axis()`](crate::ArrayBase::insert_axis), are
+/// `<IxN<N> as Dimension>::Smaller` and `<IxN<N> as Dimension>::Larger`.
+///
+/// ```
+/// use ndarray::{array, Array, ArrayViewN, Axis, Dimension, IxN, RemoveAxis};
+///
+/// /// Sum over the first axis
+/// fn sum_first<const N: usize>(a: ArrayViewN<'_, f64, N>)
+///     -> Array<f64, <IxN<N> as Dimension>::Smaller>
+/// where
+///     IxN<N>: RemoveAxis,
+/// {
+///     a.sum_axis(Axis(0))
+/// }
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// assert_eq!(sum_first(a.view()), array![4., 6.]);
+/// let b = a.insert_axis(Axis(0));
+/// assert_eq!(sum_first(b.view()), array![[1., 2.], [3., 4.]]);
+/// ```
+pub type IxN<const N: usize> = Dim<[Ix; N]>;
 /// dynamic-dimensional
 ///
 /// You can use the `IxDyn` function to create a dimension for an array with
@@ -112,6 +140,8 @@ pub type Array4<A> = Array<A, Ix4>;
 pub type Array5<A> = Array<A, Ix5>;
 /// six-dimensional array
 pub type Array6<A> = Array<A, Ix6>;
+/// `N`-dimensional array, see [`IxN`]
+pub type ArrayN<A, const N: usize> = Array<A, IxN<N>>;
 /// dynamic-dimensional array
 pub type ArrayD<A> = Array<A, IxDyn>;
 
@@ -129,6 +159,8 @@ pub type ArrayView4<'a, A> = ArrayView<'a, A, Ix4>;
 pub type ArrayView5<'a, A> = ArrayView<'a, A, Ix5>;
 /// six-dimensional array view
 pub type ArrayView6<'a, A> = ArrayView<'a, A, Ix6>;
+/// `N`-dimensional array view, see [`IxN`]
+pub type ArrayViewN<'a, A, const N: usize> = ArrayView<'a, A, IxN<N>>;
 /// dynamic-dimensional array view
 pub type ArrayViewD<'a, A> = ArrayView<'a, A, IxDyn>;
 
@@ -146,6 +178,8 @@ pub type ArrayViewMut4<'a, A> = ArrayViewMut<'a, A, Ix4>;
 pub type ArrayViewMut5<'a, A> = ArrayViewMut<'a, A, Ix5>;
 /// six-dimensional read-write array view
 pub type ArrayViewMut6<'a, A> = ArrayViewMut<'a, A, Ix6>;
+/// `N`-dimensional read-write array view, see [`IxN`]
+pub type ArrayViewMutN<'a, A, const N: usize> = ArrayViewMut<'a, A, IxN<N>>;
 /// dynamic-dimensional read-write array view
 pub type ArrayViewMutD<'a, A> = ArrayViewMut<'a, A, IxDyn>;
 
@@ -116,6 +116,19 @@ where
     {
         <Self as RelativeEq<_>>::relative_eq(self, other, epsilon, max_relative)
     }
+
+    /// A test for equality that uses the elementwise distance in units of least precision
+    /// if the values are far apart; and the absolute difference otherwise.
+    ///
+    /// **Requires crate feature `"approx"`**
+    pub fn ulps_eq<S2>(&self, other: &ArrayBase<S2, D>, epsilon: A::Epsilon, max_ulps: u32) -> bool
+    where
+        A: UlpsEq<S2::Elem>,
+        A::Epsilon: Clone,
+        S2: Data
+    {
+        <Self as UlpsEq<_>>::ulps_eq(self, other, epsilon, max_ulps)
+    }
 }
 
 
@@ -0,0 +1,121 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use num_traits::{One, Zero};
+
+use crate::imp_prelude::*;
+use crate::{Order, Shape, ShapeBuilder, ShapeError};
+
+/// A builder of new arrays: the shape and memory order, followed by the
+/// elements.
+///
+/// `ArrayBuilder` gathers the array constructors in one place. Start with
+/// [`ArrayBuilder::shape()`], optionally choose the memory order with
+/// [`.order()`](Self::order), and create the array with one of the other
+/// methods, which are the same as the corresponding constructors of
+/// [`Array`]: [`.fill()`](Self::fill) is [`Array::from_elem`],
+/// [`.fill_with()`](Self::fill_with) is [`Array::from_shape_fn`] and
+/// [`.with_vec()`](Self::with_vec) is [`Array::from_shape_vec`].
+///
+/// Without an explicit order, the arrays are row major, or in the order of
+/// [`with_default_order`](crate::with_default_order) if one is set, like
+/// with the constructors.
+///
+/// ```
+/// use ndarray::{array, ArrayBuilder, Order};
+///
+/// let a = ArrayBuilder::shape((2, 3)).order(Order::ColumnMajor).fill(7.);
+/// assert_eq!(a, array![[7., 7., 7.], [7., 7., 7.]]);
+/// assert!(a.t().is_standard_layout());
+///
+/// let b = ArrayBuilder::shape((2, 2)).fill_with(|(i, j)| i * 10 + j);
+/// assert_eq!(b, array![[0, 1], [10, 11]]);
+///
+/// // Elements from a vector are in the memory order
+/// let c = ArrayBuilder::shape((2, 2)).order(Order::F).with_vec(vec![1, 2, 3, 4]).unwrap();
+/// assert_eq!(c, array![[1, 3], [2, 4]]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ArrayBuilder<D> {
+    shape: Shape<D>,
+}
+
+impl<D> ArrayBuilder<D>
+where
+    D: Dimension,
+{
+    /// Start building an array of shape `shape`, which can be a dimension
+    /// like `(2, 3)` or a shape with a memory order like `(2, 3).f()`.
+    pub fn shape<Sh>(shape: Sh) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        ArrayBuilder {
+            shape: shape.into_shape(),
+        }
+    }
+
+    /// Set the memory order of the array.
+    pub fn order(self, order: Order) -> Self {
+        ArrayBuilder {
+            shape: self.shape.set_f(order.is_column_major()),
+        }
+    }
+
+    /// Create an array with all elements equal to `elem`.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    pub fn fill<A>(self, elem: A) -> Array<A, D>
+    where
+        A: Clone,
+    {
+        Array::from_elem(self.shape, elem)
+    }
+
+    /// Create an array of zeros.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    pub fn zeros<A>(self) -> Array<A, D>
+    where
+        A: Clone + Zero,
+    {
+        Array::zeros(self.shape)
+    }
+
+    /// Create an array of ones.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    pub fn ones<A>(self) -> Array<A, D>
+    where
+        A: Clone + One,
+    {
+        Array::ones(self.shape)
+    }
+
+    /// Create an array with the elements given by calling `f` with the index
+    /// of each element.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    pub fn fill_with<A, F>(self, f: F) -> Array<A, D>
+    where
+        F: FnMut(D::Pattern) -> A,
+    {
+        Array::from_shape_fn(self.shape, f)
+    }
+
+    /// Create an array with the elements of `v`, in the memory order of
+    /// the array.
+    ///
+    /// **Errors** if the length of `v` is not the number of elements of the
+    /// shape.
+    pub fn with_vec<A>(self, v: Vec<A>) -> Result<Array<A, D>, ShapeError> {
+        Array::from_shape_vec(self.shape, v)
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+use num_traits::Zero;
+
+use crate::imp_prelude::*;
+use crate::ShapeBuilder;
+
+/// A pool of reusable buffers for temporary owned arrays.
+///
+/// [`.take()`](ArrayPool::take) hands out an owned array of the requested shape, using a buffer
+/// from the pool if one is large enough, and the buffer is returned to the pool when the
+/// [`PooledArray`] is dropped. In a loop that creates temporaries of the same shapes, for example
+/// once per frame or per batch, only the first iteration allocates.
+///
+/// The pool holds buffers for any element type, and can be shared between threads.
+///
+/// ```
+/// use ndarray::{ArrayPool, Axis};
+///
+/// let pool = ArrayPool::new();
+/// for frame in 0..10 {
+///     let mut image = pool.take::<f32, _>((480, 640));
+///     image.fill(frame as f32);
+///     let mut row_sums = pool.take::<f32, _>(480);
+///     row_sums.assign(&image.sum_axis(Axis(1)));
+///     assert_eq!(row_sums[0], 640. * frame as f32);
+/// }
+/// assert_eq!(pool.len(), 2);
+/// ```
+pub struct ArrayPool {
+    buffers: Mutex<HashMap<TypeId, Vec<Box<dyn Any + Send>>>>,
+}
+
+impl ArrayPool {
+    /// Create a new, empty pool.
+    pub fn new() -> Self {
+        ArrayPool {
+            buffers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return an array of zeros with the given shape, reusing a buffer from the pool if possible.
+    ///
+    /// The shape can be in either C or F order; see [`ShapeBuilder`].
+    pub fn take<A, Sh>(&self, shape: Sh) -> PooledArray<'_, A, Sh::Dim>
+    where
+        A: Clone + Zero + Send + 'static,
+        Sh: ShapeBuilder,
+    {
+        self.take_elem(shape, A::zero())
+    }
+
+    /// Return an array filled with `elem` with the given shape, reusing a buffer from the pool
+    /// if possible.
+    ///
+    /// The shape can be in either C or F order; see [`ShapeBuilder`].
+    pub fn take_elem<A, Sh>(&self, shape: Sh, elem: A) -> PooledArray<'_, A, Sh::Dim>
+    where
+        A: Clone + Send + 'static,
+        Sh: ShapeBuilder,
+    {
+        let shape = shape.into_shape();
+        let size = shape.size();
+        let mut data = self.take_buffer::<A>(size);
+        data.resize(size, elem);
+        PooledArray {
+            array: Some(Array::from_shape_vec(shape, data).unwrap()),
+            pool: self,
+        }
+    }
+
+    /// Return the number of buffers in the pool, for all element types.
+    pub fn len(&self) -> usize {
+        self.lock().values().map(Vec::len).sum()
+    }
+
+    /// Return `true` if there are no buffers in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all buffers in the pool.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Remove the smallest buffer with a capacity of at least `size` from the pool, or allocate
+    /// a new one. The buffer is empty.
+    fn take_buffer<A: Send + 'static>(&self, size: usize) -> Vec<A> {
+        let mut buffers = self.lock();
+        let buffers = match buffers.get_mut(&TypeId::of::<A>()) {
+            Some(buffers) => buffers,
+            None => return Vec::with_capacity(size),
+        };
+        let capacity =
+            |buffer: &Box<dyn Any + Send>| buffer.downcast_ref::<Vec<A>>().unwrap().capacity();
+        let best = buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, buffer)| capacity(buffer) >= size)
+            .min_by_key(|(_, buffer)| capacity(buffer))
+            .map(|(i, _)| i);
+        match best {
+            Some(i) => *buffers.swap_remove(i).downcast::<Vec<A>>().unwrap(),
+            None => Vec::with_capacity(size),
+        }
+    }
+
+    fn put_buffer<A: Send + 'static>(&self, mut buffer: Vec<A>) {
+        buffer.clear();
+        if buffer.capacity() > 0 {
+            self.lock()
+                .entry(TypeId::of::<A>())
+                .or_default()
+                .push(Box::new(buffer));
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<TypeId, Vec<Box<dyn Any + Send>>>> {
+        // The map is always in a consistent state, even if another thread panicked
+        self.buffers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ArrayPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ArrayPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayPool")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// An owned array from an [`ArrayPool`], which returns its buffer to the pool when dropped.
+///
+/// It dereferences to the array, [`Array<A, D>`](Array).
+pub struct PooledArray<'p, A, D>
+where
+    A: Send + 'static,
+    D: Dimension,
+{
+    array: Option<Array<A, D>>,
+    pool: &'p ArrayPool,
+}
+
+impl<'p, A, D> PooledArray<'p, A, D>
+where
+    A: Send + 'static,
+    D: Dimension,
+{
+    /// Take the array out of the pool; its buffer is not returned to the pool.
+    pub fn into_inner(mut self) -> Array<A, D> {
+        self.array.take().unwrap()
+    }
+}
+
+impl<'p, A, D> Deref for PooledArray<'p, A, D>
+where
+    A: Send + 'static,
+    D: Dimension,
+{
+    type Target = Array<A, D>;
+
+    fn deref(&self) -> &Array<A, D> {
+        self.array.as_ref().unwrap()
+    }
+}
+
+impl<'p, A, D> DerefMut for PooledArray<'p, A, D>
+where
+    A: Send + 'static,
+    D: Dimension,
+{
+    fn deref_mut(&mut self) -> &mut Array<A, D> {
+        self.array.as_mut().unwrap()
+    }
+}
+
+impl<'p, A, D> Drop for PooledArray<'p, A, D>
+where
+    A: Send + 'static,
+    D: Dimension,
+{
+    fn drop(&mut self) {
+        if let Some(array) = self.array.take() {
+            self.pool.put_buffer(array.into_raw_vec());
+        }
+    }
+}
+
+impl<'p, A, D> fmt::Debug for PooledArray<'p, A, D>
+where
+    A: fmt::Debug + Send + 'static,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
@@ -82,6 +82,20 @@ impl<'de> Deserialize<'de> for IxDyn {
 }
 
 /// **Requires crate feature `"serde"`**
+///
+/// Arrays of any storage (`Array`, `ArcArray`, `ArrayView`, ...) and any
+/// dimension type are serialized as a struct named `Array` with the fields:
+///
+/// - `v`: the format version, currently `1`
+/// - `dim`: the shape, as a sequence of integers (a tuple for the fixed
+///   dimension types)
+/// - `data`: the elements as a flat sequence in logical order (row major,
+///   the order of `.iter()`), regardless of the memory layout
+///
+/// For example, `array![[1, 2], [3, 4]]` in JSON is
+/// `{"v":1,"dim":[2,2],"data":[1,2,3,4]}`. This format is stable and is
+/// deserialized into any owned array type (`Array`, `ArcArray`, ...) with
+/// the same number of dimensions, or into `ArrayD`.
 impl<A, D, S> Serialize for ArrayBase<S, D>
 where
     A: Serialize,
@@ -121,6 +135,24 @@ where
     }
 }
 
+/// Create the array, or return an error if the length of the data doesn't match the shape.
+fn array_from_dim_data<A, Di, S, E>(dim: Di, data: Vec<A>) -> Result<ArrayBase<S, Di>, E>
+where
+    Di: Dimension,
+    S: DataOwned<Elem = A>,
+    E: de::Error,
+{
+    let len = data.len();
+    match ArrayBase::from_shape_vec(dim.clone(), data) {
+        Ok(array) => Ok(array),
+        Err(_) => Err(de::Error::custom(format!(
+            "data and dimension must match in size: {} elements for shape {:?}",
+            len,
+            dim.slice()
+        ))),
+    }
+}
+
 struct ArrayVisitor<S, Di> {
     _marker_a: PhantomData<S>,
     _marker_b: PhantomData<Di>,
@@ -144,6 +176,11 @@ impl<S, Di> ArrayVisitor<S, Di> {
 static ARRAY_FIELDS: &[&str] = &["v", "dim", "data"];
 
 /// **Requires crate feature `"serde"`**
+///
+/// See the `Serialize` implementation for the format. Deserialization
+/// returns an error if the version is unknown, if the shape has the wrong
+/// number of dimensions or if the number of elements doesn't match the
+/// shape.
 impl<'de, A, Di, S> Deserialize<'de> for ArrayBase<S, Di>
 where
     A: Deserialize<'de>,
@@ -243,11 +280,7 @@ where
             }
         };
 
-        if let Ok(array) = ArrayBase::from_shape_vec(dim, data) {
-            Ok(array)
-        } else {
-            Err(de::Error::custom("data and dimension must match in size"))
-        }
+        array_from_dim_data(dim, data)
     }
 
     fn visit_map<V>(self, mut visitor: V) -> Result<ArrayBase<S, Di>, V::Error>
@@ -289,10 +322,6 @@ where
             None => Err(de::Error::missing_field("dim"))?,
         };
 
-        if let Ok(array) = ArrayBase::from_shape_vec(dim, data) {
-            Ok(array)
-        } else {
-            Err(de::Error::custom("data and dimension must match in size"))
-        }
+        array_from_dim_data(dim, data)
     }
 }
@@ -9,6 +9,7 @@ use super::{ArrayBase, ArrayView, Axis, Data, Dimension, NdProducer};
 use crate::aliases::{Ix1, IxDyn};
 use std::fmt;
 use alloc::format;
+use alloc::string::String;
 
 /// Default threshold, below this element count, we don't ellipsize
 const ARRAY_MANY_ELEMENT_LIMIT: usize = 500;
@@ -28,22 +29,30 @@ const AXIS_2D_OVERFLOW_LIMIT: usize = 22;
 /// The string used as an ellipsis.
 const ELLIPSIS: &str = "...";
 
+/// The separator of the elements in the last axis.
+const SEPARATOR: &str = ", ";
+
 #[derive(Clone, Debug)]
-struct FormatOptions {
+struct FormatOptions<'s> {
     axis_collapse_limit: usize,
     axis_collapse_limit_next_last: usize,
     axis_collapse_limit_last: usize,
+    separator: &'s str,
 }
 
-impl FormatOptions {
+impl FormatOptions<'static> {
     pub(crate) fn default_for_array(nelem: usize, no_limit: bool) -> Self {
         let default = Self {
             axis_collapse_limit: AXIS_LIMIT_STACKED,
             axis_collapse_limit_next_last: AXIS_LIMIT_COL,
             axis_collapse_limit_last: AXIS_LIMIT_ROW,
+            separator: SEPARATOR,
         };
         default.set_no_limit(no_limit || nelem < ARRAY_MANY_ELEMENT_LIMIT)
     }
+}
+
+impl<'s> FormatOptions<'s> {
 
     fn set_no_limit(mut self, no_limit: bool) -> Self {
         if no_limit {
@@ -95,12 +104,15 @@ fn format_with_overflow(
         }
     } else {
         let edge = limit / 2;
-        fmt_elem(f, 0)?;
-        for i in 1..edge {
-            f.write_str(separator)?;
+        for i in 0..edge {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
             fmt_elem(f, i)?;
         }
-        f.write_str(separator)?;
+        if edge > 0 {
+            f.write_str(separator)?;
+        }
         f.write_str(ellipsis)?;
         for i in length - edge..length {
             f.write_str(separator)?;
@@ -154,7 +166,7 @@ where
                 f,
                 len,
                 fmt_opt.collapse_limit(0),
-                ", ",
+                fmt_opt.separator,
                 ELLIPSIS,
                 &mut |f, index| format(&view[index], f),
             )?;
@@ -282,6 +294,122 @@ where
     }
 }
 
+/// Displays an array with custom formatting options, created by
+/// [`.display()`](ArrayBase::display).
+///
+/// The options that are not set have the same defaults as the `Display`
+/// implementation of the array.
+#[derive(Clone)]
+pub struct ArrayDisplay<'a, A, D> {
+    view: ArrayView<'a, A, D>,
+    precision: Option<usize>,
+    edge_items: Option<usize>,
+    threshold: usize,
+    separator: String,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return an adapter that displays the array with custom formatting
+    /// options, like the precision and when to summarize large arrays.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::linspace(0., 1., 1000);
+    /// assert_eq!(
+    ///     a.display().precision(3).edge_items(2).to_string(),
+    ///     "[0.000, 0.001, ..., 0.999, 1.000]"
+    /// );
+    ///
+    /// let b = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    /// assert_eq!(
+    ///     b.display().threshold(10).edge_items(1).separator(" ").to_string(),
+    ///     "[[0 ... 3],\n ...,\n [8 ... 11]]"
+    /// );
+    /// ```
+    pub fn display(&self) -> ArrayDisplay<'_, A, D> {
+        ArrayDisplay {
+            view: self.view(),
+            precision: None,
+            edge_items: None,
+            threshold: ARRAY_MANY_ELEMENT_LIMIT,
+            separator: String::from(SEPARATOR),
+        }
+    }
+}
+
+impl<'a, A, D> ArrayDisplay<'a, A, D> {
+    /// Format each element with `precision` digits after the decimal point,
+    /// overriding the precision of the format string, if any.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Show `edge_items` elements at the beginning and the end of each axis
+    /// when the array is summarized.
+    ///
+    /// By default, more elements are shown along the last two axes than
+    /// along the others.
+    pub fn edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = Some(edge_items);
+        self
+    }
+
+    /// Summarize arrays with at least `threshold` elements, by showing only
+    /// the elements at the edges of the axes that are long; the others are
+    /// replaced by `...`. The default is 500.
+    ///
+    /// The alternate flag (`{:#}`) disables summarizing.
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Separate the elements along the last axis with `separator`. The
+    /// default is `", "`.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = String::from(separator);
+        self
+    }
+}
+
+/// Format the array with the options of the adapter, using `Display` and
+/// the formatting parameters for each element.
+impl<'a, A: fmt::Display, D: Dimension> fmt::Display for ArrayDisplay<'a, A, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let no_limit = f.alternate() || self.view.len() < self.threshold;
+        let mut fmt_opt = FormatOptions {
+            axis_collapse_limit: AXIS_LIMIT_STACKED,
+            axis_collapse_limit_next_last: AXIS_LIMIT_COL,
+            axis_collapse_limit_last: AXIS_LIMIT_ROW,
+            separator: &self.separator,
+        };
+        if let Some(edge_items) = self.edge_items {
+            // An axis is summarized if it's longer than the limit; then
+            // `limit / 2` elements are shown at each edge.
+            let limit = edge_items.saturating_mul(2);
+            fmt_opt.axis_collapse_limit = limit;
+            fmt_opt.axis_collapse_limit_next_last = limit;
+            fmt_opt.axis_collapse_limit_last = limit;
+        }
+        let fmt_opt = fmt_opt.set_no_limit(no_limit);
+        match self.precision {
+            Some(precision) => format_array(
+                &self.view,
+                f,
+                |elt: &A, f: &mut fmt::Formatter<'_>| write!(f, "{:.*}", precision, elt),
+                &fmt_opt,
+            ),
+            None => format_array(&self.view, f, <_>::fmt, &fmt_opt),
+        }
+    }
+}
+
 #[cfg(test)]
 mod formatting_with_omit {
     use itertools::Itertools;
@@ -17,8 +17,8 @@ use alloc::vec::Vec;
 use crate::imp_prelude::*;
 use crate::iter::{Iter, IterMut};
 use crate::NdIndex;
+use crate::{aview2, aview_mut2};
 
-use crate::numeric_util;
 use crate::{FoldWhile, Zip};
 
 #[cold]
@@ -95,9 +95,13 @@ where
         if self.shape() != rhs.shape() {
             return false;
         }
-        if let Some(self_s) = self.as_slice() {
-            if let Some(rhs_s) = rhs.as_slice() {
-                return numeric_util::unrolled_eq(self_s, rhs_s);
+        // Compare contiguous arrays with the same memory layout as slices,
+        // which uses memcmp for elements that can be compared bytewise
+        if self.dim.strides_equivalent(&self.strides, &rhs.strides) {
+            if let Some(self_s) = self.as_slice_memory_order() {
+                if let Some(rhs_s) = rhs.as_slice_memory_order() {
+                    return self_s == rhs_s;
+                }
             }
         }
         Zip::from(self)
@@ -323,6 +327,18 @@ where
     }
 }
 
+/// Implementation of `ArrayView::from(&S)` where `S` is a slice of arrays.
+impl<'a, A, const N: usize> From<&'a [[A; N]]> for ArrayView<'a, A, Ix2> {
+    /// Create a two-dimensional read-only array view of the data in `xs`,
+    /// with one row per element of `xs` and `N` columns.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// (This can only occur if `A` is zero-sized or if `N` is zero.)
+    fn from(xs: &'a [[A; N]]) -> Self {
+        aview2(xs)
+    }
+}
+
 /// Implementation of `ArrayView::from(&A)` where `A` is an array.
 impl<'a, A, S, D> From<&'a ArrayBase<S, D>> for ArrayView<'a, A, D>
 where
@@ -355,6 +371,18 @@ where
     }
 }
 
+/// Implementation of `ArrayViewMut::from(&mut S)` where `S` is a slice of arrays.
+impl<'a, A, const N: usize> From<&'a mut [[A; N]]> for ArrayViewMut<'a, A, Ix2> {
+    /// Create a two-dimensional read-write array view of the data in `xs`,
+    /// with one row per element of `xs` and `N` columns.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    /// (This can only occur if `A` is zero-sized or if `N` is zero.)
+    fn from(xs: &'a mut [[A; N]]) -> Self {
+        aview_mut2(xs)
+    }
+}
+
 /// Implementation of `ArrayViewMut::from(&mut A)` where `A` is an array.
 impl<'a, A, S, D> From<&'a mut ArrayBase<S, D>> for ArrayViewMut<'a, A, D>
 where
@@ -0,0 +1,259 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between arrays and [Apache Arrow](https://arrow.apache.org/)
+//! arrays from the `arrow-array` crate.
+//!
+//! One-dimensional arrays correspond to Arrow primitive arrays, and
+//! two-dimensional arrays to fixed-size list arrays of primitives, where
+//! each list is a row of the array.
+//!
+//! - [`ArrayView1::from_arrow`] and
+//!   [`ArrayView2::from_arrow_fixed_size_list`] borrow the Arrow buffers
+//!   without copying. Arrow buffers are always aligned for their element
+//!   type, so this never fails because of the layout.
+//! - [`Array1::from_arrow`] takes the buffer of a primitive array without
+//!   copying if it isn't shared, and copies the elements otherwise.
+//! - [`.into_arrow()`](Array1::into_arrow) and
+//!   [`.into_arrow_fixed_size_list()`](Array2::into_arrow_fixed_size_list)
+//!   reuse the allocation of an array in standard layout, and copy the
+//!   elements otherwise.
+//!
+//! Arrow arrays with null elements can't be converted.
+//!
+//! This requires crate feature `arrow`.
+//!
+//! ```
+//! use arrow_array::Float64Array;
+//! use ndarray::{array, ArrayView1};
+//!
+//! let column = Float64Array::from(vec![1., 2., 3.]);
+//! let view = ArrayView1::from_arrow(&column).unwrap();
+//! assert_eq!(view.sum(), 6.);
+//!
+//! let a = array![1., 2., 3.] * 2.;
+//! let column: Float64Array = a.into_arrow();
+//! assert_eq!(column.values(), &[2., 4., 6.]);
+//! ```
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use alloc::vec::Vec;
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::Array as ArrowArray;
+use arrow_array::{FixedSizeListArray, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, ScalarBuffer};
+use arrow_schema::{DataType, Field};
+
+use crate::imp_prelude::*;
+
+/// An error converting an Arrow array.
+///
+/// This requires crate feature `arrow`.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum FromArrowError {
+    /// The Arrow array has null elements.
+    Nulls,
+    /// The values of the Arrow array don't have the expected data type.
+    DataType {
+        /// The data type of the requested element type.
+        expected: DataType,
+        /// The data type of the Arrow array.
+        found: DataType,
+    },
+}
+
+impl fmt::Display for FromArrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromArrowError::Nulls => write!(f, "FromArrowError: the array has null elements"),
+            FromArrowError::DataType { expected, found } => write!(
+                f,
+                "FromArrowError: expected data type {}, found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl Error for FromArrowError {}
+
+fn check_no_nulls(array: &dyn ArrowArray) -> Result<(), FromArrowError> {
+    if array.null_count() > 0 {
+        Err(FromArrowError::Nulls)
+    } else {
+        Ok(())
+    }
+}
+
+impl<'a, A: ArrowNativeType> ArrayView1<'a, A> {
+    /// Create a view of the values of the Arrow primitive array `array`,
+    /// without copying.
+    ///
+    /// **Errors** if `array` has null elements.
+    ///
+    /// This requires crate feature `arrow`.
+    pub fn from_arrow<T>(array: &'a PrimitiveArray<T>) -> Result<Self, FromArrowError>
+    where
+        T: ArrowPrimitiveType<Native = A>,
+    {
+        check_no_nulls(array)?;
+        let values: &'a [A] = array.values();
+        Ok(ArrayView1::from(values))
+    }
+}
+
+impl<'a, A: ArrowNativeType> ArrayView2<'a, A> {
+    /// Create a view of the Arrow fixed-size list array `array`, with one
+    /// row for each list, without copying.
+    ///
+    /// The values of the lists must be an Arrow primitive array of type `T`.
+    ///
+    /// **Errors** if the lists or their values have null elements, or if the
+    /// values are not a primitive array of type `T`.
+    ///
+    /// This requires crate feature `arrow`.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow_array::types::Int32Type;
+    /// use arrow_array::{FixedSizeListArray, Int32Array};
+    /// use arrow_schema::{DataType, Field};
+    /// use ndarray::{array, ArrayView2};
+    ///
+    /// let field = Arc::new(Field::new("item", DataType::Int32, false));
+    /// let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5, 6]));
+    /// let lists = FixedSizeListArray::new(field, 3, values, None);
+    /// let view = ArrayView2::from_arrow_fixed_size_list::<Int32Type>(&lists).unwrap();
+    /// assert_eq!(view, array![[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn from_arrow_fixed_size_list<T>(
+        array: &'a FixedSizeListArray,
+    ) -> Result<Self, FromArrowError>
+    where
+        T: ArrowPrimitiveType<Native = A>,
+    {
+        check_no_nulls(array)?;
+        let values = array.values();
+        let values = match values.as_any().downcast_ref::<PrimitiveArray<T>>() {
+            Some(values) => values,
+            None => {
+                return Err(FromArrowError::DataType {
+                    expected: T::DATA_TYPE,
+                    found: values.data_type().clone(),
+                })
+            }
+        };
+        check_no_nulls(values)?;
+        let shape = (array.len(), array.value_length() as usize);
+        // The values of a fixed-size list array always have this length
+        Ok(ArrayView2::from_shape(shape, &values.values()[..]).unwrap())
+    }
+}
+
+impl<A: ArrowNativeType> Array1<A> {
+    /// Convert the Arrow primitive array `array` to an array.
+    ///
+    /// The buffer of `array` is reused if it isn't shared with other Arrow
+    /// arrays; otherwise the elements are copied.
+    ///
+    /// **Errors** if `array` has null elements.
+    ///
+    /// This requires crate feature `arrow`.
+    ///
+    /// ```
+    /// use arrow_array::Int64Array;
+    /// use ndarray::{array, Array1};
+    ///
+    /// let a = Array1::from_arrow(Int64Array::from(vec![1, 2, 3])).unwrap();
+    /// assert_eq!(a, array![1, 2, 3]);
+    /// ```
+    pub fn from_arrow<T>(array: PrimitiveArray<T>) -> Result<Self, FromArrowError>
+    where
+        T: ArrowPrimitiveType<Native = A>,
+    {
+        check_no_nulls(&array)?;
+        let (_, values, _) = array.into_parts();
+        let len = values.len();
+        let v = match values.into_inner().into_vec::<A>() {
+            Ok(mut v) => {
+                v.truncate(len);
+                v
+            }
+            Err(buffer) => ScalarBuffer::<A>::new(buffer, 0, len).to_vec(),
+        };
+        Ok(Array1::from(v))
+    }
+
+    /// Convert the array to an Arrow primitive array.
+    ///
+    /// The allocation of the array is reused if it is in standard layout and
+    /// uses all of its allocation; otherwise the elements are copied.
+    ///
+    /// This requires crate feature `arrow`.
+    pub fn into_arrow<T>(self) -> PrimitiveArray<T>
+    where
+        T: ArrowPrimitiveType<Native = A>,
+    {
+        PrimitiveArray::new(ScalarBuffer::from(into_logical_vec(self)), None)
+    }
+}
+
+impl<A: ArrowNativeType> Array2<A> {
+    /// Convert the array to an Arrow fixed-size list array, with one list
+    /// for each row.
+    ///
+    /// The allocation of the array is reused if it is in standard layout and
+    /// uses all of its allocation; otherwise the elements are copied.
+    ///
+    /// **Panics** if the number of columns doesn't fit in `i32`.
+    ///
+    /// This requires crate feature `arrow`.
+    ///
+    /// ```
+    /// use arrow_array::types::Float32Type;
+    /// use ndarray::{array, ArrayView2};
+    ///
+    /// let a = array![[1f32, 2.], [3., 4.], [5., 6.]];
+    /// let lists = a.clone().into_arrow_fixed_size_list::<Float32Type>();
+    /// assert_eq!(lists.value_length(), 2);
+    /// let view = ArrayView2::from_arrow_fixed_size_list::<Float32Type>(&lists).unwrap();
+    /// assert_eq!(view, a);
+    /// ```
+    pub fn into_arrow_fixed_size_list<T>(self) -> FixedSizeListArray
+    where
+        T: ArrowPrimitiveType<Native = A>,
+    {
+        let ncols = self.ncols();
+        let size = i32::try_from(ncols).expect("number of columns must fit in i32");
+        let values = Array1::from(into_logical_vec(self)).into_arrow::<T>();
+        let field = Arc::new(Field::new("item", T::DATA_TYPE, false));
+        FixedSizeListArray::new(field, size, Arc::new(values), None)
+    }
+}
+
+/// Return the elements of `array` in logical order, reusing the allocation
+/// if possible.
+fn into_logical_vec<A, D>(array: Array<A, D>) -> Vec<A>
+where
+    A: Clone,
+    D: Dimension,
+{
+    if array.is_standard_layout()
+        && array.as_ptr() == array.data.as_ptr()
+        && array.len() == array.data.len()
+    {
+        array.into_raw_vec()
+    } else {
+        array.iter().cloned().collect()
+    }
+}
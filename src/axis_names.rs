@@ -0,0 +1,264 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::ops::{Add, Deref, Div};
+
+use num_traits::{FromPrimitive, Zero};
+
+use crate::imp_prelude::*;
+use crate::{IxDyn, OwnedRepr, Slice};
+
+/// A dynamic-dimensional array whose axes have names, so that the axis of an
+/// operation can be given by its name.
+///
+/// The names follow the axes through the operations of `Named`: the axis
+/// removed by a reduction or an index loses its name, and transposing
+/// permutes the names with the axes. A named array dereferences to the array
+/// itself for all other methods, except those that modify it, which are
+/// available through [`.view_mut()`](Self::view_mut).
+///
+/// Create a named array with
+/// [`.with_axis_names()`](ArrayBase::with_axis_names). For names that are
+/// checked at compile time, see [`Tagged`](crate::Tagged).
+///
+/// ```
+/// use ndarray::{ArrayD, IxDyn, Slice};
+///
+/// let a = ArrayD::from_shape_fn(IxDyn(&[2, 3, 4]), |ix| ix[1] as f64)
+///     .with_axis_names(&["batch", "time", "feature"]);
+///
+/// let totals = a.sum_axis_by_name("time");
+/// assert_eq!(totals.names(), ["batch", "feature"]);
+/// assert_eq!(totals[[0, 0]], 3.);
+///
+/// let late = a.slice_axis_by_name("time", Slice::from(1..));
+/// assert_eq!(late.len_of_name("time"), 2);
+///
+/// let t = a.t();
+/// assert_eq!(t.names(), ["feature", "time", "batch"]);
+/// assert_eq!(t.sum_axis_by_name("time").into_inner(), totals.t().into_inner());
+/// ```
+pub struct Named<S>
+where
+    S: RawData,
+{
+    array: ArrayBase<S, IxDyn>,
+    names: Vec<String>,
+}
+
+impl<S> ArrayBase<S, IxDyn>
+where
+    S: RawData,
+{
+    /// Name the axes of the array, in order.
+    ///
+    /// The names can be given as a slice or vector of string slices or
+    /// strings, for example `&["batch", "time"]`.
+    ///
+    /// **Panics** if the number of names is not the number of axes, or if a
+    /// name is repeated.
+    pub fn with_axis_names<I>(self, names: I) -> Named<S>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let names: Vec<String> = names.into_iter().map(|name| name.as_ref().into()).collect();
+        assert_eq!(
+            names.len(),
+            self.ndim(),
+            "with_axis_names: the array must have one axis for each name"
+        );
+        for (i, name) in names.iter().enumerate() {
+            assert!(
+                !names[..i].contains(name),
+                "with_axis_names: the axis name {:?} is repeated",
+                name
+            );
+        }
+        Named { array: self, names }
+    }
+}
+
+impl<A, S> Named<S>
+where
+    S: RawData<Elem = A>,
+{
+    /// Return the names of the axes, in order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Return the axis named `name`, or `None` if there is none.
+    pub fn axis_by_name(&self, name: &str) -> Option<Axis> {
+        self.names.iter().position(|n| n == name).map(Axis)
+    }
+
+    /// Return the axis named `name`, for the method `method`.
+    fn named_axis(&self, method: &str, name: &str) -> Axis {
+        match self.axis_by_name(name) {
+            Some(axis) => axis,
+            None => panic!("{}: there is no axis named {:?} in {:?}", method, name, self.names),
+        }
+    }
+
+    /// Return the names without the name of `axis`.
+    fn names_without(&self, axis: Axis) -> Vec<String> {
+        let mut names = self.names.clone();
+        names.remove(axis.index());
+        names
+    }
+
+    /// Return the length of the axis named `name`.
+    ///
+    /// **Panics** if there is no axis named `name`.
+    pub fn len_of_name(&self, name: &str) -> usize {
+        self.array.len_of(self.named_axis("len_of_name", name))
+    }
+
+    /// Return the sum along the axis named `name`, like
+    /// [`.sum_axis()`](ArrayBase::sum_axis), with the names of the other
+    /// axes.
+    ///
+    /// **Panics** if there is no axis named `name`.
+    pub fn sum_axis_by_name(&self, name: &str) -> Named<OwnedRepr<A>>
+    where
+        S: Data,
+        A: Clone + Zero + Add<Output = A>,
+    {
+        let axis = self.named_axis("sum_axis_by_name", name);
+        Named {
+            array: self.array.sum_axis(axis),
+            names: self.names_without(axis),
+        }
+    }
+
+    /// Return the mean along the axis named `name`, like
+    /// [`.mean_axis()`](ArrayBase::mean_axis), with the names of the other
+    /// axes.
+    ///
+    /// **Panics** if there is no axis named `name`.
+    pub fn mean_axis_by_name(&self, name: &str) -> Option<Named<OwnedRepr<A>>>
+    where
+        S: Data,
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+    {
+        let axis = self.named_axis("mean_axis_by_name", name);
+        Some(Named {
+            array: self.array.mean_axis(axis)?,
+            names: self.names_without(axis),
+        })
+    }
+
+    /// Return a view of the subarray at `index` along the axis named
+    /// `name`, like [`.index_axis()`](ArrayBase::index_axis), with the names
+    /// of the other axes.
+    ///
+    /// **Panics** if there is no axis named `name`, or if `index` is out of
+    /// bounds.
+    pub fn index_axis_by_name(&self, name: &str, index: usize) -> Named<ViewRepr<&'_ A>>
+    where
+        S: Data,
+    {
+        let axis = self.named_axis("index_axis_by_name", name);
+        Named {
+            array: self.array.index_axis(axis, index),
+            names: self.names_without(axis),
+        }
+    }
+
+    /// Return a view of the array sliced along the axis named `name`, like
+    /// [`.slice_axis()`](ArrayBase::slice_axis).
+    ///
+    /// **Panics** if there is no axis named `name`, or if the slice is out
+    /// of bounds.
+    pub fn slice_axis_by_name(&self, name: &str, indices: Slice) -> Named<ViewRepr<&'_ A>>
+    where
+        S: Data,
+    {
+        let axis = self.named_axis("slice_axis_by_name", name);
+        Named {
+            array: self.array.slice_axis(axis, indices),
+            names: self.names.clone(),
+        }
+    }
+
+    /// Return a view of the array with its names.
+    pub fn view(&self) -> Named<ViewRepr<&'_ A>>
+    where
+        S: Data,
+    {
+        Named {
+            array: self.array.view(),
+            names: self.names.clone(),
+        }
+    }
+
+    /// Return a mutable view of the array, to modify its elements.
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, A, IxDyn>
+    where
+        S: DataMut,
+    {
+        self.array.view_mut()
+    }
+
+    /// Return a transposed view of the array, with the names reversed.
+    pub fn t(&self) -> Named<ViewRepr<&'_ A>>
+    where
+        S: Data,
+    {
+        self.view().reversed_axes()
+    }
+
+    /// Reverse the order of the axes and their names, like
+    /// [`.reversed_axes()`](ArrayBase::reversed_axes).
+    pub fn reversed_axes(mut self) -> Self {
+        self.names.reverse();
+        Named {
+            array: self.array.reversed_axes(),
+            names: self.names,
+        }
+    }
+
+    /// Permute the axes so that they are in the order of `names`, like
+    /// [`.permuted_axes()`](ArrayBase::permuted_axes).
+    ///
+    /// **Panics** if `names` are not the names of the axes, in any order.
+    pub fn permuted_axes_by_name(self, names: &[&str]) -> Self {
+        assert_eq!(
+            names.len(),
+            self.ndim(),
+            "permuted_axes_by_name: there must be one name for each axis"
+        );
+        let axes: Vec<usize> = names
+            .iter()
+            .map(|name| self.named_axis("permuted_axes_by_name", name).index())
+            .collect();
+        Named {
+            array: self.array.permuted_axes(axes),
+            names: names.iter().map(|&name| name.into()).collect(),
+        }
+    }
+
+    /// Return the array, without the names.
+    pub fn into_inner(self) -> ArrayBase<S, IxDyn> {
+        self.array
+    }
+}
+
+impl<S> Deref for Named<S>
+where
+    S: RawData,
+{
+    type Target = ArrayBase<S, IxDyn>;
+
+    fn deref(&self) -> &ArrayBase<S, IxDyn> {
+        &self.array
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+use std::ops::{Add, Deref, Div};
+
+use num_traits::{FromPrimitive, Zero};
+
+use crate::imp_prelude::*;
+
+/// A list of axis tags: the meaning of each axis of a [`Tagged`] array.
+///
+/// Implement this trait, and [`HasAxis`] for each tag, with the
+/// [`axis_tags!`](crate::axis_tags) macro.
+pub trait AxisTags {
+    /// The number of axes
+    const NDIM: usize;
+}
+
+/// The tag `T` is in the list of axis tags, at axis `AXIS`.
+///
+/// Implement this trait with the [`axis_tags!`](crate::axis_tags) macro.
+pub trait HasAxis<T>: AxisTags {
+    /// The axis of the tag
+    const AXIS: usize;
+}
+
+/// Define a list of axis tags, whose axes can be named by their tag in the
+/// methods of [`Tagged`] arrays.
+///
+/// `axis_tags!(struct Name[Tag0, Tag1, ...]);` defines the unit struct
+/// `Name`, with the attributes and visibility given, and implements
+/// [`AxisTags`] for it, and [`HasAxis<TagN>`](HasAxis) with axis `N` for
+/// each tag. The tags are any types, usually unit structs like
+/// `struct Time;`, and each can appear only once in the list.
+///
+/// ```
+/// use ndarray::{axis_tags, Array3};
+///
+/// struct Batch;
+/// struct Time;
+/// struct Channel;
+///
+/// axis_tags!(struct Recording[Batch, Time, Channel]);
+///
+/// let signals = Array3::<f64>::ones((2, 100, 4)).into_tagged::<Recording>();
+/// assert_eq!(signals.len_of_tag::<Time>(), 100);
+///
+/// // Average over time, for each recording and channel
+/// let levels = signals.mean_axis_tag::<Time>().unwrap();
+/// assert_eq!(levels.shape(), &[2, 4]);
+/// ```
+///
+/// Naming an axis with a tag that is not in the list doesn't compile:
+///
+/// ```compile_fail
+/// use ndarray::{axis_tags, Array2};
+///
+/// struct Time;
+/// struct Channel;
+/// struct Batch;
+///
+/// axis_tags!(struct Frames[Time, Channel]);
+///
+/// let frames = Array2::<f64>::zeros((10, 3)).into_tagged::<Frames>();
+/// frames.sum_axis_tag::<Batch>();
+/// ```
+#[macro_export]
+macro_rules! axis_tags {
+    (@count) => { 0usize };
+    (@count $head:tt $($rest:tt)*) => { 1usize + $crate::axis_tags!(@count $($rest)*) };
+    (@impl $name:ident, $axis:expr $(,)?) => {};
+    (@impl $name:ident, $axis:expr, $tag:ty $(, $rest:ty)*) => {
+        impl $crate::HasAxis<$tag> for $name {
+            const AXIS: usize = $axis;
+        }
+        $crate::axis_tags!(@impl $name, $axis + 1 $(, $rest)*);
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident [$($tag:ty),* $(,)?] $(;)?) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug)]
+        $vis struct $name;
+
+        impl $crate::AxisTags for $name {
+            const NDIM: usize = $crate::axis_tags!(@count $($tag)*);
+        }
+
+        $crate::axis_tags!(@impl $name, 0usize $(, $tag)*);
+    };
+}
+
+/// An array whose axes are named by the tags of `L`, so that the axis of
+/// an operation can be given by its tag instead of its number.
+///
+/// Operations with a tag that is not in `L` don't compile, which catches
+/// operations on the wrong axis. A tagged array dereferences to the array
+/// itself for all other methods, except those that modify it, which are
+/// available through [`.view_mut()`](Self::view_mut). The results of its
+/// operations are plain arrays.
+///
+/// Create a tagged array with [`.into_tagged()`](ArrayBase::into_tagged)
+/// or [`.tagged()`](ArrayBase::tagged), and define the tags with
+/// [`axis_tags!`](crate::axis_tags).
+pub struct Tagged<S, D, L>
+where
+    S: RawData,
+{
+    array: ArrayBase<S, D>,
+    tags: PhantomData<L>,
+}
+
+impl<S, D> ArrayBase<S, D>
+where
+    S: RawData,
+    D: Dimension,
+{
+    /// Name the axes of the array by the tags of `L`.
+    ///
+    /// **Panics** if the array doesn't have `L::NDIM` axes.
+    pub fn into_tagged<L: AxisTags>(self) -> Tagged<S, D, L> {
+        assert_eq!(
+            self.ndim(),
+            L::NDIM,
+            "into_tagged: the array must have one axis for each tag"
+        );
+        Tagged {
+            array: self,
+            tags: PhantomData,
+        }
+    }
+
+    /// Return a view of the array with the axes named by the tags of `L`.
+    ///
+    /// **Panics** if the array doesn't have `L::NDIM` axes.
+    pub fn tagged<L: AxisTags>(&self) -> Tagged<ViewRepr<&'_ S::Elem>, D, L>
+    where
+        S: Data,
+    {
+        self.view().into_tagged()
+    }
+}
+
+impl<A, S, D, L> Tagged<S, D, L>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+    L: AxisTags,
+{
+    /// Return the axis of tag `T`.
+    pub fn axis<T>(&self) -> Axis
+    where
+        L: HasAxis<T>,
+    {
+        Axis(<L as HasAxis<T>>::AXIS)
+    }
+
+    /// Return the length of the axis of tag `T`.
+    pub fn len_of_tag<T>(&self) -> usize
+    where
+        L: HasAxis<T>,
+    {
+        self.array.len_of(self.axis::<T>())
+    }
+
+    /// Return the sum along the axis of tag `T`, like
+    /// [`.sum_axis()`](ArrayBase::sum_axis).
+    pub fn sum_axis_tag<T>(&self) -> Array<A, D::Smaller>
+    where
+        L: HasAxis<T>,
+        S: Data,
+        A: Clone + Zero + Add<Output = A>,
+        D: RemoveAxis,
+    {
+        self.array.sum_axis(self.axis::<T>())
+    }
+
+    /// Return the mean along the axis of tag `T`, like
+    /// [`.mean_axis()`](ArrayBase::mean_axis).
+    pub fn mean_axis_tag<T>(&self) -> Option<Array<A, D::Smaller>>
+    where
+        L: HasAxis<T>,
+        S: Data,
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+        D: RemoveAxis,
+    {
+        self.array.mean_axis(self.axis::<T>())
+    }
+
+    /// Return a view of the subarray at `index` along the axis of tag `T`,
+    /// like [`.index_axis()`](ArrayBase::index_axis).
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn index_axis_tag<T>(&self, index: usize) -> ArrayView<'_, A, D::Smaller>
+    where
+        L: HasAxis<T>,
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.array.index_axis(self.axis::<T>(), index)
+    }
+
+    /// Return a mutable view of the subarray at `index` along the axis of
+    /// tag `T`, like [`.index_axis_mut()`](ArrayBase::index_axis_mut).
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn index_axis_tag_mut<T>(&mut self, index: usize) -> ArrayViewMut<'_, A, D::Smaller>
+    where
+        L: HasAxis<T>,
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        let axis = self.axis::<T>();
+        self.array.index_axis_mut(axis, index)
+    }
+
+    /// Return a mutable view of the array, to modify its elements.
+    ///
+    /// The tagged array dereferences only to a shared reference, so that
+    /// its axes can't be permuted or changed by mistake.
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, A, D>
+    where
+        S: DataMut,
+    {
+        self.array.view_mut()
+    }
+
+    /// Return the array, without the tags.
+    pub fn into_inner(self) -> ArrayBase<S, D> {
+        self.array
+    }
+}
+
+impl<S, D, L> Deref for Tagged<S, D, L>
+where
+    S: RawData,
+{
+    type Target = ArrayBase<S, D>;
+
+    fn deref(&self) -> &ArrayBase<S, D> {
+        &self.array
+    }
+}
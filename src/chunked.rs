@@ -0,0 +1,92 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+
+/// Map-reduce over a sequence of chunks, one chunk at a time.
+///
+/// Each chunk is passed to `map` together with a scratch value, which is
+/// created once with `init()` and reused for all chunks; use it for buffers
+/// that would otherwise be allocated for every chunk. The results are combined
+/// in order with `reduce`, which should be associative.
+///
+/// The chunks can be anything, for example arrays read one at a time from
+/// files or memory mapped views of parts of a larger array; only one chunk is
+/// alive at a time. Returns `None` if there are no chunks.
+///
+/// See also [`ArrayBase::process_chunks`] for chunks of an array, and
+/// `par_process_chunks` in the [`parallel`](crate::parallel) module for the
+/// parallel version.
+///
+/// ```
+/// use ndarray::{process_chunks, Array1};
+///
+/// // Sum of squares of chunks that are created on demand
+/// let chunks = (0..10).map(|i| Array1::from_elem(100, i as f64));
+/// let total = process_chunks(
+///     chunks,
+///     || Array1::zeros(100),
+///     |scratch: &mut Array1<f64>, chunk| {
+///         scratch.assign(&chunk);
+///         scratch.mapv_inplace(|x| x * x);
+///         scratch.sum()
+///     },
+///     |a, b| a + b,
+/// );
+/// assert_eq!(total, Some(28500.));
+/// ```
+pub fn process_chunks<I, B, T, F, M, R>(chunks: I, init: F, mut map: M, reduce: R) -> Option<T>
+where
+    I: IntoIterator,
+    F: FnOnce() -> B,
+    M: FnMut(&mut B, I::Item) -> T,
+    R: FnMut(T, T) -> T,
+{
+    let mut scratch = init();
+    chunks.into_iter().map(|chunk| map(&mut scratch, chunk)).reduce(reduce)
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Map-reduce over the array in chunks of `chunk_len` along `axis`.
+    ///
+    /// The chunks are the views of [`.axis_chunks_iter()`](Self::axis_chunks_iter);
+    /// see [`process_chunks`](crate::process_chunks) for the scratch value
+    /// and the reduction. Returns `None` if the array has length zero along
+    /// `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `chunk_len` is zero.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((1000, 3), |(i, j)| (i * 3 + j) as u64);
+    /// let max_row_sum = a.process_chunks(Axis(0), 64, || (), |_, chunk| {
+    ///     chunk.sum_axis(Axis(1)).fold(0, |m, &x| m.max(x))
+    /// }, u64::max);
+    /// assert_eq!(max_row_sum, Some(999 * 9 + 3));
+    /// ```
+    pub fn process_chunks<B, T, F, M, R>(
+        &self,
+        axis: Axis,
+        chunk_len: usize,
+        init: F,
+        map: M,
+        reduce: R,
+    ) -> Option<T>
+    where
+        F: FnOnce() -> B,
+        M: FnMut(&mut B, ArrayView<'_, A, D>) -> T,
+        R: FnMut(T, T) -> T,
+    {
+        process_chunks(self.axis_chunks_iter(axis, chunk_len), init, map, reduce)
+    }
+}
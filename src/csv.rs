@@ -0,0 +1,288 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reading and writing two-dimensional arrays as CSV and other delimited
+//! text.
+//!
+//! Each record (line) of the text is a row of the array, and each field is
+//! an element. Use [`Array2::from_csv_reader`] to read and
+//! [`.write_csv()`](ArrayBase::write_csv) to write; both take [`CsvOptions`]
+//! for the delimiter and the header row.
+//!
+//! This requires crate feature `csv`.
+//!
+//! ```
+//! use ndarray::{array, Array2};
+//! use ndarray::csv::CsvOptions;
+//!
+//! let text = "x;y\n1.5;2\n3;4.25\n";
+//! let options = CsvOptions::new().delimiter(b';').has_header(true);
+//! let a = Array2::<f64>::from_csv_reader(text.as_bytes(), &options).unwrap();
+//! assert_eq!(a, array![[1.5, 2.], [3., 4.25]]);
+//!
+//! let mut out = Vec::new();
+//! a.write_csv(&mut out, &CsvOptions::new().header(&["x", "y"])).unwrap();
+//! assert_eq!(String::from_utf8(out).unwrap(), "x,y\n1.5,2\n3,4.25\n");
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use csv_::{ReaderBuilder, Trim, WriterBuilder};
+
+use crate::imp_prelude::*;
+use crate::ShapeError;
+
+/// Options for reading and writing CSV.
+///
+/// The default is comma separated fields without a header row.
+///
+/// This requires crate feature `csv`.
+#[derive(Clone, Debug)]
+pub struct CsvOptions {
+    delimiter: u8,
+    has_header: bool,
+    header: Option<Vec<String>>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: b',',
+            has_header: false,
+            header: None,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Create the default options: comma separated fields without a header
+    /// row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the field delimiter, for example `b'\t'` for tab separated values.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set whether the first record is a header row.
+    ///
+    /// When reading, the header row is skipped. When writing, this has no
+    /// effect unless the column names are set with
+    /// [`.header()`](Self::header).
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Set the column names to write as the header row.
+    ///
+    /// This also sets [`.has_header(true)`](Self::has_header).
+    pub fn header<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.has_header = true;
+        self.header = Some(names.into_iter().map(|name| name.to_string()).collect());
+        self
+    }
+}
+
+/// An error from reading or writing CSV.
+///
+/// Rows and columns are counted from zero; the header row is not counted.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum CsvError {
+    /// An I/O error.
+    Io(io::Error),
+    /// The text is not valid CSV, for example because it's not valid UTF-8.
+    Format(String),
+    /// A field could not be parsed as an element.
+    Parse {
+        /// The row of the field
+        row: usize,
+        /// The column of the field
+        column: usize,
+        /// The text of the field
+        field: String,
+        /// The error message from parsing
+        message: String,
+    },
+    /// A row has a different number of fields than the first row.
+    RowLength {
+        /// The row with the wrong number of fields
+        row: usize,
+        /// The number of fields in the first row
+        expected: usize,
+        /// The number of fields in this row
+        found: usize,
+    },
+    /// The number of column names is not the number of columns.
+    HeaderLength {
+        /// The number of columns of the array
+        expected: usize,
+        /// The number of column names
+        found: usize,
+    },
+    /// The array could not be created from the data.
+    Shape(ShapeError),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "CsvError: {}", err),
+            CsvError::Format(msg) => write!(f, "CsvError: {}", msg),
+            CsvError::Parse { row, column, field, message } => write!(
+                f,
+                "CsvError: can't parse {:?} at row {}, column {}: {}",
+                field, row, column, message
+            ),
+            CsvError::RowLength { row, expected, found } => write!(
+                f,
+                "CsvError: row {} has {} fields, expected {}",
+                row, found, expected
+            ),
+            CsvError::HeaderLength { expected, found } => write!(
+                f,
+                "CsvError: header has {} names, expected {}",
+                found, expected
+            ),
+            CsvError::Shape(err) => write!(f, "CsvError: {}", err),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            CsvError::Shape(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+impl From<ShapeError> for CsvError {
+    fn from(err: ShapeError) -> Self {
+        CsvError::Shape(err)
+    }
+}
+
+fn from_csv_error(err: csv_::Error) -> CsvError {
+    let msg = err.to_string();
+    match err.into_kind() {
+        csv_::ErrorKind::Io(err) => CsvError::Io(err),
+        _ => CsvError::Format(msg),
+    }
+}
+
+impl<A> Array2<A> {
+    /// Read a two-dimensional array from CSV text.
+    ///
+    /// Each record is a row of the array, and each field is parsed with
+    /// `A::from_str` after trimming surrounding whitespace. Empty input gives
+    /// an array of shape *0 × 0*.
+    ///
+    /// **Errors** if reading fails, if the rows have different lengths or if
+    /// a field can't be parsed; the error gives the row and column.
+    ///
+    /// This requires crate feature `csv`. See the [`csv`](crate::csv) module
+    /// for an example.
+    pub fn from_csv_reader<R>(reader: R, options: &CsvOptions) -> Result<Self, CsvError>
+    where
+        R: Read,
+        A: FromStr,
+        A::Err: Display,
+    {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(options.delimiter)
+            .has_headers(options.has_header)
+            .flexible(true)
+            .trim(Trim::All)
+            .from_reader(reader);
+        let mut data = Vec::new();
+        let mut ncols = None;
+        let mut nrows = 0;
+        for (row, record) in reader.records().enumerate() {
+            let record = record.map_err(from_csv_error)?;
+            let expected = *ncols.get_or_insert(record.len());
+            if record.len() != expected {
+                return Err(CsvError::RowLength { row, expected, found: record.len() });
+            }
+            for (column, field) in record.iter().enumerate() {
+                let elt = field.parse().map_err(|err: A::Err| CsvError::Parse {
+                    row,
+                    column,
+                    field: field.to_string(),
+                    message: err.to_string(),
+                })?;
+                data.push(elt);
+            }
+            nrows += 1;
+        }
+        Ok(Array2::from_shape_vec((nrows, ncols.unwrap_or(0)), data)?)
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Write the array as CSV text.
+    ///
+    /// Each row of the array is written as a record, with the elements
+    /// formatted with `Display`. If column names are set in the options, they
+    /// are written first as the header row.
+    ///
+    /// **Errors** if writing fails or if the number of column names is not
+    /// the number of columns.
+    ///
+    /// This requires crate feature `csv`. See the [`csv`](crate::csv) module
+    /// for an example.
+    pub fn write_csv<W>(&self, writer: W, options: &CsvOptions) -> Result<(), CsvError>
+    where
+        W: Write,
+        A: Display,
+    {
+        let mut writer = WriterBuilder::new()
+            .delimiter(options.delimiter)
+            .from_writer(writer);
+        if let Some(header) = &options.header {
+            if header.len() != self.ncols() {
+                return Err(CsvError::HeaderLength { expected: self.ncols(), found: header.len() });
+            }
+            writer.write_record(header).map_err(from_csv_error)?;
+        }
+        let mut fields = Vec::with_capacity(self.ncols());
+        for row in self.rows() {
+            fields.clear();
+            fields.extend(row.iter().map(|elt| elt.to_string()));
+            writer.write_record(&fields).map_err(from_csv_error)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
@@ -141,21 +141,6 @@ macro_rules! tuple_to_array {
             }
         }
 
-        impl Index<usize> for Dim<[Ix; $n]> {
-            type Output = usize;
-            #[inline(always)]
-            fn index(&self, index: usize) -> &Self::Output {
-                &self.ix()[index]
-            }
-        }
-
-        impl IndexMut<usize> for Dim<[Ix; $n]> {
-            #[inline(always)]
-            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                &mut self.ixm()[index]
-            }
-        }
-
         impl Zero for Dim<[Ix; $n]> {
             #[inline]
             fn zero() -> Self {
@@ -171,3 +156,18 @@ macro_rules! tuple_to_array {
 }
 
 index_item!(tuple_to_array [] 7);
+
+impl<const N: usize> Index<usize> for Dim<[Ix; N]> {
+    type Output = usize;
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.ix()[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Dim<[Ix; N]> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.ixm()[index]
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Return the Euclidean distance from each element of `mask` to the nearest
+/// `true` element, in units of the distance between neighboring elements.
+///
+/// The `true` elements have distance zero. If `mask` has no `true`
+/// elements, all distances are infinite.
+///
+/// The squared distances are computed exactly, with the algorithm of
+/// Felzenszwalb and Huttenlocher: one pass over the lanes along each axis in
+/// turn, finding the lower envelope of the parabolas centered on the
+/// elements of the lane. This takes time linear in the number of elements.
+///
+/// ```
+/// use ndarray::{array, distance_transform_edt};
+///
+/// let mask = array![[true, false, false],
+///                   [false, false, false],
+///                   [false, false, true]];
+/// let distances = distance_transform_edt(&mask);
+/// assert_eq!(distances, array![[0., 1., 2.],
+///                              [1., 2f64.sqrt(), 1.],
+///                              [2., 1., 0.]]);
+/// ```
+pub fn distance_transform_edt<S, D>(mask: &ArrayBase<S, D>) -> Array<f64, D>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let mut out = mask.map(|&m| if m { 0. } else { f64::INFINITY });
+    let max_len = mask.shape().iter().copied().max().unwrap_or(0);
+    let mut lane_buf = vec![0.; max_len];
+    let mut centers = vec![0; max_len];
+    let mut bounds = vec![0.; max_len];
+    for axis in 0..out.ndim() {
+        let len = out.len_of(Axis(axis));
+        let f = &mut lane_buf[..len];
+        let v = &mut centers[..len];
+        let z = &mut bounds[..len];
+        Zip::from(out.lanes_mut(Axis(axis))).for_each(|mut lane| {
+            for (x, y) in f.iter_mut().zip(&lane) {
+                *x = *y;
+            }
+            squared_distances_1d(f, v, z, |q, d| lane[q] = d);
+        });
+    }
+    out.mapv_inplace(f64::sqrt);
+    out
+}
+
+/// Compute the one-dimensional squared distance transform of the sampled
+/// function `f`: the minimum of `(q - p)² + f[p]` over all `p`, for each
+/// `q`, which is passed to `store`.
+///
+/// The parabolas of the lower envelope are stored in `v`, their centers,
+/// and `z`, the start of the range where each is the lowest; both must be
+/// as long as `f`.
+fn squared_distances_1d<F>(f: &[f64], v: &mut [usize], z: &mut [f64], mut store: F)
+where
+    F: FnMut(usize, f64),
+{
+    // The number of parabolas in the envelope; elements at infinity have
+    // no parabola.
+    let mut k = 0;
+    for (q, &fq) in f.iter().enumerate() {
+        if fq == f64::INFINITY {
+            continue;
+        }
+        let mut s = f64::NEG_INFINITY;
+        while k > 0 {
+            // The intersection with the last parabola of the envelope
+            let p = v[k - 1];
+            let (qf, pf) = (q as f64, p as f64);
+            s = ((fq + qf * qf) - (f[p] + pf * pf)) / (2. * (qf - pf));
+            if s <= z[k - 1] {
+                k -= 1;
+                s = f64::NEG_INFINITY;
+            } else {
+                break;
+            }
+        }
+        v[k] = q;
+        z[k] = s;
+        k += 1;
+    }
+    if k == 0 {
+        for q in 0..f.len() {
+            store(q, f64::INFINITY);
+        }
+        return;
+    }
+    let mut j = 0;
+    for q in 0..f.len() {
+        let qf = q as f64;
+        while j + 1 < k && z[j + 1] < qf {
+            j += 1;
+        }
+        let d = qf - v[j] as f64;
+        store(q, d * d + f[v[j]]);
+    }
+}
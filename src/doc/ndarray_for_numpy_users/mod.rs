@@ -483,7 +483,21 @@
 //!
 //! <tr><td>
 //!
-//! `np.allclose(a, b, atol=1e-8)`
+//! `np.allclose(a, b, rtol=1e-5, atol=1e-8)`
+//!
+//! </td><td>
+//!
+//! [`a.all_close(&b, 1e-5, 1e-8)`][.all_close()]
+//!
+//! </td><td>
+//!
+//! check if the elements of `a` are within a relative and absolute tolerance of those of `b`; [`assert_all_close!`][assert_all_close!] also reports the first element that isn't
+//!
+//! </td></tr>
+//!
+//! <tr><td>
+//!
+//! `np.allclose(a, b, rtol=0, atol=1e-8)`
 //!
 //! </td><td>
 //!
@@ -571,6 +585,8 @@
 //! `a.shape[0] == a.shape[1]` | [`a.is_square()`][.is_square()] | check if the array is square
 //!
 //! [.abs_diff_eq()]: ../../struct.ArrayBase.html#impl-AbsDiffEq<ArrayBase<S2%2C%20D>>
+//! [.all_close()]: ../../struct.ArrayBase.html#method.all_close
+//! [assert_all_close!]: ../../macro.assert_all_close.html
 //! [ArcArray]: ../../type.ArcArray.html
 //! [arr2()]: ../../fn.arr2.html
 //! [array!]: ../../macro.array.html
@@ -0,0 +1,171 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// The vector norm that measures the distance between two rows, in
+/// [`.near_duplicate_rows()`](ArrayBase::near_duplicate_rows).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Norm {
+    /// The sum of the absolute differences.
+    L1,
+    /// The Euclidean distance.
+    L2,
+    /// The largest absolute difference.
+    Max,
+}
+
+impl Norm {
+    fn distance<A: Float>(self, a: ArrayView1<'_, A>, b: ArrayView1<'_, A>) -> A {
+        let diffs = Zip::from(&a).and(&b);
+        match self {
+            Norm::L1 => diffs.fold(A::zero(), |acc, &x, &y| acc + (x - y).abs()),
+            Norm::L2 => diffs
+                .fold(A::zero(), |acc, &x, &y| acc + (x - y) * (x - y))
+                .sqrt(),
+            Norm::Max => diffs.fold(A::zero(), |acc, &x, &y| acc.max((x - y).abs())),
+        }
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the groups of row indices of rows that are equal.
+    ///
+    /// Only groups of at least two rows are returned. Each group is in
+    /// increasing order, and the groups are ordered by their first index.
+    /// The rows are compared by hashing them, in expected linear time.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2], [3, 4], [1, 2], [5, 6], [3, 4], [1, 2]];
+    /// assert_eq!(a.duplicate_rows(), vec![vec![0, 2, 5], vec![1, 4]]);
+    /// ```
+    pub fn duplicate_rows(&self) -> Vec<Vec<usize>>
+    where
+        A: Hash + Eq,
+    {
+        // The groups in order of their first row, and the group of each distinct row
+        let mut groups = Vec::<Vec<usize>>::new();
+        let mut group_of = HashMap::new();
+        for (i, row) in self.rows().into_iter().enumerate() {
+            let group = *group_of.entry(row).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(i);
+        }
+        groups.retain(|group| group.len() > 1);
+        groups
+    }
+
+    /// Return the groups of row indices of rows that are within `tolerance`
+    /// of each other, in the distance measured by `norm`.
+    ///
+    /// Rows are in the same group if they are connected by a chain of rows
+    /// that are each within `tolerance` of the next, so two rows in a group
+    /// can be further apart than `tolerance`. Only groups of at least two
+    /// rows are returned. Each group is in increasing order, and the groups
+    /// are ordered by their first index. Rows with a NaN element are not
+    /// near any row.
+    ///
+    /// The rows are sorted by their first element, and only the rows whose
+    /// first elements are within `tolerance` are compared, which is fast
+    /// unless many rows have nearly equal first elements.
+    ///
+    /// ```
+    /// use ndarray::{array, Norm};
+    ///
+    /// let a = array![[0., 0.], [5., 5.], [0.1, 0.], [5., 5.05], [0.3, 0.3]];
+    /// assert_eq!(a.near_duplicate_rows(0.2, Norm::Max), vec![vec![0, 2], vec![1, 3]]);
+    /// assert_eq!(a.near_duplicate_rows(0.4, Norm::L2), vec![vec![0, 2, 4], vec![1, 3]]);
+    /// ```
+    pub fn near_duplicate_rows(&self, tolerance: A, norm: Norm) -> Vec<Vec<usize>>
+    where
+        A: Float,
+    {
+        let nrows = self.nrows();
+        let key = |i: usize| self.get((i, 0)).copied().unwrap_or_else(A::zero);
+        let mut order = (0..nrows)
+            .filter(|&i| !self.row(i).iter().any(|x| x.is_nan()))
+            .collect::<Vec<_>>();
+        // There are no NaNs, so the comparison is a total order
+        order.sort_unstable_by(|&i, &j| key(i).partial_cmp(&key(j)).unwrap());
+
+        let mut components = DisjointSets::new(nrows);
+        for (k, &i) in order.iter().enumerate() {
+            for &j in &order[k + 1..] {
+                // The difference of the first elements is a lower bound of the
+                // distance in all norms
+                if key(j) - key(i) > tolerance {
+                    break;
+                }
+                if norm.distance(self.row(i), self.row(j)) <= tolerance {
+                    components.union(i, j);
+                }
+            }
+        }
+
+        let mut groups = vec![Vec::new(); nrows];
+        for i in 0..nrows {
+            let root = components.find(i);
+            groups[root].push(i);
+        }
+        let mut groups = groups
+            .into_iter()
+            .filter(|group| group.len() > 1)
+            .collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|group| group[0]);
+        groups
+    }
+}
+
+/// Disjoint sets of `0..n`, with union by size and path halving.
+struct DisjointSets {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(n: usize) -> Self {
+        DisjointSets {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn union(&mut self, i: usize, j: usize) {
+        let (mut i, mut j) = (self.find(i), self.find(j));
+        if i == j {
+            return;
+        }
+        if self.size[i] < self.size[j] {
+            std::mem::swap(&mut i, &mut j);
+        }
+        self.parent[j] = i;
+        self.size[i] += self.size[j];
+    }
+}
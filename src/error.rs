@@ -6,6 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use super::Dimension;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::error::Error;
 use std::fmt;
@@ -15,6 +17,7 @@ use std::fmt;
 pub struct ShapeError {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    shapes: Option<Box<(Vec<usize>, Vec<usize>)>>,
 }
 
 impl ShapeError {
@@ -24,6 +27,24 @@ impl ShapeError {
         self.repr
     }
 
+    /// Return the incompatible shapes, if they are known: the shape of the
+    /// array (or of the data), and the shape it was reshaped or broadcast
+    /// to, or combined with.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let err = Array::<f64, _>::zeros((2, 3)).into_shape((4, 2)).unwrap_err();
+    /// assert_eq!(err.shapes(), Some((&[2, 3][..], &[4, 2][..])));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "ShapeError/IncompatibleShape: incompatible shapes [2, 3] and [4, 2]"
+    /// );
+    /// ```
+    pub fn shapes(&self) -> Option<(&[usize], &[usize])> {
+        self.shapes.as_ref().map(|shapes| (&shapes.0[..], &shapes.1[..]))
+    }
+
     /// Create a new `ShapeError`
     pub fn from_kind(error: ErrorKind) -> Self {
         from_kind(error)
@@ -53,7 +74,7 @@ pub enum ErrorKind {
 
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError {
-    ShapeError { repr: k }
+    ShapeError { repr: k, shapes: None }
 }
 
 impl PartialEq for ErrorKind {
@@ -83,7 +104,11 @@ impl fmt::Display for ShapeError {
             ErrorKind::Unsupported => "unsupported operation",
             ErrorKind::Overflow => "arithmetic overflow",
         };
-        write!(f, "ShapeError/{:?}: {}", self.kind(), description)
+        write!(f, "ShapeError/{:?}: {}", self.kind(), description)?;
+        if let Some((a, b)) = self.shapes() {
+            write!(f, " {:?} and {:?}", a, b)?;
+        }
+        Ok(())
     }
 }
 
@@ -93,10 +118,97 @@ impl fmt::Debug for ShapeError {
     }
 }
 
-pub fn incompatible_shapes<D, E>(_a: &D, _b: &E) -> ShapeError
+pub fn incompatible_shapes<D, E>(a: &D, b: &E) -> ShapeError
 where
     D: Dimension,
     E: Dimension,
 {
-    from_kind(ErrorKind::IncompatibleShape)
+    ShapeError {
+        repr: ErrorKind::IncompatibleShape,
+        shapes: Some(Box::new((a.slice().to_vec(), b.slice().to_vec()))),
+    }
+}
+
+/// An error from indexing an axis, from the fallible methods like
+/// [`.try_index_axis()`](crate::ArrayBase::try_index_axis).
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AxisError {
+    /// The axis is out of bounds for an array with `ndim` dimensions.
+    AxisOutOfBounds {
+        /// The axis
+        axis: usize,
+        /// The number of dimensions of the array
+        ndim: usize,
+    },
+    /// The index is out of bounds for the axis, which has length `len`.
+    IndexOutOfBounds {
+        /// The axis
+        axis: usize,
+        /// The index
+        index: usize,
+        /// The length of the axis
+        len: usize,
+    },
 }
+
+impl fmt::Display for AxisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            AxisError::AxisOutOfBounds { axis, ndim } => write!(
+                f,
+                "AxisError: axis {} is out of bounds for an array with {} dimensions",
+                axis, ndim
+            ),
+            AxisError::IndexOutOfBounds { axis, index, len } => write!(
+                f,
+                "AxisError: index {} is out of bounds for axis {} with length {}",
+                index, axis, len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AxisError {}
+
+/// Convert to a `ShapeError` of kind `OutOfBounds`, which drops the
+/// details.
+impl From<AxisError> for ShapeError {
+    fn from(_: AxisError) -> Self {
+        from_kind(ErrorKind::OutOfBounds)
+    }
+}
+
+/// An error from the checked elementwise arithmetic methods, like
+/// [`.checked_add()`](crate::ArrayBase::checked_add), when an operation
+/// overflows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverflowError {
+    index: Vec<usize>,
+}
+
+impl OverflowError {
+    pub(crate) fn at_index(index: &[usize]) -> Self {
+        OverflowError {
+            index: index.to_vec(),
+        }
+    }
+
+    /// Return the index of the first element, in logical order, where the
+    /// operation overflowed.
+    pub fn index(&self) -> &[usize] {
+        &self.index
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OverflowError: arithmetic overflow at index {:?}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for OverflowError {}
@@ -0,0 +1,173 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::dimension::size_of_shape_checked;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+
+/// Keep an axis of the array, in a pattern for
+/// [`.expand_to()`](ArrayBase::expand_to).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Keep;
+
+/// One axis of a pattern for [`.expand_to()`](ArrayBase::expand_to).
+///
+/// It can be created from [`Keep`] or from a `usize`, the length of a new
+/// axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExpandAxis {
+    /// The next axis of the array.
+    Keep,
+    /// A new axis with the given length.
+    New(usize),
+}
+
+impl From<Keep> for ExpandAxis {
+    #[inline]
+    fn from(_: Keep) -> ExpandAxis {
+        ExpandAxis::Keep
+    }
+}
+
+impl From<usize> for ExpandAxis {
+    #[inline]
+    fn from(len: usize) -> ExpandAxis {
+        ExpandAxis::New(len)
+    }
+}
+
+/// A pattern for [`.expand_to()`](ArrayBase::expand_to): a tuple of
+/// [`Keep`] and lengths of new axes, like `(3, Keep, 4, Keep)`, or a slice
+/// or vector of [`ExpandAxis`].
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+pub trait ExpandPattern {
+    /// The dimension of the expanded array.
+    type Dim: Dimension;
+
+    #[doc(hidden)]
+    fn axes(self) -> Vec<ExpandAxis>;
+
+    private_decl! {}
+}
+
+macro_rules! impl_expand_pattern_tuple {
+    ($n:expr; $($T:ident $i:tt),*) => {
+        impl<$($T),*> ExpandPattern for ($($T,)*)
+        where
+            $($T: Into<ExpandAxis>,)*
+        {
+            type Dim = Dim<[Ix; $n]>;
+
+            fn axes(self) -> Vec<ExpandAxis> {
+                vec![$(self.$i.into()),*]
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_expand_pattern_tuple!(1; T0 0);
+impl_expand_pattern_tuple!(2; T0 0, T1 1);
+impl_expand_pattern_tuple!(3; T0 0, T1 1, T2 2);
+impl_expand_pattern_tuple!(4; T0 0, T1 1, T2 2, T3 3);
+impl_expand_pattern_tuple!(5; T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_expand_pattern_tuple!(6; T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+
+impl ExpandPattern for &[ExpandAxis] {
+    type Dim = IxDyn;
+
+    fn axes(self) -> Vec<ExpandAxis> {
+        self.to_vec()
+    }
+
+    private_impl! {}
+}
+
+impl ExpandPattern for Vec<ExpandAxis> {
+    type Dim = IxDyn;
+
+    fn axes(self) -> Vec<ExpandAxis> {
+        self
+    }
+
+    private_impl! {}
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Insert new axes with the given lengths and return a view of the
+    /// expanded array.
+    ///
+    /// `pattern` has one item for each axis of the result: [`Keep`] for the
+    /// axes of the array, in order, and the length of each new axis. The
+    /// elements are repeated along the new axes, which have stride zero, so
+    /// no elements are copied; use `.to_owned()` on the view to copy them
+    /// into a new array.
+    ///
+    /// This is the same as inserting axes of length one with
+    /// [`.insert_axis()`](Self::insert_axis) and broadcasting them with
+    /// [`.broadcast()`](Self::broadcast).
+    ///
+    /// **Errors** if the number of [`Keep`] items is not the number of axes
+    /// of the array, or if the product of the non-zero axis lengths of the
+    /// result overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, Keep};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// let b = a.expand_to((3, Keep, 4, Keep)).unwrap();
+    /// assert_eq!(b.shape(), &[3, 2, 4, 2]);
+    /// assert_eq!(b[[2, 1, 3, 0]], 3);
+    ///
+    /// // Repeat each row three times
+    /// let rows = a.expand_to((Keep, 3, Keep)).unwrap().to_owned();
+    /// assert_eq!(rows, array![[[1, 2], [1, 2], [1, 2]], [[3, 4], [3, 4], [3, 4]]]);
+    ///
+    /// assert!(a.expand_to((3, Keep)).is_err());
+    /// ```
+    pub fn expand_to<P>(&self, pattern: P) -> Result<ArrayView<'_, A, P::Dim>, ShapeError>
+    where
+        P: ExpandPattern,
+        S: Data,
+    {
+        let axes = pattern.axes();
+        let nkeep = axes.iter().filter(|&&ax| ax == ExpandAxis::Keep).count();
+        if nkeep != self.ndim() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        let mut dim = P::Dim::zeros(axes.len());
+        let mut strides = P::Dim::zeros(axes.len());
+        let mut kept = 0;
+        for (i, &ax) in axes.iter().enumerate() {
+            match ax {
+                ExpandAxis::Keep => {
+                    dim[i] = self.dim[kept];
+                    strides[i] = self.strides[kept];
+                    kept += 1;
+                }
+                ExpandAxis::New(len) => {
+                    dim[i] = len;
+                    strides[i] = 0;
+                }
+            }
+        }
+        size_of_shape_checked(&dim)?;
+        // Safe because the new axes have stride zero, the kept axes have
+        // the lengths and strides of the array, and the size is checked.
+        unsafe { Ok(ArrayView::new(self.ptr, dim, strides)) }
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+
+/// The length of an axis in a [`ShapePattern`]: `usize` for a required
+/// length, or `Option<usize>` where `None` matches any length.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+pub trait AxisLenPattern {
+    #[doc(hidden)]
+    fn axis_len(&self) -> Option<usize>;
+
+    private_decl! {}
+}
+
+impl AxisLenPattern for usize {
+    fn axis_len(&self) -> Option<usize> {
+        Some(*self)
+    }
+
+    private_impl! {}
+}
+
+impl AxisLenPattern for Option<usize> {
+    fn axis_len(&self) -> Option<usize> {
+        *self
+    }
+
+    private_impl! {}
+}
+
+/// A pattern for the shape of an array, used by
+/// [`.expect_shape()`](ArrayBase::expect_shape).
+///
+/// The pattern is a tuple, an array or a slice of axis lengths, where each
+/// length is a `usize` or an `Option<usize>`, and `None` matches any length:
+/// for example `(3, None, 5)`. A single `usize` is the pattern for a
+/// one-dimensional shape.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+pub trait ShapePattern {
+    #[doc(hidden)]
+    fn axis_lens(&self) -> Vec<Option<usize>>;
+
+    private_decl! {}
+}
+
+impl ShapePattern for usize {
+    fn axis_lens(&self) -> Vec<Option<usize>> {
+        vec![Some(*self)]
+    }
+
+    private_impl! {}
+}
+
+impl<T: AxisLenPattern> ShapePattern for [T] {
+    fn axis_lens(&self) -> Vec<Option<usize>> {
+        self.iter().map(T::axis_len).collect()
+    }
+
+    private_impl! {}
+}
+
+impl<T: AxisLenPattern, const N: usize> ShapePattern for [T; N] {
+    fn axis_lens(&self) -> Vec<Option<usize>> {
+        self[..].axis_lens()
+    }
+
+    private_impl! {}
+}
+
+impl<P: ShapePattern + ?Sized> ShapePattern for &P {
+    fn axis_lens(&self) -> Vec<Option<usize>> {
+        (**self).axis_lens()
+    }
+
+    private_impl! {}
+}
+
+macro_rules! impl_shape_pattern_tuple {
+    ($($t:ident $i:tt),*) => {
+        impl<$($t: AxisLenPattern),*> ShapePattern for ($($t,)*) {
+            fn axis_lens(&self) -> Vec<Option<usize>> {
+                vec![$(self.$i.axis_len()),*]
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+impl ShapePattern for () {
+    fn axis_lens(&self) -> Vec<Option<usize>> {
+        Vec::new()
+    }
+
+    private_impl! {}
+}
+
+impl_shape_pattern_tuple!(T0 0);
+impl_shape_pattern_tuple!(T0 0, T1 1);
+impl_shape_pattern_tuple!(T0 0, T1 1, T2 2);
+impl_shape_pattern_tuple!(T0 0, T1 1, T2 2, T3 3);
+impl_shape_pattern_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_shape_pattern_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+
+/// The shape that was expected, in an [`ExpectShapeError`].
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectedShape {
+    /// A shape matching the pattern, where `None` matches any length
+    Pattern(Vec<Option<usize>>),
+    /// Any shape with this number of dimensions
+    Ndim(usize),
+    /// A square matrix
+    Square,
+}
+
+impl fmt::Display for ExpectedShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectedShape::Pattern(pattern) => {
+                write!(f, "shape [")?;
+                for (i, len) in pattern.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match len {
+                        Some(len) => write!(f, "{}", len)?,
+                        None => write!(f, "_")?,
+                    }
+                }
+                write!(f, "]")
+            }
+            ExpectedShape::Ndim(ndim) => write!(f, "{} dimensions", ndim),
+            ExpectedShape::Square => write!(f, "a square matrix"),
+        }
+    }
+}
+
+/// An error from the shape validators
+/// [`.expect_shape()`](ArrayBase::expect_shape),
+/// [`.expect_ndim()`](ArrayBase::expect_ndim) and
+/// [`.expect_square()`](ArrayBase::expect_square).
+///
+/// The error holds the expected and actual shapes, and optionally a context
+/// string, usually the name of the array, that is included in the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectShapeError {
+    expected: ExpectedShape,
+    found: Vec<usize>,
+    context: Option<String>,
+}
+
+impl ExpectShapeError {
+    /// Return the expected shape.
+    pub fn expected(&self) -> &ExpectedShape {
+        &self.expected
+    }
+
+    /// Return the actual shape of the array.
+    pub fn found(&self) -> &[usize] {
+        &self.found
+    }
+
+    /// Return the context of the error, if any.
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// Set the context of the error, usually the name of the array.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let weights = Array2::<f64>::zeros((3, 4));
+    /// let err = weights.expect_square().map_err(|e| e.with_context("weights")).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "ExpectShapeError: weights: expected a square matrix, found shape [3, 4]"
+    /// );
+    /// ```
+    pub fn with_context<C: Into<String>>(mut self, context: C) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+}
+
+impl fmt::Display for ExpectShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ExpectShapeError: ")?;
+        if let Some(context) = &self.context {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "expected {}, found shape {:?}", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ExpectShapeError {}
+
+/// Convert to a `ShapeError` of kind `IncompatibleShape`, which drops the
+/// details.
+impl From<ExpectShapeError> for ShapeError {
+    fn from(_: ExpectShapeError) -> Self {
+        from_kind(ErrorKind::IncompatibleShape)
+    }
+}
+
+/// # Shape Validation
+///
+/// These methods check the shape of the array and return it unchanged if the
+/// check passes, so that they can be chained, or an [`ExpectShapeError`]
+/// describing the mismatch.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Check that the shape of the array matches `pattern`.
+    ///
+    /// The pattern is a tuple, array or slice of axis lengths, where `None`
+    /// matches any length; see [`ShapePattern`]. The number of dimensions
+    /// must match the length of the pattern.
+    ///
+    /// **Errors** if the shape doesn't match.
+    ///
+    /// ```
+    /// use ndarray::{Array3, ShapeError};
+    ///
+    /// fn total_weight(w: &Array3<f64>) -> Result<f64, ShapeError> {
+    ///     Ok(w.expect_shape((3, None, 5))?.sum())
+    /// }
+    ///
+    /// assert!(total_weight(&Array3::zeros((3, 7, 5))).is_ok());
+    /// assert!(total_weight(&Array3::zeros((3, 7, 4))).is_err());
+    ///
+    /// let err = Array3::<u8>::zeros((3, 7, 4)).expect_shape([Some(3), None, Some(5)]).unwrap_err();
+    /// assert_eq!(err.to_string(), "ExpectShapeError: expected shape [3, _, 5], found shape [3, 7, 4]");
+    /// ```
+    pub fn expect_shape<P>(&self, pattern: P) -> Result<&Self, ExpectShapeError>
+    where
+        P: ShapePattern,
+    {
+        let pattern = pattern.axis_lens();
+        let matches = pattern.len() == self.ndim()
+            && pattern
+                .iter()
+                .zip(self.shape())
+                .all(|(&expected, &len)| expected.is_none() || expected == Some(len));
+        if matches {
+            Ok(self)
+        } else {
+            Err(self.expect_shape_error(ExpectedShape::Pattern(pattern)))
+        }
+    }
+
+    /// Check that the array has `ndim` dimensions.
+    ///
+    /// This is mostly useful for arrays with dynamic dimension.
+    ///
+    /// **Errors** if the number of dimensions is different.
+    ///
+    /// ```
+    /// use ndarray::ArrayD;
+    ///
+    /// let a = ArrayD::<f32>::zeros(vec![2, 3]);
+    /// assert!(a.expect_ndim(2).is_ok());
+    /// assert!(a.expect_ndim(3).is_err());
+    /// ```
+    pub fn expect_ndim(&self, ndim: usize) -> Result<&Self, ExpectShapeError> {
+        if self.ndim() == ndim {
+            Ok(self)
+        } else {
+            Err(self.expect_shape_error(ExpectedShape::Ndim(ndim)))
+        }
+    }
+
+    /// Check that the array is a square matrix: it has two dimensions of
+    /// equal length.
+    ///
+    /// **Errors** if the array is not a square matrix.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let a = arr2(&[[1., 2.], [3., 4.]]);
+    /// assert_eq!(a.expect_square().unwrap().diag().sum(), 5.);
+    /// assert!(Array2::<f64>::zeros((2, 3)).expect_square().is_err());
+    /// ```
+    pub fn expect_square(&self) -> Result<&Self, ExpectShapeError> {
+        if self.ndim() == 2 && self.shape()[0] == self.shape()[1] {
+            Ok(self)
+        } else {
+            Err(self.expect_shape_error(ExpectedShape::Square))
+        }
+    }
+
+    fn expect_shape_error(&self, expected: ExpectedShape) -> ExpectShapeError {
+        ExpectShapeError {
+            expected,
+            found: self.shape().to_vec(),
+            context: None,
+        }
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! FFI-safe array views, for exchanging arrays with C and C++.
+//!
+//! [`CArrayView`] and [`CArrayViewMut`] are `#[repr(C)]` structs with the
+//! layout of the C declarations in [`C_HEADER`]:
+//!
+//! ```c
+//! typedef struct {
+//!     const void *ptr;
+//!     size_t ndim;
+//!     const size_t *shape;
+//!     const ptrdiff_t *strides;
+//! } NdArrayView;
+//! ```
+//!
+//! - `ptr` points to the first element (the element at index zero along
+//!   every axis), which is not necessarily the lowest address in memory.
+//! - `shape` and `strides` point to `ndim` lengths and strides. Strides are
+//!   in units of elements, not bytes, and may be negative.
+//! - If `ndim` is zero, `shape` and `strides` may be null; if the array has
+//!   no elements, `ptr` may be null.
+//!
+//! The element type is not part of the C declaration; the Rust side uses
+//! `CArrayView<A>` for the element type `A`, which must be FFI-safe itself.
+//!
+//! ```
+//! use ndarray::ffi::CArrayView;
+//! use ndarray::array;
+//!
+//! // A function that could be called from C as
+//! // `double sum(NdArrayView view);`
+//! extern "C" fn sum(view: CArrayView<'_, f64>) -> f64 {
+//!     // Safe if the caller passes a valid view
+//!     unsafe { view.as_array_view().sum() }
+//! }
+//!
+//! let a = array![[1., 2., 3.], [4., 5., 6.]];
+//! assert_eq!(sum(CArrayView::from(&a)), 21.);
+//! assert_eq!(sum(CArrayView::from(&a.slice(ndarray::s![.., ..;-2]))), 14.);
+//! ```
+
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::slice;
+
+use crate::imp_prelude::*;
+use crate::RawArrayViewMut;
+
+/// C declarations of the structs [`CArrayView`] and [`CArrayViewMut`], for
+/// use in a C or C++ header.
+pub const C_HEADER: &str = "\
+#include <stddef.h>
+
+/* A read-only view of an n-dimensional array, with strides in elements. */
+typedef struct {
+    const void *ptr;
+    size_t ndim;
+    const size_t *shape;
+    const ptrdiff_t *strides;
+} NdArrayView;
+
+/* A read-write view of an n-dimensional array, with strides in elements. */
+typedef struct {
+    void *ptr;
+    size_t ndim;
+    const size_t *shape;
+    const ptrdiff_t *strides;
+} NdArrayViewMut;
+";
+
+/// An FFI-safe read-only array view with elements of type `A`, the
+/// `NdArrayView` struct in [`C_HEADER`].
+///
+/// Create it from an array or view with `From`, which borrows the array for
+/// the lifetime `'a`, and convert it back with
+/// [`.as_array_view()`](CArrayView::as_array_view). See the
+/// [module documentation](self) for the layout.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CArrayView<'a, A> {
+    ptr: *const A,
+    ndim: usize,
+    shape: *const usize,
+    strides: *const isize,
+    life: PhantomData<&'a A>,
+}
+
+/// An FFI-safe read-write array view with elements of type `A`, the
+/// `NdArrayViewMut` struct in [`C_HEADER`].
+///
+/// Create it from a mutable array or view with `From`, which borrows the
+/// array mutably for the lifetime `'a`, and convert it back with
+/// [`.into_array_view_mut()`](CArrayViewMut::into_array_view_mut). See the
+/// [module documentation](self) for the layout.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CArrayViewMut<'a, A> {
+    ptr: *mut A,
+    ndim: usize,
+    shape: *const usize,
+    strides: *const isize,
+    life: PhantomData<&'a mut A>,
+}
+
+impl<'a, A> Clone for CArrayView<'a, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A> Copy for CArrayView<'a, A> {}
+
+impl<'a, A> CArrayView<'a, A> {
+    /// Create a view from its parts.
+    ///
+    /// # Safety
+    ///
+    /// The parts must describe a valid array view, as documented in the
+    /// [module documentation](self), that is valid for reading for the
+    /// lifetime `'a`.
+    pub unsafe fn from_raw_parts(
+        ptr: *const A,
+        ndim: usize,
+        shape: *const usize,
+        strides: *const isize,
+    ) -> Self {
+        CArrayView {
+            ptr,
+            ndim,
+            shape,
+            strides,
+            life: PhantomData,
+        }
+    }
+
+    /// Return the number of dimensions.
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Convert to an array view with dynamic dimension.
+    ///
+    /// # Safety
+    ///
+    /// The view must be valid, as documented in the
+    /// [module documentation](self). This is always the case for views
+    /// created from arrays with `From`.
+    pub unsafe fn as_array_view(&self) -> ArrayViewD<'a, A> {
+        raw_view_from_parts(self.ptr as *mut A, self.ndim, self.shape, self.strides)
+            .deref_into_view()
+    }
+}
+
+impl<'a, A> CArrayViewMut<'a, A> {
+    /// Create a view from its parts.
+    ///
+    /// # Safety
+    ///
+    /// The parts must describe a valid array view, as documented in the
+    /// [module documentation](self), that is valid for reading and writing
+    /// for the lifetime `'a`, and the elements must not be accessed through
+    /// any other pointer during that lifetime.
+    pub unsafe fn from_raw_parts(
+        ptr: *mut A,
+        ndim: usize,
+        shape: *const usize,
+        strides: *const isize,
+    ) -> Self {
+        CArrayViewMut {
+            ptr,
+            ndim,
+            shape,
+            strides,
+            life: PhantomData,
+        }
+    }
+
+    /// Return the number of dimensions.
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Convert to a mutable array view with dynamic dimension.
+    ///
+    /// # Safety
+    ///
+    /// The view must be valid, as documented in the
+    /// [module documentation](self). This is always the case for views
+    /// created from arrays with `From`.
+    pub unsafe fn into_array_view_mut(self) -> ArrayViewMutD<'a, A> {
+        raw_view_from_parts(self.ptr, self.ndim, self.shape, self.strides).deref_into_view_mut()
+    }
+}
+
+impl<'a, A, S, D> From<&'a ArrayBase<S, D>> for CArrayView<'a, A>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn from(array: &'a ArrayBase<S, D>) -> Self {
+        CArrayView {
+            ptr: array.as_ptr(),
+            ndim: array.ndim(),
+            shape: array.shape().as_ptr(),
+            strides: array.strides().as_ptr(),
+            life: PhantomData,
+        }
+    }
+}
+
+impl<'a, A, S, D> From<&'a mut ArrayBase<S, D>> for CArrayViewMut<'a, A>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    fn from(array: &'a mut ArrayBase<S, D>) -> Self {
+        let ptr = array.as_mut_ptr();
+        CArrayViewMut {
+            ptr,
+            ndim: array.ndim(),
+            shape: array.shape().as_ptr(),
+            strides: array.strides().as_ptr(),
+            life: PhantomData,
+        }
+    }
+}
+
+/// Create a raw view from the parts of a C view, which can have null
+/// pointers.
+unsafe fn raw_view_from_parts<A>(
+    mut ptr: *mut A,
+    ndim: usize,
+    shape: *const usize,
+    strides: *const isize,
+) -> RawArrayViewMut<A, IxDyn> {
+    let (shape, strides) = if ndim == 0 {
+        (&[][..], &[][..])
+    } else {
+        (
+            slice::from_raw_parts(shape, ndim),
+            slice::from_raw_parts(strides, ndim),
+        )
+    };
+    if ptr.is_null() {
+        debug_assert!(shape.contains(&0), "null pointer for a non-empty array");
+        ptr = NonNull::dangling().as_ptr();
+    }
+    RawArrayViewMut::from_shape_strides_ptr(shape, strides, ptr)
+}
@@ -99,9 +99,9 @@ pub fn aview2<A, V: FixedInitializer<Elem = A>>(xs: &[V]) -> ArrayView2<'_, A> {
     }
     // `rows` is guaranteed to fit in `isize` because we've checked the ZST
     // case and slices never contain > `isize::MAX` bytes. `cols` is guaranteed
-    // to fit in `isize` because `FixedInitializer` is not implemented for any
-    // array lengths > `isize::MAX`. `cols * rows` is guaranteed to fit in
-    // `isize` because we've checked the ZST case and slices never contain >
+    // to fit in `isize` because we've checked the ZST case and a non-ZST `V`
+    // never contains > `isize::MAX` bytes. `cols * rows` is guaranteed to fit
+    // in `isize` because we've checked the ZST case and slices never contain >
     // `isize::MAX` bytes.
     unsafe {
         let data = slice::from_raw_parts(xs.as_ptr() as *const A, cols * rows);
@@ -135,8 +135,7 @@ pub fn aview_mut1<A>(xs: &mut [A]) -> ArrayViewMut1<'_, A> {
 /// ```
 /// use ndarray::aview_mut2;
 ///
-/// // The inner (nested) array must be of length 1 to 16, but the outer
-/// // can be of any length.
+/// // The inner (nested) and outer arrays can be of any length.
 /// let mut data = [[0.; 2]; 128];
 /// {
 ///     // Make a 128 x 2 mut array view then turn it into 2 x 128
@@ -158,9 +157,9 @@ pub fn aview_mut2<A, V: FixedInitializer<Elem = A>>(xs: &mut [V]) -> ArrayViewMu
     }
     // `rows` is guaranteed to fit in `isize` because we've checked the ZST
     // case and slices never contain > `isize::MAX` bytes. `cols` is guaranteed
-    // to fit in `isize` because `FixedInitializer` is not implemented for any
-    // array lengths > `isize::MAX`. `cols * rows` is guaranteed to fit in
-    // `isize` because we've checked the ZST case and slices never contain >
+    // to fit in `isize` because we've checked the ZST case and a non-ZST `V`
+    // never contains > `isize::MAX` bytes. `cols * rows` is guaranteed to fit
+    // in `isize` because we've checked the ZST case and slices never contain >
     // `isize::MAX` bytes.
     unsafe {
         let data = slice::from_raw_parts_mut(xs.as_mut_ptr() as *mut A, cols * rows);
@@ -175,29 +174,16 @@ pub unsafe trait FixedInitializer {
     fn len() -> usize;
 }
 
-macro_rules! impl_arr_init {
-    (__impl $n: expr) => (
-        unsafe impl<T> FixedInitializer for [T;  $n] {
-            type Elem = T;
-            fn as_init_slice(&self) -> &[T] { self }
-            fn len() -> usize { $n }
-        }
-    );
-    () => ();
-    ($n: expr, $($m:expr,)*) => (
-        impl_arr_init!(__impl $n);
-        impl_arr_init!($($m,)*);
-    )
-
+unsafe impl<T, const N: usize> FixedInitializer for [T; N] {
+    type Elem = T;
+    fn as_init_slice(&self) -> &[T] {
+        self
+    }
+    fn len() -> usize {
+        N
+    }
 }
 
-// For implementors: If you ever implement `FixedInitializer` for array lengths
-// > `isize::MAX` (e.g. once Rust adds const generics), you must update
-// `aview2` and `aview_mut2` to perform the necessary checks. In particular,
-// the assumption that `cols` can never exceed `isize::MAX` would be incorrect.
-// (Consider e.g. `let xs: &[[i32; ::std::usize::MAX]] = &[]`.)
-impl_arr_init!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,);
-
 /// Create a two-dimensional array with elements from `xs`.
 ///
 /// ```
@@ -222,6 +208,16 @@ where
 {
     /// Converts the `Vec` of arrays to an owned 2-D array.
     ///
+    /// The elements are not copied; the allocation of the vector is reused.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let points = vec![[0., 1., 2.], [3., 4., 5.]];
+    /// let a = Array2::from(points);
+    /// assert_eq!(a.shape(), &[2, 3]);
+    /// ```
+    ///
     /// **Panics** if the product of non-zero axis lengths overflows `isize`.
     fn from(mut xs: Vec<V>) -> Self {
         let dim = Ix2(xs.len(), V::len());
@@ -0,0 +1,163 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{PadMode, Slice};
+
+/// Fill the ghost cells of a grid of tiles from their neighbors.
+///
+/// The domain is decomposed into the tiles of the grid `tiles`, which has
+/// one axis for each axis of the tiles. Each tile holds its part of the
+/// domain in its interior, surrounded by `width` ghost cells on both sides
+/// of each axis. The ghost cells are set to the adjacent interior cells of
+/// the neighboring tiles, including the edge and corner regions that are
+/// shared by more than two tiles.
+///
+/// The ghost cells on the outside of the domain are filled with `boundary`:
+/// with [`PadMode::Wrap`] from the tiles on the opposite side of the grid,
+/// for a periodic domain, and with the other modes from the tile's own
+/// interior, like [`.pad()`](ArrayBase::pad) does. With
+/// [`PadMode::Reflect`], the interior of the tiles at the boundary must be
+/// longer than `width`.
+///
+/// The axes are exchanged one at a time, and the cells copied along an axis
+/// include the ghost cells of the previous axes, which fills the corner
+/// regions. The tiles can be owned arrays or mutable views, for example of
+/// the parts of a larger array.
+///
+/// **Panics** if the tiles don't have the same number of axes as the grid,
+/// if the interior of a tile is too short for `width` along an axis, or if
+/// the shapes of neighboring tiles don't match across their shared face.
+///
+/// ```
+/// use ndarray::{array, exchange_ghosts, Array2, PadMode};
+///
+/// // A 2 × 4 domain split into two 2 × 2 tiles, with ghost cells of width 1
+/// let mut left = Array2::zeros((4, 4));
+/// let mut right = Array2::zeros((4, 4));
+/// left.slice_mut(ndarray::s![1..3, 1..3]).assign(&array![[1, 2], [5, 6]]);
+/// right.slice_mut(ndarray::s![1..3, 1..3]).assign(&array![[3, 4], [7, 8]]);
+///
+/// let mut tiles = array![[left, right]];
+/// exchange_ghosts(&mut tiles, 1, &PadMode::Wrap);
+/// assert_eq!(
+///     tiles[[0, 0]],
+///     array![[8, 5, 6, 7],
+///            [4, 1, 2, 3],
+///            [8, 5, 6, 7],
+///            [4, 1, 2, 3]]
+/// );
+///
+/// exchange_ghosts(&mut tiles, 1, &PadMode::Constant(0));
+/// assert_eq!(
+///     tiles[[0, 1]],
+///     array![[0, 0, 0, 0],
+///            [2, 3, 4, 0],
+///            [6, 7, 8, 0],
+///            [0, 0, 0, 0]]
+/// );
+/// ```
+pub fn exchange_ghosts<A, S, T, D>(tiles: &mut ArrayBase<S, D>, width: usize, boundary: &PadMode<A>)
+where
+    A: Clone,
+    S: DataMut<Elem = ArrayBase<T, D>>,
+    T: DataMut<Elem = A>,
+    D: Dimension,
+{
+    for tile in tiles.iter() {
+        assert_eq!(
+            tile.ndim(),
+            tiles.ndim(),
+            "exchange_ghosts: the tiles must have one axis for each axis of the grid"
+        );
+        for &len in tile.shape() {
+            assert!(
+                len >= 3 * width,
+                "exchange_ghosts: the interior of tile of shape {:?} is shorter than the width {}",
+                tile.shape(),
+                width
+            );
+        }
+    }
+    if width == 0 {
+        return;
+    }
+    for axis in (0..tiles.ndim()).map(Axis) {
+        let n = tiles.len_of(axis);
+        let wrap = matches!(boundary, PadMode::Wrap);
+        for mut lane in tiles.lanes_mut(axis) {
+            for pos in 0..n {
+                // The ghost cells before the interior
+                if pos > 0 || wrap {
+                    let src = &lane[(pos + n - 1) % n];
+                    let len = src.len_of(axis);
+                    let src = src
+                        .slice_axis(axis, Slice::from(len - 2 * width..len - width))
+                        .to_owned();
+                    copy_ghosts(&mut lane[pos], axis, 0, &src);
+                } else {
+                    fill_ghosts(&mut lane[pos], axis, width, true, boundary);
+                }
+                // The ghost cells after the interior
+                if pos + 1 < n || wrap {
+                    let src = lane[(pos + 1) % n]
+                        .slice_axis(axis, Slice::from(width..2 * width))
+                        .to_owned();
+                    let len = lane[pos].len_of(axis);
+                    copy_ghosts(&mut lane[pos], axis, len - width, &src);
+                } else {
+                    fill_ghosts(&mut lane[pos], axis, width, false, boundary);
+                }
+            }
+        }
+    }
+}
+
+/// Copy `src` to the cells of `tile` starting at `start` along `axis`.
+fn copy_ghosts<A, T, D>(tile: &mut ArrayBase<T, D>, axis: Axis, start: usize, src: &Array<A, D>)
+where
+    A: Clone,
+    T: DataMut<Elem = A>,
+    D: Dimension,
+{
+    let mut ghosts = tile.slice_axis_mut(axis, Slice::from(start..start + src.len_of(axis)));
+    assert_eq!(
+        ghosts.shape(),
+        src.shape(),
+        "exchange_ghosts: the shapes of neighboring tiles don't match"
+    );
+    ghosts.assign(src);
+}
+
+/// Fill the ghost cells of `tile` before or after the interior along `axis`
+/// from the interior, with the non-periodic `boundary`.
+fn fill_ghosts<A, T, D>(tile: &mut ArrayBase<T, D>, axis: Axis, width: usize, before: bool, boundary: &PadMode<A>)
+where
+    A: Clone,
+    T: DataMut<Elem = A>,
+    D: Dimension,
+{
+    let len = tile.len_of(axis) - 2 * width;
+    let ghosts = if before { 0..width } else { width + len..2 * width + len };
+    if let PadMode::Constant(value) = boundary {
+        tile.slice_axis_mut(axis, Slice::from(ghosts)).fill(value.clone());
+        return;
+    }
+    if let PadMode::Reflect = boundary {
+        assert!(
+            len > width,
+            "exchange_ghosts: the interior of the tiles at the boundary must be longer than the width to reflect"
+        );
+    }
+    assert!(len > 0, "exchange_ghosts: can't fill ghost cells of an empty tile except with a constant");
+    for i in ghosts {
+        let j = width + boundary.source_index(i as isize - width as isize, len);
+        let src = tile.slice_axis(axis, Slice::from(j..j + 1)).to_owned();
+        tile.slice_axis_mut(axis, Slice::from(i..i + 1)).assign(&src);
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Return the two-dimensional histogram of the points `(x[i], y[i])`, and
+/// the edges of its bins along x and y.
+///
+/// The range of each coordinate, from its smallest to its largest value, is
+/// divided into `bins.0` (for x) or `bins.1` (for y) bins of equal width.
+/// Each bin includes its lower edge; the last bin also includes its upper
+/// edge, the largest value. If all values of a coordinate are equal, its
+/// range is that value ± 0.5. Element `[i, j]` of the histogram is the
+/// number of points in bin `i` along x and bin `j` along y, or with
+/// `weights`, the sum of their weights. Points with a NaN coordinate are not
+/// counted.
+///
+/// The edges have `bins.0 + 1` and `bins.1 + 1` elements. The points are
+/// binned in a single pass, accumulating into the flat histogram.
+///
+/// **Panics** if `x`, `y` and `weights` don't have the same length, or if
+/// either number of bins is zero.
+///
+/// ```
+/// use ndarray::{array, histogram2d};
+///
+/// let x = array![0., 0.5, 1., 2.];
+/// let y = array![0., 0., 4., 4.];
+/// let (counts, x_edges, y_edges) = histogram2d(&x, &y, (2, 2), None);
+/// assert_eq!(counts, array![[2., 0.], [0., 2.]]);
+/// assert_eq!(x_edges, array![0., 1., 2.]);
+/// assert_eq!(y_edges, array![0., 2., 4.]);
+///
+/// let weights = array![1., 2., 3., 4.];
+/// let (sums, _, _) = histogram2d(&x, &y, (2, 2), Some(weights.view()));
+/// assert_eq!(sums, array![[3., 0.], [0., 7.]]);
+/// ```
+pub fn histogram2d<A, Sx, Sy>(
+    x: &ArrayBase<Sx, Ix1>,
+    y: &ArrayBase<Sy, Ix1>,
+    bins: (usize, usize),
+    weights: Option<ArrayView1<'_, A>>,
+) -> (Array2<A>, Array1<A>, Array1<A>)
+where
+    A: Float + FromPrimitive,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+{
+    let (bins_x, bins_y) = bins;
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "histogram2d: x and y must have the same length"
+    );
+    assert!(
+        bins_x > 0 && bins_y > 0,
+        "histogram2d: the number of bins must be positive"
+    );
+    if let Some(ref weights) = weights {
+        assert_eq!(
+            weights.len(),
+            x.len(),
+            "histogram2d: the weights must have the length of x and y"
+        );
+    }
+    let x_bins = EqualBins::new(x, bins_x);
+    let y_bins = EqualBins::new(y, bins_y);
+
+    let mut hist = vec![A::zero(); bins_x * bins_y];
+    let mut add = |x: A, y: A, weight: A| {
+        if let (Some(i), Some(j)) = (x_bins.index(x), y_bins.index(y)) {
+            hist[i * bins_y + j] = hist[i * bins_y + j] + weight;
+        }
+    };
+    match weights {
+        Some(weights) => Zip::from(x)
+            .and(y)
+            .and(&weights)
+            .for_each(|&x, &y, &w| add(x, y, w)),
+        None => Zip::from(x).and(y).for_each(|&x, &y| add(x, y, A::one())),
+    }
+    let hist = Array2::from_shape_vec((bins_x, bins_y), hist).unwrap();
+    (hist, x_bins.edges(), y_bins.edges())
+}
+
+/// Bins of equal width spanning the range of some values.
+struct EqualBins<A> {
+    low: A,
+    high: A,
+    bins: usize,
+    /// The number of bins per unit
+    scale: A,
+}
+
+impl<A> EqualBins<A>
+where
+    A: Float + FromPrimitive,
+{
+    fn new<S>(values: &ArrayBase<S, Ix1>, bins: usize) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        let (mut low, mut high) = values
+            .iter()
+            .filter(|x| !x.is_nan())
+            .fold((A::infinity(), A::neg_infinity()), |(low, high), &x| {
+                (low.min(x), high.max(x))
+            });
+        if low > high {
+            // No values
+            low = A::zero();
+            high = A::one();
+        } else if low == high {
+            let half = A::from_f64(0.5).unwrap();
+            low = low - half;
+            high = high + half;
+        }
+        let bins_a = A::from_usize(bins).unwrap();
+        EqualBins {
+            low,
+            high,
+            bins,
+            scale: bins_a / (high - low),
+        }
+    }
+
+    /// Return the bin of `value`, or `None` if it is NaN.
+    fn index(&self, value: A) -> Option<usize> {
+        if value.is_nan() {
+            return None;
+        }
+        // The largest value belongs to the last bin
+        let bin = ((value - self.low) * self.scale).to_usize().unwrap_or(0);
+        Some(bin.min(self.bins - 1))
+    }
+
+    fn edges(&self) -> Array1<A> {
+        let bins_a = A::from_usize(self.bins).unwrap();
+        Array1::from_shape_fn(self.bins + 1, |i| {
+            let t = A::from_usize(i).unwrap() / bins_a;
+            self.low + (self.high - self.low) * t
+        })
+    }
+}
@@ -8,6 +8,10 @@
 
 //! Methods for two-dimensional arrays.
 use crate::imp_prelude::*;
+use crate::iter::Diagonals;
+use crate::Slice;
+use num_traits::Zero;
+use std::ops::Add;
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -121,4 +125,217 @@ where
         let (m, n) = self.dim();
         m == n
     }
+
+    /// Return a view of the `k`-th diagonal of the array.
+    ///
+    /// The diagonal offset `k` is `0` for the main diagonal, positive for the
+    /// diagonals above it and negative for the diagonals below it. The
+    /// diagonal is empty if `k` is outside the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.diag_offset(0), array![1, 5]);
+    /// assert_eq!(a.diag_offset(1), array![2, 6]);
+    /// assert_eq!(a.diag_offset(-1), array![4]);
+    /// assert_eq!(a.diag_offset(3).len(), 0);
+    /// ```
+    pub fn diag_offset(&self, k: isize) -> ArrayView1<'_, A>
+    where
+        S: Data,
+    {
+        self.view().into_diag_offset(k)
+    }
+
+    /// Return a read-write view of the `k`-th diagonal of the array.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the meaning of `k`.
+    pub fn diag_offset_mut(&mut self, k: isize) -> ArrayViewMut1<'_, A>
+    where
+        S: DataMut,
+    {
+        self.view_mut().into_diag_offset(k)
+    }
+
+    /// Return the `k`-th diagonal as a one-dimensional array.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the meaning of `k`.
+    pub fn into_diag_offset(mut self, k: isize) -> ArrayBase<S, Ix1> {
+        if k >= 0 {
+            let start = Ord::min(k as usize, self.ncols());
+            self.slice_axis_inplace(Axis(1), Slice::from(start..));
+        } else {
+            let start = Ord::min(k.unsigned_abs(), self.nrows());
+            self.slice_axis_inplace(Axis(0), Slice::from(start..));
+        }
+        self.into_diag()
+    }
+
+    /// Return an iterator over all the diagonals of the array, starting with
+    /// the single element in the bottom left corner and ending with the single
+    /// element in the top right corner.
+    ///
+    /// An array with shape *(m, n)* has *m + n - 1* diagonals (none if it is
+    /// empty); the diagonal with offset `k` (see
+    /// [`.diag_offset()`](Self::diag_offset)) is produced as number *k + m - 1*.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let sums: Vec<i32> = a.diagonals_iter().map(|d| d.sum()).collect();
+    /// assert_eq!(sums, vec![4, 1 + 5, 2 + 6, 3]);
+    /// ```
+    pub fn diagonals_iter(&self) -> Diagonals<'_, A>
+    where
+        S: Data,
+    {
+        Diagonals::new(self.view())
+    }
+
+    /// Return the sum of the elements on the `k`-th diagonal.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the meaning of `k`; the
+    /// trace of a square matrix is `.trace(0)`. The array does not need to be
+    /// square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.], [5., 6.]];
+    /// assert_eq!(a.trace(0), 5.);
+    /// assert_eq!(a.trace(-1), 9.);
+    /// ```
+    pub fn trace(&self, k: isize) -> A
+    where
+        S: Data,
+        A: Clone + Add<Output = A> + Zero,
+    {
+        self.diag_offset(k).sum()
+    }
+
+    /// Return a copy of the upper triangular part of the array, with the
+    /// other elements set to zero.
+    ///
+    /// The diagonal offset `k` selects where the kept part starts: element
+    /// *(i, j)* is kept if *j - i >= k*, so `0` is the main diagonal, positive
+    /// values are diagonals above it and negative values diagonals below it.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// assert_eq!(a.triu(0), array![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+    /// assert_eq!(a.triu(1), array![[0, 2, 3], [0, 0, 6], [0, 0, 0]]);
+    /// assert_eq!(a.triu(-1), array![[1, 2, 3], [4, 5, 6], [0, 8, 9]]);
+    /// ```
+    pub fn triu(&self, k: isize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let mut res = self.to_owned();
+        res.triu_inplace(k);
+        res
+    }
+
+    /// Return a copy of the lower triangular part of the array, with the
+    /// other elements set to zero.
+    ///
+    /// Element *(i, j)* is kept if *j - i <= k*; see [`.triu()`](Self::triu)
+    /// for the meaning of the diagonal offset `k`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.tril(0), array![[1, 0, 0], [4, 5, 0]]);
+    /// assert_eq!(a.tril(1), array![[1, 2, 0], [4, 5, 6]]);
+    /// ```
+    pub fn tril(&self, k: isize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let mut res = self.to_owned();
+        res.tril_inplace(k);
+        res
+    }
+
+    /// Set the elements below the `k`-th diagonal to zero, keeping the upper
+    /// triangular part in place.
+    ///
+    /// See [`.triu()`](Self::triu) for the meaning of `k`.
+    pub fn triu_inplace(&mut self, k: isize)
+    where
+        S: DataMut,
+        A: Clone + Zero,
+    {
+        self.zero_outside_diagonals(k, isize::MAX);
+    }
+
+    /// Set the elements above the `k`-th diagonal to zero, keeping the lower
+    /// triangular part in place.
+    ///
+    /// See [`.tril()`](Self::tril) for the meaning of `k`.
+    pub fn tril_inplace(&mut self, k: isize)
+    where
+        S: DataMut,
+        A: Clone + Zero,
+    {
+        self.zero_outside_diagonals(isize::MIN, k);
+    }
+
+    /// Return a copy of the band of the array made up of the main diagonal,
+    /// `lower` subdiagonals and `upper` superdiagonals, with the other
+    /// elements set to zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    /// assert_eq!(a.band(1, 0), array![[1, 0, 0, 0], [5, 6, 0, 0], [0, 10, 11, 0]]);
+    /// ```
+    pub fn band(&self, lower: usize, upper: usize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let mut res = self.to_owned();
+        res.band_inplace(lower, upper);
+        res
+    }
+
+    /// Set the elements outside the band made up of the main diagonal,
+    /// `lower` subdiagonals and `upper` superdiagonals to zero.
+    pub fn band_inplace(&mut self, lower: usize, upper: usize)
+    where
+        S: DataMut,
+        A: Clone + Zero,
+    {
+        let lower = -(lower.min(isize::MAX as usize) as isize);
+        let upper = upper.min(isize::MAX as usize) as isize;
+        self.zero_outside_diagonals(lower, upper);
+    }
+
+    /// Set element *(i, j)* to zero unless *lo <= j - i <= hi*.
+    fn zero_outside_diagonals(&mut self, lo: isize, hi: isize)
+    where
+        S: DataMut,
+        A: Clone + Zero,
+    {
+        // Column counts of arrays with non-zero-sized elements always fit in isize;
+        // clamping to the number of columns makes the conversions exact.
+        let ncols = self.ncols().min(isize::MAX as usize) as isize;
+        let clamp = |j: isize| j.max(0).min(ncols) as usize;
+        for (i, mut row) in self.rows_mut().into_iter().enumerate() {
+            let i = i as isize;
+            let keep_start = clamp(i.saturating_add(lo));
+            let keep_end = clamp(i.saturating_add(hi).saturating_add(1));
+            row.slice_axis_mut(Axis(0), Slice::from(..keep_start)).fill(A::zero());
+            row.slice_axis_mut(Axis(0), Slice::from(keep_end.max(keep_start)..))
+                .fill(A::zero());
+        }
+    }
 }
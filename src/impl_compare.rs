@@ -0,0 +1,283 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Borrow;
+
+use num_complex::Complex;
+
+use crate::imp_prelude::*;
+use crate::{DimMax, Zip};
+
+macro_rules! compare_method {
+    ($(#[$meta:meta])* $name:ident, $trt:ident, $op:tt) => {
+        $(#[$meta])*
+        ///
+        /// If their shapes disagree, `self` and `rhs` are broadcast to their
+        /// common shape, which is the shape of the result. To compare with a
+        /// scalar, use a zero-dimensional array like `&arr0(x)`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        pub fn $name<B, S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, <D as DimMax<E>>::Output>
+        where
+            A: $trt<B>,
+            S2: Data<Elem = B>,
+            D: DimMax<E>,
+            E: Dimension,
+        {
+            let (lhs, rhs) = self.broadcast_with(rhs).unwrap();
+            Zip::from(&lhs).and(&rhs).map_collect(|a, b| a $op b)
+        }
+    };
+}
+
+/// # Elementwise Comparisons
+///
+/// These methods compare two arrays elementwise and return an array of `bool`,
+/// for example to use as a mask with [`.select_where()`](ArrayBase::select_where)
+/// or [`where_`](crate::where_).
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    compare_method!(
+        /// Return an array that is `true` where the elements of `self` and `rhs`
+        /// are equal.
+        ///
+        /// ```
+        /// use ndarray::{arr0, array};
+        ///
+        /// let a = array![[1, 2], [3, 4]];
+        /// assert_eq!(a.eq_elem(&array![1, 4]), array![[true, false], [false, true]]);
+        /// assert_eq!(a.eq_elem(&arr0(3)), array![[false, false], [true, false]]);
+        /// ```
+        eq_elem, PartialEq, ==);
+    compare_method!(
+        /// Return an array that is `true` where the elements of `self` and `rhs`
+        /// are not equal.
+        ne_elem, PartialEq, !=);
+    compare_method!(
+        /// Return an array that is `true` where the elements of `self` are
+        /// less than those of `rhs`.
+        ///
+        /// ```
+        /// use ndarray::{arr0, array};
+        ///
+        /// let a = array![0.5, -1., f64::NAN];
+        /// assert_eq!(a.lt(&arr0(0.)), array![false, true, false]);
+        /// ```
+        lt, PartialOrd, <);
+    compare_method!(
+        /// Return an array that is `true` where the elements of `self` are
+        /// less than or equal to those of `rhs`.
+        le, PartialOrd, <=);
+    compare_method!(
+        /// Return an array that is `true` where the elements of `self` are
+        /// greater than those of `rhs`.
+        gt, PartialOrd, >);
+    compare_method!(
+        /// Return an array that is `true` where the elements of `self` are
+        /// greater than or equal to those of `rhs`.
+        ///
+        /// ```
+        /// use ndarray::{array, Array};
+        ///
+        /// let a = Array::from_shape_fn((2, 3), |(i, j)| i + j);
+        /// let thresholds = array![[1], [3]];
+        /// assert_eq!(a.ge(&thresholds), array![[false, true, true], [false, false, true]]);
+        /// ```
+        ge, PartialOrd, >=);
+
+    /// Return an array that is `true` where the elements of `self` are equal
+    /// to `value`.
+    ///
+    /// The elements are compared by their borrowed form, so that an array of
+    /// `String`, `Arc<str>` or `&str` can be compared with a `&str`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use std::sync::Arc;
+    ///
+    /// let a = array!["cat", "dog", "cat"].map(|&s| Arc::<str>::from(s));
+    /// assert_eq!(a.eq_value("cat"), array![true, false, true]);
+    /// assert_eq!(array![1, 2].eq_value(&2), array![false, true]);
+    /// ```
+    pub fn eq_value<B>(&self, value: &B) -> Array<bool, D>
+    where
+        A: Borrow<B>,
+        B: PartialEq + ?Sized,
+    {
+        self.map(|elt| elt.borrow() == value)
+    }
+}
+
+/// # Boolean Reductions
+impl<S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    /// Return `true` if any element is `true`.
+    ///
+    /// Returns `false` for an empty array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert!(array![[false, true], [false, false]].any());
+    /// assert!(!array![false, false].any());
+    /// ```
+    pub fn any(&self) -> bool {
+        self.iter().any(|&x| x)
+    }
+
+    /// Return `true` if all elements are `true`.
+    ///
+    /// Returns `true` for an empty array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 2., 3.];
+    /// assert!(a.gt(&ndarray::arr0(0.)).all());
+    /// assert!(!a.lt(&ndarray::arr0(2.)).all());
+    /// ```
+    pub fn all(&self) -> bool {
+        self.iter().all(|&x| x)
+    }
+
+    /// Return an array that is `true` where any element of the lane along
+    /// `axis` is `true`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[false, true], [false, false]];
+    /// assert_eq!(a.any_axis(Axis(0)), array![false, true]);
+    /// assert_eq!(a.any_axis(Axis(1)), array![true, false]);
+    /// ```
+    pub fn any_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), false);
+        for subview in self.axis_iter(axis) {
+            Zip::from(&mut res).and(&subview).for_each(|r, &x| *r |= x);
+        }
+        res
+    }
+
+    /// Return an array that is `true` where all elements of the lane along
+    /// `axis` are `true`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[true, true], [false, true]];
+    /// assert_eq!(a.all_axis(Axis(0)), array![false, true]);
+    /// assert_eq!(a.all_axis(Axis(1)), array![true, false]);
+    /// ```
+    pub fn all_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), true);
+        for subview in self.axis_iter(axis) {
+            Zip::from(&mut res).and(&subview).for_each(|r, &x| *r &= x);
+        }
+        res
+    }
+}
+
+/// Element types that can be compared by their bit patterns, with
+/// [`.bit_eq()`](ArrayBase::bit_eq): the primitive integer and floating
+/// point types, `bool`, `char` and complex numbers, and with crate feature
+/// `half`, the half precision floating point types.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+///
+/// # Safety
+///
+/// Implementors have no padding bytes, so that their values can be compared
+/// as bytes.
+pub unsafe trait BitEq: Copy {
+    private_decl! {}
+}
+
+macro_rules! impl_bit_eq {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl BitEq for $t {
+                private_impl! {}
+            }
+        )*
+    };
+}
+
+impl_bit_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_bit_eq!(f32, f64, bool, char, Complex<f32>, Complex<f64>);
+#[cfg(feature = "half")]
+impl_bit_eq!(half::f16, half::bf16);
+
+/// Return the bytes of the value `x`.
+fn bytes_of<A: BitEq>(x: &A) -> &[u8] {
+    // Safe because `A` has no padding bytes
+    unsafe { std::slice::from_raw_parts(x as *const A as *const u8, std::mem::size_of::<A>()) }
+}
+
+/// # Bitwise Comparison
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: BitEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return `true` if the shapes of `self` and `rhs` are equal, and their
+    /// elements have the same bit patterns.
+    ///
+    /// Unlike `==`, NaNs with the same bit pattern compare equal, and zero
+    /// and negative zero compare unequal. Contiguous arrays with the same
+    /// memory layout are compared with `memcmp`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, 0.];
+    /// assert!(a != a);
+    /// assert!(a.bit_eq(&a));
+    /// assert!(!a.bit_eq(&array![1., f64::NAN, -0.]));
+    /// ```
+    pub fn bit_eq<S2>(&self, rhs: &ArrayBase<S2, D>) -> bool
+    where
+        S2: Data<Elem = A>,
+    {
+        if self.shape() != rhs.shape() {
+            return false;
+        }
+        if self.dim.strides_equivalent(&self.strides, &rhs.strides) {
+            if let Some(self_s) = self.as_slice_memory_order() {
+                if let Some(rhs_s) = rhs.as_slice_memory_order() {
+                    let len = std::mem::size_of_val(self_s);
+                    // Safe because `A` has no padding bytes
+                    unsafe {
+                        let self_b = std::slice::from_raw_parts(self_s.as_ptr() as *const u8, len);
+                        let rhs_b = std::slice::from_raw_parts(rhs_s.as_ptr() as *const u8, len);
+                        return self_b == rhs_b;
+                    }
+                }
+            }
+        }
+        Zip::from(self)
+            .and(rhs)
+            .all(|a, b| bytes_of(a) == bytes_of(b))
+    }
+}
@@ -0,0 +1,347 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Neg;
+use std::ptr::NonNull;
+
+use num_complex::Complex;
+#[cfg(feature = "std")]
+use num_traits::{Float, FromPrimitive};
+use num_traits::Num;
+
+use crate::imp_prelude::*;
+#[cfg(feature = "std")]
+use crate::Zip;
+
+/// Methods specific to arrays with complex elements.
+///
+/// ***See also all methods for [`ArrayBase`]***
+///
+/// [`ArrayBase`]: struct.ArrayBase.html
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = Complex<A>>,
+    D: Dimension,
+{
+    /// Return views of the real and imaginary parts of the elements, as the
+    /// `re` and `im` fields of a `Complex`.
+    ///
+    /// The views borrow the storage of the array without copying; they have
+    /// the same shape as the array and twice its strides, since `Complex` is
+    /// laid out as the real part followed by the imaginary part.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let a = array![Complex::new(1., 2.), Complex::new(3., 4.)];
+    /// let Complex { re, im } = a.view_split_complex();
+    /// assert_eq!(re, array![1., 3.]);
+    /// assert_eq!(im, array![2., 4.]);
+    /// assert_eq!(re.strides(), &[2]);
+    /// ```
+    pub fn view_split_complex(&self) -> Complex<ArrayView<'_, A, D>>
+    where
+        S: Data,
+    {
+        let (re, im, strides) = split_complex_parts(self.ptr, &self.strides);
+        unsafe {
+            Complex {
+                re: ArrayView::new(re, self.dim.clone(), strides.clone()),
+                im: ArrayView::new(im, self.dim.clone(), strides),
+            }
+        }
+    }
+
+    /// Return mutable views of the real and imaginary parts of the elements,
+    /// as the `re` and `im` fields of a `Complex`.
+    ///
+    /// See [`.view_split_complex()`](Self::view_split_complex).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let mut a = array![Complex::new(1., 2.), Complex::new(3., 4.)];
+    /// let Complex { re, mut im } = a.view_split_complex_mut();
+    /// im += &re;
+    /// assert_eq!(a, array![Complex::new(1., 3.), Complex::new(3., 7.)]);
+    /// ```
+    pub fn view_split_complex_mut(&mut self) -> Complex<ArrayViewMut<'_, A, D>>
+    where
+        S: DataMut,
+    {
+        S::ensure_unique(self);
+        let (re, im, strides) = split_complex_parts(self.ptr, &self.strides);
+        unsafe {
+            Complex {
+                re: ArrayViewMut::new(re, self.dim.clone(), strides.clone()),
+                im: ArrayViewMut::new(im, self.dim.clone(), strides),
+            }
+        }
+    }
+
+    /// Return a view of the real parts of the elements.
+    ///
+    /// See [`.view_split_complex()`](Self::view_split_complex).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let a = array![[Complex::new(1., 2.)], [Complex::new(3., 4.)]];
+    /// assert_eq!(a.real(), array![[1.], [3.]]);
+    /// assert_eq!(a.imag(), array![[2.], [4.]]);
+    /// ```
+    pub fn real(&self) -> ArrayView<'_, A, D>
+    where
+        S: Data,
+    {
+        self.view_split_complex().re
+    }
+
+    /// Return a view of the imaginary parts of the elements.
+    ///
+    /// See [`.view_split_complex()`](Self::view_split_complex).
+    pub fn imag(&self) -> ArrayView<'_, A, D>
+    where
+        S: Data,
+    {
+        self.view_split_complex().im
+    }
+
+    /// Return a mutable view of the real parts of the elements.
+    ///
+    /// See [`.view_split_complex_mut()`](Self::view_split_complex_mut).
+    pub fn real_mut(&mut self) -> ArrayViewMut<'_, A, D>
+    where
+        S: DataMut,
+    {
+        self.view_split_complex_mut().re
+    }
+
+    /// Return a mutable view of the imaginary parts of the elements.
+    ///
+    /// See [`.view_split_complex_mut()`](Self::view_split_complex_mut).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let mut a = array![Complex::new(1., 2.), Complex::new(3., 4.)];
+    /// a.imag_mut().fill(0.);
+    /// assert_eq!(a, array![Complex::new(1., 0.), Complex::new(3., 0.)]);
+    /// ```
+    pub fn imag_mut(&mut self) -> ArrayViewMut<'_, A, D>
+    where
+        S: DataMut,
+    {
+        self.view_split_complex_mut().im
+    }
+
+    /// Return an array of the complex conjugates of the elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let mut a = array![Complex::new(1., 2.), Complex::new(3., -4.)];
+    /// assert_eq!(a.conj(), array![Complex::new(1., -2.), Complex::new(3., 4.)]);
+    /// a.conj_inplace();
+    /// assert_eq!(a, array![Complex::new(1., -2.), Complex::new(3., 4.)]);
+    /// ```
+    pub fn conj(&self) -> Array<Complex<A>, D>
+    where
+        A: Clone + Neg<Output = A>,
+        S: Data,
+    {
+        self.map(|z| Complex::new(z.re.clone(), -z.im.clone()))
+    }
+
+    /// Replace the elements by their complex conjugates.
+    pub fn conj_inplace(&mut self)
+    where
+        A: Clone + Neg<Output = A>,
+        S: DataMut,
+    {
+        self.imag_mut().map_inplace(|x| *x = -x.clone());
+    }
+
+    /// Return an array of the squared magnitudes |z|² of the elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let a = array![Complex::new(3, 4), Complex::new(0, -2)];
+    /// assert_eq!(a.norm_sqr(), array![25, 4]);
+    /// ```
+    pub fn norm_sqr(&self) -> Array<A, D>
+    where
+        A: Clone + Num,
+        S: Data,
+    {
+        self.map(Complex::norm_sqr)
+    }
+}
+
+/// Return the pointers to the real and imaginary parts of the element at
+/// `ptr`, and the strides of the parts for an array of complex elements with
+/// `strides`.
+fn split_complex_parts<A, D>(ptr: NonNull<Complex<A>>, strides: &D) -> (NonNull<A>, NonNull<A>, D)
+where
+    D: Dimension,
+{
+    let mut part_strides = strides.clone();
+    for s in part_strides.slice_mut() {
+        *s = (*s as isize).wrapping_mul(2) as usize;
+    }
+    let re = ptr.cast::<A>();
+    // `Complex` is `repr(C)`, so `im` directly follows `re`. The array can be
+    // empty, so the pointer can be dangling.
+    let im = unsafe { NonNull::new_unchecked(re.as_ptr().wrapping_add(1)) };
+    (re, im, part_strides)
+}
+
+/// # Complex numbers in polar form
+///
+/// These methods require crate feature `std`.
+#[cfg(feature = "std")]
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    /// Return the magnitudes and the phases (in radians, between -π and π)
+    /// of the elements.
+    ///
+    /// ```
+    /// use ndarray::{array, Array};
+    /// use num_complex::Complex;
+    /// use std::f64::consts::PI;
+    ///
+    /// let a = array![Complex::new(0., 2.), Complex::new(-3., 0.)];
+    /// let (r, theta) = a.to_polar();
+    /// assert_eq!(r, array![2., 3.]);
+    /// assert_eq!(theta, array![PI / 2., PI]);
+    ///
+    /// let b = Array::from_polar(&r, &theta);
+    /// assert!(b.iter().zip(&a).all(|(x, y)| (x - y).norm_sqr() < 1e-24));
+    /// ```
+    pub fn to_polar(&self) -> (Array<A, D>, Array<A, D>) {
+        (self.map(|z| z.re.hypot(z.im)), self.map(|z| z.im.atan2(z.re)))
+    }
+
+    /// Return the L² norm of the array, the square root of the sum of the
+    /// squared magnitudes |z|² of the elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex;
+    ///
+    /// let a = array![Complex::new(3., 4.), Complex::new(0., 12.)];
+    /// assert_eq!(a.norm_l2(), 13.);
+    /// ```
+    pub fn norm_l2(&self) -> A {
+        self.fold(A::zero(), |acc, z| acc + z.norm_sqr()).sqrt()
+    }
+}
+
+/// # Statistics of complex numbers
+///
+/// These methods require crate feature `std`.
+#[cfg(feature = "std")]
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    /// Return the variance along `axis`, which is real: the mean of the
+    /// squared magnitudes |xᵢ - x̅|² of the differences from the mean.
+    ///
+    /// This is computed like [`.var_axis()`](ArrayBase::var_axis), with the
+    /// Welford one-pass algorithm; `ddof` is the "delta degrees of freedom".
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// the axis, or if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use num_complex::Complex;
+    ///
+    /// let a = array![[Complex::new(1., 0.), Complex::new(0., 1.)],
+    ///                [Complex::new(-1., 0.), Complex::new(0., -1.)]];
+    /// assert_eq!(a.var_axis_complex(Axis(1), 1.), array![1., 1.]);
+    /// assert_eq!(a.std_axis_complex(Axis(0), 0.), array![1., 1.]);
+    /// ```
+    pub fn var_axis_complex(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let n = A::from_usize(self.len_of(axis)).expect("Converting length to `A` must not fail.");
+        assert!(
+            !(ddof < A::zero() || ddof > n),
+            "`ddof` must not be less than zero or greater than the length of \
+             the axis",
+        );
+        let dof = n - ddof;
+        let mut mean = Array::<Complex<A>, _>::zeros(self.dim.remove_axis(axis));
+        let mut sum_sq = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        for (i, subview) in self.axis_iter(axis).enumerate() {
+            let count = A::from_usize(i + 1).expect("Converting index to `A` must not fail.");
+            Zip::from(&mut mean)
+                .and(&mut sum_sq)
+                .and(&subview)
+                .for_each(|mean, sum_sq, &x| {
+                    let delta = x - *mean;
+                    *mean = *mean + delta / count;
+                    // The real part of (x - mean) * conj(delta)
+                    let d = x - *mean;
+                    *sum_sq = *sum_sq + d.re * delta.re + d.im * delta.im;
+                });
+        }
+        sum_sq.mapv_into(|s| s / dof)
+    }
+
+    /// Return the standard deviation along `axis`, the square root of
+    /// [`.var_axis_complex()`](Self::var_axis_complex).
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// the axis, or if `axis` is out of bounds.
+    pub fn std_axis_complex(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        self.var_axis_complex(axis, ddof).mapv_into(A::sqrt)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, D> Array<Complex<A>, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Create an array of complex numbers from their magnitudes `r` and
+    /// phases `theta` (in radians).
+    ///
+    /// **Panics** if the shapes of `r` and `theta` are not the same.
+    ///
+    /// This method requires crate feature `std`.
+    pub fn from_polar<S1, S2>(r: &ArrayBase<S1, D>, theta: &ArrayBase<S2, D>) -> Self
+    where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = A>,
+    {
+        Zip::from(r)
+            .and(theta)
+            .map_collect(|&r, &theta| Complex::new(r * theta.cos(), r * theta.sin()))
+    }
+}
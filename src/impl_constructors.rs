@@ -30,7 +30,7 @@ use crate::indices;
 use crate::iterators::to_vec;
 use crate::iterators::to_vec_mapped;
 use crate::iterators::TrustedIterator;
-use crate::StrideShape;
+use crate::{ShapeBuilder, StrideShape};
 #[cfg(feature = "std")]
 use crate::{geomspace, linspace, logspace};
 use rawpointer::PointerExt;
@@ -230,6 +230,94 @@ where
         arr.diag_mut().assign(diag);
         arr
     }
+
+    /// Create a 2D array from an iterator of rows.
+    ///
+    /// Each row can be anything that converts into a one-dimensional view,
+    /// like a slice, a `Vec` reference or an array reference. The elements
+    /// are copied into a single buffer as the rows are read, so the iterator
+    /// is consumed once and the array is allocated without an intermediate
+    /// `Vec` of rows. An empty iterator gives a 0 × 0 array.
+    ///
+    /// **Errors** if the rows don't all have the same length.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let a = Array2::from_rows(&rows).unwrap();
+    /// assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert!(Array2::from_rows(&[&[1, 2][..], &[3]]).is_err());
+    /// ```
+    pub fn from_rows<'a, I, V>(rows: I) -> Result<Self, ShapeError>
+    where
+        A: Clone + 'a,
+        I: IntoIterator<Item = V>,
+        V: Into<ArrayView1<'a, A>>,
+    {
+        let (v, nrows, ncols) = collect_lanes(rows)?;
+        Self::from_shape_vec((nrows, ncols), v)
+    }
+
+    /// Create a 2D array from an iterator of columns.
+    ///
+    /// This is like [`from_rows`](Self::from_rows), but each item is a
+    /// column. The result has column major (“f” order) memory layout, which
+    /// lets the columns be copied into place as they are read.
+    ///
+    /// **Errors** if the columns don't all have the same length.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = Array2::from_columns(vec![array![1, 2], array![3, 4]].iter()).unwrap();
+    /// assert_eq!(a, array![[1, 3], [2, 4]]);
+    /// assert!(a.t().is_standard_layout());
+    /// ```
+    pub fn from_columns<'a, I, V>(columns: I) -> Result<Self, ShapeError>
+    where
+        A: Clone + 'a,
+        I: IntoIterator<Item = V>,
+        V: Into<ArrayView1<'a, A>>,
+    {
+        let (v, ncols, nrows) = collect_lanes(columns)?;
+        Self::from_shape_vec((nrows, ncols).f(), v)
+    }
+}
+
+/// Copy the elements of the lanes into one vector, and return it with the
+/// number of lanes and their common length.
+fn collect_lanes<'a, A, I, V>(lanes: I) -> Result<(Vec<A>, usize, usize), ShapeError>
+where
+    A: Clone + 'a,
+    I: IntoIterator<Item = V>,
+    V: Into<ArrayView1<'a, A>>,
+{
+    let mut lanes = lanes.into_iter();
+    let first = match lanes.next() {
+        Some(lane) => lane.into(),
+        None => return Ok((Vec::new(), 0, 0)),
+    };
+    let len = first.len();
+    // Reserve room for all lanes only if their number is known and the
+    // capacity doesn't overflow; let the vector grow otherwise
+    let capacity = match lanes.size_hint() {
+        (n, Some(m)) if n == m => n.checked_add(1).and_then(|n| n.checked_mul(len)),
+        _ => None,
+    };
+    let mut v = Vec::with_capacity(capacity.unwrap_or(len));
+    v.extend(first.iter().cloned());
+    let mut n = 1;
+    for lane in lanes {
+        let lane = lane.into();
+        if lane.len() != len {
+            return Err(error::incompatible_shapes(&Ix1(len), &lane.raw_dim()));
+        }
+        v.extend(lane.iter().cloned());
+        n += 1;
+    }
+    Ok((v, n, len))
 }
 
 #[cfg(not(debug_assertions))]
@@ -305,7 +393,7 @@ where
         A: Clone,
         Sh: ShapeBuilder<Dim = D>,
     {
-        let shape = shape.into_shape();
+        let shape = shape.into_shape().with_current_default_order();
         let size = size_of_shape_checked_unwrap!(&shape.dim);
         let v = vec![elem; size];
         unsafe { Self::from_shape_vec_unchecked(shape, v) }
@@ -359,7 +447,7 @@ where
         Sh: ShapeBuilder<Dim = D>,
         F: FnMut() -> A,
     {
-        let shape = shape.into_shape();
+        let shape = shape.into_shape().with_current_default_order();
         let len = size_of_shape_checked_unwrap!(&shape.dim);
         let v = to_vec_mapped(0..len, move |_| f());
         unsafe { Self::from_shape_vec_unchecked(shape, v) }
@@ -390,7 +478,7 @@ where
         Sh: ShapeBuilder<Dim = D>,
         F: FnMut(D::Pattern) -> A,
     {
-        let shape = shape.into_shape();
+        let shape = shape.into_shape().with_current_default_order();
         let _ = size_of_shape_checked_unwrap!(&shape.dim);
         if shape.is_c() {
             let v = to_vec_mapped(indices(shape.dim.clone()).into_iter(), f);
@@ -443,6 +531,50 @@ where
         Self::from_shape_vec_impl(shape.into(), v)
     }
 
+    /// Create an array with the given shape from the elements of an
+    /// iterator.
+    ///
+    /// The elements fill the array in the memory order of `shape`: row major
+    /// by default, or column major with `.f()`. This is the same as
+    /// collecting into a `Vec` and using
+    /// [`from_shape_vec`](Self::from_shape_vec), but the vector is
+    /// allocated once with the size of the shape, and at most one element
+    /// more than the shape holds is read from the iterator.
+    ///
+    /// **Errors** if the iterator has fewer or more elements than `shape`,
+    /// or if the shape would result in overflowing `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array, ShapeBuilder};
+    ///
+    /// let a = Array::from_iter_with_shape((2, 3), 0..6).unwrap();
+    /// assert_eq!(a, arr2(&[[0, 1, 2], [3, 4, 5]]));
+    ///
+    /// let b = Array::from_iter_with_shape((2, 3).f(), 0..6).unwrap();
+    /// assert_eq!(b, arr2(&[[0, 2, 4], [1, 3, 5]]));
+    ///
+    /// assert!(Array::from_iter_with_shape((2, 3), 0..5).is_err());
+    /// assert!(Array::from_iter_with_shape((2, 3), 0..).is_err());
+    /// ```
+    pub fn from_iter_with_shape<Sh, I>(shape: Sh, iterable: I) -> Result<Self, ShapeError>
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        I: IntoIterator<Item = A>,
+    {
+        let shape = shape.into_shape();
+        let size = dimension::size_of_shape_checked(&shape.dim)?;
+        let mut iter = iterable.into_iter();
+        let mut v = Vec::with_capacity(size);
+        v.extend(iter.by_ref().take(size));
+        if v.len() != size {
+            return Err(error::incompatible_shapes(&Ix1(v.len()), &shape.dim));
+        }
+        if iter.next().is_some() {
+            return Err(error::from_kind(error::ErrorKind::IncompatibleShape));
+        }
+        unsafe { Ok(Self::from_shape_vec_unchecked(shape, v)) }
+    }
+
     fn from_shape_vec_impl(shape: StrideShape<D>, v: Vec<A>) -> Result<Self, ShapeError> {
         let dim = shape.dim;
         let is_custom = shape.strides.is_custom();
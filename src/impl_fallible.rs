@@ -0,0 +1,237 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num_traits::Zero;
+
+use crate::error::{self, AxisError};
+use crate::imp_prelude::*;
+use crate::{DimMax, IntoDimension, ShapeError, Zip};
+
+macro_rules! try_binary_op {
+    ($(#[$meta:meta])* $name:ident, $trt:ident, $mth:ident) => {
+        $(#[$meta])*
+        ///
+        /// If their shapes disagree, `self` and `rhs` are broadcast to their
+        /// common shape, like for the arithmetic operators.
+        ///
+        /// **Errors** if broadcasting isn’t possible; the error holds the
+        /// shapes of `self` and `rhs`.
+        pub fn $name<B, S2, E>(&self, rhs: &ArrayBase<S2, E>)
+            -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+        where
+            A: Clone + $trt<B, Output = A>,
+            B: Clone,
+            S: Data,
+            S2: Data<Elem = B>,
+            D: DimMax<E>,
+            E: Dimension,
+        {
+            let (lhs, rhs) = self.broadcast_with(rhs)?;
+            Ok(Zip::from(&lhs).and(&rhs).map_collect(|a, b| a.clone().$mth(b.clone())))
+        }
+    };
+}
+
+/// # Fallible Methods
+///
+/// These are variants of methods that panic on an out of bounds axis or
+/// index, or on incompatible shapes, which return an error instead. They
+/// can be used to validate shapes and indices from untrusted input.
+///
+/// The errors say which axis or index was out of bounds, or which shapes
+/// were incompatible (see [`ShapeError::shapes()`]).
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Return an error if `axis` is out of bounds.
+    fn check_axis(&self, axis: Axis) -> Result<(), AxisError> {
+        if axis.index() < self.ndim() {
+            Ok(())
+        } else {
+            Err(AxisError::AxisOutOfBounds {
+                axis: axis.index(),
+                ndim: self.ndim(),
+            })
+        }
+    }
+
+    /// Return an error if `axis` or `index` is out of bounds.
+    fn check_axis_index(&self, axis: Axis, index: usize) -> Result<(), AxisError> {
+        self.check_axis(axis)?;
+        let len = self.len_of(axis);
+        if index < len {
+            Ok(())
+        } else {
+            Err(AxisError::IndexOutOfBounds {
+                axis: axis.index(),
+                index,
+                len,
+            })
+        }
+    }
+
+    /// Return a view restricted to `index` along the axis, with the axis
+    /// removed, like [`.index_axis()`](Self::index_axis).
+    ///
+    /// **Errors** if `axis` or `index` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, AxisError};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(a.try_index_axis(Axis(0), 1), Ok(array![3, 4].view()));
+    /// assert_eq!(
+    ///     a.try_index_axis(Axis(1), 2),
+    ///     Err(AxisError::IndexOutOfBounds { axis: 1, index: 2, len: 2 })
+    /// );
+    /// assert_eq!(
+    ///     a.try_index_axis(Axis(2), 0),
+    ///     Err(AxisError::AxisOutOfBounds { axis: 2, ndim: 2 })
+    /// );
+    /// ```
+    pub fn try_index_axis(&self, axis: Axis, index: usize) -> Result<ArrayView<'_, A, D::Smaller>, AxisError>
+    where
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.view().try_index_axis_move(axis, index)
+    }
+
+    /// Return a mutable view restricted to `index` along the axis, with the
+    /// axis removed, like [`.index_axis_mut()`](Self::index_axis_mut).
+    ///
+    /// **Errors** if `axis` or `index` is out of bounds.
+    pub fn try_index_axis_mut(&mut self, axis: Axis, index: usize) -> Result<ArrayViewMut<'_, A, D::Smaller>, AxisError>
+    where
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        self.check_axis_index(axis, index)?;
+        Ok(self.index_axis_mut(axis, index))
+    }
+
+    /// Collapse the array to `index` along the axis and remove the axis,
+    /// like [`.index_axis_move()`](Self::index_axis_move).
+    ///
+    /// **Errors** if `axis` or `index` is out of bounds.
+    pub fn try_index_axis_move(self, axis: Axis, index: usize) -> Result<ArrayBase<S, D::Smaller>, AxisError>
+    where
+        D: RemoveAxis,
+    {
+        self.check_axis_index(axis, index)?;
+        Ok(self.index_axis_move(axis, index))
+    }
+
+    /// Insert a new axis of length one at `axis`, like
+    /// [`.insert_axis()`](Self::insert_axis).
+    ///
+    /// **Errors** if `axis` is greater than the number of dimensions.
+    pub fn try_insert_axis(self, axis: Axis) -> Result<ArrayBase<S, D::Larger>, AxisError> {
+        if axis.index() > self.ndim() {
+            return Err(AxisError::AxisOutOfBounds {
+                axis: axis.index(),
+                ndim: self.ndim(),
+            });
+        }
+        Ok(self.insert_axis(axis))
+    }
+
+    /// Return a view of the array broadcast to the shape `dim`, like
+    /// [`.broadcast()`](Self::broadcast).
+    ///
+    /// **Errors** if the array can't be broadcast to `dim`; the error holds
+    /// the shape of the array and `dim`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3]];
+    /// assert_eq!(a.try_broadcast((2, 3)).unwrap(), array![[1, 2, 3], [1, 2, 3]]);
+    ///
+    /// let err = a.try_broadcast((3, 2)).unwrap_err();
+    /// assert_eq!(err.shapes(), Some((&[1, 3][..], &[3, 2][..])));
+    /// ```
+    pub fn try_broadcast<E>(&self, dim: E) -> Result<ArrayView<'_, A, E::Dim>, ShapeError>
+    where
+        E: IntoDimension,
+        S: Data,
+    {
+        let dim = dim.into_dimension();
+        match self.broadcast(dim.clone()) {
+            Some(view) => Ok(view),
+            None => Err(error::incompatible_shapes(&self.dim, &dim)),
+        }
+    }
+
+    /// Return the sum of the elements along `axis`, like
+    /// [`.sum_axis()`](Self::sum_axis).
+    ///
+    /// **Errors** if `axis` is out of bounds.
+    pub fn try_sum_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, AxisError>
+    where
+        A: Clone + Zero + Add<Output = A>,
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.check_axis(axis)?;
+        Ok(self.sum_axis(axis))
+    }
+
+    /// Select the subviews at `indices` along `axis` and concatenate them,
+    /// like [`.select()`](Self::select).
+    ///
+    /// **Errors** if `axis` or any of the indices is out of bounds; the
+    /// error holds the first index that is out of bounds.
+    pub fn try_select(&self, axis: Axis, indices: &[Ix]) -> Result<Array<A, D>, AxisError>
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.check_axis(axis)?;
+        for &index in indices {
+            self.check_axis_index(axis, index)?;
+        }
+        Ok(self.select(axis, indices))
+    }
+
+    try_binary_op! {
+        /// Return the elementwise sum of `self` and `rhs`.
+        ///
+        /// ```
+        /// use ndarray::array;
+        ///
+        /// let a = array![[1], [2], [3]];
+        /// let b = array![[10, 20, 30, 40]];
+        /// assert_eq!(a.try_add(&b).unwrap().shape(), &[3, 4]);
+        ///
+        /// let err = a.try_add(&array![[1, 2], [3, 4]]).unwrap_err();
+        /// assert_eq!(err.shapes(), Some((&[3, 1][..], &[2, 2][..])));
+        /// ```
+        try_add, Add, add
+    }
+
+    try_binary_op! {
+        /// Return the elementwise difference of `self` and `rhs`.
+        try_sub, Sub, sub
+    }
+
+    try_binary_op! {
+        /// Return the elementwise product of `self` and `rhs`.
+        try_mul, Mul, mul
+    }
+
+    try_binary_op! {
+        /// Return the elementwise quotient of `self` and `rhs`.
+        try_div, Div, div
+    }
+}
@@ -6,7 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::mem::{size_of, ManuallyDrop};
+use std::ops::Range;
+use std::mem::{size_of, ManuallyDrop, MaybeUninit};
 use alloc::slice;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -34,9 +35,11 @@ use crate::zip::{IntoNdProducer, Zip};
 
 use crate::iter::{
     AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, ExactChunks, ExactChunksMut,
-    IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
+    IndexedIter, IndexedIterMut, Iter, IterMut, IterPtrs, Lanes, LanesMut, Windows, AxisWindows,
+    OverlappingChunks,
 };
-use crate::slice::{MultiSliceArg, SliceArg};
+use crate::iterators::ChunkTail;
+use crate::slice::{GatherElem, MultiSliceArg, SliceArg};
 use crate::stacking::concatenate;
 use crate::{NdIndex, Slice, SliceInfoElem};
 
@@ -393,6 +396,79 @@ where
         self.view_mut().into_iter_()
     }
 
+    /// Return an iterator of references to the elements of the array, in
+    /// the order of the elements in memory.
+    ///
+    /// The axes are visited from the largest stride to the smallest, each in
+    /// the direction of increasing memory address, so that a contiguous
+    /// array is visited like a slice, whatever its memory layout. Use this
+    /// instead of [`.iter()`](Self::iter), which visits the elements in
+    /// logical order, when the order doesn't matter, for example to add up
+    /// the elements.
+    ///
+    /// Iterator element type is `&A`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array, ShapeBuilder};
+    ///
+    /// let a = Array::from_shape_vec((2, 3).f(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(a, array![[1, 3, 5], [2, 4, 6]]);
+    /// assert!(a.iter_memory_order().eq(&[1, 2, 3, 4, 5, 6]));
+    /// assert!(a.iter().eq(&[1, 3, 5, 2, 4, 6]));
+    /// ```
+    pub fn iter_memory_order(&self) -> Iter<'_, A, D>
+    where
+        S: Data,
+    {
+        self.view().into_memory_order().into_iter_()
+    }
+
+    /// Return an iterator of mutable references to the elements of the
+    /// array, in the order of the elements in memory.
+    ///
+    /// See [`.iter_memory_order()`](Self::iter_memory_order) for the order,
+    /// and use [`.iter_mut()`](Self::iter_mut) for logical order.
+    ///
+    /// Iterator element type is `&mut A`.
+    pub fn iter_memory_order_mut(&mut self) -> IterMut<'_, A, D>
+    where
+        S: DataMut,
+    {
+        self.view_mut().into_memory_order().into_iter_()
+    }
+
+    /// Return an iterator of raw pointers to the elements of the array, in
+    /// row major (logical) order or column major order.
+    ///
+    /// The pointers are valid for reads as long as the array is not mutated,
+    /// moved or dropped, also after the iterator is gone. This is useful for
+    /// building lists of element addresses for foreign functions, like the
+    /// buffers of a vectored write, without computing the offsets from the
+    /// strides.
+    ///
+    /// Iterator element type is `*const A`.
+    ///
+    /// ```
+    /// use ndarray::{array, Order};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let ptrs: Vec<*const i32> = a.iter_ptrs(Order::ColumnMajor).collect();
+    /// assert_eq!(ptrs[1], &a[[1, 0]] as *const i32);
+    /// assert_eq!(unsafe { *ptrs[2] }, 2);
+    ///
+    /// let ptrs: Vec<*const i32> = a.iter_ptrs(Order::RowMajor).collect();
+    /// assert_eq!(unsafe { *ptrs[2] }, 3);
+    /// ```
+    pub fn iter_ptrs(&self, order: Order) -> IterPtrs<'_, A, D>
+    where
+        S: Data,
+    {
+        match order {
+            Order::RowMajor => IterPtrs::new(self.view()),
+            Order::ColumnMajor => IterPtrs::new(self.view().reversed_axes()),
+        }
+    }
+
     /// Return an iterator of indexes and references to the elements of the array.
     ///
     /// Elements are visited in the *logical order* of the array, which
@@ -611,6 +687,78 @@ where
         view_mut
     }
 
+    /// Return a view of the elements `range` along `axis`, *without* checking
+    /// that the range is in bounds.
+    ///
+    /// This is the unchecked version of `.slice_axis(axis, Slice::from(range))`,
+    /// for inner loops where the range is known to be valid. The axis itself
+    /// is still checked.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// **Note:** the range is only checked in debug builds of ndarray.
+    ///
+    /// # Safety
+    ///
+    /// `range.start <= range.end <= self.len_of(axis)` must hold.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    /// let v = unsafe { a.slice_axis_unchecked(Axis(1), 1..3) };
+    /// assert_eq!(v, arr2(&[[2, 3], [5, 6]]));
+    /// ```
+    pub unsafe fn slice_axis_unchecked(&self, axis: Axis, range: Range<usize>) -> ArrayView<'_, A, D>
+    where
+        S: Data,
+    {
+        let mut view = self.view();
+        view.slice_axis_unchecked_inplace(axis, range);
+        view
+    }
+
+    /// Return a mutable view of the elements `range` along `axis`, *without*
+    /// checking that the range is in bounds.
+    ///
+    /// See [`.slice_axis_unchecked()`](Self::slice_axis_unchecked).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// **Note:** the range is only checked in debug builds of ndarray.
+    ///
+    /// # Safety
+    ///
+    /// `range.start <= range.end <= self.len_of(axis)` must hold.
+    pub unsafe fn slice_axis_unchecked_mut(
+        &mut self,
+        axis: Axis,
+        range: Range<usize>,
+    ) -> ArrayViewMut<'_, A, D>
+    where
+        S: DataMut,
+    {
+        let mut view = self.view_mut();
+        view.slice_axis_unchecked_inplace(axis, range);
+        view
+    }
+
+    unsafe fn slice_axis_unchecked_inplace(&mut self, axis: Axis, range: Range<usize>) {
+        let len = self.len_of(axis);
+        debug_assert!(
+            range.start <= range.end && range.end <= len,
+            "slice_axis_unchecked: range {:?} out of bounds for axis of length {}",
+            range,
+            len
+        );
+        // Don't offset the pointer for an empty range, which could be past
+        // the end of the axis
+        if range.start < range.end {
+            self.ptr = self.ptr.offset(stride_offset(range.start, self.strides[axis.index()]));
+        }
+        self.dim[axis.index()] = range.end - range.start;
+    }
+
     /// Slice the array in place along the specified axis.
     ///
     /// **Panics** if an index is out of bounds or step size is zero.<br>
@@ -843,6 +991,172 @@ where
         );
     }
 
+    /// Swap the elements of each pair of indices in `pairs`, in order.
+    ///
+    /// This is equivalent to calling [`.swap()`](Self::swap) for each pair,
+    /// but makes sure that the data is uniquely held only once. Indices in a
+    /// pair may be equal. See [`.uswap()`](Self::uswap) for swapping without
+    /// bounds checks.
+    ///
+    /// ***Panics*** if an index is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2], [3, 4]];
+    /// a.swap_many(&[([0, 0], [1, 1]), ([0, 1], [1, 0])]);
+    /// assert_eq!(a, array![[4, 3], [2, 1]]);
+    /// ```
+    pub fn swap_many<I>(&mut self, pairs: &[(I, I)])
+    where
+        S: DataMut,
+        I: NdIndex<D>,
+    {
+        let ptr = self.as_mut_ptr();
+        for (index1, index2) in pairs {
+            match (
+                index1.index_checked(&self.dim, &self.strides),
+                index2.index_checked(&self.dim, &self.strides),
+            ) {
+                (Some(offset1), Some(offset2)) => unsafe {
+                    std::ptr::swap(ptr.offset(offset1), ptr.offset(offset2));
+                },
+                _ => panic!("swap_many: index out of bounds for indices {:?} {:?}", index1, index2),
+            }
+        }
+    }
+
+    /// Rotate the elements along `axis` in place, so that the elements at
+    /// index `mid` become the first, and the first `mid` elements become
+    /// the last.
+    ///
+    /// This is [`slice::rotate_left`] for each lane along `axis`; the elements
+    /// are moved by swapping, without allocating.
+    ///
+    /// ***Panics*** if `axis` is out of bounds or if `mid` is greater than the
+    /// length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3, 4], [5, 6, 7, 8]];
+    /// a.rotate_axis_left(Axis(1), 1);
+    /// assert_eq!(a, array![[2, 3, 4, 1], [6, 7, 8, 5]]);
+    /// a.rotate_axis_left(Axis(0), 1);
+    /// assert_eq!(a, array![[6, 7, 8, 5], [2, 3, 4, 1]]);
+    /// ```
+    pub fn rotate_axis_left(&mut self, axis: Axis, mid: usize)
+    where
+        S: DataMut,
+    {
+        let len = self.len_of(axis);
+        assert!(
+            mid <= len,
+            "rotate_axis_left: mid {} is greater than axis length {}",
+            mid,
+            len
+        );
+        if mid == 0 || mid == len {
+            return;
+        }
+        // Rotate by three reversals
+        let (front, back) = self.view_mut().split_at(axis, mid);
+        reverse_axis(front, axis);
+        reverse_axis(back, axis);
+        reverse_axis(self.view_mut(), axis);
+    }
+
+    /// Rotate the elements along `axis` in place, so that the last `k`
+    /// elements become the first.
+    ///
+    /// This is [`slice::rotate_right`] for each lane along `axis`; see
+    /// [`.rotate_axis_left()`](Self::rotate_axis_left).
+    ///
+    /// ***Panics*** if `axis` is out of bounds or if `k` is greater than the
+    /// length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![1, 2, 3, 4, 5];
+    /// a.rotate_axis_right(Axis(0), 2);
+    /// assert_eq!(a, array![4, 5, 1, 2, 3]);
+    /// ```
+    pub fn rotate_axis_right(&mut self, axis: Axis, k: usize)
+    where
+        S: DataMut,
+    {
+        let len = self.len_of(axis);
+        assert!(
+            k <= len,
+            "rotate_axis_right: k {} is greater than axis length {}",
+            k,
+            len
+        );
+        self.rotate_axis_left(axis, len - k);
+    }
+
+    /// Return a new array with the elements shifted cyclically by `shift`
+    /// along `axis`: the element at index `i` moves to index `i + shift`,
+    /// wrapping around the end of the axis.
+    ///
+    /// `shift` can be negative and larger than the axis length. See
+    /// [`.roll_axis_inplace()`](Self::roll_axis_inplace) for the in-place
+    /// version.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.roll_axis_by(Axis(1), 1), array![[3, 1, 2], [6, 4, 5]]);
+    /// assert_eq!(a.roll_axis_by(Axis(1), -4), array![[2, 3, 1], [5, 6, 4]]);
+    /// assert_eq!(a.roll_axis_by(Axis(0), 1), array![[4, 5, 6], [1, 2, 3]]);
+    /// ```
+    pub fn roll_axis_by(&self, axis: Axis, shift: isize) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        let len = self.len_of(axis);
+        let k = roll_offset(shift, len);
+        let mut rolled = Array::uninit(self.raw_dim());
+        self.slice_axis(axis, Slice::from(..len - k))
+            .assign_to(rolled.slice_axis_mut(axis, Slice::from(k..)));
+        self.slice_axis(axis, Slice::from(len - k..))
+            .assign_to(rolled.slice_axis_mut(axis, Slice::from(..k)));
+        unsafe {
+            // Safe because every element was assigned above
+            rolled.assume_init()
+        }
+    }
+
+    /// Shift the elements cyclically by `shift` along `axis`, in place: the
+    /// element at index `i` moves to index `i + shift`, wrapping around the
+    /// end of the axis.
+    ///
+    /// `shift` can be negative and larger than the axis length. The elements
+    /// are moved by swapping, without allocating; see
+    /// [`.rotate_axis_right()`](Self::rotate_axis_right).
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![0, 1, 2, 3, 4];
+    /// a.roll_axis_inplace(Axis(0), -2);
+    /// assert_eq!(a, array![2, 3, 4, 0, 1]);
+    /// ```
+    pub fn roll_axis_inplace(&mut self, axis: Axis, shift: isize)
+    where
+        S: DataMut,
+    {
+        let k = roll_offset(shift, self.len_of(axis));
+        self.rotate_axis_right(axis, k);
+    }
+
     // `get` for zero-dimensional arrays
     // panics if dimension is not zero. otherwise an element is always present.
     fn get_0d(&self) -> &A
@@ -941,6 +1255,80 @@ where
         debug_assert!(self.pointer_is_inbounds());
     }
 
+    /// Return a view restricted to `index` along `axis`, with the axis
+    /// removed, *without* checking that `index` is in bounds.
+    ///
+    /// This is the unchecked version of [`.index_axis()`](Self::index_axis),
+    /// for inner loops where the index is known to be valid. The axis itself
+    /// is still checked.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// **Note:** the index is only checked in debug builds of ndarray.
+    ///
+    /// # Safety
+    ///
+    /// `index < self.len_of(axis)` must hold.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2], [3, 4]]);
+    /// let mut sum = arr1(&[0, 0]);
+    /// for i in 0..a.nrows() {
+    ///     // Safe because `i` is less than the number of rows
+    ///     sum += &unsafe { a.index_axis_unchecked(Axis(0), i) };
+    /// }
+    /// assert_eq!(sum, arr1(&[4, 6]));
+    /// ```
+    pub unsafe fn index_axis_unchecked(&self, axis: Axis, index: usize) -> ArrayView<'_, A, D::Smaller>
+    where
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.view().index_axis_unchecked_move(axis, index)
+    }
+
+    /// Return a mutable view restricted to `index` along `axis`, with the
+    /// axis removed, *without* checking that `index` is in bounds.
+    ///
+    /// See [`.index_axis_unchecked()`](Self::index_axis_unchecked).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// **Note:** the index is only checked in debug builds of ndarray.
+    ///
+    /// # Safety
+    ///
+    /// `index < self.len_of(axis)` must hold.
+    pub unsafe fn index_axis_unchecked_mut(
+        &mut self,
+        axis: Axis,
+        index: usize,
+    ) -> ArrayViewMut<'_, A, D::Smaller>
+    where
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        self.view_mut().index_axis_unchecked_move(axis, index)
+    }
+
+    unsafe fn index_axis_unchecked_move(mut self, axis: Axis, index: usize) -> ArrayBase<S, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        debug_assert!(
+            index < self.len_of(axis),
+            "index_axis_unchecked: index {} out of bounds for axis of length {}",
+            index,
+            self.len_of(axis)
+        );
+        self.ptr = self.ptr.offset(stride_offset(index, self.strides[axis.index()]));
+        let dim = self.dim.remove_axis(axis);
+        let strides = self.strides.remove_axis(axis);
+        self.with_strides_dim(strides, dim)
+    }
+
     /// Along `axis`, select arbitrary subviews corresponding to `indices`
     /// and and copy them into a new array.
     ///
@@ -1000,71 +1388,339 @@ where
         }
     }
 
-    /// Return a producer and iterable that traverses over the *generalized*
-    /// rows of the array. For a 2D array these are the regular rows.
-    ///
-    /// This is equivalent to `.lanes(Axis(n - 1))` where *n* is `self.ndim()`.
-    ///
-    /// For an array of dimensions *a* × *b* × *c* × ... × *l* × *m*
-    /// it has *a* × *b* × *c* × ... × *l* rows each of length *m*.
+    /// Slice the array and select lists of indices along its axes, and copy
+    /// the result into a new array.
     ///
-    /// For example, in a 2 × 2 × 3 array, each row is 3 elements long
-    /// and there are 2 × 2 = 4 rows in total.
+    /// `info` has one [`GatherElem`](crate::GatherElem) for each axis, a
+    /// slice or a list of indices, and is usually created with the
+    /// [`gather![]`](crate::gather!) macro. The axes with a list of indices
+    /// are selected like with [`.select()`](Self::select), and the other
+    /// axes are sliced like with [`.slice()`](Self::slice). The lists of
+    /// indices of different axes are applied independently, so that the
+    /// result has the length of the list along each of these axes.
     ///
-    /// Iterator element is `ArrayView1<A>` (1D array view).
+    /// **Panics** if the length of `info` is not the number of axes, if a
+    /// slice is out of bounds or has step size zero, or if an index is out of
+    /// bounds.
     ///
     /// ```
-    /// use ndarray::arr3;
+    /// use ndarray::{gather, Array};
     ///
-    /// let a = arr3(&[[[ 0,  1,  2],    // -- row 0, 0
-    ///                 [ 3,  4,  5]],   // -- row 0, 1
-    ///                [[ 6,  7,  8],    // -- row 1, 0
-    ///                 [ 9, 10, 11]]]); // -- row 1, 1
+    /// let a = Array::from_shape_fn((50, 8), |(i, j)| 10 * i + j);
     ///
-    /// // `rows` will yield the four generalized rows of the array.
-    /// for row in a.rows() {
-    ///     /* loop body */
-    /// }
+    /// // Rows 3, 17 and 42, and the columns 1..4
+    /// let b = a.gather(&gather![[3, 17, 42], 1..4]);
+    /// assert_eq!(b, ndarray::array![[31, 32, 33], [171, 172, 173], [421, 422, 423]]);
     /// ```
-    pub fn rows(&self) -> Lanes<'_, A, D::Smaller>
+    pub fn gather(&self, info: &[GatherElem]) -> Array<A, D>
     where
+        A: Clone,
         S: Data,
+        D: RemoveAxis,
     {
-        let mut n = self.ndim();
-        if n == 0 {
-            n += 1;
+        assert_eq!(
+            info.len(),
+            self.ndim(),
+            "gather: expected {} axes, got {}",
+            self.ndim(),
+            info.len()
+        );
+        let view = self.slice_each_axis(|ax| match info[ax.axis.index()] {
+            GatherElem::Slice(slice) => slice,
+            GatherElem::Indices(_) => Slice::from(..),
+        });
+        let mut result: Option<Array<A, D>> = None;
+        for (axis, elem) in info.iter().enumerate() {
+            if let GatherElem::Indices(indices) = elem {
+                result = Some(match result {
+                    None => view.select(Axis(axis), indices),
+                    Some(result) => result.select(Axis(axis), indices),
+                });
+            }
         }
-        Lanes::new(self.view(), Axis(n - 1))
+        result.unwrap_or_else(|| view.to_owned())
     }
 
-    #[deprecated(note="Renamed to .rows()", since="0.15.0")]
-    pub fn genrows(&self) -> Lanes<'_, A, D::Smaller>
+    /// Repeat each subview along `axis` `n` times, and return the result
+    /// as a new array.
+    ///
+    /// The length of `axis` is multiplied by `n`. See [`.tile()`](Self::tile)
+    /// to repeat the whole array instead.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// assert_eq!(a.repeat_axis(Axis(0), 2), array![[1, 2], [1, 2], [3, 4], [3, 4]]);
+    /// assert_eq!(a.repeat_axis(Axis(1), 3), array![[1, 1, 1, 2, 2, 2], [3, 3, 3, 4, 4, 4]]);
+    /// ```
+    pub fn repeat_axis(&self, axis: Axis, n: usize) -> Array<A, D>
     where
+        A: Clone,
         S: Data,
+        D: RemoveAxis,
     {
-        self.rows()
+        let indices: Vec<Ix> = (0..self.len_of(axis) * n).map(|i| i / n).collect();
+        self.select(axis, &indices)
     }
 
-    /// Return a producer and iterable that traverses over the *generalized*
-    /// rows of the array and yields mutable array views.
+    /// Repeat the whole array `reps[i]` times along each axis `i`, and
+    /// return the result as a new array.
     ///
-    /// Iterator element is `ArrayView1<A>` (1D read-write array view).
-    pub fn rows_mut(&mut self) -> LanesMut<'_, A, D::Smaller>
+    /// The length of each axis is multiplied by its number of repetitions.
+    /// See [`.repeat_axis()`](Self::repeat_axis) to repeat each element
+    /// instead, and [`.broadcast()`](Self::broadcast) for a view that repeats
+    /// the array along new or length one axes without copying.
+    ///
+    /// **Panics** if the length of `reps` is not the number of axes.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// assert_eq!(a.tile(&[1, 2]), array![[1, 2, 1, 2], [3, 4, 3, 4]]);
+    /// assert_eq!(a.tile(&[2, 1]), array![[1, 2], [3, 4], [1, 2], [3, 4]]);
+    /// ```
+    pub fn tile(&self, reps: &[usize]) -> Array<A, D>
     where
-        S: DataMut,
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
     {
-        let mut n = self.ndim();
-        if n == 0 {
-            n += 1;
+        assert_eq!(
+            reps.len(),
+            self.ndim(),
+            "tile: expected repetitions for {} axes, got {}",
+            self.ndim(),
+            reps.len()
+        );
+        let mut tiled = self.to_owned();
+        for (axis, &n) in reps.iter().enumerate() {
+            if n != 1 {
+                let len = self.len_of(Axis(axis));
+                let indices: Vec<Ix> = (0..n).flat_map(|_| 0..len).collect();
+                tiled = tiled.select(Axis(axis), &indices);
+            }
         }
-        LanesMut::new(self.view_mut(), Axis(n - 1))
+        tiled
     }
 
-    #[deprecated(note="Renamed to .rows_mut()", since="0.15.0")]
-    pub fn genrows_mut(&mut self) -> LanesMut<'_, A, D::Smaller>
-    where
-        S: DataMut,
-    {
+    /// Gather elements along `axis` using an array of indices.
+    ///
+    /// `indices` has the same number of dimensions as `self`. Each lane of
+    /// `indices` along `axis` picks elements from the corresponding lane of
+    /// `self`, so that, for example in two dimensions with `axis` 0, the
+    /// result is:
+    ///
+    /// ```text
+    /// result[[i, j]] = self[[indices[[i, j]], j]]
+    /// ```
+    ///
+    /// Along the other axes, `self` and `indices` are broadcast together; the
+    /// result has the length of `indices` along `axis`. This is the
+    /// counterpart of NumPy's `take_along_axis`, and can be used to apply the
+    /// result of a sort or arg-max along an axis to the data.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the number of dimensions
+    /// differ, if the shapes can't be broadcast together, or if an index is
+    /// out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[10, 30, 20],
+    ///                [60, 40, 50]];
+    /// // Index of the largest element in each row
+    /// let argmax = array![[1], [0]];
+    /// assert_eq!(a.take_along_axis(Axis(1), &argmax), array![[30], [60]]);
+    ///
+    /// let order = array![[0, 2, 1], [1, 2, 0]];
+    /// assert_eq!(a.take_along_axis(Axis(1), &order), array![[10, 20, 30], [40, 50, 60]]);
+    /// ```
+    pub fn take_along_axis<S2>(&self, axis: Axis, indices: &ArrayBase<S2, D>) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = Ix>,
+    {
+        assert_eq!(
+            self.ndim(),
+            indices.ndim(),
+            "ndarray: take_along_axis: indices must have the same number of dimensions as the array"
+        );
+        let axis_len = self.len_of(axis);
+        let mut dim = indices.raw_dim();
+        for (ax, (len, &self_len)) in dim.slice_mut().iter_mut().zip(self.shape()).enumerate() {
+            if ax == axis.index() || *len == self_len || self_len == 1 {
+                continue;
+            }
+            if *len == 1 {
+                *len = self_len;
+            } else {
+                panic!(
+                    "ndarray: could not broadcast indices of shape {:?} with array of shape {:?}",
+                    indices.shape(),
+                    self.shape()
+                );
+            }
+        }
+        let mut src_dim = dim.clone();
+        src_dim.set_axis(axis, axis_len);
+        let src = self.broadcast(src_dim).unwrap();
+        let indices = indices.broadcast(dim.clone()).unwrap();
+
+        let mut out = Array::uninit(dim);
+        Zip::from(out.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(src.lanes(axis))
+            .for_each(|out_lane, index_lane, src_lane| {
+                Zip::from(out_lane)
+                    .and(index_lane)
+                    .for_each(|out, &index| {
+                        if index >= axis_len {
+                            panic!("ndarray: index {} is out of bounds in array of len {}",
+                                   index, axis_len);
+                        }
+                        *out = MaybeUninit::new(src_lane[index].clone());
+                    });
+            });
+        unsafe {
+            // Safe because every element was assigned above
+            out.assume_init()
+        }
+    }
+
+    /// Scatter `values` along `axis` into `self` using an array of indices.
+    ///
+    /// `indices` has the same number of dimensions as `self`. Each lane of
+    /// `indices` along `axis` picks the elements of the corresponding lane of
+    /// `self` to assign, so that, for example in two dimensions with `axis` 0:
+    ///
+    /// ```text
+    /// self[[indices[[i, j]], j]] = values[[i, j]]
+    /// ```
+    ///
+    /// Along the other axes, `indices` is broadcast to the shape of `self`;
+    /// `values` is broadcast to the shape of the broadcast `indices`. If an
+    /// index is repeated in a lane, the last value is the one that remains.
+    /// This is the counterpart of NumPy's `put_along_axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the number of dimensions
+    /// differ, if broadcasting isn’t possible, or if an index is out of
+    /// bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[10, 30, 20],
+    ///                    [60, 40, 50]];
+    /// let argmax = array![[1], [0]];
+    /// a.put_along_axis(Axis(1), &argmax, &array![0]);
+    /// assert_eq!(a, array![[10, 0, 20], [0, 40, 50]]);
+    /// ```
+    pub fn put_along_axis<S2, S3, E>(
+        &mut self,
+        axis: Axis,
+        indices: &ArrayBase<S2, D>,
+        values: &ArrayBase<S3, E>,
+    ) where
+        A: Clone,
+        S: DataMut,
+        S2: Data<Elem = Ix>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+    {
+        assert_eq!(
+            self.ndim(),
+            indices.ndim(),
+            "ndarray: put_along_axis: indices must have the same number of dimensions as the array"
+        );
+        let axis_len = self.len_of(axis);
+        let mut dim = self.raw_dim();
+        dim.set_axis(axis, indices.len_of(axis));
+        let indices = indices.broadcast_unwrap(dim.clone());
+        let values = values.broadcast_unwrap(dim);
+        Zip::from(self.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(values.lanes(axis))
+            .for_each(|mut lane, index_lane, value_lane| {
+                for (&index, value) in index_lane.iter().zip(value_lane) {
+                    if index >= axis_len {
+                        panic!("ndarray: index {} is out of bounds in array of len {}",
+                               index, axis_len);
+                    }
+                    lane[index] = value.clone();
+                }
+            });
+    }
+
+    /// Return a producer and iterable that traverses over the *generalized*
+    /// rows of the array. For a 2D array these are the regular rows.
+    ///
+    /// This is equivalent to `.lanes(Axis(n - 1))` where *n* is `self.ndim()`.
+    ///
+    /// For an array of dimensions *a* × *b* × *c* × ... × *l* × *m*
+    /// it has *a* × *b* × *c* × ... × *l* rows each of length *m*.
+    ///
+    /// For example, in a 2 × 2 × 3 array, each row is 3 elements long
+    /// and there are 2 × 2 = 4 rows in total.
+    ///
+    /// Iterator element is `ArrayView1<A>` (1D array view).
+    ///
+    /// ```
+    /// use ndarray::arr3;
+    ///
+    /// let a = arr3(&[[[ 0,  1,  2],    // -- row 0, 0
+    ///                 [ 3,  4,  5]],   // -- row 0, 1
+    ///                [[ 6,  7,  8],    // -- row 1, 0
+    ///                 [ 9, 10, 11]]]); // -- row 1, 1
+    ///
+    /// // `rows` will yield the four generalized rows of the array.
+    /// for row in a.rows() {
+    ///     /* loop body */
+    /// }
+    /// ```
+    pub fn rows(&self) -> Lanes<'_, A, D::Smaller>
+    where
+        S: Data,
+    {
+        let mut n = self.ndim();
+        if n == 0 {
+            n += 1;
+        }
+        Lanes::new(self.view(), Axis(n - 1))
+    }
+
+    #[deprecated(note="Renamed to .rows()", since="0.15.0")]
+    pub fn genrows(&self) -> Lanes<'_, A, D::Smaller>
+    where
+        S: Data,
+    {
+        self.rows()
+    }
+
+    /// Return a producer and iterable that traverses over the *generalized*
+    /// rows of the array and yields mutable array views.
+    ///
+    /// Iterator element is `ArrayView1<A>` (1D read-write array view).
+    pub fn rows_mut(&mut self) -> LanesMut<'_, A, D::Smaller>
+    where
+        S: DataMut,
+    {
+        let mut n = self.ndim();
+        if n == 0 {
+            n += 1;
+        }
+        LanesMut::new(self.view_mut(), Axis(n - 1))
+    }
+
+    #[deprecated(note="Renamed to .rows_mut()", since="0.15.0")]
+    pub fn genrows_mut(&mut self) -> LanesMut<'_, A, D::Smaller>
+    where
+        S: DataMut,
+    {
         self.rows_mut()
     }
 
@@ -1296,6 +1952,133 @@ where
         AxisChunksIterMut::new(self.view_mut(), axis, size)
     }
 
+    /// Return an iterator over chunks of `size` along `axis`, where
+    /// consecutive chunks overlap by `overlap` elements.
+    ///
+    /// The chunks start `size - overlap` elements apart, like the frames of
+    /// a short-time Fourier transform. `tail` says what to do with the final
+    /// chunk if it is shorter than `size`; see [`ChunkTail`]. A partial chunk
+    /// is only produced if it has elements that aren't in the previous chunk.
+    ///
+    /// Iterator element is `CowArray<A, D>`, which is a view of the array
+    /// except for a padded final chunk.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `size` is zero or if
+    /// `overlap` is not smaller than `size`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array, Axis, ChunkTail};
+    ///
+    /// let signal = Array::from_iter(0..7);
+    /// let frames = signal
+    ///     .axis_chunks_with_overlap(Axis(0), 4, 2, ChunkTail::Pad(0))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(frames, vec![arr1(&[0, 1, 2, 3]), arr1(&[2, 3, 4, 5]), arr1(&[4, 5, 6, 0])]);
+    ///
+    /// let frames = signal.axis_chunks_with_overlap(Axis(0), 4, 2, ChunkTail::Drop);
+    /// assert_eq!(frames.count(), 2);
+    /// ```
+    pub fn axis_chunks_with_overlap(
+        &self,
+        axis: Axis,
+        size: usize,
+        overlap: usize,
+        tail: ChunkTail<A>,
+    ) -> OverlappingChunks<'_, A, D>
+    where
+        S: Data,
+    {
+        OverlappingChunks::new(self.view(), axis, size, overlap, tail)
+    }
+
+    /// Split the array along `axis` into `n` contiguous views of nearly equal
+    /// length.
+    ///
+    /// If `n` doesn't divide the length of the axis, the first parts are one
+    /// element longer than the others, as in NumPy's `array_split`. If `n`
+    /// is larger than the length, the last parts are empty.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array, Axis};
+    ///
+    /// let a = Array::from_iter(0..7);
+    /// let parts = a.split_axis(Axis(0), 3);
+    /// assert_eq!(parts, vec![arr1(&[0, 1, 2]), arr1(&[3, 4]), arr1(&[5, 6])]);
+    /// ```
+    pub fn split_axis(&self, axis: Axis, n: usize) -> Vec<ArrayView<'_, A, D>>
+    where
+        S: Data,
+    {
+        assert!(n > 0, "split_axis: the number of parts must be positive");
+        let len = self.len_of(axis);
+        let mut parts = Vec::with_capacity(n);
+        let mut rest = self.view();
+        for i in 0..n {
+            let (part, tail) = rest.split_at(axis, split_part_len(len, n, i));
+            parts.push(part);
+            rest = tail;
+        }
+        parts
+    }
+
+    /// Split the array along `axis` into `n` contiguous mutable views of
+    /// nearly equal length.
+    ///
+    /// See [`.split_axis()`](ArrayBase::split_axis) for the lengths of the
+    /// parts.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is zero.
+    pub fn split_axis_mut(&mut self, axis: Axis, n: usize) -> Vec<ArrayViewMut<'_, A, D>>
+    where
+        S: DataMut,
+    {
+        assert!(n > 0, "split_axis: the number of parts must be positive");
+        let len = self.len_of(axis);
+        let mut parts = Vec::with_capacity(n);
+        let mut rest = self.view_mut();
+        for i in 0..n {
+            let (part, tail) = rest.split_at(axis, split_part_len(len, n, i));
+            parts.push(part);
+            rest = tail;
+        }
+        parts
+    }
+
+    /// Split the array along `axis` into `n` strided views, where view `k`
+    /// has the elements at positions `k`, `k + n`, `k + 2n`, … along `axis`.
+    ///
+    /// This is the inverse of [`interleave`](crate::interleave), and doesn't
+    /// copy any elements. If the length of the axis isn't a multiple of `n`,
+    /// the first views are one element longer than the others.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let packed = arr2(&[[1., 0.5, 2., 0.], [3., 0., 4., -1.]]);
+    /// let parts = packed.deinterleave(Axis(1), 2);
+    /// assert_eq!(parts[0], arr2(&[[1., 2.], [3., 4.]]));
+    /// assert_eq!(parts[1], arr2(&[[0.5, 0.], [0., -1.]]));
+    ///
+    /// let samples = arr1(&[0, 1, 2, 3, 4]);
+    /// assert_eq!(samples.deinterleave(Axis(0), 2), vec![arr1(&[0, 2, 4]), arr1(&[1, 3])]);
+    /// ```
+    pub fn deinterleave(&self, axis: Axis, n: usize) -> Vec<ArrayView<'_, A, D>>
+    where
+        S: Data,
+    {
+        assert!(n > 0, "deinterleave: the number of parts must be positive");
+        (0..n)
+            .map(|k| {
+                let start = (k as isize).min(self.len_of(axis) as isize);
+                self.slice_axis(axis, Slice::new(start, None, n as isize))
+            })
+            .collect()
+    }
+
     /// Return an exact chunks producer (and iterable).
     ///
     /// It produces the whole chunks of a given n-dimensional chunk size,
@@ -1399,6 +2182,77 @@ where
         Windows::new(self.view(), window_size)
     }
 
+    /// Return a window producer and iterable, with windows `stride` elements
+    /// apart along each axis.
+    ///
+    /// This is like [`.windows()`](ArrayBase::windows), which has a stride of
+    /// one along every axis, but only every `stride[i]`-th window is produced
+    /// along axis `i`. The windows overlap if the stride is smaller than the
+    /// window size, and skip elements if it is larger.
+    ///
+    /// **Panics** if any dimension of `window_size` or `stride` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` or `stride` does not
+    /// match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array};
+    ///
+    /// let a = Array::from_iter(0..20).into_shape((4, 5)).unwrap();
+    /// // 2 × 2 patches with a stride of 2, as in a convolution with stride 2
+    /// let patches = a.windows_with_stride((2, 2), (2, 2));
+    /// let sums = patches.into_iter().map(|w| w.sum()).collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![12, 20, 52, 60]);
+    ///
+    /// let mut windows = a.windows_with_stride((1, 3), (3, 1)).into_iter();
+    /// assert_eq!(windows.next().unwrap(), arr2(&[[0, 1, 2]]));
+    /// assert_eq!(windows.last().unwrap(), arr2(&[[17, 18, 19]]));
+    /// ```
+    pub fn windows_with_stride<E>(&self, window_size: E, stride: E) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Windows::new_with_stride(self.view(), window_size, stride)
+    }
+
+    /// Return a producer and iterable of windows along `axis`, each of which
+    /// has length `window_size` along `axis` and the full length along the
+    /// other axes.
+    ///
+    /// The windows overlap and are one element apart, as in a rolling
+    /// computation along one axis. If `window_size` is larger than the length
+    /// of the axis, or if the array has no elements, there are no windows.
+    /// Unlike [`.windows()`](ArrayBase::windows), the producer is
+    /// one-dimensional, so that it can be zipped with the per-window results.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window_size` is zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Array1, Axis, Zip};
+    ///
+    /// // Three series of five samples each, one per column
+    /// let a = arr2(&[[1., 0., 4.],
+    ///                [2., 0., 4.],
+    ///                [3., 1., 4.],
+    ///                [4., 1., 4.],
+    ///                [5., 2., 4.]]);
+    /// let windows = a.axis_windows(Axis(0), 2);
+    /// assert_eq!(windows.clone().into_iter().next().unwrap(), arr2(&[[1., 0., 4.], [2., 0., 4.]]));
+    ///
+    /// // The rolling total of all series
+    /// let mut totals = Array1::zeros(4);
+    /// Zip::from(&mut totals)
+    ///     .and(windows)
+    ///     .for_each(|total, window| *total = window.sum());
+    /// assert_eq!(totals, arr1(&[11., 14., 17., 20.]));
+    /// ```
+    pub fn axis_windows(&self, axis: Axis, window_size: usize) -> AxisWindows<'_, A, D>
+    where
+        S: Data,
+    {
+        AxisWindows::new(self.view(), axis, window_size)
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs) {
         /* empty shape has len 1 */
@@ -1997,9 +2851,23 @@ where
     /// For two arrays or views, find their common shape if possible and
     /// broadcast them as array views into that shape.
     ///
-    /// Return `ShapeError` if their shapes can not be broadcast together.
+    /// **Errors** if their shapes can not be broadcast together; the error
+    /// holds the two shapes.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1], [2]];
+    /// let b = array![10, 20, 30];
+    /// let (a1, b1) = a.broadcast_with(&b).unwrap();
+    /// assert_eq!(a1.shape(), &[2, 3]);
+    /// assert_eq!(b1, array![[10, 20, 30], [10, 20, 30]]);
+    ///
+    /// let err = b.broadcast_with(&array![1, 2]).unwrap_err();
+    /// assert_eq!(err.shapes(), Some((&[3][..], &[2][..])));
+    /// ```
     #[allow(clippy::type_complexity)]
-    pub(crate) fn broadcast_with<'a, 'b, B, S2, E>(&'a self, other: &'b ArrayBase<S2, E>) ->
+    pub fn broadcast_with<'a, 'b, B, S2, E>(&'a self, other: &'b ArrayBase<S2, E>) ->
         Result<(ArrayView<'a, A, DimMaxOf<D, E>>, ArrayView<'b, B, DimMaxOf<D, E>>), ShapeError>
     where
         S: Data<Elem=A>,
@@ -2007,7 +2875,8 @@ where
         D: Dimension + DimMax<E>,
         E: Dimension,
     {
-        let shape = co_broadcast::<D, E, <D as DimMax<E>>::Output>(&self.dim, &other.dim)?;
+        let shape = co_broadcast::<D, E, <D as DimMax<E>>::Output>(&self.dim, &other.dim)
+            .map_err(|_| error::incompatible_shapes(&self.dim, &other.dim))?;
         let view1 = if shape.slice() == self.dim.slice() {
             self.view().into_dimensionality::<<D as DimMax<E>>::Output>().unwrap()
         } else if let Some(view1) = self.broadcast(shape.clone()) {
@@ -2098,6 +2967,67 @@ where
         }
     }
 
+    /// Return a copy of the array with the channel axis `axis` moved to the
+    /// front, in standard layout.
+    ///
+    /// This converts an interleaved image, such as one with axes *height ×
+    /// width × channels*, to a planar one, *channels × height × width*. The
+    /// result is the same as `.view().permuted_axes(..).to_owned()`, but the
+    /// elements are copied in cache friendly blocks.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // A 2 × 2 image with channels r, g, b
+    /// let hwc = array![[[1, 2, 3], [4, 5, 6]],
+    ///                  [[7, 8, 9], [10, 11, 12]]];
+    /// let chw = hwc.to_planar(Axis(2));
+    /// assert_eq!(chw, array![[[1, 4], [7, 10]],
+    ///                        [[2, 5], [8, 11]],
+    ///                        [[3, 6], [9, 12]]]);
+    /// assert_eq!(chw.to_interleaved(Axis(0)), hwc);
+    /// ```
+    pub fn to_planar(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let n = self.ndim();
+        assert!(axis.index() < n, "to_planar: axis {} out of bounds", axis.index());
+        let mut axes = D::zeros(n);
+        axes[0] = axis.index();
+        for (dst, src) in axes.slice_mut()[1..].iter_mut().zip((0..n).filter(|&i| i != axis.index())) {
+            *dst = src;
+        }
+        to_owned_blocked(self.view().permuted_axes(axes))
+    }
+
+    /// Return a copy of the array with the channel axis `axis` moved to the
+    /// back, in standard layout.
+    ///
+    /// This converts a planar image, such as one with axes *channels × height
+    /// × width*, to an interleaved one, *height × width × channels*. It is the
+    /// inverse of [`.to_planar()`](Self::to_planar), see its documentation for
+    /// an example.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn to_interleaved(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let n = self.ndim();
+        assert!(axis.index() < n, "to_interleaved: axis {} out of bounds", axis.index());
+        let mut axes = D::zeros(n);
+        axes[n - 1] = axis.index();
+        for (dst, src) in axes.slice_mut()[..n - 1].iter_mut().zip((0..n).filter(|&i| i != axis.index())) {
+            *dst = src;
+        }
+        to_owned_blocked(self.view().permuted_axes(axes))
+    }
+
     /// Transpose the array by reversing axes.
     ///
     /// Transposition reverses the order of the axes (dimensions and strides)
@@ -2152,6 +3082,61 @@ where
         }
     }
 
+    /// Invert the axes with negative strides, and permute the axes in order
+    /// of decreasing stride, so that the logical order of the result is the
+    /// order of the elements in memory.
+    fn into_memory_order(mut self) -> Self {
+        for k in 0..self.ndim() {
+            if (self.strides[k] as isize) < 0 {
+                self.invert_axis(Axis(k));
+            }
+        }
+        let mut axes: Vec<usize> = (0..self.ndim()).collect();
+        axes.sort_by_key(|&k| std::cmp::Reverse(self.strides[k] as isize));
+        let mut perm = D::zeros(self.ndim());
+        perm.slice_mut().copy_from_slice(&axes);
+        self.permuted_axes(perm)
+    }
+
+    /// Reverse the order of the elements along `axis`, and return the result.
+    ///
+    /// This is the consuming version of [`.invert_axis()`](Self::invert_axis); it does not move
+    /// any data. Use it on a view, like `a.view().flipped(Axis(0))`, to flip an array inside an
+    /// expression.
+    ///
+    /// ***Panics*** if the axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// // Flip up-down and left-right
+    /// assert_eq!(a.view().flipped(Axis(0)), array![[3, 4], [1, 2]]);
+    /// assert_eq!(a.view().flipped(Axis(1)), array![[2, 1], [4, 3]]);
+    /// assert_eq!(&a + &a.view().flipped(Axis(1)), array![[3, 3], [7, 7]]);
+    /// ```
+    pub fn flipped(mut self, axis: Axis) -> Self {
+        self.invert_axis(axis);
+        self
+    }
+
+    /// Reverse the order of the elements along every axis, and return the result.
+    ///
+    /// This does not move any data; see [`.flipped()`](Self::flipped).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.flipped_all(), array![[6, 5, 4], [3, 2, 1]]);
+    /// ```
+    pub fn flipped_all(mut self) -> Self {
+        for axis in 0..self.ndim() {
+            self.invert_axis(Axis(axis));
+        }
+        self
+    }
+
     /// If possible, merge in the axis `take` to `into`.
     ///
     /// Returns `true` iff the axes are now merged.
@@ -2356,6 +3341,285 @@ where
         }
     }
 
+    /// Traverse two arrays in unspecified order, in lock step, calling the
+    /// closure `f` on each element pair where `mask` is `true`.
+    ///
+    /// If their shapes disagree, `mask` and `rhs` are broadcast to the shape
+    /// of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2], [3, 4]];
+    /// a.zip_mut_with_where(&array![true, false], &array![[10], [20]], |x, &y| *x += y);
+    /// assert_eq!(a, array![[11, 2], [23, 4]]);
+    /// ```
+    pub fn zip_mut_with_where<B, S2, E, SM, EM, F>(
+        &mut self,
+        mask: &ArrayBase<SM, EM>,
+        rhs: &ArrayBase<S2, E>,
+        mut f: F,
+    ) where
+        S: DataMut,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        SM: Data<Elem = bool>,
+        EM: Dimension,
+        F: FnMut(&mut A, &B),
+    {
+        let dim = self.raw_dim();
+        let mask = mask.broadcast_unwrap(dim.clone());
+        let rhs = rhs.broadcast_unwrap(dim);
+        Zip::from(self.view_mut())
+            .and(mask)
+            .and(rhs)
+            .for_each(|x, &m, y| {
+                if m {
+                    f(x, y)
+                }
+            });
+    }
+
+    /// Perform an elementwise assigment to `self` from `rhs`, for the
+    /// elements where `mask` is `true`.
+    ///
+    /// Return the number of elements that were assigned, including those
+    /// that already had their new value. See
+    /// [`.replace_where_from()`](Self::replace_where_from) for the number
+    /// of elements that changed.
+    ///
+    /// If their shapes disagree, `mask` and `rhs` are broadcast to the shape
+    /// of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1., 2.], [3., 4.]];
+    /// let b = array![[0., -2.], [-3., 0.]];
+    /// let mask = a.mapv(|x| x > 1.5);
+    /// assert_eq!(a.assign_where(&mask, &b), 3);
+    /// assert_eq!(a, array![[1., -2.], [-3., 0.]]);
+    /// ```
+    pub fn assign_where<E, S2, SM, EM>(
+        &mut self,
+        mask: &ArrayBase<SM, EM>,
+        rhs: &ArrayBase<S2, E>,
+    ) -> usize
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+        E: Dimension,
+        SM: Data<Elem = bool>,
+        EM: Dimension,
+    {
+        let mut count = 0;
+        self.zip_mut_with_where(mask, rhs, |x, y| {
+            *x = y.clone();
+            count += 1;
+        });
+        count
+    }
+
+    /// Perform an elementwise assigment to `self` from element `x`, for the
+    /// elements where `mask` is `true`.
+    ///
+    /// Return the number of elements that were assigned, including those
+    /// that were already equal to `x`. See
+    /// [`.replace_where()`](Self::replace_where) for the number of elements
+    /// that changed.
+    ///
+    /// If their shapes disagree, `mask` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![1., f64::NAN, 3.];
+    /// let mask = a.mapv(f64::is_nan);
+    /// assert_eq!(a.fill_where(&mask, 0.), 1);
+    /// assert_eq!(a, array![1., 0., 3.]);
+    /// ```
+    pub fn fill_where<SM, EM>(&mut self, mask: &ArrayBase<SM, EM>, x: A) -> usize
+    where
+        S: DataMut,
+        A: Clone,
+        SM: Data<Elem = bool>,
+        EM: Dimension,
+    {
+        let mask = mask.broadcast_unwrap(self.raw_dim());
+        Zip::from(self.view_mut())
+            .and(mask)
+            .fold(0, |count, elt, &m| {
+                if m {
+                    *elt = x.clone();
+                    count + 1
+                } else {
+                    count
+                }
+            })
+    }
+
+    /// Replace the elements for which `pred` returns `true` with `x`.
+    ///
+    /// Return the number of elements that changed, that is, the replaced
+    /// elements that were not equal to `x`. The predicate is called once for
+    /// each element, in unspecified order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1., f64::NAN], [f64::NAN, 4.]];
+    /// assert_eq!(a.replace_where(|x| x.is_nan(), 0.), 2);
+    /// assert_eq!(a, array![[1., 0.], [0., 4.]]);
+    ///
+    /// // Thresholding: the elements that already are 0 don't count
+    /// assert_eq!(a.replace_where(|&x| x < 2., 0.), 1);
+    /// assert_eq!(a, array![[0., 0.], [0., 4.]]);
+    /// ```
+    pub fn replace_where<F>(&mut self, mut pred: F, x: A) -> usize
+    where
+        S: DataMut,
+        A: Clone + PartialEq,
+        F: FnMut(&A) -> bool,
+    {
+        Zip::from(self.view_mut()).fold(0, |count, elt| {
+            if pred(elt) {
+                let changed = *elt != x;
+                *elt = x.clone();
+                count + changed as usize
+            } else {
+                count
+            }
+        })
+    }
+
+    /// Replace the elements for which `pred` returns `true` with the
+    /// corresponding elements of `rhs`.
+    ///
+    /// Return the number of elements that changed, that is, the replaced
+    /// elements that were not equal to their new value. The predicate is
+    /// called once for each element, in unspecified order.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // Clip each column to its own upper bound
+    /// let mut a = array![[1, 8], [5, 2]];
+    /// let upper = array![3, 6];
+    /// assert_eq!(a.replace_where_from(|&x| x > 4, &upper), 2);
+    /// assert_eq!(a, array![[1, 6], [3, 2]]);
+    /// ```
+    pub fn replace_where_from<F, S2, E>(&mut self, mut pred: F, rhs: &ArrayBase<S2, E>) -> usize
+    where
+        S: DataMut,
+        A: Clone + PartialEq,
+        S2: Data<Elem = A>,
+        E: Dimension,
+        F: FnMut(&A) -> bool,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self.view_mut())
+            .and(rhs)
+            .fold(0, |count, elt, y| {
+                if pred(elt) {
+                    let changed = *elt != *y;
+                    *elt = y.clone();
+                    count + changed as usize
+                } else {
+                    count
+                }
+            })
+    }
+
+    /// Perform an elementwise assigment to `self` from `then` where `cond`
+    /// is `true`, and from `otherwise` where it is `false`.
+    ///
+    /// This is the in-place version of [`where_`](crate::where_); see
+    /// [`.assign_where()`](Self::assign_where) to leave the elements where
+    /// the condition is `false` unchanged.
+    ///
+    /// If their shapes disagree, `cond`, `then` and `otherwise` are broadcast
+    /// to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let x = array![[1., -2.], [-3., 4.]];
+    /// let mut relu = Array2::zeros((2, 2));
+    /// relu.assign_if(&x.mapv(|x| x > 0.), &x, &array![0.]);
+    /// assert_eq!(relu, array![[1., 0.], [0., 4.]]);
+    /// ```
+    pub fn assign_if<SM, EM, S2, E2, S3, E3>(
+        &mut self,
+        cond: &ArrayBase<SM, EM>,
+        then: &ArrayBase<S2, E2>,
+        otherwise: &ArrayBase<S3, E3>,
+    ) where
+        S: DataMut,
+        A: Clone,
+        SM: Data<Elem = bool>,
+        EM: Dimension,
+        S2: Data<Elem = A>,
+        E2: Dimension,
+        S3: Data<Elem = A>,
+        E3: Dimension,
+    {
+        let dim = self.raw_dim();
+        let cond = cond.broadcast_unwrap(dim.clone());
+        let then = then.broadcast_unwrap(dim.clone());
+        let otherwise = otherwise.broadcast_unwrap(dim);
+        Zip::from(self.view_mut())
+            .and(cond)
+            .and(then)
+            .and(otherwise)
+            .for_each(|elt, &c, t, e| {
+                *elt = if c { t.clone() } else { e.clone() };
+            });
+    }
+
+    /// Return a one-dimensional array of the elements where `mask` is
+    /// `true`, in logical order.
+    ///
+    /// If their shapes disagree, `mask` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.select_where(&a.mapv(|x| x % 2 == 0)), array![2, 4, 6]);
+    /// // The mask is broadcast, here selecting columns
+    /// assert_eq!(a.select_where(&array![true, false, true]), array![1, 3, 4, 6]);
+    /// ```
+    pub fn select_where<SM, EM>(&self, mask: &ArrayBase<SM, EM>) -> Array<A, Ix1>
+    where
+        S: Data,
+        A: Clone,
+        SM: Data<Elem = bool>,
+        EM: Dimension,
+    {
+        let mask = mask.broadcast_unwrap(self.raw_dim());
+        let elements = self
+            .iter()
+            .zip(mask.iter())
+            .filter(|&(_, &m)| m)
+            .map(|(elt, _)| elt.clone());
+        Array::from_iter(elements)
+    }
+
     /// Traverse the array elements and apply a fold,
     /// returning the resulting value.
     ///
@@ -2609,6 +3873,74 @@ where
         res
     }
 
+    /// Fold along an axis, passing the index along the axis to the fold
+    /// function.
+    ///
+    /// Like [`fold_axis`](Self::fold_axis), but `fold` is called as
+    /// `fold(&acc, index, &elt)`, where `index` is the position of `elt`
+    /// along `axis`. The subviews are visited in order of increasing index.
+    ///
+    /// Return the result as an `Array`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // Index of the first element greater than 2 in each row
+    /// let a = array![[1, 3, 5], [0, 0, 4], [1, 1, 1]];
+    /// let first = a.indexed_fold_axis(Axis(1), None, |&acc, i, &x| {
+    ///     acc.or(if x > 2 { Some(i) } else { None })
+    /// });
+    /// assert_eq!(first, array![Some(1), Some(2), None]);
+    /// ```
+    pub fn indexed_fold_axis<B, F>(&self, axis: Axis, init: B, mut fold: F) -> Array<B, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: FnMut(&B, usize, &A) -> B,
+        B: Clone,
+        S: Data,
+    {
+        let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), init);
+        for (index, subview) in self.axis_iter(axis).enumerate() {
+            res.zip_mut_with(&subview, |x, y| *x = fold(x, index, y));
+        }
+        res
+    }
+
+    /// Reduce along an axis with the function `f`, using the first subview
+    /// along the axis as the initial value.
+    ///
+    /// The subviews are combined in order of increasing index, as
+    /// `f(&acc, &elt)`, so `f` doesn't need to be commutative.
+    ///
+    /// Return `None` if the axis has length zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // Read the digits of each column from top to bottom
+    /// let a = array![[1, 1, 0, 1], [2, 0, 0, 1]];
+    /// let product = a.reduce_axis(Axis(0), |&x, &y| x * 10 + y).unwrap();
+    /// assert_eq!(product, array![12, 10, 0, 11]);
+    /// ```
+    pub fn reduce_axis<F>(&self, axis: Axis, mut f: F) -> Option<Array<A, D::Smaller>>
+    where
+        D: RemoveAxis,
+        F: FnMut(&A, &A) -> A,
+        A: Clone,
+        S: Data,
+    {
+        let mut subviews = self.axis_iter(axis);
+        let mut res = subviews.next()?.to_owned();
+        for subview in subviews {
+            res.zip_mut_with(&subview, |x, y| *x = f(x, y));
+        }
+        Some(res)
+    }
+
     /// Reduce the values along an axis into just one value, producing a new
     /// array with one less dimension.
     ///
@@ -2673,6 +4005,35 @@ where
         }
     }
 
+    /// Call `f` with a mutable view of each lane along `axis`, to rewrite
+    /// the lanes in place.
+    ///
+    /// The lanes are one-dimensional views that can have any stride. Lanes
+    /// are visited in arbitrary order.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // Normalize each row to sum to one
+    /// let mut a = array![[1., 3.], [2., 2.], [0., 5.]];
+    /// a.map_axis_inplace(Axis(1), |mut row| {
+    ///     let sum = row.sum();
+    ///     row /= sum;
+    /// });
+    /// assert_eq!(a, array![[0.25, 0.75], [0.5, 0.5], [0., 1.]]);
+    /// ```
+    pub fn map_axis_inplace<F>(&mut self, axis: Axis, mut f: F)
+    where
+        F: FnMut(ArrayViewMut1<'_, A>),
+        S: DataMut,
+    {
+        for lane in self.lanes_mut(axis) {
+            f(lane);
+        }
+    }
+
     /// Remove the `index`th elements along `axis` and shift down elements from higher indexes.
     ///
     /// Note that this "removes" the elements by swapping them around to the end of the axis and
@@ -2697,6 +4058,41 @@ where
         self.slice_axis_inplace(axis, Slice::new(0, Some(-1), 1));
     }
 
+    /// Remove the `index`th elements along `axis` and replace them with the last elements
+    /// along `axis`.
+    ///
+    /// This is like `Vec::swap_remove`: it does not preserve the order along `axis`, but only
+    /// moves the elements of one subview, instead of all the elements after `index` like
+    /// [`.remove_index()`](Self::remove_index). As with `.remove_index()`, the removed elements
+    /// are moved out of view, not dropped.
+    ///
+    /// Decreases the length of `axis` by one.
+    ///
+    /// ***Panics*** if `axis` is out of bounds<br>
+    /// ***Panics*** if not `index < self.len_of(axis)`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut particles = array![[0., 0.], [1., 1.], [2., 2.], [3., 3.]];
+    /// particles.swap_remove_index(Axis(0), 1);
+    /// assert_eq!(particles, array![[0., 0.], [3., 3.], [2., 2.]]);
+    /// ```
+    pub fn swap_remove_index(&mut self, axis: Axis, index: usize)
+    where
+        S: DataOwned + DataMut,
+    {
+        let len = self.len_of(axis);
+        assert!(index < len, "index {} must be less than length of Axis({})",
+                index, axis.index());
+        if index != len - 1 {
+            let (mut head, tail) = self.view_mut().split_at(axis, len - 1);
+            head.collapse_axis(axis, index);
+            Zip::from(head).and(tail).for_each(std::mem::swap);
+        }
+        self.slice_axis_inplace(axis, Slice::new(0, Some(-1), 1));
+    }
+
     /// Iterates over pairs of consecutive elements along the axis.
     ///
     /// The first argument to the closure is an element, and the second
@@ -2752,6 +4148,55 @@ where
             f(&*prev, &mut *curr)
         });
     }
+
+    /// Scan along `axis`, threading an accumulator through each lane and
+    /// returning all the intermediate accumulator values.
+    ///
+    /// For each lane, the accumulator starts at `init` and is updated as
+    /// `acc = f(&acc, &elt)` for the elements in order along `axis`; the
+    /// result has the same shape as `self`, with the value of the accumulator
+    /// after each element. This is the lane-wise version of
+    /// `Iterator::scan`, for running maxima, exponential moving averages or
+    /// prefix products, for example.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 3., 2.], [4., 0., 8.]];
+    /// let running_max = a.scan_axis(Axis(1), f64::NEG_INFINITY, |&acc, &x| acc.max(x));
+    /// assert_eq!(running_max, array![[1., 3., 3.], [4., 4., 8.]]);
+    ///
+    /// let ema = a.scan_axis(Axis(1), 0., |&acc, &x| 0.5 * acc + 0.5 * x);
+    /// assert_eq!(ema, array![[0.5, 1.75, 1.875], [2., 1., 4.5]]);
+    ///
+    /// let products = a.scan_axis(Axis(0), 1., |&acc, &x| acc * x);
+    /// assert_eq!(products, array![[1., 3., 2.], [4., 0., 16.]]);
+    /// ```
+    pub fn scan_axis<B, F>(&self, axis: Axis, init: B, mut f: F) -> Array<B, D>
+    where
+        D: RemoveAxis,
+        F: FnMut(&B, &A) -> B,
+        B: Clone,
+        S: Data,
+    {
+        let mut acc = Array::from_elem(self.raw_dim().remove_axis(axis), init);
+        let mut out = Array::uninit(self.raw_dim());
+        for (subview, out_subview) in self.axis_iter(axis).zip(out.axis_iter_mut(axis)) {
+            Zip::from(&mut acc)
+                .and(&subview)
+                .and(out_subview)
+                .for_each(|acc, x, out| {
+                    *acc = f(acc, x);
+                    *out = MaybeUninit::new(acc.clone());
+                });
+        }
+        unsafe {
+            // Safe because every element was assigned above
+            out.assume_init()
+        }
+    }
 }
 
 
@@ -2769,4 +4214,112 @@ unsafe fn unlimited_transmute<A, B>(data: A) -> B {
     (&*old_data as *const A as *const B).read()
 }
 
+/// Block size (per axis) used by `to_owned_blocked`
+const COPY_BLOCK: isize = 32;
+
+/// Copy the view into a new array in standard layout.
+///
+/// When the fastest varying axis of `src` is not its last axis, as after
+/// permuting axes, the copy is done in square blocks of those two axes so
+/// that both reads and writes stay within cache.
+fn to_owned_blocked<A, D>(src: ArrayView<'_, A, D>) -> Array<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    let n = src.ndim();
+    let src_inner = src.axes()
+        .filter(|ax| ax.len > 1)
+        .min_by_key(|ax| ax.stride.abs())
+        .map(|ax| ax.axis.index());
+    let p = match src_inner {
+        Some(p) if p != n - 1 && src.len_of(Axis(n - 1)) > 1 && !src.is_empty() => p,
+        _ => return src.to_owned(),
+    };
+    let mut out = Array::uninit(src.raw_dim());
+    // Put the two blocked axes last, with the destination's contiguous axis innermost
+    let mut axes = D::zeros(n);
+    for (dst, src) in axes.slice_mut().iter_mut().zip((0..n).filter(|&i| i != p && i != n - 1)) {
+        *dst = src;
+    }
+    axes[n - 2] = p;
+    axes[n - 1] = n - 1;
+    let src = src.permuted_axes(axes.clone());
+    let mut out_view = out.view_mut().permuted_axes(axes);
+    let (len_p, len_q) = (src.len_of(Axis(n - 2)) as isize, src.len_of(Axis(n - 1)) as isize);
+    let (sp, sq) = (src.strides()[n - 2], src.strides()[n - 1]);
+    let op = out_view.strides()[n - 2];
+    debug_assert_eq!(out_view.strides()[n - 1], 1);
+
+    let mut outer_dim = src.raw_dim();
+    outer_dim[n - 2] = 1;
+    outer_dim[n - 1] = 1;
+    let src_strides = src.strides.clone();
+    let out_strides = out_view.strides.clone();
+    let src_ptr = src.as_ptr();
+    let out_ptr = out_view.as_mut_ptr() as *mut A;
+    let mut index = outer_dim.first_index();
+    while let Some(ix) = index {
+        unsafe {
+            // Safe because all offsets are within the bounds of the arrays, and
+            // each element of `out` is written exactly once.
+            let s = src_ptr.offset(D::stride_offset(&ix, &src_strides));
+            let o = out_ptr.offset(D::stride_offset(&ix, &out_strides));
+            for i in (0..len_p).step_by(COPY_BLOCK as usize) {
+                for j in (0..len_q).step_by(COPY_BLOCK as usize) {
+                    let (end_p, end_q) = (isize::min(i + COPY_BLOCK, len_p), isize::min(j + COPY_BLOCK, len_q));
+                    // Use the longer side of the block for the inner loop
+                    if end_q - j >= end_p - i {
+                        for a in i..end_p {
+                            for b in j..end_q {
+                                o.offset(a * op + b).write((*s.offset(a * sp + b * sq)).clone());
+                            }
+                        }
+                    } else {
+                        for b in j..end_q {
+                            for a in i..end_p {
+                                o.offset(a * op + b).write((*s.offset(a * sp + b * sq)).clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        index = outer_dim.next_for(ix);
+    }
+    unsafe {
+        // Safe because every element was written above
+        out.assume_init()
+    }
+}
+
 type DimMaxOf<A, B> = <A as DimMax<B>>::Output;
+
+/// Reverse the order of the elements along `axis`, by swapping.
+fn reverse_axis<A, D>(view: ArrayViewMut<'_, A, D>, axis: Axis)
+where
+    D: Dimension,
+{
+    let half = view.len_of(axis) / 2;
+    let (front, mut back) = view.split_at(axis, half);
+    back.invert_axis(axis);
+    back.slice_axis_inplace(axis, Slice::from(..half));
+    Zip::from(front).and(back).for_each(std::mem::swap);
+}
+
+/// Return `shift` modulo `len` as an index in `0..len`, or zero if `len` is zero.
+fn roll_offset(shift: isize, len: usize) -> usize {
+    if len == 0 {
+        0
+    } else if shift >= 0 {
+        shift as usize % len
+    } else {
+        (len - shift.unsigned_abs() % len) % len
+    }
+}
+
+/// Return the length of part `i` when splitting a length `len` into `n`
+/// parts of nearly equal length, with the longer parts first.
+fn split_part_len(len: usize, n: usize, i: usize) -> usize {
+    len / n + (i < len % n) as usize
+}
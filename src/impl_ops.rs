@@ -7,7 +7,6 @@
 // except according to those terms.
 
 use crate::dimension::DimMax;
-use crate::Zip;
 use num_complex::Complex;
 
 /// Elements that can be used as direct operands in arithmetic with arrays.
@@ -31,6 +30,43 @@ use num_complex::Complex;
 /// This trait ***does not*** limit which elements can be stored in an array in general.
 /// Non-`ScalarOperand` types can still participate in arithmetic as array elements in
 /// in array-array operations.
+///
+/// For the binary operators, the scalar does not need to have the same type
+/// as the elements: `&A @ K` only requires that the element type implements
+/// the operator with right hand side `K`. This makes it possible to use
+/// arrays of newtype wrappers, like the quantities of a units-of-measure
+/// crate, without converting each element:
+///
+/// ```
+/// use ndarray::{array, ScalarOperand};
+/// use std::ops::{Add, AddAssign, Mul};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// impl Add for Meters {
+///     type Output = Meters;
+///     fn add(self, rhs: Meters) -> Meters { Meters(self.0 + rhs.0) }
+/// }
+/// impl AddAssign for Meters {
+///     fn add_assign(&mut self, rhs: Meters) { self.0 += rhs.0 }
+/// }
+/// impl Mul<f64> for Meters {
+///     type Output = Meters;
+///     fn mul(self, rhs: f64) -> Meters { Meters(self.0 * rhs) }
+/// }
+/// impl ScalarOperand for Meters {}
+///
+/// let mut a = array![Meters(1.), Meters(2.)];
+/// a += Meters(0.5);
+/// let b = &a * 2.;
+/// assert_eq!(b, array![Meters(3.), Meters(5.)]);
+/// assert_eq!(&a + &b, array![Meters(4.5), Meters(7.5)]);
+/// ```
+///
+/// Left hand side scalar operations `K @ &A` can be implemented for a newtype `K`
+/// in the crate that defines it. The [`scalar_newtype!`](crate::scalar_newtype)
+/// macro implements this trait and the usual arithmetic for such newtypes.
 pub trait ScalarOperand: 'static + Clone {}
 impl ScalarOperand for bool {}
 impl ScalarOperand for i8 {}
@@ -50,6 +86,106 @@ impl ScalarOperand for f64 {}
 impl ScalarOperand for Complex<f32> {}
 impl ScalarOperand for Complex<f64> {}
 
+/// Implement [`ScalarOperand`] and unit-preserving arithmetic for newtype
+/// wrappers around a number type.
+///
+/// For each `Name(Inner)` given, where `Inner` is a numeric type like `f64`,
+/// this implements:
+///
+/// - `ScalarOperand` for `Name`
+/// - `Name + Name` and `Name - Name`, giving `Name`, and `-Name`
+/// - `Name * Inner`, `Inner * Name` and `Name / Inner`, giving `Name`
+/// - the corresponding assignment operators `+=`, `-=`, `*=` and `/=`
+///
+/// Arrays of the newtype then support arithmetic with arrays of the same
+/// newtype (`&a + &b`), with a scalar of the newtype (`&a + Name(1.)`) and
+/// with a scalar of the inner type (`&a * 2.`), without unwrapping the
+/// elements. Operations that would change the unit, like `Name * Name`, are
+/// deliberately not implemented. The assignment operators of the array take
+/// a scalar of the element type (`a += Name(1.)`); scale by the inner type
+/// with `a = a * 2.`.
+///
+/// The newtype must be `Clone` (or `Copy`), and the tuple field must be
+/// accessible where the macro is invoked.
+///
+/// ```
+/// use ndarray::{array, scalar_newtype};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// scalar_newtype!(Meters(f64));
+///
+/// let mut a = array![Meters(1.), Meters(2.)] * 3.;
+/// a += Meters(1.);
+/// assert_eq!(a, array![Meters(4.), Meters(7.)]);
+/// assert_eq!(&a - &a / 2., array![Meters(2.), Meters(3.5)]);
+/// ```
+#[macro_export]
+macro_rules! scalar_newtype {
+    ($($name:ident($inner:ty)),+ $(,)?) => {
+        $(
+            impl $crate::ScalarOperand for $name {}
+
+            impl ::core::ops::Add for $name {
+                type Output = $name;
+                #[inline]
+                fn add(self, rhs: $name) -> $name { $name(self.0 + rhs.0) }
+            }
+
+            impl ::core::ops::Sub for $name {
+                type Output = $name;
+                #[inline]
+                fn sub(self, rhs: $name) -> $name { $name(self.0 - rhs.0) }
+            }
+
+            impl ::core::ops::Neg for $name {
+                type Output = $name;
+                #[inline]
+                fn neg(self) -> $name { $name(-self.0) }
+            }
+
+            impl ::core::ops::Mul<$inner> for $name {
+                type Output = $name;
+                #[inline]
+                fn mul(self, rhs: $inner) -> $name { $name(self.0 * rhs) }
+            }
+
+            impl ::core::ops::Mul<$name> for $inner {
+                type Output = $name;
+                #[inline]
+                fn mul(self, rhs: $name) -> $name { $name(self * rhs.0) }
+            }
+
+            impl ::core::ops::Div<$inner> for $name {
+                type Output = $name;
+                #[inline]
+                fn div(self, rhs: $inner) -> $name { $name(self.0 / rhs) }
+            }
+
+            impl ::core::ops::AddAssign for $name {
+                #[inline]
+                fn add_assign(&mut self, rhs: $name) { self.0 += rhs.0 }
+            }
+
+            impl ::core::ops::SubAssign for $name {
+                #[inline]
+                fn sub_assign(&mut self, rhs: $name) { self.0 -= rhs.0 }
+            }
+
+            impl ::core::ops::MulAssign<$inner> for $name {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $inner) { self.0 *= rhs }
+            }
+
+            impl ::core::ops::DivAssign<$inner> for $name {
+                #[inline]
+                fn div_assign(&mut self, rhs: $inner) { self.0 /= rhs }
+            }
+        )+
+    };
+}
+
 macro_rules! impl_binary_op(
     ($trt:ident, $operator:tt, $mth:ident, $iop:tt, $doc:expr) => (
 /// Perform elementwise
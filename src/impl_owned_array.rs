@@ -1,4 +1,5 @@
 
+use alloc::vec;
 use alloc::vec::Vec;
 use std::mem;
 use std::mem::MaybeUninit;
@@ -169,6 +170,76 @@ impl<A> Array<A, Ix2> {
     {
         self.append(Axis(1), column.insert_axis(Axis(1)))
     }
+
+    /// Transpose the array in place, moving the elements so that the memory layout of the array
+    /// is preserved.
+    ///
+    /// Unlike [`.reversed_axes()`](ArrayBase::reversed_axes), which only swaps the strides, the
+    /// result has the same memory order as the input: a row major array stays row major. This
+    /// avoids the extra memory of `a.t().to_owned()`.
+    ///
+    /// - Square matrices are transposed by swapping elements across the diagonal, in blocks,
+    ///   for any memory layout.
+    /// - Rectangular matrices in row major ("C") layout are transposed by following the
+    ///   cycles of the permutation. This uses one bit of extra memory per element.
+    /// - Rectangular matrices in column major ("F") layout have the same memory layout as their
+    ///   transpose in row major layout, so only the strides are changed.
+    /// - Other rectangular matrices, for example sliced arrays, are moved into a new
+    ///   allocation in row major layout.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// a.transpose_inplace();
+    /// assert_eq!(a, array![[1, 4], [2, 5], [3, 6]]);
+    /// assert!(a.is_standard_layout());
+    /// ```
+    pub fn transpose_inplace(&mut self) {
+        const BLOCK: usize = 32;
+        let (m, n) = self.dim();
+        if m == n {
+            for ib in (0..n).step_by(BLOCK) {
+                for jb in (ib..n).step_by(BLOCK) {
+                    for i in ib..usize::min(ib + BLOCK, n) {
+                        for j in usize::max(jb, i + 1)..usize::min(jb + BLOCK, n) {
+                            // Safe because the indices are in bounds and owned arrays
+                            // are uniquely held.
+                            unsafe { self.uswap([i, j], [j, i]) }
+                        }
+                    }
+                }
+            }
+        } else if self.is_standard_layout() {
+            // The element at row i, column j moves to row j, column i of the n × m result
+            let data = self.as_slice_mut().unwrap();
+            let mut visited = vec![0u64; data.len() / 64 + 1];
+            for start in 0..data.len() {
+                if visited[start / 64] & (1 << (start % 64)) != 0 {
+                    continue;
+                }
+                // Carry the displaced elements through position `start`
+                let mut k = (start % n) * m + start / n;
+                while k != start {
+                    data.swap(start, k);
+                    visited[k / 64] |= 1 << (k % 64);
+                    k = (k % n) * m + k / n;
+                }
+            }
+            self.dim = Dim([n, m]);
+            self.strides = Dim([m, 1]);
+        } else if self.t().is_standard_layout() {
+            self.swap_axes(0, 1);
+        } else {
+            let mut transposed = Array::uninit((n, m));
+            let a = mem::replace(self, Array::from_shape_vec((0, 0), Vec::new()).unwrap());
+            a.reversed_axes().move_into_uninit(&mut transposed);
+            unsafe {
+                // Safe because every element was moved into `transposed`
+                *self = transposed.assume_init();
+            }
+        }
+    }
 }
 
 impl<A, D> Array<A, D>
@@ -665,6 +736,91 @@ impl<A, D> Array<A, D>
     }
 }
 
+impl<A, D> Array<A, D>
+where
+    D: Dimension,
+{
+    /// Apply the fallible function `f` to the elements by value, in logical
+    /// order, stopping at the first error; the result is in f-order if `self`
+    /// is, otherwise in standard layout.
+    fn try_map_into_elements<B, E, F>(self, f: F) -> Result<Array<B, D>, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        let is_f = self.ndim() > 1 && !self.is_standard_layout() && self.t().is_standard_layout();
+        let array = if is_f { self.reversed_axes() } else { self };
+        let dim = array.raw_dim();
+        let v = array.into_iter().map(f).collect::<Result<Vec<_>, _>>()?;
+        // Safe because: `v` has one element for each element of the array
+        let output = unsafe { Array::from_shape_vec_unchecked(dim, v) };
+        Ok(if is_f { output.reversed_axes() } else { output })
+    }
+}
+
+/// Methods specific to arrays of `Option`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+///
+/// [`ArrayBase`]: struct.ArrayBase.html
+impl<A, D> Array<Option<A>, D>
+where
+    D: Dimension,
+{
+    /// Return an array of the values of the elements if they are all
+    /// `Some`, or `None` if any of them is `None`.
+    ///
+    /// This is the array version of collecting an iterator of `Option<A>`
+    /// into an `Option<Vec<A>>`. The elements are moved, not cloned, and
+    /// the traversal stops at the first `None`. The result has the same
+    /// shape as `self`, and is in f-order if `self` is, otherwise in standard
+    /// layout.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[Some(1), Some(2)], [Some(3), Some(4)]];
+    /// assert_eq!(a.transpose_option(), Some(array![[1, 2], [3, 4]]));
+    ///
+    /// let b = array![[Some(1), None], [Some(3), Some(4)]];
+    /// assert_eq!(b.transpose_option(), None);
+    /// ```
+    pub fn transpose_option(self) -> Option<Array<A, D>> {
+        self.try_map_into_elements(|x| x.ok_or(())).ok()
+    }
+}
+
+/// Methods specific to arrays of `Result`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+///
+/// [`ArrayBase`]: struct.ArrayBase.html
+impl<A, E, D> Array<Result<A, E>, D>
+where
+    D: Dimension,
+{
+    /// Return an array of the values of the elements if they are all `Ok`,
+    /// or the first error in logical order.
+    ///
+    /// This is the array version of collecting an iterator of `Result<A, E>`
+    /// into a `Result<Vec<A>, E>`. The elements are moved, not cloned, and
+    /// the traversal stops at the first `Err`. The result has the same shape
+    /// as `self`, and is in f-order if `self` is, otherwise in standard
+    /// layout.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array!["1", "2", "x", "y"].map(|s| s.parse::<i32>());
+    /// assert!(a.transpose_result().is_err());
+    ///
+    /// let b = array![["1", "2"], ["3", "4"]].map(|s| s.parse::<i32>());
+    /// assert_eq!(b.transpose_result(), Ok(array![[1, 2], [3, 4]]));
+    /// ```
+    pub fn transpose_result(self) -> Result<Array<A, D>, E> {
+        self.try_map_into_elements(|x| x)
+    }
+}
+
 /// This drops all "unreachable" elements in `self_` given the data pointer and data length.
 ///
 /// # Safety
@@ -5,6 +5,7 @@ use crate::dimension::{self, stride_offset};
 use crate::extension::nonnull::nonnull_debug_checked_from_ptr;
 use crate::imp_prelude::*;
 use crate::is_aligned;
+use crate::IntoDimension;
 use crate::shape_builder::{Strides, StrideShape};
 
 impl<A, D> RawArrayView<A, D>
@@ -84,6 +85,63 @@ where
         RawArrayView::new_(ptr, dim, strides)
     }
 
+    /// Create an `RawArrayView<A, D>` from a raw pointer to the first element and
+    /// strides in units of elements, which can be negative or zero.
+    ///
+    /// This is the layout used by most foreign libraries: `ptr` points to the
+    /// element at index zero along every axis (not necessarily the lowest
+    /// address in memory), and element `[i, j, ...]` is at
+    /// `ptr.offset(i * strides[0] + j * strides[1] + ...)`. Use
+    /// [`.deref_into_view()`](Self::deref_into_view) to access the
+    /// elements.
+    ///
+    /// **Panics** if the number of strides is not the same as the number of
+    /// axes.
+    ///
+    /// # Safety
+    ///
+    /// The caller is responsible for ensuring all of the following:
+    ///
+    /// * `ptr` must be non-null, and it must be safe to [`.offset()`] `ptr` by
+    ///   zero.
+    ///
+    /// * All possible pointers generated by moving along all axes must be in
+    ///   bounds or one byte past the end of a single allocation with element
+    ///   type `A`. The only exceptions are if the array is empty or the element
+    ///   type is zero-sized. In these cases, `ptr` may be dangling, but it must
+    ///   still be safe to [`.offset()`] the pointer along the axes.
+    ///
+    /// * The offset in units of bytes between the least address and greatest
+    ///   address by moving along all axes must not exceed `isize::MAX`.
+    ///
+    /// * The product of non-zero axis lengths must not exceed `isize::MAX`.
+    ///
+    /// This function can use debug assertions to check some of these requirements,
+    /// but it's not a complete check.
+    ///
+    /// [`.offset()`]: https://doc.rust-lang.org/stable/std/primitive.pointer.html#method.offset
+    pub unsafe fn from_shape_strides_ptr<Sh>(shape: Sh, strides: &[isize], ptr: *const A) -> Self
+    where
+        Sh: IntoDimension<Dim = D>,
+    {
+        let dim = shape.into_dimension();
+        assert_eq!(
+            strides.len(),
+            dim.ndim(),
+            "the number of strides must be the same as the number of axes"
+        );
+        // Negative strides are stored as their two's complement
+        let mut dim_strides = D::zeros(dim.ndim());
+        for (s, &stride) in dim_strides.slice_mut().iter_mut().zip(strides) {
+            *s = stride as Ix;
+        }
+        if cfg!(debug_assertions) {
+            assert!(!ptr.is_null(), "The pointer must be non-null.");
+            dimension::max_abs_offset_check_overflow::<A, _>(&dim, &dim_strides).unwrap();
+        }
+        RawArrayView::new_(ptr, dim, dim_strides)
+    }
+
     /// Converts to a read-only view of the array.
     ///
     /// # Safety
@@ -226,6 +284,69 @@ where
         RawArrayViewMut::new_(ptr, dim, strides)
     }
 
+    /// Create an `RawArrayViewMut<A, D>` from a raw pointer to the first element and
+    /// strides in units of elements, which can be negative or zero.
+    ///
+    /// This is the layout used by most foreign libraries: `ptr` points to the
+    /// element at index zero along every axis (not necessarily the lowest
+    /// address in memory), and element `[i, j, ...]` is at
+    /// `ptr.offset(i * strides[0] + j * strides[1] + ...)`. Use
+    /// [`.deref_into_view()`](Self::deref_into_view) or
+    /// [`.deref_into_view_mut()`](Self::deref_into_view_mut) to access the
+    /// elements.
+    ///
+    /// A mutable view must not have two indices that refer to the same
+    /// element, so before using `.deref_into_view_mut()`, make sure that axes
+    /// with stride zero have a length of at most one and that the axes don't
+    /// overlap otherwise.
+    ///
+    /// **Panics** if the number of strides is not the same as the number of
+    /// axes.
+    ///
+    /// # Safety
+    ///
+    /// The caller is responsible for ensuring all of the following:
+    ///
+    /// * `ptr` must be non-null, and it must be safe to [`.offset()`] `ptr` by
+    ///   zero.
+    ///
+    /// * All possible pointers generated by moving along all axes must be in
+    ///   bounds or one byte past the end of a single allocation with element
+    ///   type `A`. The only exceptions are if the array is empty or the element
+    ///   type is zero-sized. In these cases, `ptr` may be dangling, but it must
+    ///   still be safe to [`.offset()`] the pointer along the axes.
+    ///
+    /// * The offset in units of bytes between the least address and greatest
+    ///   address by moving along all axes must not exceed `isize::MAX`.
+    ///
+    /// * The product of non-zero axis lengths must not exceed `isize::MAX`.
+    ///
+    /// This function can use debug assertions to check some of these requirements,
+    /// but it's not a complete check.
+    ///
+    /// [`.offset()`]: https://doc.rust-lang.org/stable/std/primitive.pointer.html#method.offset
+    pub unsafe fn from_shape_strides_ptr<Sh>(shape: Sh, strides: &[isize], ptr: *mut A) -> Self
+    where
+        Sh: IntoDimension<Dim = D>,
+    {
+        let dim = shape.into_dimension();
+        assert_eq!(
+            strides.len(),
+            dim.ndim(),
+            "the number of strides must be the same as the number of axes"
+        );
+        // Negative strides are stored as their two's complement
+        let mut dim_strides = D::zeros(dim.ndim());
+        for (s, &stride) in dim_strides.slice_mut().iter_mut().zip(strides) {
+            *s = stride as Ix;
+        }
+        if cfg!(debug_assertions) {
+            assert!(!ptr.is_null(), "The pointer must be non-null.");
+            dimension::max_abs_offset_check_overflow::<A, _>(&dim, &dim_strides).unwrap();
+        }
+        RawArrayViewMut::new_(ptr, dim, dim_strides)
+    }
+
     /// Converts to a non-mutable `RawArrayView`.
     #[inline]
     pub(crate) fn into_raw_view(self) -> RawArrayView<A, D> {
@@ -6,10 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::mem::MaybeUninit;
+use std::mem::{size_of, MaybeUninit};
+use std::ptr::{addr_of, NonNull};
 
 use crate::imp_prelude::*;
-use crate::RawDataSubst;
+use crate::{RawDataSubst, Zip};
 
 
 /// Methods specific to arrays with `MaybeUninit` elements.
@@ -43,3 +44,162 @@ where
         ArrayBase::from_data_ptr(data, ptr).with_strides_dim(strides, dim)
     }
 }
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Return the pointer, shape and strides for a view of the component of
+    /// each element that is found at byte offset `offset` within the element.
+    ///
+    /// Return `None` if the component is zero-sized or if the element size is
+    /// not a multiple of the component size, so that the component can't be
+    /// addressed with strides counted in components.
+    // usize::is_multiple_of needs a newer Rust than the MSRV
+    #[allow(clippy::manual_is_multiple_of)]
+    fn component_parts<C>(&self, offset: usize) -> Option<(NonNull<C>, D, D)> {
+        if size_of::<C>() == 0 || size_of::<A>() % size_of::<C>() != 0 {
+            return None;
+        }
+        let factor = (size_of::<A>() / size_of::<C>()) as isize;
+        let mut strides = self.strides.clone();
+        for s in strides.slice_mut() {
+            *s = (*s as isize * factor) as usize;
+        }
+        // The pointer is non-null and properly aligned for C, even if the array is empty,
+        // because the field offset is a multiple of C's alignment.
+        let ptr = (self.ptr.as_ptr() as *mut u8).wrapping_add(offset) as *mut C;
+        unsafe { Some((NonNull::new_unchecked(ptr), self.dim.clone(), strides)) }
+    }
+}
+
+/// Methods specific to arrays with pairs as elements.
+///
+/// ***See also all methods for [`ArrayBase`]***
+///
+/// [`ArrayBase`]: struct.ArrayBase.html
+impl<A, B, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = (A, B)>,
+    D: Dimension,
+{
+    /// Split the array of pairs into one array for each component
+    /// (array of structs to struct of arrays).
+    ///
+    /// The arrays have the same shape as `self`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![(1, 'a'), (2, 'b'), (3, 'c')];
+    /// let (numbers, letters) = a.soa_split();
+    /// assert_eq!(numbers, array![1, 2, 3]);
+    /// assert_eq!(letters, array!['a', 'b', 'c']);
+    /// ```
+    pub fn soa_split(&self) -> (Array<A, D>, Array<B, D>)
+    where
+        S: Data,
+        A: Clone,
+        B: Clone,
+    {
+        (self.map(|elt| elt.0.clone()), self.map(|elt| elt.1.clone()))
+    }
+
+    /// Return a view of each component of the pairs, without copying.
+    ///
+    /// The views have the same shape as `self`, and strides that step over
+    /// the other component of each element.
+    ///
+    /// Return `None` if a component can't be viewed like this, which happens
+    /// if it is zero-sized or if the size of the pair is not a multiple of
+    /// its size.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![(1., 10), (2., 20), (3., 30)];
+    /// let (x, y) = a.soa_views().unwrap();
+    /// assert_eq!(x, array![1., 2., 3.]);
+    /// assert_eq!(y.sum(), 60);
+    /// ```
+    pub fn soa_views(&self) -> Option<(ArrayView<'_, A, D>, ArrayView<'_, B, D>)>
+    where
+        S: Data,
+    {
+        let (first, second) = pair_offsets::<A, B>();
+        let (ptr_a, dim_a, strides_a) = self.component_parts::<A>(first)?;
+        let (ptr_b, dim_b, strides_b) = self.component_parts::<B>(second)?;
+        unsafe {
+            Some((ArrayView::new(ptr_a, dim_a, strides_a),
+                  ArrayView::new(ptr_b, dim_b, strides_b)))
+        }
+    }
+
+    /// Return a mutable view of each component of the pairs, without
+    /// copying.
+    ///
+    /// Return `None` if a component can't be viewed like this; see
+    /// [`.soa_views()`](Self::soa_views).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![(1., 10), (2., 20)];
+    /// let (mut x, _) = a.soa_views_mut().unwrap();
+    /// x *= 2.;
+    /// assert_eq!(a, array![(2., 10), (4., 20)]);
+    /// ```
+    pub fn soa_views_mut(&mut self) -> Option<(ArrayViewMut<'_, A, D>, ArrayViewMut<'_, B, D>)>
+    where
+        S: DataMut,
+    {
+        let view = self.view_mut();
+        let (first, second) = pair_offsets::<A, B>();
+        let (ptr_a, dim_a, strides_a) = view.component_parts::<A>(first)?;
+        let (ptr_b, dim_b, strides_b) = view.component_parts::<B>(second)?;
+        unsafe {
+            Some((ArrayViewMut::new(ptr_a, dim_a, strides_a),
+                  ArrayViewMut::new(ptr_b, dim_b, strides_b)))
+        }
+    }
+}
+
+impl<A, B, D> Array<(A, B), D>
+where
+    D: Dimension,
+{
+    /// Join two arrays of components into one array of pairs
+    /// (struct of arrays to array of structs).
+    ///
+    /// This is the inverse of [`.soa_split()`](ArrayBase::soa_split).
+    ///
+    /// **Panics** if the arrays don't have the same shape.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1};
+    ///
+    /// let a = Array1::soa_join(&array![1, 2], &array!['a', 'b']);
+    /// assert_eq!(a, array![(1, 'a'), (2, 'b')]);
+    /// ```
+    pub fn soa_join<S1, S2>(a: &ArrayBase<S1, D>, b: &ArrayBase<S2, D>) -> Self
+    where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = B>,
+        A: Clone,
+        B: Clone,
+    {
+        Zip::from(a).and(b).map_collect(|a, b| (a.clone(), b.clone()))
+    }
+}
+
+/// Return the byte offsets of the two fields of `(A, B)`.
+fn pair_offsets<A, B>() -> (usize, usize) {
+    let elt = MaybeUninit::<(A, B)>::uninit();
+    let base = elt.as_ptr();
+    unsafe {
+        let first = addr_of!((*base).0) as usize - base as usize;
+        let second = addr_of!((*base).1) as usize - base as usize;
+        (first, second)
+    }
+}
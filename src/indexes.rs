@@ -302,6 +302,165 @@ where
 
 impl<D> ExactSizeIterator for IndicesIterF<D> where D: Dimension {}
 
+/// A counter of the multi-indices of an array shape, in logical order, for
+/// custom loops.
+///
+/// The counter starts at the index of all zeros. [`.advance()`](Self::advance)
+/// moves to the next index, carrying over to the previous axes like the
+/// digits of a number, and [`.advance_axis()`](Self::advance_axis) skips to
+/// the next index along an axis. [`.offset()`](Self::offset) computes the
+/// element offset of the current index for the strides of an array.
+///
+/// For a fixed dimension type like `Ix3`, the counter and its index are
+/// stored on the stack.
+///
+/// ```
+/// use ndarray::{array, IndexCounter};
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// let mut counter = IndexCounter::new(a.raw_dim());
+/// let mut elements = Vec::new();
+/// while !counter.is_done() {
+///     // Safe because the index is in bounds
+///     let offset = counter.offset(a.strides());
+///     elements.push(unsafe { *a.as_ptr().offset(offset) });
+///     counter.advance();
+/// }
+/// assert_eq!(elements, [1, 2, 3, 4, 5, 6]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexCounter<D> {
+    dim: D,
+    index: D,
+    done: bool,
+}
+
+impl<D> IndexCounter<D>
+where
+    D: Dimension,
+{
+    /// Create a counter of the indices of the shape `shape`, starting at the
+    /// index of all zeros.
+    ///
+    /// The counter is done from the start if the shape has no elements.
+    pub fn new<E>(shape: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let dim = shape.into_dimension();
+        IndexCounter {
+            index: D::zeros(dim.ndim()),
+            done: dim.size_checked() == Some(0),
+            dim,
+        }
+    }
+
+    /// Return the shape.
+    pub fn shape(&self) -> &[usize] {
+        self.dim.slice()
+    }
+
+    /// Return the current index.
+    ///
+    /// When the counter is done, the index is all zeros.
+    #[inline]
+    pub fn index(&self) -> &D {
+        &self.index
+    }
+
+    /// Return `true` if the counter has moved past the last index.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Move to the next index in logical order.
+    ///
+    /// Return `false`, and mark the counter as done, if the current index
+    /// was the last one.
+    #[inline]
+    pub fn advance(&mut self) -> bool {
+        match self.dim.ndim() {
+            0 => {
+                self.done = true;
+                false
+            }
+            n => self.advance_axis(Axis(n - 1)),
+        }
+    }
+
+    /// Move to the next index along `axis`, and reset the index of the
+    /// following axes to zero.
+    ///
+    /// This skips the rest of the subview at the current index along
+    /// `axis`; for the last axis, it's the same as
+    /// [`.advance()`](Self::advance). Return `false`, and mark the counter as
+    /// done, if there is no next index.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Axis, IndexCounter, Ix3};
+    ///
+    /// let mut counter = IndexCounter::new(Ix3(2, 3, 4));
+    /// counter.advance();
+    /// assert!(counter.advance_axis(Axis(1)));
+    /// assert_eq!(counter.index(), &Ix3(0, 1, 0));
+    /// assert!(counter.advance_axis(Axis(0)));
+    /// assert_eq!(counter.index(), &Ix3(1, 0, 0));
+    /// assert!(!counter.advance_axis(Axis(0)));
+    /// assert!(counter.is_done());
+    /// ```
+    #[inline]
+    pub fn advance_axis(&mut self, axis: Axis) -> bool {
+        let axis = axis.index();
+        assert!(axis < self.dim.ndim(), "IndexCounter: axis {} is out of bounds", axis);
+        if self.done {
+            return false;
+        }
+        for ix in &mut self.index.slice_mut()[axis + 1..] {
+            *ix = 0;
+        }
+        let dim = self.dim.slice();
+        for (&len, ix) in dim[..=axis].iter().zip(&mut self.index.slice_mut()[..=axis]).rev() {
+            *ix += 1;
+            if *ix < len {
+                return true;
+            }
+            *ix = 0;
+        }
+        self.done = true;
+        false
+    }
+
+    /// Return the offset in elements of the element at the current index,
+    /// for an array with the shape of the counter and `strides`.
+    ///
+    /// **Panics** if the length of `strides` is not the number of axes.
+    #[inline]
+    pub fn offset(&self, strides: &[isize]) -> isize {
+        assert_eq!(
+            strides.len(),
+            self.dim.ndim(),
+            "IndexCounter: expected {} strides, got {}",
+            self.dim.ndim(),
+            strides.len()
+        );
+        self.index
+            .slice()
+            .iter()
+            .zip(strides)
+            .map(|(&i, &s)| i as isize * s)
+            .sum()
+    }
+
+    /// Move back to the index of all zeros.
+    pub fn reset(&mut self) {
+        self.index = D::zeros(self.dim.ndim());
+        self.done = self.dim.size_checked() == Some(0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::indices;
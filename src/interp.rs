@@ -0,0 +1,312 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::{indices, IntoDimension, PadMode, Zip};
+
+/// How to interpolate between the elements, in [`map_coordinates`] and
+/// [`warp_affine`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InterpOrder {
+    /// The element nearest to the coordinates, rounding halfway cases away
+    /// from zero.
+    Nearest,
+    /// Linear interpolation between the `2^ndim` elements around the
+    /// coordinates: bilinear for two dimensions, trilinear for three.
+    Linear,
+}
+
+/// Sample `array` at the fractional coordinates `coords`, interpolating
+/// between its elements.
+///
+/// The last axis of `coords` has the coordinates of each point, one for
+/// each axis of `array`; the coordinates of element `[i, j]` are
+/// `[i as f64, j as f64]`. The result has the shape of `coords` without
+/// its last axis, with the value at each point.
+///
+/// The elements needed outside of `array` are taken from `boundary`, like
+/// [`.pad()`](ArrayBase::pad) does. For example, with
+/// [`PadMode::Constant`] the points far outside are the constant, and with
+/// [`PadMode::Edge`] they are the nearest edge element. Points with NaN
+/// coordinates are NaN.
+///
+/// **Panics** if the last axis of `coords` doesn't have one coordinate for
+/// each axis of `array`, or if `array` is empty and `boundary` is not
+/// a constant.
+///
+/// ```
+/// use ndarray::{array, map_coordinates, InterpOrder, PadMode};
+///
+/// let a = array![[0., 10.],
+///                [20., 30.]];
+/// let points = array![[0.5, 0.5], [0., 0.25], [1., 2.]];
+///
+/// let linear = map_coordinates(&a, &points, InterpOrder::Linear, &PadMode::Constant(0.));
+/// assert_eq!(linear, array![15., 2.5, 0.]);
+///
+/// let nearest = map_coordinates(&a, &points, InterpOrder::Nearest, &PadMode::Edge);
+/// assert_eq!(nearest, array![30., 0., 30.]);
+/// ```
+pub fn map_coordinates<A, S, S2, D, E>(
+    array: &ArrayBase<S, D>,
+    coords: &ArrayBase<S2, E>,
+    order: InterpOrder,
+    boundary: &PadMode<A>,
+) -> Array<A, E::Smaller>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    E: RemoveAxis,
+{
+    let ndim = array.ndim();
+    let last = Axis(coords.ndim().saturating_sub(1));
+    assert!(
+        coords.ndim() > 0 && coords.len_of(last) == ndim,
+        "map_coordinates: the last axis of the coordinates of shape {:?} must have length {}",
+        coords.shape(),
+        ndim
+    );
+    let constant = match *boundary {
+        PadMode::Constant(value) => Some(value),
+        _ => None,
+    };
+    assert!(
+        constant.is_some() || !array.is_empty(),
+        "map_coordinates: can't sample an empty array except with a constant boundary"
+    );
+    let mut sampler = Sampler::new(array.shape(), array.strides(), order, boundary);
+    let ptr = array.as_ptr();
+    Zip::from(coords.lanes(last)).map_collect(|point| {
+        if !sampler.locate(point.iter().copied()) {
+            return A::nan();
+        }
+        let mut value = A::zero();
+        sampler.for_each_corner(|weight, offset| {
+            let elt = match offset {
+                // Safe because the offset is of an element in bounds
+                Some(offset) => unsafe { *ptr.offset(offset) },
+                None => constant.unwrap(),
+            };
+            value = value + weight * elt;
+        });
+        value
+    })
+}
+
+/// Sample `img` at the coordinates given by the affine transform `matrix`
+/// of the coordinates of each element of the result, interpolating between
+/// the elements of `img`.
+///
+/// The first two axes of `img` are the rows and columns of an image; any
+/// further axes, like color channels, are sampled together. `matrix` is a
+/// 2 × 3 matrix, or a 3 × 3 matrix whose last row is ignored, that maps
+/// the row and column `[i, j]` of the result to the coordinates in `img`:
+/// `matrix · [i, j, 1]`. This is the inverse of the transform of the image,
+/// so that each element of the result is computed once. The result has
+/// shape `out_shape` along the first two axes and the shape of `img` along
+/// the other axes.
+///
+/// This gives the same result as [`map_coordinates`] with the transformed
+/// coordinates of all elements of the result, but computes them on the fly
+/// and only once for all channels.
+///
+/// **Panics** if `img` has fewer than two axes, if `matrix` is not 2 × 3 or
+/// 3 × 3, or if `img` has no rows or columns and `boundary` is not a
+/// constant.
+///
+/// ```
+/// use ndarray::{array, warp_affine, InterpOrder, PadMode};
+///
+/// let img = array![[1., 2., 3.],
+///                  [4., 5., 6.]];
+///
+/// // Rotate by a quarter turn: row i of the result is column 2 - i of img
+/// let rotate = array![[0., 1., 0.],
+///                     [-1., 0., 2.]];
+/// let rotated = warp_affine(&img, &rotate, (3, 2), InterpOrder::Nearest, &PadMode::Constant(0.));
+/// assert_eq!(rotated, array![[3., 6.], [2., 5.], [1., 4.]]);
+///
+/// // Shift by half a column
+/// let shift = array![[1., 0., 0.], [0., 1., 0.5]];
+/// let shifted = warp_affine(&img, &shift, (2, 3), InterpOrder::Linear, &PadMode::Edge);
+/// assert_eq!(shifted, array![[1.5, 2.5, 3.], [4.5, 5.5, 6.]]);
+/// ```
+pub fn warp_affine<A, S, S2, D>(
+    img: &ArrayBase<S, D>,
+    matrix: &ArrayBase<S2, Ix2>,
+    out_shape: (usize, usize),
+    order: InterpOrder,
+    boundary: &PadMode<A>,
+) -> Array<A, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    assert!(img.ndim() >= 2, "warp_affine: the image must have at least two axes");
+    assert!(
+        matrix.ncols() == 3 && (matrix.nrows() == 2 || matrix.nrows() == 3),
+        "warp_affine: the matrix must be 2 × 3 or 3 × 3, not {:?}",
+        matrix.shape()
+    );
+    let constant = match *boundary {
+        PadMode::Constant(value) => Some(value),
+        _ => None,
+    };
+    assert!(
+        constant.is_some() || (img.len_of(Axis(0)) > 0 && img.len_of(Axis(1)) > 0),
+        "warp_affine: can't sample an empty image except with a constant boundary"
+    );
+    let mut dim = img.raw_dim();
+    dim[0] = out_shape.0;
+    dim[1] = out_shape.1;
+    let mut out = Array::zeros(dim);
+    if out.is_empty() {
+        return out;
+    }
+
+    // The memory offsets in `img` of the channels, in the order of the
+    // channels of each element of `out`
+    let channel_strides = &img.strides()[2..];
+    let channel_offsets: Vec<isize> = indices(&img.shape()[2..])
+        .into_iter()
+        .map(|index| {
+            let index = index.into_dimension();
+            index.slice().iter().zip(channel_strides).map(|(&i, &s)| i as isize * s).sum()
+        })
+        .collect();
+
+    let mut sampler = Sampler::new(&img.shape()[..2], &img.strides()[..2], order, boundary);
+    let ptr = img.as_ptr();
+    let m = |r: usize, c: usize| matrix[[r, c]];
+    let cols = out_shape.1;
+    let data = out.as_slice_mut().unwrap();
+    for (p, pixel) in data.chunks_exact_mut(channel_offsets.len()).enumerate() {
+        let (i, j) = (A::from(p / cols).unwrap(), A::from(p % cols).unwrap());
+        let y = m(0, 0) * i + m(0, 1) * j + m(0, 2);
+        let x = m(1, 0) * i + m(1, 1) * j + m(1, 2);
+        if !sampler.locate([y, x].iter().copied()) {
+            pixel.iter_mut().for_each(|v| *v = A::nan());
+            continue;
+        }
+        sampler.for_each_corner(|weight, offset| {
+            for (v, &channel) in pixel.iter_mut().zip(&channel_offsets) {
+                let elt = match offset {
+                    // Safe because the offset is of an element in bounds
+                    Some(offset) => unsafe { *ptr.offset(offset + channel) },
+                    None => constant.unwrap(),
+                };
+                *v = *v + weight * elt;
+            }
+        });
+    }
+    out
+}
+
+/// Interpolation of an array at fractional coordinates: the memory offsets
+/// and weights of the elements around a point.
+struct Sampler<'a, A> {
+    order: InterpOrder,
+    boundary: &'a PadMode<A>,
+    shape: &'a [usize],
+    strides: &'a [isize],
+    /// The number of elements around the point along each axis
+    taps: usize,
+    /// The memory offset along each axis of each tap; `None` for an element
+    /// outside of a constant boundary
+    offsets: Vec<Option<isize>>,
+    /// The weight along each axis of each tap
+    weights: Vec<A>,
+}
+
+impl<'a, A: Float> Sampler<'a, A> {
+    fn new(shape: &'a [usize], strides: &'a [isize], order: InterpOrder, boundary: &'a PadMode<A>) -> Self {
+        let taps = match order {
+            InterpOrder::Nearest => 1,
+            InterpOrder::Linear => 2,
+        };
+        Sampler {
+            order,
+            boundary,
+            shape,
+            strides,
+            taps,
+            offsets: vec![None; shape.len() * taps],
+            weights: vec![A::one(); shape.len() * taps],
+        }
+    }
+
+    /// Find the elements around `point`, which has one coordinate for each
+    /// axis. Return `false` if any coordinate is NaN or too large.
+    fn locate<I>(&mut self, point: I) -> bool
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let taps = self.taps;
+        for (k, x) in point.into_iter().enumerate() {
+            let start = match self.order {
+                InterpOrder::Nearest => x.round(),
+                InterpOrder::Linear => x.floor(),
+            };
+            let start = match start.to_isize() {
+                Some(start) => start,
+                None => return false,
+            };
+            if let InterpOrder::Linear = self.order {
+                let t = x - x.floor();
+                self.weights[2 * k] = A::one() - t;
+                self.weights[2 * k + 1] = t;
+            }
+            let len = self.shape[k];
+            for tap in 0..taps {
+                let i = start + tap as isize;
+                let index = if 0 <= i && i < len as isize {
+                    Some(i as usize)
+                } else if let PadMode::Constant(_) = self.boundary {
+                    None
+                } else {
+                    Some(self.boundary.source_index(i, len))
+                };
+                self.offsets[taps * k + tap] = index.map(|i| i as isize * self.strides[k]);
+            }
+        }
+        true
+    }
+
+    /// Call `f` with the weight and memory offset of each element around the
+    /// point, skipping those with weight zero. The offset is `None` for
+    /// elements outside of a constant boundary.
+    fn for_each_corner<F>(&self, mut f: F)
+    where
+        F: FnMut(A, Option<isize>),
+    {
+        let taps = self.taps;
+        // Bit `k` of `corner` selects the element along axis `k`
+        for corner in 0..taps.pow(self.shape.len() as u32) {
+            let mut weight = A::one();
+            let mut offset = Some(0);
+            for k in 0..self.shape.len() {
+                let tap = (corner >> k) & (taps - 1);
+                weight = weight * self.weights[taps * k + tap];
+                offset = offset.and_then(|off| Some(off + self.offsets[taps * k + tap]?));
+            }
+            if weight != A::zero() {
+                f(weight, offset);
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reading and writing arrays in ndarray's own formats.
+//!
+//! - [`snapshot`]: a small, stable binary format for checkpointing arrays.
+//!
+//! This requires crate feature `std`.
+
+pub mod snapshot;
@@ -0,0 +1,642 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A small binary snapshot format for arrays.
+//!
+//! [`save`] writes an array with a short self-describing header, and
+//! [`load`] reads it back into an [`ArrayD`]. The format is meant for
+//! checkpointing long computations: it is cheap to read and write, it
+//! doesn't depend on serde, and files written by one version of ndarray can
+//! be read by later versions. It is not compatible with other tools; use
+//! the `npy` module for that.
+//!
+//! [`AxisAppender`] writes a snapshot that grows along its first or last
+//! axis, for example a time series of states, without rewriting what was
+//! already written.
+//!
+//! The element type must implement [`SnapshotElement`], which covers
+//! `bool`, the primitive integer and floating point types and complex
+//! numbers of `f32` and `f64`.
+//!
+//! # Format
+//!
+//! All integers are little endian.
+//!
+//! | Field         | Size          | Contents                                      |
+//! |---------------|---------------|-----------------------------------------------|
+//! | magic         | 8 bytes       | `\x89NDARRAY`                                 |
+//! | version       | 2 bytes       | major and minor version, currently 1.0        |
+//! | header length | 4 bytes       | the number of header bytes that follow        |
+//! | element type  | 1 byte        | the [tag](SnapshotElement::TAG) of the type   |
+//! | order         | 1 byte        | 0 for row major, 1 for column major           |
+//! | ndim          | 4 bytes       | the number of axes                            |
+//! | shape         | 8 bytes each  | the length of each axis                       |
+//! | data          |               | the elements in the given order               |
+//!
+//! Later minor versions may add fields at the end of the header, which
+//! readers of older minor versions skip using the header length; a new
+//! major version is not readable by older versions.
+//!
+//! This requires crate feature `std`.
+//!
+//! ```
+//! use ndarray::array;
+//! use ndarray::io::snapshot;
+//!
+//! let a = array![[1., 2., 3.], [4., 5., 6.]];
+//! let mut file = Vec::new();
+//! snapshot::save(&mut file, &a).unwrap();
+//!
+//! let b = snapshot::load::<_, f64>(&file[..]).unwrap();
+//! assert_eq!(b, a.into_dyn());
+//!
+//! // The element type is checked
+//! assert!(snapshot::load::<_, f32>(&file[..]).is_err());
+//! ```
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use num_complex::Complex;
+
+use crate::dimension;
+use crate::error::incompatible_shapes;
+use crate::imp_prelude::*;
+use crate::{ShapeBuilder, ShapeError};
+
+/// The magic string at the start of every snapshot
+const MAGIC: &[u8; 8] = b"\x89NDARRAY";
+
+/// The length of the magic string, version and header length
+const PREFIX_LEN: usize = 14;
+
+/// The major version of the format written by this version of ndarray
+const MAJOR_VERSION: u8 = 1;
+
+/// The minor version of the format written by this version of ndarray
+const MINOR_VERSION: u8 = 0;
+
+/// Size of the buffer used when writing the elements
+const WRITE_BUFFER_SIZE: usize = 1 << 13;
+
+/// An error from reading or writing snapshots.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// An I/O error.
+    Io(io::Error),
+    /// The data is not a valid snapshot.
+    Format(String),
+    /// The snapshot was written in a newer, incompatible version of the
+    /// format.
+    Version {
+        /// The major version of the snapshot
+        major: u8,
+        /// The minor version of the snapshot
+        minor: u8,
+    },
+    /// The element type of the snapshot is not the requested one.
+    DType {
+        /// The requested type
+        expected: &'static str,
+        /// The type in the snapshot
+        found: String,
+    },
+    /// The shape in the snapshot doesn't match the data.
+    Shape(ShapeError),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(err) => write!(f, "SnapshotError: {}", err),
+            SnapshotError::Format(msg) => write!(f, "SnapshotError: invalid snapshot: {}", msg),
+            SnapshotError::Version { major, minor } => {
+                write!(f, "SnapshotError: unsupported version {}.{}", major, minor)
+            }
+            SnapshotError::DType { expected, found } => {
+                write!(f, "SnapshotError: expected element type {}, found {}", expected, found)
+            }
+            SnapshotError::Shape(err) => write!(f, "SnapshotError: {}", err),
+        }
+    }
+}
+
+impl Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SnapshotError::Io(err) => Some(err),
+            SnapshotError::Shape(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        SnapshotError::Io(err)
+    }
+}
+
+impl From<ShapeError> for SnapshotError {
+    fn from(err: ShapeError) -> Self {
+        SnapshotError::Shape(err)
+    }
+}
+
+/// An element type that can be stored in snapshots.
+///
+/// This trait is implemented for `bool`, the primitive integer and floating
+/// point types (except `isize` and `usize`) and `Complex<f32>` and
+/// `Complex<f64>`. It is sealed and can't be implemented outside of ndarray.
+pub trait SnapshotElement: Copy {
+    /// The tag of the type in the snapshot header. The tags are part of the
+    /// format and never change.
+    const TAG: u8;
+
+    /// The name of the type, for example `"f64"`.
+    const NAME: &'static str;
+
+    #[doc(hidden)]
+    /// Read the element from `bytes` in little endian order, which has the
+    /// size of the element.
+    fn read_le(bytes: &[u8]) -> Self;
+
+    #[doc(hidden)]
+    /// Append the element in little endian order to `out`.
+    fn write_le(&self, out: &mut Vec<u8>);
+
+    private_decl! {}
+}
+
+macro_rules! impl_snapshot_element {
+    ($($elem:ident => $tag:expr),*) => {
+        $(
+        impl SnapshotElement for $elem {
+            const TAG: u8 = $tag;
+            const NAME: &'static str = stringify!($elem);
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let mut buf = [0; size_of::<$elem>()];
+                buf.copy_from_slice(bytes);
+                <$elem>::from_le_bytes(buf)
+            }
+
+            fn write_le(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+impl_snapshot_element!(i8 => 2, i16 => 3, i32 => 4, i64 => 5, i128 => 6,
+                       u8 => 7, u16 => 8, u32 => 9, u64 => 10, u128 => 11,
+                       f32 => 12, f64 => 13);
+
+impl SnapshotElement for bool {
+    const TAG: u8 = 1;
+    const NAME: &'static str = "bool";
+
+    fn read_le(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
+
+    fn write_le(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    private_impl! {}
+}
+
+macro_rules! impl_snapshot_complex {
+    ($($elem:ident => $tag:expr),*) => {
+        $(
+        impl SnapshotElement for Complex<$elem> {
+            const TAG: u8 = $tag;
+            const NAME: &'static str = concat!("Complex<", stringify!($elem), ">");
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let (re, im) = bytes.split_at(size_of::<$elem>());
+                Complex::new(<$elem>::read_le(re), <$elem>::read_le(im))
+            }
+
+            fn write_le(&self, out: &mut Vec<u8>) {
+                self.re.write_le(out);
+                self.im.write_le(out);
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+impl_snapshot_complex!(f32 => 14, f64 => 15);
+
+/// Return the name of the element type with tag `tag`.
+fn tag_name(tag: u8) -> String {
+    let name = match tag {
+        1 => bool::NAME,
+        2 => i8::NAME,
+        3 => i16::NAME,
+        4 => i32::NAME,
+        5 => i64::NAME,
+        6 => i128::NAME,
+        7 => u8::NAME,
+        8 => u16::NAME,
+        9 => u32::NAME,
+        10 => u64::NAME,
+        11 => u128::NAME,
+        12 => f32::NAME,
+        13 => f64::NAME,
+        14 => Complex::<f32>::NAME,
+        15 => Complex::<f64>::NAME,
+        _ => return format!("unknown type (tag {})", tag),
+    };
+    name.to_string()
+}
+
+/// The header of a snapshot.
+#[derive(Debug, PartialEq)]
+struct Header {
+    tag: u8,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl Header {
+    /// The length of the fields of the header that all minor versions have
+    const FIXED_LEN: usize = 6;
+
+    /// Encode the header, including the magic string and version.
+    fn to_bytes(&self) -> Vec<u8> {
+        let header_len = Self::FIXED_LEN + 8 * self.shape.len();
+        let mut out = Vec::with_capacity(PREFIX_LEN + header_len);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[MAJOR_VERSION, MINOR_VERSION]);
+        out.extend_from_slice(&(header_len as u32).to_le_bytes());
+        out.push(self.tag);
+        out.push(self.fortran_order as u8);
+        out.extend_from_slice(&(self.shape.len() as u32).to_le_bytes());
+        for &len in &self.shape {
+            out.extend_from_slice(&(len as u64).to_le_bytes());
+        }
+        out
+    }
+
+    /// Read and parse the header, leaving `reader` at the start of the data.
+    fn read<R: Read>(reader: &mut R) -> Result<Header, SnapshotError> {
+        let mut prefix = [0; PREFIX_LEN];
+        reader.read_exact(&mut prefix)?;
+        if &prefix[..8] != MAGIC {
+            return Err(SnapshotError::Format("missing magic string".to_string()));
+        }
+        let (major, minor) = (prefix[8], prefix[9]);
+        if major != MAJOR_VERSION {
+            return Err(SnapshotError::Version { major, minor });
+        }
+        let header_len = u32::from_le_bytes([prefix[10], prefix[11], prefix[12], prefix[13]]) as usize;
+        let mut header = Vec::new();
+        reader.take(header_len as u64).read_to_end(&mut header)?;
+        if header.len() != header_len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Header::parse(&header)
+    }
+
+    /// Parse the header fields, ignoring any trailing fields of later minor
+    /// versions.
+    fn parse(header: &[u8]) -> Result<Header, SnapshotError> {
+        let truncated = || SnapshotError::Format("truncated header".to_string());
+        if header.len() < Self::FIXED_LEN {
+            return Err(truncated());
+        }
+        let tag = header[0];
+        let fortran_order = match header[1] {
+            0 => false,
+            1 => true,
+            order => return Err(SnapshotError::Format(format!("invalid order {}", order))),
+        };
+        let ndim = u32::from_le_bytes([header[2], header[3], header[4], header[5]]) as usize;
+        let shape = ndim
+            .checked_mul(8)
+            .and_then(|n| header[Self::FIXED_LEN..].get(..n))
+            .ok_or_else(truncated)?;
+        let shape = shape
+            .chunks_exact(8)
+            .map(|len| usize::try_from(u64::from_le_bytes(<[u8; 8]>::try_from(len).unwrap())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| SnapshotError::Format("axis length overflows usize".to_string()))?;
+        Ok(Header { tag, fortran_order, shape })
+    }
+}
+
+/// Write the array to `writer` as a snapshot.
+///
+/// Arrays that are in Fortran (column major) layout are written in that
+/// order; all other arrays are written in standard (row major) order.
+///
+/// **Errors** if writing fails.
+///
+/// ```no_run
+/// use ndarray::Array2;
+/// use ndarray::io::snapshot;
+/// use std::fs::File;
+/// use std::io::BufWriter;
+///
+/// let state = Array2::<f64>::zeros((1000, 1000));
+/// snapshot::save(BufWriter::new(File::create("state.snap")?), &state)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn save<W, A, S, D>(mut writer: W, array: &ArrayBase<S, D>) -> Result<(), SnapshotError>
+where
+    W: Write,
+    A: SnapshotElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let fortran_order = !array.is_standard_layout() && array.t().is_standard_layout();
+    let header = Header {
+        tag: A::TAG,
+        fortran_order,
+        shape: array.shape().to_vec(),
+    };
+    writer.write_all(&header.to_bytes())?;
+    let elements = if fortran_order { array.t() } else { array.view() };
+    write_elements(&mut writer, elements)
+}
+
+/// Write the elements of `elements` in logical order.
+fn write_elements<W, A, D>(writer: &mut W, elements: ArrayView<'_, A, D>) -> Result<(), SnapshotError>
+where
+    W: Write,
+    A: SnapshotElement,
+    D: Dimension,
+{
+    let mut buf = Vec::with_capacity(WRITE_BUFFER_SIZE + size_of::<A>());
+    for elt in elements {
+        elt.write_le(&mut buf);
+        if buf.len() >= WRITE_BUFFER_SIZE {
+            writer.write_all(&buf)?;
+            buf.clear();
+        }
+    }
+    writer.write_all(&buf)?;
+    Ok(())
+}
+
+/// Read a snapshot from `reader`.
+///
+/// The element type of the snapshot must be `A`. A snapshot written in
+/// column major order is returned in Fortran (column major) layout.
+///
+/// **Errors** if reading fails, if the data is not a valid snapshot, if it
+/// was written in a newer major version of the format or if its element
+/// type is not `A`.
+///
+/// ```no_run
+/// use ndarray::{ArrayD, Ix2};
+/// use ndarray::io::snapshot;
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// let state: ArrayD<f64> = snapshot::load(BufReader::new(File::open("state.snap")?))?;
+/// let state = state.into_dimensionality::<Ix2>()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn load<R, A>(mut reader: R) -> Result<ArrayD<A>, SnapshotError>
+where
+    R: Read,
+    A: SnapshotElement,
+{
+    let header = Header::read(&mut reader)?;
+    if header.tag != A::TAG {
+        return Err(SnapshotError::DType {
+            expected: A::NAME,
+            found: tag_name(header.tag),
+        });
+    }
+    let shape = IxDyn(&header.shape);
+    let len = dimension::size_of_shape_checked(&shape)?;
+    let n_bytes = len
+        .checked_mul(size_of::<A>())
+        .ok_or_else(|| SnapshotError::Format("data size overflows usize".to_string()))?;
+    let mut bytes = Vec::new();
+    reader.take(n_bytes as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != n_bytes {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    let data = bytes.chunks_exact(size_of::<A>()).map(A::read_le).collect();
+    Ok(Array::from_shape_vec(shape.set_f(header.fortran_order), data)?)
+}
+
+/// Writer for snapshots that grow along an axis, one array at a time.
+///
+/// The arrays are appended along the first axis, in row major order, or
+/// along the last axis, in column major order, so each array is written to
+/// the end of the data and nothing is rewritten. Their shapes must be the
+/// same except along the appended axis. The length of the appended axis in
+/// the header is updated by [`.flush()`](Self::flush) and
+/// [`.finish()`](Self::finish).
+///
+/// A snapshot that was not finished, for example because the program was
+/// interrupted, can be continued with [`AxisAppender::resume`]: the length
+/// of the appended axis is recovered from the size of the file.
+///
+/// ```
+/// use ndarray::{array, Axis};
+/// use ndarray::io::snapshot::{self, AxisAppender};
+/// use std::io::Cursor;
+///
+/// // A time series of states of shape (3,), stacked along axis 0
+/// let mut appender = AxisAppender::new(Cursor::new(Vec::new()), &[0, 3], Axis(0)).unwrap();
+/// appender.append(&array![[1, 2, 3]]).unwrap();
+/// appender.append(&array![[4, 5, 6], [7, 8, 9]]).unwrap();
+/// assert_eq!(appender.len(), 3);
+/// let file = appender.finish().unwrap().into_inner();
+///
+/// let a = snapshot::load::<_, i32>(&file[..]).unwrap();
+/// assert_eq!(a, array![[1, 2, 3], [4, 5, 6], [7, 8, 9]].into_dyn());
+/// ```
+pub struct AxisAppender<W: Write + Seek, A> {
+    writer: W,
+    /// The position of the start of the snapshot
+    start: u64,
+    axis: Axis,
+    shape: Vec<usize>,
+    elem: PhantomData<A>,
+}
+
+impl<W, A> AxisAppender<W, A>
+where
+    W: Write + Seek,
+    A: SnapshotElement,
+{
+    /// Start a snapshot at the current position of `writer`, of arrays of
+    /// shape `shape` that are appended along `axis`.
+    ///
+    /// The length of `shape` along `axis` is ignored; the snapshot starts
+    /// with length zero along `axis`.
+    ///
+    /// **Errors** if writing fails.<br>
+    /// **Panics** if `shape` is empty, or if `axis` is not the first or the
+    /// last axis of `shape`.
+    pub fn new(mut writer: W, shape: &[usize], axis: Axis) -> Result<Self, SnapshotError> {
+        assert!(
+            !shape.is_empty(),
+            "AxisAppender: the shape must have at least one axis"
+        );
+        assert!(
+            axis.index() == 0 || axis.index() + 1 == shape.len(),
+            "AxisAppender: can only append along the first or the last axis"
+        );
+        let mut shape = shape.to_vec();
+        shape[axis.index()] = 0;
+        let start = writer.stream_position()?;
+        let header = Header {
+            tag: A::TAG,
+            fortran_order: axis.index() != 0,
+            shape,
+        };
+        writer.write_all(&header.to_bytes())?;
+        Ok(AxisAppender {
+            writer,
+            start,
+            axis,
+            shape: header.shape,
+            elem: PhantomData,
+        })
+    }
+
+    /// Return the axis that arrays are appended along.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// Return the shape of the snapshot so far.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Return the length of the snapshot along the appended axis.
+    pub fn len(&self) -> usize {
+        self.shape[self.axis.index()]
+    }
+
+    /// Return `true` if nothing has been appended.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append `array` along the axis of the snapshot.
+    ///
+    /// **Errors** if the shape of `array` is not the shape of the snapshot,
+    /// except along the appended axis, or if writing fails.
+    pub fn append<S, D>(&mut self, array: &ArrayBase<S, D>) -> Result<(), SnapshotError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let axis = self.axis.index();
+        let matches = array.ndim() == self.shape.len()
+            && (array.shape().iter().zip(&self.shape).enumerate()).all(|(i, (a, b))| i == axis || a == b);
+        if !matches {
+            return Err(incompatible_shapes(&IxDyn(array.shape()), &IxDyn(&self.shape)).into());
+        }
+        if axis == 0 {
+            write_elements(&mut self.writer, array.view())?;
+        } else {
+            write_elements(&mut self.writer, array.t())?;
+        }
+        self.shape[axis] += array.len_of(self.axis);
+        Ok(())
+    }
+
+    /// Update the length of the appended axis in the header and flush the
+    /// writer, so that the snapshot can be read up to this point.
+    ///
+    /// **Errors** if writing fails.
+    pub fn flush(&mut self) -> Result<(), SnapshotError> {
+        let offset = (PREFIX_LEN + Header::FIXED_LEN + 8 * self.axis.index()) as u64;
+        let end = self.writer.stream_position()?;
+        self.writer.seek(SeekFrom::Start(self.start + offset))?;
+        self.writer.write_all(&(self.len() as u64).to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Update the header, and return the underlying writer positioned at
+    /// the end of the snapshot.
+    ///
+    /// **Errors** if writing fails.
+    pub fn finish(mut self) -> Result<W, SnapshotError> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<A: SnapshotElement> AxisAppender<File, A> {
+    /// Continue appending to the snapshot in `file`, which must be open for
+    /// reading and writing.
+    ///
+    /// Row major snapshots are appended along the first axis and column
+    /// major snapshots along the last axis. The length of that axis is
+    /// recovered from the size of the file, so a snapshot whose header was
+    /// not updated is continued after its last complete array; an
+    /// incomplete array at the end of the file is discarded.
+    ///
+    /// **Errors** if reading or writing fails, if the file is not a valid
+    /// snapshot or if its element type is not `A`.
+    pub fn resume(mut file: File) -> Result<Self, SnapshotError> {
+        file.seek(SeekFrom::Start(0))?;
+        let header = Header::read(&mut file)?;
+        if header.tag != A::TAG {
+            return Err(SnapshotError::DType {
+                expected: A::NAME,
+                found: tag_name(header.tag),
+            });
+        }
+        if header.shape.is_empty() {
+            return Err(SnapshotError::Format("can't append to a zero-dimensional snapshot".to_string()));
+        }
+        let axis = if header.fortran_order { Axis(header.shape.len() - 1) } else { Axis(0) };
+        let data_start = file.stream_position()?;
+        let data_len = file.metadata()?.len().saturating_sub(data_start);
+        let mut shape = header.shape;
+        let len = shape[axis.index()];
+        shape[axis.index()] = 1;
+        let slab_len = dimension::size_of_shape_checked(&IxDyn(&shape))? as u64 * size_of::<A>() as u64;
+        // Arrays with no elements take no space; keep their length
+        shape[axis.index()] = data_len.checked_div(slab_len).map_or(len, |n| n as usize);
+        file.set_len(data_start + shape[axis.index()] as u64 * slab_len)?;
+        file.seek(SeekFrom::End(0))?;
+        let mut appender = AxisAppender {
+            writer: file,
+            start: 0,
+            axis,
+            shape,
+            elem: PhantomData,
+        };
+        appender.flush()?;
+        Ok(appender)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::imp_prelude::*;
+
+/// An iterator over the diagonals of a two-dimensional array, from the
+/// bottom left corner to the top right corner.
+///
+/// Iterator element type is `ArrayView1<'a, A>`.
+///
+/// See [`.diagonals_iter()`](../struct.ArrayBase.html#method.diagonals_iter)
+/// for more information.
+pub struct Diagonals<'a, A> {
+    base: ArrayView2<'a, A>,
+    /// Offset of the next diagonal from the front
+    front: isize,
+    /// Offset one past the next diagonal from the back
+    back: isize,
+}
+
+impl<'a, A> Diagonals<'a, A> {
+    pub(crate) fn new(base: ArrayView2<'a, A>) -> Self {
+        let (m, n) = base.dim();
+        // A non-empty array has m + n - 1 diagonals
+        let (front, back) = if m == 0 || n == 0 {
+            (0, 0)
+        } else {
+            (1 - m as isize, n as isize)
+        };
+        Diagonals { base, front, back }
+    }
+}
+
+impl<'a, A> Clone for Diagonals<'a, A> {
+    fn clone(&self) -> Self {
+        Diagonals {
+            base: self.base,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, A> Iterator for Diagonals<'a, A> {
+    type Item = ArrayView1<'a, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let k = self.front;
+        self.front += 1;
+        Some(self.base.into_diag_offset(k))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, A> DoubleEndedIterator for Diagonals<'a, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.base.into_diag_offset(self.back))
+    }
+}
+
+impl<'a, A> ExactSizeIterator for Diagonals<'a, A> {
+    fn len(&self) -> usize {
+        (self.back - self.front) as usize
+    }
+}
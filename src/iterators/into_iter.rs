@@ -79,13 +79,17 @@ where
     D: Dimension
 {
     fn drop(&mut self) {
-        if !self.has_unreachable_elements || mem::size_of::<A>() == 0 || !mem::needs_drop::<A>() {
+        if !mem::needs_drop::<A>() {
             return;
         }
 
-        // iterate til the end
+        // iterate til the end, dropping the remaining elements
         while let Some(_) = self.next() { }
 
+        if !self.has_unreachable_elements || mem::size_of::<A>() == 0 {
+            return;
+        }
+
         unsafe {
             let data_ptr = self.array_data.as_ptr_mut();
             let view = RawArrayViewMut::new(self.array_head_ptr, self.inner.dim.clone(),
@@ -9,7 +9,8 @@
 pub use crate::dimension::Axes;
 pub use crate::indexes::{Indices, IndicesIter};
 pub use crate::iterators::{
-    AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, ExactChunks, ExactChunksIter,
-    ExactChunksIterMut, ExactChunksMut, IndexedIter, IndexedIterMut, Iter, IterMut, Lanes,
-    LanesIter, LanesIterMut, LanesMut, Windows,
+    AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, AxisWindows, AxisWindowsIter,
+    Diagonals, ExactChunks, ExactChunksIter, ExactChunksIterMut, ExactChunksMut, IndexedIter,
+    IndexedIterMut, Iter, IterMut, IterPtrs, Lanes, LanesIter, LanesIterMut, LanesMut,
+    OverlappingChunks, Windows,
 };
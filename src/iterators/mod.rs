@@ -9,9 +9,12 @@
 #[macro_use]
 mod macros;
 mod chunks;
+mod diagonals;
 mod into_iter;
 pub mod iter;
 mod lanes;
+mod overlapping;
+mod ptrs;
 mod windows;
 
 use std::iter::FromIterator;
@@ -25,8 +28,11 @@ use super::{ArrayBase, ArrayView, ArrayViewMut, Axis, Data, NdProducer, RemoveAx
 use super::{Dimension, Ix, Ixs};
 
 pub use self::chunks::{ExactChunks, ExactChunksIter, ExactChunksIterMut, ExactChunksMut};
+pub use self::diagonals::Diagonals;
 pub use self::lanes::{Lanes, LanesMut};
-pub use self::windows::Windows;
+pub use self::overlapping::{ChunkTail, OverlappingChunks};
+pub use self::ptrs::IterPtrs;
+pub use self::windows::{AxisWindows, AxisWindowsIter, Windows};
 pub use self::into_iter::IntoIter;
 
 use std::slice::{self, Iter as SliceIter, IterMut as SliceIterMut};
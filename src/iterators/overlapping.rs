@@ -0,0 +1,115 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::Slice;
+
+/// What to do with the final chunk if it is shorter than the chunk size, in
+/// [`.axis_chunks_with_overlap()`](crate::ArrayBase::axis_chunks_with_overlap).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChunkTail<A> {
+    /// Don't produce the partial chunk.
+    Drop,
+    /// Produce the partial chunk as it is, shorter than the others.
+    Keep,
+    /// Pad the partial chunk with the value to the full chunk size.
+    Pad(A),
+}
+
+/// An iterator over overlapping chunks along an axis.
+///
+/// The chunks are borrowed views, except a padded final chunk, which is
+/// owned.
+///
+/// See [`.axis_chunks_with_overlap()`](crate::ArrayBase::axis_chunks_with_overlap)
+/// for more information.
+#[derive(Clone)]
+pub struct OverlappingChunks<'a, A, D> {
+    view: ArrayView<'a, A, D>,
+    axis: Axis,
+    size: usize,
+    step: usize,
+    /// The start of the next chunk
+    start: usize,
+    /// The end of the previous chunk
+    covered: usize,
+    tail: ChunkTail<A>,
+}
+
+impl<'a, A, D: Dimension> OverlappingChunks<'a, A, D> {
+    pub(crate) fn new(
+        view: ArrayView<'a, A, D>,
+        axis: Axis,
+        size: usize,
+        overlap: usize,
+        tail: ChunkTail<A>,
+    ) -> Self {
+        assert_ne!(size, 0, "chunk size must be positive");
+        assert!(
+            overlap < size,
+            "overlap {} must be smaller than the chunk size {}",
+            overlap,
+            size
+        );
+        // Check the axis
+        view.len_of(axis);
+        OverlappingChunks {
+            view,
+            axis,
+            size,
+            step: size - overlap,
+            start: 0,
+            covered: 0,
+            tail,
+        }
+    }
+
+    fn chunk(&self, start: usize, end: usize) -> ArrayView<'a, A, D> {
+        let mut chunk = self.view.clone();
+        chunk.slice_axis_inplace(self.axis, Slice::from(start..end));
+        chunk
+    }
+}
+
+impl<'a, A, D> Iterator for OverlappingChunks<'a, A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    type Item = CowArray<'a, A, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.view.len_of(self.axis);
+        let (start, end) = (self.start, self.start + self.size);
+        if end <= len {
+            self.start += self.step;
+            self.covered = end;
+            return Some(self.chunk(start, end).into());
+        }
+        // The partial chunk, if it has elements that no other chunk has
+        if self.covered >= len || start >= len {
+            return None;
+        }
+        self.covered = len;
+        let partial = self.chunk(start, len);
+        match self.tail {
+            ChunkTail::Drop => None,
+            ChunkTail::Keep => Some(partial.into()),
+            ChunkTail::Pad(ref fill) => {
+                let mut dim = partial.raw_dim();
+                dim[self.axis.index()] = self.size;
+                let mut padded = Array::from_elem(dim, fill.clone());
+                padded
+                    .slice_axis_mut(self.axis, Slice::from(..len - start))
+                    .assign(&partial);
+                Some(padded.into())
+            }
+        }
+    }
+}
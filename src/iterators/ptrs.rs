@@ -0,0 +1,62 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+
+use super::Baseiter;
+use crate::imp_prelude::*;
+
+/// An iterator over raw pointers to the elements of an array, in row major
+/// or column major order.
+///
+/// Iterator element type is `*const A`.
+///
+/// See [`.iter_ptrs()`](../struct.ArrayBase.html#method.iter_ptrs) for more
+/// information.
+pub struct IterPtrs<'a, A, D> {
+    inner: Baseiter<A, D>,
+    life: PhantomData<&'a A>,
+}
+
+impl<'a, A, D: Dimension> IterPtrs<'a, A, D> {
+    pub(crate) fn new(v: ArrayView<'a, A, D>) -> Self {
+        IterPtrs {
+            inner: v.into_base_iter(),
+            life: PhantomData,
+        }
+    }
+}
+
+clone_bounds!(
+    ['a, A, D: Clone]
+    IterPtrs['a, A, D] {
+        @copy {
+            life,
+        }
+        inner,
+    }
+);
+
+impl<'a, A, D: Dimension> Iterator for IterPtrs<'a, A, D> {
+    type Item = *const A;
+
+    #[inline]
+    fn next(&mut self) -> Option<*const A> {
+        self.inner.next().map(|p| p as *const A)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, A, D: Dimension> ExactSizeIterator for IterPtrs<'a, A, D> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
@@ -20,6 +20,19 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
         E: IntoDimension<Dim = D>,
     {
         let window = window_size.into_dimension();
+        let mut stride = window.clone();
+        for st in stride.slice_mut() {
+            *st = 1;
+        }
+        Windows::new_with_stride(a, window, stride)
+    }
+
+    pub(crate) fn new_with_stride<E>(a: ArrayView<'a, A, D>, window_size: E, axis_strides: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let window = window_size.into_dimension();
+        let axis_strides = axis_strides.into_dimension();
         ndassert!(
             a.ndim() == window.ndim(),
             concat!(
@@ -30,18 +43,39 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
             a.ndim(),
             a.shape()
         );
+        ndassert!(
+            a.ndim() == axis_strides.ndim(),
+            concat!(
+                "Stride dimension {} does not match array dimension {} ",
+                "(with array of shape {:?})"
+            ),
+            axis_strides.ndim(),
+            a.ndim(),
+            a.shape()
+        );
         let mut size = a.dim;
-        for (sz, &ws) in size.slice_mut().iter_mut().zip(window.slice()) {
+        for ((sz, &ws), &st) in size
+            .slice_mut()
+            .iter_mut()
+            .zip(window.slice())
+            .zip(axis_strides.slice())
+        {
             assert_ne!(ws, 0, "window-size must not be zero!");
+            assert_ne!(st, 0, "stride must not be zero!");
             // cannot use std::cmp::max(0, ..) since arithmetic underflow panics
-            *sz = if *sz < ws { 0 } else { *sz - ws + 1 };
+            *sz = if *sz < ws { 0 } else { (*sz - ws) / st + 1 };
         }
 
         let window_strides = a.strides.clone();
+        // The windows are `axis_strides` elements apart along each axis
+        let mut base_strides = a.strides;
+        for (s, &st) in base_strides.slice_mut().iter_mut().zip(axis_strides.slice()) {
+            *s = (*s as isize * st as isize) as usize;
+        }
 
         unsafe {
             Windows {
-                base: ArrayView::new(a.ptr, size, a.strides),
+                base: ArrayView::new(a.ptr, size, base_strides),
                 window,
                 strides: window_strides,
             }
@@ -115,3 +149,155 @@ impl_iterator! {
         }
     }
 }
+
+/// Window producer and iterable over a single axis
+///
+/// See [`.axis_windows()`](../struct.ArrayBase.html#method.axis_windows) for
+/// more information.
+pub struct AxisWindows<'a, A, D> {
+    /// One element for each window, at the start of the window
+    base: ArrayView1<'a, A>,
+    window: D,
+    strides: D,
+}
+
+impl<'a, A, D: Dimension> AxisWindows<'a, A, D> {
+    pub(crate) fn new(a: ArrayView<'a, A, D>, axis: Axis, window_size: usize) -> Self {
+        assert_ne!(window_size, 0, "window-size must not be zero!");
+        let len = a.len_of(axis);
+        // cannot use std::cmp::max(0, ..) since arithmetic underflow panics;
+        // an array without elements has no windows, since `base` would
+        // otherwise refer to elements that don't exist
+        let nwindows = if len < window_size || a.is_empty() { 0 } else { len - window_size + 1 };
+        let mut window = a.raw_dim();
+        window[axis.index()] = window_size;
+        let axis_stride = a.strides[axis.index()];
+
+        unsafe {
+            AxisWindows {
+                base: ArrayView::new(a.ptr, Ix1(nwindows), Ix1(axis_stride)),
+                window,
+                strides: a.strides,
+            }
+        }
+    }
+}
+
+impl<'a, A, D: Dimension> NdProducer for AxisWindows<'a, A, D> {
+    type Item = ArrayView<'a, A, D>;
+    type Dim = Ix1;
+    type Ptr = *mut A;
+    type Stride = isize;
+
+    #[doc(hidden)]
+    fn raw_dim(&self) -> Ix1 {
+        self.base.raw_dim()
+    }
+
+    #[doc(hidden)]
+    fn layout(&self) -> Layout {
+        self.base.layout()
+    }
+
+    #[doc(hidden)]
+    fn as_ptr(&self) -> *mut A {
+        self.base.as_ptr() as *mut _
+    }
+
+    #[doc(hidden)]
+    fn contiguous_stride(&self) -> isize {
+        self.base.contiguous_stride()
+    }
+
+    #[doc(hidden)]
+    unsafe fn as_ref(&self, ptr: *mut A) -> Self::Item {
+        ArrayView::new_(ptr, self.window.clone(), self.strides.clone())
+    }
+
+    #[doc(hidden)]
+    unsafe fn uget_ptr(&self, i: &Ix1) -> *mut A {
+        self.base.uget_ptr(i)
+    }
+
+    #[doc(hidden)]
+    fn stride_of(&self, axis: Axis) -> isize {
+        self.base.stride_of(axis)
+    }
+
+    #[doc(hidden)]
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a, b) = self.base.split_at(axis, index);
+        (
+            AxisWindows {
+                base: a,
+                window: self.window.clone(),
+                strides: self.strides.clone(),
+            },
+            AxisWindows {
+                base: b,
+                window: self.window,
+                strides: self.strides,
+            },
+        )
+    }
+
+    private_impl! {}
+}
+
+impl<'a, A, D: Clone> Clone for AxisWindows<'a, A, D> {
+    fn clone(&self) -> Self {
+        AxisWindows {
+            base: self.base,
+            window: self.window.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+}
+
+impl<'a, A, D> IntoIterator for AxisWindows<'a, A, D>
+where
+    D: Dimension,
+    A: 'a,
+{
+    type Item = <Self::IntoIter as Iterator>::Item;
+    type IntoIter = AxisWindowsIter<'a, A, D>;
+    fn into_iter(self) -> Self::IntoIter {
+        AxisWindowsIter {
+            iter: self.base.into_elements_base(),
+            window: self.window,
+            strides: self.strides,
+        }
+    }
+}
+
+/// Window iterator over a single axis.
+///
+/// See [`.axis_windows()`](../struct.ArrayBase.html#method.axis_windows) for
+/// more information.
+pub struct AxisWindowsIter<'a, A, D> {
+    iter: ElementsBase<'a, A, Ix1>,
+    window: D,
+    strides: D,
+}
+
+impl_iterator! {
+    ['a, A, D: Dimension]
+    [Clone => 'a, A, D: Clone]
+    AxisWindowsIter {
+        iter,
+        window,
+        strides,
+    }
+    AxisWindowsIter<'a, A, D> {
+        type Item = ArrayView<'a, A, D>;
+
+        fn item(&mut self, elt) {
+            unsafe {
+                ArrayView::new_(
+                    elt,
+                    self.window.clone(),
+                    self.strides.clone())
+            }
+        }
+    }
+}
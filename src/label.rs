@@ -0,0 +1,200 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::IntoDimension;
+
+/// Which elements are neighbors, in
+/// [`label_connected_components`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Connectivity {
+    /// Elements that share a face are neighbors: elements whose indices
+    /// differ by one along a single axis. These are the 4 direct neighbors
+    /// of a pixel, and the 6 direct neighbors of a voxel.
+    Face,
+    /// Elements that share a face, an edge or a corner are neighbors:
+    /// elements whose indices differ by at most one along every axis. These
+    /// are the 8 neighbors of a pixel, and the 26 neighbors of a voxel.
+    Full,
+}
+
+/// Label the connected components of the `true` elements of `mask`.
+///
+/// Return the array of labels, which has the shape of `mask`, and the
+/// number of components. The elements of each component are labeled with
+/// the same number from `1` to the number of components, in the order of
+/// the first element of each component, in logical order. The `false`
+/// elements are labeled `0`.
+///
+/// The components are found in two passes over the array, merging the
+/// labels of neighboring elements with a union-find structure, so this
+/// takes time linear in the number of elements.
+///
+/// ```
+/// use ndarray::{array, label_connected_components, Connectivity};
+///
+/// let mask = array![[true, true, false, false],
+///                   [false, false, false, true],
+///                   [false, true, true, false]];
+///
+/// let (labels, count) = label_connected_components(&mask, Connectivity::Face);
+/// assert_eq!(count, 3);
+/// assert_eq!(labels, array![[1, 1, 0, 0],
+///                           [0, 0, 0, 2],
+///                           [0, 3, 3, 0]]);
+///
+/// // The last two components touch diagonally
+/// let (labels, count) = label_connected_components(&mask, Connectivity::Full);
+/// assert_eq!(count, 2);
+/// assert_eq!(labels[[2, 2]], 2);
+/// ```
+pub fn label_connected_components<S, D>(mask: &ArrayBase<S, D>, connectivity: Connectivity) -> (Array<usize, D>, usize)
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let shape = mask.shape();
+    let ndim = shape.len();
+    // The strides of the labels, in standard layout
+    let mut strides = vec![1isize; ndim];
+    for k in (1..ndim).rev() {
+        strides[k - 1] = strides[k] * shape[k] as isize;
+    }
+    // The neighbors that come before an element in logical order, as index
+    // differences along each axis
+    let neighbors: Vec<Vec<isize>> = match connectivity {
+        Connectivity::Face => (0..ndim)
+            .map(|k| (0..ndim).map(|j| if j == k { -1 } else { 0 }).collect())
+            .collect(),
+        Connectivity::Full => {
+            let mut neighbors = Vec::new();
+            for code in 0..3usize.pow(ndim as u32) {
+                let mut delta = vec![0; ndim];
+                let mut rest = code;
+                for d in delta.iter_mut().rev() {
+                    *d = (rest % 3) as isize - 1;
+                    rest /= 3;
+                }
+                if delta.iter().find(|&&d| d != 0) == Some(&-1) {
+                    neighbors.push(delta);
+                }
+            }
+            neighbors
+        }
+    };
+
+    // First pass: give each element the label of a neighbor, or a new
+    // provisional label, and merge the labels of its neighbors.
+    let mut labels = vec![0; mask.len()];
+    let mut parent = vec![0];
+    for (i, (index, &m)) in mask.indexed_iter().enumerate() {
+        if !m {
+            continue;
+        }
+        let index = index.into_dimension();
+        let index = index.slice();
+        let mut label = 0;
+        for delta in &neighbors {
+            let mut offset = 0;
+            let in_bounds = index.iter().zip(delta).zip(shape).zip(&strides).all(|(((&x, &d), &n), &s)| {
+                offset += d * s;
+                let y = x as isize + d;
+                0 <= y && y < n as isize
+            });
+            if !in_bounds {
+                continue;
+            }
+            let neighbor = labels[(i as isize + offset) as usize];
+            if neighbor == 0 {
+                continue;
+            }
+            label = if label == 0 {
+                find_root(&mut parent, neighbor)
+            } else {
+                union(&mut parent, label, neighbor)
+            };
+        }
+        if label == 0 {
+            label = parent.len();
+            parent.push(label);
+        }
+        labels[i] = label;
+    }
+
+    // Second pass: number the components in order
+    let mut numbers = vec![0; parent.len()];
+    let mut count = 0;
+    for label in &mut labels {
+        if *label != 0 {
+            let root = find_root(&mut parent, *label);
+            if numbers[root] == 0 {
+                count += 1;
+                numbers[root] = count;
+            }
+            *label = numbers[root];
+        }
+    }
+    (Array::from_shape_vec(mask.raw_dim(), labels).unwrap(), count)
+}
+
+/// Return the root of the set of `label`, halving the path to it.
+fn find_root(parent: &mut [usize], mut label: usize) -> usize {
+    while parent[label] != label {
+        parent[label] = parent[parent[label]];
+        label = parent[label];
+    }
+    label
+}
+
+/// Merge the sets of `a` and `b`, and return the root of the merged set.
+fn union(parent: &mut [usize], a: usize, b: usize) -> usize {
+    let a = find_root(parent, a);
+    let b = find_root(parent, b);
+    let (root, child) = if a < b { (a, b) } else { (b, a) };
+    parent[child] = root;
+    root
+}
+
+/// Return the number of elements with each label in `labels`, as returned
+/// by [`label_connected_components`] with `count` components.
+///
+/// Element `i` of the result is the size of component `i`, for `i` from
+/// `1` to `count`; element `0` is the number of elements labeled `0`.
+///
+/// **Panics** if any label is greater than `count`.
+///
+/// ```
+/// use ndarray::{array, component_sizes, label_connected_components, Connectivity};
+///
+/// let mask = array![[true, false, true],
+///                   [true, false, true],
+///                   [true, false, false]];
+/// let (labels, count) = label_connected_components(&mask, Connectivity::Face);
+/// assert_eq!(component_sizes(&labels, count), vec![4, 3, 2]);
+/// ```
+pub fn component_sizes<S, D>(labels: &ArrayBase<S, D>, count: usize) -> Vec<usize>
+where
+    S: Data<Elem = usize>,
+    D: Dimension,
+{
+    let mut sizes = vec![0; count + 1];
+    for &label in labels {
+        assert!(
+            label <= count,
+            "component_sizes: label {} is greater than the number of components {}",
+            label,
+            count
+        );
+        sizes[label] += 1;
+    }
+    sizes
+}
@@ -69,7 +69,8 @@
 //!     needs matching memory layout to be efficient (with some exceptions).
 //!   + Efficient floating point matrix multiplication even for very large
 //!     matrices; can optionally use BLAS to improve it further.
-//! - **Requires Rust 1.49 or later**
+//! - **Requires Rust 1.51 or later**, except for the crate features `arrow`,
+//!   `mmap`, `half` and `wasm`, whose dependencies need a newer Rust.
 //!
 //! ## Crate Feature Flags
 //!
@@ -90,6 +91,36 @@
 //!   - Implies std
 //! - `approx`
 //!   - Enables implementations of traits from the [`approx`] crate.
+//! - `linalg`
+//!   - Enables the linear system solvers, inverse and determinant in [`linalg::solve`],
+//!     the symmetric eigendecomposition and matrix functions in [`linalg::eigh`],
+//!     and the matrix norms and condition number estimate in [`linalg::norm`].
+//!   - Implies std
+//! - `npy`
+//!   - Enables reading and writing NumPy's `.npy` and `.npz` files in [`npy`].
+//!   - Implies std
+//! - `csv`
+//!   - Enables reading and writing two-dimensional arrays as CSV, see [`csv`].
+//!   - Implies std
+//! - `arrow`
+//!   - Enables zero-copy conversions to and from Apache Arrow arrays, see [`arrow`].
+//!   - Implies std
+//! - `mmap`
+//!   - Enables arrays backed by memory-mapped files, see [`mmap`].
+//!   - Implies std
+//! - `simd`
+//!   - Enables the explicitly vectorized reductions, folds and `Zip` loops in
+//!     [`simd`].
+//! - `half`
+//!   - Enables the numeric methods for `f16` and `bf16` elements from the
+//!     `half` crate, see [`HalfFloat`].
+//! - `wasm`
+//!   - Enables conversions to and from JavaScript typed arrays for `wasm-bindgen`,
+//!     see [`wasm`].
+//!   - Implies std
+//! - `rand`
+//!   - Enables random permutations with [`Permutation::random`], and
+//!     re-exports the `rand` crate as [`rand`].
 //! - `blas`
 //!   - Enable transparent BLAS support for matrix multiplication.
 //!     Uses ``blas-src`` for pluggable backend, which needs to be configured
@@ -137,6 +168,8 @@ extern crate cblas_sys;
 #[cfg(feature = "docs")]
 pub mod doc;
 
+pub mod ffi;
+
 use std::marker::PhantomData;
 use alloc::sync::Arc;
 
@@ -144,25 +177,68 @@ pub use crate::dimension::dim::*;
 pub use crate::dimension::{Axis, AxisDescription, Dimension, IntoDimension, RemoveAxis};
 pub use crate::dimension::{DimAdd, DimMax};
 
+pub use crate::axis_names::Named;
+pub use crate::axis_tag::{AxisTags, HasAxis, Tagged};
 pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
-pub use crate::error::{ErrorKind, ShapeError};
-pub use crate::indexes::{indices, indices_of};
+#[cfg(feature = "std")]
+pub use crate::distance::distance_transform_edt;
+#[cfg(feature = "std")]
+pub use crate::duplicates::Norm;
+pub use crate::error::{AxisError, ErrorKind, OverflowError, ShapeError};
+pub use crate::expand::{ExpandAxis, ExpandPattern, Keep};
+pub use crate::expect_shape::{AxisLenPattern, ExpectShapeError, ExpectedShape, ShapePattern};
+pub use crate::halo::exchange_ghosts;
+#[cfg(feature = "std")]
+pub use crate::histogram::histogram2d;
+pub use crate::indexes::{indices, indices_of, IndexCounter};
+#[cfg(feature = "std")]
+pub use crate::interp::{map_coordinates, warp_affine, InterpOrder};
+pub use crate::iterators::ChunkTail;
+pub use crate::label::{component_sizes, label_connected_components, Connectivity};
+#[cfg(feature = "std")]
+pub use crate::numeric::{diff_report, DiffReport, QuantileSketch, Rounding, Summary};
+#[cfg(feature = "half")]
+pub use crate::numeric::HalfFloat;
+pub use crate::ops_into::{add_into, binary_op_into, div_into, mul_into, sub_into};
 pub use crate::order::Order;
+#[cfg(feature = "std")]
+pub use crate::order::{default_order, with_default_order};
+pub use crate::pad::PadMode;
+pub use crate::prune::argwhere;
+pub use crate::permutation::Permutation;
+pub use crate::pyramid::Aggregate;
+pub use crate::ragged::{RaggedArray, RaggedRows};
+pub use crate::resample::BinSpec;
+#[cfg(feature = "std")]
+pub use crate::resample::Reducer;
+pub use crate::searchsorted::SearchSide;
+pub use crate::selection::Selection;
+pub use crate::sort::{SortOrder, SortedIndicesIter};
+pub use crate::table_display::{TableDisplay, TableFormat};
 pub use crate::slice::{
-    MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim,
+    GatherElem, MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim,
 };
 
 use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut};
 
+pub use crate::arrayformat::ArrayDisplay;
 pub use crate::arraytraits::AsArray;
+pub use crate::array_builder::ArrayBuilder;
 #[cfg(feature = "std")]
 pub use crate::linalg_traits::NdFloat;
 pub use crate::linalg_traits::LinalgScalar;
 
+#[cfg(feature = "std")]
+pub use crate::array_pool::{ArrayPool, PooledArray};
+pub use crate::chunked::process_chunks;
+pub use crate::ternary::where_;
 #[allow(deprecated)] // stack_new_axis
-pub use crate::stacking::{concatenate, stack, stack_new_axis};
+pub use crate::stacking::{
+    concatenate, concatenate_checked, concatenate_fixed, concatenate_iter, interleave, stack,
+    stack_iter, stack_new_axis, ConcatError,
+};
 
 pub use crate::math_cell::MathCell;
 pub use crate::impl_views::IndexLonger;
@@ -173,16 +249,26 @@ mod macro_utils;
 #[macro_use]
 mod private;
 mod aliases;
+#[cfg(feature = "std")]
+mod array_pool;
 #[macro_use]
 mod itertools;
 mod argument_traits;
+mod array_builder;
 #[cfg(feature = "serde")]
 mod array_serde;
 mod arrayformat;
 mod arraytraits;
+mod axis_names;
+mod axis_tag;
+mod chunked;
 pub use crate::argument_traits::AssignElem;
 mod data_repr;
 mod data_traits;
+#[cfg(feature = "std")]
+mod distance;
+#[cfg(feature = "std")]
+mod duplicates;
 
 pub use crate::aliases::*;
 
@@ -196,23 +282,47 @@ pub use crate::free_functions::*;
 pub use crate::iterators::iter;
 
 mod error;
+mod expand;
+mod expect_shape;
 mod extension;
 mod geomspace;
+mod halo;
+#[cfg(feature = "std")]
+mod histogram;
 mod indexes;
+#[cfg(feature = "std")]
+mod interp;
 mod iterators;
+mod label;
 mod layout;
 mod linalg_traits;
 mod linspace;
 mod logspace;
 mod math_cell;
+mod morphology;
 mod numeric_util;
+mod ops_into;
 mod order;
+mod pack;
+mod pad;
+mod searchsorted;
+mod selection;
 mod partial;
+mod permutation;
+mod position;
+mod prune;
+mod pyramid;
+mod ragged;
+mod resample;
 mod shape_builder;
 #[macro_use]
 mod slice;
+mod sort;
 mod split_at;
 mod stacking;
+mod table_display;
+mod ternary;
+mod unique;
 mod low_level_util;
 #[macro_use]
 mod zip;
@@ -220,6 +330,8 @@ mod zip;
 mod dimension;
 
 pub use crate::zip::{FoldWhile, IntoNdProducer, NdProducer, Zip};
+#[doc(hidden)]
+pub use crate::zip::zip_axis_iter_part;
 
 pub use crate::layout::Layout;
 
@@ -1535,8 +1647,10 @@ mod impl_internal_constructors;
 mod impl_constructors;
 
 mod impl_methods;
+mod impl_fallible;
 mod impl_owned_array;
 mod impl_special_element_types;
+mod impl_complex;
 
 /// Private Methods
 impl<A, S, D> ArrayBase<S, D>
@@ -1613,9 +1727,40 @@ mod numeric;
 
 pub mod linalg;
 
+#[cfg(feature = "std")]
+pub mod io;
+
+#[cfg(feature = "npy")]
+pub mod npy;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "mmap")]
+pub mod mmap;
+
+#[cfg(feature = "simd")]
+pub mod simd;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// The `rand` crate, version 0.8, used by [`Permutation::random`].
+///
+/// **Requires crate feature `"rand"`**
+#[cfg(feature = "rand")]
+pub use rand_ as rand;
+
 mod impl_ops;
 pub use crate::impl_ops::ScalarOperand;
 
+// Elementwise comparison methods
+mod impl_compare;
+pub use crate::impl_compare::BitEq;
+
 #[cfg(feature = "approx")]
 mod array_approx;
 
@@ -0,0 +1,192 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Eigendecomposition of symmetric matrices, and functions of symmetric
+//! matrices.
+//!
+//! This module provides the methods [`.eigh()`](ArrayBase::eigh), the
+//! eigenvalues and eigenvectors of a symmetric matrix, and
+//! [`.sqrtm()`](ArrayBase::sqrtm),
+//! [`.expm_symmetric()`](ArrayBase::expm_symmetric) and
+//! [`.logm_symmetric()`](ArrayBase::logm_symmetric), which apply a function
+//! to the eigenvalues, on two-dimensional arrays of `f32` or `f64`.
+//!
+//! The eigendecomposition uses the cyclic Jacobi method. It is accurate,
+//! but each sweep takes time proportional to *n³*, so it is intended for
+//! small to medium sized matrices, such as covariance matrices.
+//!
+//! This requires crate feature `linalg`.
+//!
+//! ```
+//! use ndarray::array;
+//!
+//! // The square root of a covariance matrix
+//! let sigma = array![[4f64, 2.], [2., 5.]];
+//! let root = sigma.sqrtm().unwrap();
+//! assert!((root.dot(&root) - &sigma).iter().all(|d| d.abs() < 1e-12));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::linalg::solve::LinalgError;
+use crate::NdFloat;
+
+/// The maximum number of Jacobi sweeps; the method converges quadratically,
+/// usually in fewer than ten.
+const MAX_SWEEPS: usize = 100;
+
+/// # Symmetric Matrix Functions
+///
+/// These methods require crate feature `linalg`.
+/// See also the [`linalg::eigh`](crate::linalg::eigh) module.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the eigenvalues and eigenvectors of the symmetric matrix.
+    ///
+    /// Return the eigenvalues in ascending order and the matrix whose
+    /// columns are the corresponding orthonormal eigenvectors, so that
+    /// *A = V diag(λ) Vᵀ*. Only the symmetric part of the matrix,
+    /// *(A + Aᵀ) / 2*, is used.
+    ///
+    /// **Errors** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2f64, 1.], [1., 2.]];
+    /// let (values, vectors) = a.eigh().unwrap();
+    /// assert!((values[0] - 1.).abs() < 1e-12 && (values[1] - 3.).abs() < 1e-12);
+    /// let v = vectors.column(1);
+    /// assert!((a.dot(&v) - &v * 3.).iter().all(|d| d.abs() < 1e-12));
+    /// ```
+    pub fn eigh(&self) -> Result<(Array1<A>, Array2<A>), LinalgError> {
+        let (rows, cols) = self.dim();
+        if rows != cols {
+            return Err(LinalgError::NotSquare { rows, cols });
+        }
+        let n = rows;
+        let two = A::one() + A::one();
+        let mut a = (self + &self.t()) / two;
+        let mut v = Array2::eye(n);
+        let total = a.iter().fold(A::zero(), |acc, &x| acc + x * x);
+        let tol = A::epsilon() * A::epsilon() * total;
+        for _ in 0..MAX_SWEEPS {
+            let off = a
+                .indexed_iter()
+                .filter(|&((i, j), _)| i != j)
+                .fold(A::zero(), |acc, (_, &x)| acc + x * x);
+            if off <= tol {
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a[[p, q]];
+                    if apq == A::zero() {
+                        continue;
+                    }
+                    // The rotation that zeroes a[[p, q]]
+                    let theta = (a[[q, q]] - a[[p, p]]) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + A::one()).sqrt());
+                    let c = A::one() / (t * t + A::one()).sqrt();
+                    let s = t * c;
+                    rotate(a.view_mut(), p, q, c, s);
+                    rotate(a.view_mut().reversed_axes(), p, q, c, s);
+                    rotate(v.view_mut(), p, q, c, s);
+                }
+            }
+        }
+
+        // Sort the eigenvalues, with their eigenvectors
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[[i, i]].partial_cmp(&a[[j, j]]).unwrap());
+        let values = order.iter().map(|&i| a[[i, i]]).collect();
+        Ok((values, v.select(Axis(1), &order)))
+    }
+
+    /// Return the square root of the symmetric positive semidefinite matrix:
+    /// the symmetric positive semidefinite matrix *B* with *B B = A*.
+    ///
+    /// Eigenvalues that are negative by no more than the rounding error of
+    /// the eigendecomposition are treated as zero.
+    ///
+    /// **Errors** if the matrix is not square, or not positive semidefinite.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[9f64, 0.], [0., 4.]];
+    /// let root = a.sqrtm().unwrap();
+    /// assert!((root - array![[3., 0.], [0., 2.]]).iter().all(|d| d.abs() < 1e-12));
+    /// assert!(array![[1f64, 2.], [2., 1.]].sqrtm().is_err());
+    /// ```
+    pub fn sqrtm(&self) -> Result<Array2<A>, LinalgError> {
+        let (values, vectors) = self.eigh()?;
+        let largest = values.iter().fold(A::zero(), |acc, &x| acc.max(x.abs()));
+        let tol = A::from(values.len()).unwrap() * A::epsilon() * largest;
+        if values.iter().any(|&x| x < -tol) {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        Ok(compose(&vectors, values.mapv(|x| x.max(A::zero()).sqrt())))
+    }
+
+    /// Return the matrix exponential of the symmetric matrix.
+    ///
+    /// **Errors** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0f64, 0.], [0., 2f64.ln()]];
+    /// let exp = a.expm_symmetric().unwrap();
+    /// assert!((exp - array![[1., 0.], [0., 2.]]).iter().all(|d| d.abs() < 1e-12));
+    /// ```
+    pub fn expm_symmetric(&self) -> Result<Array2<A>, LinalgError> {
+        let (values, vectors) = self.eigh()?;
+        Ok(compose(&vectors, values.mapv(A::exp)))
+    }
+
+    /// Return the matrix logarithm of the symmetric positive definite
+    /// matrix: the symmetric matrix whose exponential is the matrix.
+    ///
+    /// **Errors** if the matrix is not square, or not positive definite.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2f64, 1.], [1., 2.]];
+    /// let log = a.logm_symmetric().unwrap();
+    /// let back = log.expm_symmetric().unwrap();
+    /// assert!((back - &a).iter().all(|d| d.abs() < 1e-12));
+    /// ```
+    pub fn logm_symmetric(&self) -> Result<Array2<A>, LinalgError> {
+        let (values, vectors) = self.eigh()?;
+        if values.iter().any(|&x| x <= A::zero()) {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        Ok(compose(&vectors, values.mapv(A::ln)))
+    }
+}
+
+/// Rotate columns `p` and `q` of `m` by the Jacobi rotation with cosine `c`
+/// and sine `s`.
+fn rotate<A: NdFloat>(mut m: ArrayViewMut2<'_, A>, p: usize, q: usize, c: A, s: A) {
+    for mut row in m.rows_mut() {
+        let (x, y) = (row[p], row[q]);
+        row[p] = c * x - s * y;
+        row[q] = s * x + c * y;
+    }
+}
+
+/// Return *V diag(values) Vᵀ*.
+fn compose<A: NdFloat>(vectors: &Array2<A>, values: Array1<A>) -> Array2<A> {
+    (vectors * &values).dot(&vectors.t())
+}
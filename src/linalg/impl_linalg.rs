@@ -605,6 +605,83 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
     }
 }
 
+/// Matrix multiplication of fixed size matrices.
+///
+/// Compute *C = A B*, where A is a *M* × *K* matrix and B is a *K* × *N*
+/// matrix, stored as nested arrays in row major order.
+///
+/// The shapes are part of the types, so a mismatch in the inner dimension *K*
+/// is a compile time error instead of a panic, and the result is a nested
+/// array that needs no allocation.
+///
+/// ```
+/// use ndarray::linalg::fixed_mat_mul;
+///
+/// let a = [[1., 2., 3.],
+///          [4., 5., 6.]];
+/// let b = [[1., 0.],
+///          [0., 1.],
+///          [1., 1.]];
+/// assert_eq!(fixed_mat_mul(&a, &b), [[4., 5.], [10., 11.]]);
+/// ```
+///
+/// Operands with mismatched shapes don't compile:
+///
+/// ```compile_fail
+/// use ndarray::linalg::fixed_mat_mul;
+///
+/// let a = [[1., 2., 3.], [4., 5., 6.]];
+/// let c = fixed_mat_mul(&a, &a);
+/// ```
+pub fn fixed_mat_mul<A, const M: usize, const K: usize, const N: usize>(
+    a: &[[A; K]; M],
+    b: &[[A; N]; K],
+) -> [[A; N]; M]
+where
+    A: LinalgScalar,
+{
+    let mut c = [[A::zero(); N]; M];
+    general_mat_mul(
+        A::one(),
+        &ArrayView2::from(&a[..]),
+        &ArrayView2::from(&b[..]),
+        A::zero(),
+        &mut ArrayViewMut2::from(&mut c[..]),
+    );
+    c
+}
+
+/// Matrix-vector multiplication of a fixed size matrix and vector.
+///
+/// Compute *y = A x*, where A is a *M* × *N* matrix stored as a nested array
+/// in row major order and x is an *N*-element vector.
+///
+/// The shapes are part of the types, so a mismatch in *N* is a compile time
+/// error instead of a panic, and the result is an array that needs no
+/// allocation.
+///
+/// ```
+/// use ndarray::linalg::fixed_mat_vec_mul;
+///
+/// let a = [[1, 2, 3],
+///          [4, 5, 6]];
+/// assert_eq!(fixed_mat_vec_mul(&a, &[1, 0, -1]), [-2, -2]);
+/// ```
+pub fn fixed_mat_vec_mul<A, const M: usize, const N: usize>(a: &[[A; N]; M], x: &[A; N]) -> [A; M]
+where
+    A: LinalgScalar,
+{
+    let mut y = [A::zero(); M];
+    general_mat_vec_mul(
+        A::one(),
+        &ArrayView2::from(&a[..]),
+        &ArrayView1::from(x),
+        A::zero(),
+        &mut ArrayViewMut1::from(&mut y),
+    );
+    y
+}
+
 /// General matrix-vector multiplication
 ///
 /// Use a raw view for the destination vector, so that it can be uninitalized.
@@ -8,8 +8,19 @@
 
 //! Linear algebra.
 
+pub use self::impl_linalg::fixed_mat_mul;
+pub use self::impl_linalg::fixed_mat_vec_mul;
 pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::Dot;
+pub use self::multi_dot::multi_dot;
 
 mod impl_linalg;
+mod multi_dot;
+
+#[cfg(feature = "linalg")]
+pub mod eigh;
+#[cfg(feature = "linalg")]
+pub mod norm;
+#[cfg(feature = "linalg")]
+pub mod solve;
@@ -0,0 +1,110 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::{CowArray, LinalgScalar};
+
+/// Return the product of a chain of matrices, multiplied in the order that
+/// takes the fewest scalar multiplications.
+///
+/// The product of matrices is associative, but its cost is not: for example
+/// with `a` of shape 10 × 1000, `b` 1000 × 10 and `c` 10 × 1000,
+/// `(a b) c` takes 200 000 multiplications and `a (b c)` twenty million.
+/// The order is chosen by dynamic programming over the shapes of the
+/// matrices, in time cubic in their number, before any multiplication.
+///
+/// Transposed matrices can be included as views, for example
+/// `multi_dot(&[&a.view(), &b.t(), &c.view()])`.
+///
+/// **Panics** if `matrices` is empty, or if the shapes of consecutive
+/// matrices are not compatible for matrix multiplication.
+///
+/// ```
+/// use ndarray::Array2;
+/// use ndarray::linalg::multi_dot;
+///
+/// let a = Array2::<f64>::ones((10, 1000));
+/// let b = Array2::<f64>::ones((1000, 10));
+/// let c = Array2::<f64>::ones((10, 1000));
+/// let abc = multi_dot(&[&a, &b, &c]);
+/// assert_eq!(abc, a.dot(&b).dot(&c));
+/// ```
+pub fn multi_dot<A, S>(matrices: &[&ArrayBase<S, Ix2>]) -> Array2<A>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    assert!(!matrices.is_empty(), "multi_dot: there must be at least one matrix");
+    for (i, pair) in matrices.windows(2).enumerate() {
+        assert_eq!(
+            pair[0].ncols(),
+            pair[1].nrows(),
+            "multi_dot: matrix {} of shape {:?} can't be multiplied by matrix {} of shape {:?}",
+            i,
+            pair[0].shape(),
+            i + 1,
+            pair[1].shape()
+        );
+    }
+    let dims: Vec<usize> = matrices
+        .iter()
+        .map(|m| m.nrows())
+        .chain(Some(matrices[matrices.len() - 1].ncols()))
+        .collect();
+    let splits = optimal_splits(&dims);
+    let views: Vec<ArrayView2<'_, A>> = matrices.iter().map(|m| m.view()).collect();
+    chain_product(&views, &splits, 0, views.len() - 1).into_owned()
+}
+
+/// Return the optimal splits of the chain of matrices whose shapes are
+/// `dims[i] × dims[i + 1]`: the product of matrices `i..=j` is best computed
+/// as the product of `i..=k` and `k + 1..=j`, where `k` is `splits[i][j]`.
+fn optimal_splits(dims: &[usize]) -> Vec<Vec<usize>> {
+    let n = dims.len() - 1;
+    let mut costs = vec![vec![0usize; n]; n];
+    let mut splits = vec![vec![0; n]; n];
+    for len in 1..n {
+        for i in 0..n - len {
+            let j = i + len;
+            costs[i][j] = usize::MAX;
+            for k in i..j {
+                let last_product = dims[i].saturating_mul(dims[k + 1]).saturating_mul(dims[j + 1]);
+                let cost = costs[i][k]
+                    .saturating_add(costs[k + 1][j])
+                    .saturating_add(last_product);
+                if cost < costs[i][j] {
+                    costs[i][j] = cost;
+                    splits[i][j] = k;
+                }
+            }
+        }
+    }
+    splits
+}
+
+/// Return the product of matrices `i..=j`, in the order of `splits`.
+fn chain_product<'a, A>(
+    matrices: &[ArrayView2<'a, A>],
+    splits: &[Vec<usize>],
+    i: usize,
+    j: usize,
+) -> CowArray<'a, A, Ix2>
+where
+    A: LinalgScalar,
+{
+    if i == j {
+        return CowArray::from(matrices[i]);
+    }
+    let k = splits[i][j];
+    let left = chain_product(matrices, splits, i, k);
+    let right = chain_product(matrices, splits, k + 1, j);
+    CowArray::from(left.dot(&right))
+}
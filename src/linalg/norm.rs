@@ -0,0 +1,185 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matrix norms and condition number estimation.
+//!
+//! This module provides the methods [`.norm_fro()`](ArrayBase::norm_fro),
+//! [`.norm_1()`](ArrayBase::norm_1), [`.norm_inf()`](ArrayBase::norm_inf)
+//! and [`.cond()`](ArrayBase::cond) on two-dimensional arrays of `f32` or
+//! `f64`.
+//!
+//! The condition number is estimated from the LU factorization without
+//! computing the inverse, so that a system can be checked cheaply before it
+//! is solved: the solution of *A x = b* can lose up to about
+//! *log₁₀(cond(A))* decimal digits of accuracy.
+//!
+//! This requires crate feature `linalg`.
+//!
+//! ```
+//! use ndarray::array;
+//!
+//! // Nearly singular: expect to lose about four digits
+//! let a = array![[1f64, 1.], [1., 1.0001]];
+//! assert!(a.cond().unwrap() > 1e4);
+//! ```
+
+use crate::imp_prelude::*;
+use crate::linalg::solve::{LinalgError, LU};
+use crate::NdFloat;
+
+/// The maximum number of iterations of the condition number estimator;
+/// it usually stops after two.
+const MAX_ITERATIONS: usize = 5;
+
+/// # Matrix Norms
+///
+/// These methods require crate feature `linalg`.
+/// See also the [`linalg::norm`](crate::linalg::norm) module.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Return the Frobenius norm of the matrix, the square root of the sum
+    /// of the squares of its elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[3f64, 0.], [0., 4.]].norm_fro(), 5.);
+    /// ```
+    pub fn norm_fro(&self) -> A {
+        self.fold(A::zero(), |acc, &x| acc + x * x).sqrt()
+    }
+
+    /// Return the 1-norm of the matrix, the largest sum of the absolute
+    /// values of a column.
+    ///
+    /// The norm of a matrix without elements is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[1f64, -2.], [3., 4.]].norm_1(), 6.);
+    /// ```
+    pub fn norm_1(&self) -> A {
+        max_abs_lane_sum(self.view().reversed_axes())
+    }
+
+    /// Return the infinity norm of the matrix, the largest sum of the
+    /// absolute values of a row.
+    ///
+    /// The norm of a matrix without elements is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[1f64, -2.], [3., 4.]].norm_inf(), 7.);
+    /// ```
+    pub fn norm_inf(&self) -> A {
+        max_abs_lane_sum(self.view())
+    }
+
+    /// Estimate the condition number of the square matrix in the 1-norm,
+    /// *‖A‖₁ ‖A⁻¹‖₁*.
+    ///
+    /// *‖A⁻¹‖₁* is estimated with Hager's method, as refined by Higham,
+    /// from a few solves with the LU factorization. The estimate is a lower
+    /// bound that is almost always within a factor of three of the exact
+    /// value. The condition number of a singular matrix is infinite.
+    ///
+    /// **Errors** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2f64, 0.], [0., 0.5]];
+    /// assert!((a.cond().unwrap() - 4.).abs() < 1e-12);
+    /// assert_eq!(array![[1f64, 2.], [2., 4.]].cond().unwrap(), f64::INFINITY);
+    /// ```
+    pub fn cond(&self) -> Result<A, LinalgError> {
+        let lu = self.lu()?;
+        if lu.size() == 0 {
+            return Ok(A::zero());
+        }
+        if lu.is_singular() {
+            return Ok(A::infinity());
+        }
+        Ok(self.norm_1() * inv_norm_1_estimate(&lu))
+    }
+}
+
+/// Return the largest sum of absolute values of a row of `m`.
+fn max_abs_lane_sum<A: NdFloat>(m: ArrayView2<'_, A>) -> A {
+    m.rows()
+        .into_iter()
+        .map(|row| row.fold(A::zero(), |acc, &x| acc + x.abs()))
+        .fold(A::zero(), A::max)
+}
+
+/// Return the 1-norm of a vector.
+fn norm_1<A: NdFloat>(x: &Array1<A>) -> A {
+    x.fold(A::zero(), |acc, &x| acc + x.abs())
+}
+
+/// Estimate *‖A⁻¹‖₁* from the factorization of the nonsingular matrix *A*.
+fn inv_norm_1_estimate<A: NdFloat>(lu: &LU<A>) -> A {
+    let n = lu.size();
+    let perm = lu.permutation();
+    // Solve A y = x, where P A = L U
+    let solve = |x: &Array1<A>| {
+        let mut y: Array1<A> = perm.iter().map(|&p| x[p]).collect();
+        lu.solve_inplace(&mut y);
+        y
+    };
+    // Solve Aᵀ z = x, where Aᵀ = Uᵀ Lᵀ P
+    let solve_transposed = |x: &Array1<A>| {
+        let mut v = x.clone();
+        lu.solve_transposed_inplace(&mut v);
+        let mut z = Array1::zeros(n);
+        for (&p, &v) in perm.iter().zip(&v) {
+            z[p] = v;
+        }
+        z
+    };
+
+    // Hager's method: maximize ‖A⁻¹ x‖₁ over ‖x‖₁ = 1 by gradient steps
+    // between the unit vectors, starting from their average.
+    let mut x = Array1::from_elem(n, A::one() / A::from(n).unwrap());
+    let mut estimate = A::zero();
+    let mut last = None;
+    for _ in 0..MAX_ITERATIONS {
+        let y = solve(&x);
+        estimate = estimate.max(norm_1(&y));
+        let z = solve_transposed(&y.mapv(|y| if y < A::zero() { -A::one() } else { A::one() }));
+        let (j, z_max) = z
+            .iter()
+            .enumerate()
+            .fold((0, A::zero()), |(j, max), (i, &z)| {
+                if z.abs() > max { (i, z.abs()) } else { (j, max) }
+            });
+        if z_max <= z.dot(&x) || last == Some(j) {
+            break;
+        }
+        x.fill(A::zero());
+        x[j] = A::one();
+        last = Some(j);
+    }
+
+    // Higham's alternative estimate, which catches matrices that make the
+    // gradient steps stall.
+    let alternating: Array1<A> = (0..n)
+        .map(|i| {
+            let b = A::one() + A::from(i).unwrap() / A::from(n.max(2) - 1).unwrap();
+            if i % 2 == 0 { b } else { -b }
+        })
+        .collect();
+    let alternative = A::from(2).unwrap() * norm_1(&solve(&alternating))
+        / A::from(3 * n).unwrap();
+    estimate.max(alternative)
+}
@@ -0,0 +1,333 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solving linear systems using LU factorization.
+//!
+//! This module provides [`LU`], the LU factorization with partial pivoting of
+//! a square matrix, and the methods [`.lu()`](ArrayBase::lu),
+//! [`.solve()`](ArrayBase::solve), [`.inv()`](ArrayBase::inv) and
+//! [`.det()`](ArrayBase::det) on two-dimensional arrays of `f32` or `f64`.
+//!
+//! The implementation is in pure Rust and is intended for small to medium
+//! sized systems; for large problems, use a LAPACK based crate such as
+//! [`ndarray-linalg`](https://crates.io/crates/ndarray-linalg).
+//!
+//! This requires crate feature `linalg`.
+//!
+//! ```
+//! use ndarray::array;
+//!
+//! let a = array![[3f64, 1.], [1., 2.]];
+//! let b = array![9., 8.];
+//! let x = a.solve(&b).unwrap();
+//! assert!((x - array![2., 3.]).iter().all(|d| d.abs() < 1e-12));
+//! assert!((a.det().unwrap() - 5.).abs() < 1e-12);
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, ShapeError, ErrorKind, Slice};
+
+/// An error from a linear algebra routine.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinalgError {
+    /// The matrix was required to be square, but has the given shape.
+    NotSquare {
+        /// Number of rows
+        rows: usize,
+        /// Number of columns
+        cols: usize,
+    },
+    /// The matrix is singular.
+    Singular,
+    /// The symmetric matrix was required to be positive definite (or
+    /// semidefinite), but has a negative eigenvalue.
+    NotPositiveDefinite,
+    /// The shape of an operand doesn't match the shape of the matrix.
+    Shape(ShapeError),
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::NotSquare { rows, cols } => {
+                write!(f, "LinalgError: matrix is not square, shape: [{}, {}]", rows, cols)
+            }
+            LinalgError::Singular => write!(f, "LinalgError: matrix is singular"),
+            LinalgError::NotPositiveDefinite => {
+                write!(f, "LinalgError: matrix is not positive definite")
+            }
+            LinalgError::Shape(err) => write!(f, "LinalgError: {}", err),
+        }
+    }
+}
+
+impl Error for LinalgError {}
+
+impl From<ShapeError> for LinalgError {
+    fn from(err: ShapeError) -> Self {
+        LinalgError::Shape(err)
+    }
+}
+
+/// The LU factorization with partial pivoting of a square matrix,
+/// *P A = L U*.
+///
+/// *P* is a permutation matrix, *L* is lower triangular with unit diagonal
+/// and *U* is upper triangular. The factorization is computed with
+/// [`.lu()`](ArrayBase::lu) and can be reused for solving several systems
+/// with the same matrix.
+///
+/// This requires crate feature `linalg`.
+#[derive(Clone, Debug)]
+pub struct LU<A> {
+    /// L (below the diagonal) and U (on and above the diagonal) packed together
+    lu: Array2<A>,
+    /// Row `i` of `P A` is row `perm[i]` of `A`
+    perm: Vec<usize>,
+    /// Whether `perm` is an odd permutation
+    odd: bool,
+}
+
+impl<A> LU<A>
+where
+    A: NdFloat,
+{
+    fn factorize<S>(a: &ArrayBase<S, Ix2>) -> Result<Self, LinalgError>
+    where
+        S: Data<Elem = A>,
+    {
+        let (rows, cols) = a.dim();
+        if rows != cols {
+            return Err(LinalgError::NotSquare { rows, cols });
+        }
+        let n = rows;
+        let mut lu = a.to_owned();
+        let mut perm = (0..n).collect::<Vec<_>>();
+        let mut odd = false;
+        for k in 0..n {
+            // Partial pivoting: use the largest element in the column as pivot
+            let (p, pivot) = lu
+                .slice(s![k.., k])
+                .indexed_iter()
+                .fold((k, A::zero()), |(p, max), (i, &x)| {
+                    if x.abs() > max { (k + i, x.abs()) } else { (p, max) }
+                });
+            if pivot == A::zero() {
+                // Nothing to eliminate in this column; the matrix is singular
+                continue;
+            }
+            if p != k {
+                for j in 0..n {
+                    lu.swap([k, j], [p, j]);
+                }
+                perm.swap(k, p);
+                odd = !odd;
+            }
+            let (top, mut bottom) = lu.view_mut().split_at(Axis(0), k + 1);
+            let pivot_row = top.row(k);
+            let pivot = pivot_row[k];
+            let pivot_tail = pivot_row.slice_axis(Axis(0), Slice::from(k + 1..));
+            for mut row in bottom.rows_mut() {
+                let l = row[k] / pivot;
+                row[k] = l;
+                if l != A::zero() {
+                    row.slice_axis_mut(Axis(0), Slice::from(k + 1..))
+                        .scaled_add(-l, &pivot_tail);
+                }
+            }
+        }
+        Ok(LU { lu, perm, odd })
+    }
+
+    /// Return the size *n* of the *n × n* factorized matrix.
+    pub fn size(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// Return `true` if the factorized matrix is singular, i.e. if *U* has a
+    /// zero on its diagonal.
+    pub fn is_singular(&self) -> bool {
+        self.lu.diag().iter().any(|&x| x == A::zero())
+    }
+
+    /// Return the lower triangular factor *L*, which has a unit diagonal.
+    pub fn l(&self) -> Array2<A> {
+        let mut l = self.lu.tril(-1);
+        l.diag_mut().fill(A::one());
+        l
+    }
+
+    /// Return the upper triangular factor *U*.
+    pub fn u(&self) -> Array2<A> {
+        self.lu.triu(0)
+    }
+
+    /// Return the row permutation: row `i` of *P A* is row
+    /// `permutation()[i]` of *A*.
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Return the determinant of the factorized matrix.
+    pub fn det(&self) -> A {
+        let det = self.lu.diag().fold(A::one(), |acc, &x| acc * x);
+        if self.odd { -det } else { det }
+    }
+
+    /// Solve *A x = b* for *x*.
+    ///
+    /// `b` can be one-dimensional, or have more dimensions to solve for many
+    /// right hand sides at once; then each lane along `Axis(0)` (for example
+    /// each column of a matrix) is solved for separately.
+    ///
+    /// **Errors** if the length of `b` along `Axis(0)` is not the size of
+    /// the matrix, or if the matrix is singular.
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        let n = self.size();
+        if b.len_of(Axis(0)) != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let mut x = Array::zeros(b.raw_dim());
+        let mut work = Array1::zeros(n);
+        for (mut x_lane, b_lane) in x.lanes_mut(Axis(0)).into_iter().zip(b.lanes(Axis(0))) {
+            for (w, &p) in work.iter_mut().zip(&self.perm) {
+                *w = b_lane[p];
+            }
+            self.solve_inplace(&mut work);
+            x_lane.assign(&work);
+        }
+        Ok(x)
+    }
+
+    /// Return the inverse of the factorized matrix.
+    ///
+    /// **Errors** if the matrix is singular.
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.solve(&Array2::eye(self.size()))
+    }
+
+    /// Solve *L U x = y* in place, where `x` holds *y* on entry.
+    pub(crate) fn solve_inplace(&self, x: &mut Array1<A>) {
+        let n = self.size();
+        // Forward substitution with L, which has a unit diagonal
+        for i in 1..n {
+            let s = self.lu.slice(s![i, ..i]).dot(&x.slice(s![..i]));
+            x[i] -= s;
+        }
+        // Back substitution with U
+        for i in (0..n).rev() {
+            let s = self.lu.slice(s![i, i + 1..]).dot(&x.slice(s![i + 1..]));
+            x[i] = (x[i] - s) / self.lu[[i, i]];
+        }
+    }
+
+    /// Solve *Uᵀ Lᵀ x = y* in place, where `x` holds *y* on entry.
+    pub(crate) fn solve_transposed_inplace(&self, x: &mut Array1<A>) {
+        let n = self.size();
+        // Forward substitution with Uᵀ
+        for i in 0..n {
+            let s = self.lu.slice(s![..i, i]).dot(&x.slice(s![..i]));
+            x[i] = (x[i] - s) / self.lu[[i, i]];
+        }
+        // Back substitution with Lᵀ, which has a unit diagonal
+        for i in (0..n).rev() {
+            let s = self.lu.slice(s![i + 1.., i]).dot(&x.slice(s![i + 1..]));
+            x[i] -= s;
+        }
+    }
+}
+
+/// # Linear Algebra
+///
+/// These methods require crate feature `linalg`.
+/// See also the [`linalg::solve`](crate::linalg::solve) module.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the LU factorization with partial pivoting of the square
+    /// matrix.
+    ///
+    /// The factorization of a singular matrix succeeds, but the resulting
+    /// [`LU`] can't be used for solving.
+    ///
+    /// **Errors** if the matrix is not square.
+    pub fn lu(&self) -> Result<LU<A>, LinalgError> {
+        LU::factorize(self)
+    }
+
+    /// Solve the linear system *A x = b* for *x*, where *A* is `self`.
+    ///
+    /// `b` can be a vector or a matrix; with a matrix, each column is a
+    /// separate right hand side. See [`LU::solve`] for details, and use
+    /// [`.lu()`](Self::lu) to reuse the factorization for many systems.
+    ///
+    /// **Errors** if the matrix is not square or is singular, or if `b` has
+    /// the wrong number of rows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2., 0.], [0., 4.]];
+    /// assert_eq!(a.solve(&array![2., 2.]).unwrap(), array![1., 0.5]);
+    /// ```
+    pub fn solve<S2, D>(&self, b: &ArrayBase<S2, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        self.lu()?.solve(b)
+    }
+
+    /// Return the inverse of the square matrix.
+    ///
+    /// **Errors** if the matrix is not square or is singular.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2., 0.], [0., 4.]];
+    /// assert_eq!(a.inv().unwrap(), array![[0.5, 0.], [0., 0.25]]);
+    /// ```
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.lu()?.inv()
+    }
+
+    /// Return the determinant of the square matrix.
+    ///
+    /// The determinant of a singular matrix is zero, and the determinant of
+    /// a *0 × 0* matrix is one.
+    ///
+    /// **Errors** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1f64, 2.], [3., 4.]];
+    /// assert!((a.det().unwrap() + 2.).abs() < 1e-12);
+    /// ```
+    pub fn det(&self) -> Result<A, LinalgError> {
+        Ok(self.lu()?.det())
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arrays backed by memory-mapped files.
+//!
+//! [`MmapArray`] maps a file read-only, and [`MmapCowArray`] maps it copy on
+//! write: its elements can be modified, but the changes are private to the
+//! array and are never written to the file. The operating system reads the
+//! pages of the file when they are accessed, so arrays larger than the
+//! memory can be sliced and iterated.
+//!
+//! The file holds the elements as they are laid out in memory, in native
+//! byte order; a header can be skipped with the `offset` argument.
+//!
+//! This requires crate feature `mmap`.
+//!
+//! ```
+//! use std::fs;
+//! use ndarray::mmap::MmapArray;
+//! use ndarray::{array, s, Ix2, ShapeBuilder};
+//!
+//! let path = std::env::temp_dir().join("ndarray-mmap-example.bin");
+//! let bytes: Vec<u8> = (0..6).flat_map(|x| f64::to_ne_bytes(x as f64)).collect();
+//! fs::write(&path, &bytes).unwrap();
+//!
+//! // Safe because the file is not modified while it's mapped
+//! let a = unsafe { MmapArray::<f64, Ix2>::open_mmap(&path, 0, (2, 3).f()).unwrap() };
+//! assert_eq!(a, array![[0., 2., 4.], [1., 3., 5.]]);
+//! assert_eq!(a.slice(s![.., 1..]).sum(), 14.);
+//! # drop(a);
+//! # fs::remove_file(&path).unwrap();
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::path::Path;
+use std::ptr::NonNull;
+use std::slice;
+
+use alloc::sync::Arc;
+use memmap2::{Mmap, MmapMut, MmapOptions};
+
+use crate::data_traits::{Data, DataMut, RawData, RawDataClone, RawDataMut};
+use crate::dimension::{self, offset_from_low_addr_ptr_to_logical_ptr};
+use crate::error::{from_kind, ErrorKind};
+use crate::{is_aligned, ArrayBase, Dimension, OwnedRepr, ShapeError, StrideShape};
+
+/// Array storage in a read-only memory map of a file.
+///
+/// Clones of an array share the map.
+///
+/// This requires crate feature `mmap`.
+#[derive(Debug)]
+pub struct MmapRepr<A> {
+    map: Arc<Mmap>,
+    life: PhantomData<A>,
+}
+
+/// Array storage in a copy on write memory map of a file.
+///
+/// This requires crate feature `mmap`.
+#[derive(Debug)]
+pub struct MmapCowRepr<A> {
+    map: MmapMut,
+    life: PhantomData<A>,
+}
+
+/// An array backed by a read-only memory-mapped file.
+///
+/// This requires crate feature `mmap`.
+pub type MmapArray<A, D> = ArrayBase<MmapRepr<A>, D>;
+
+/// An array backed by a copy on write memory-mapped file: modifications of
+/// the elements are not written to the file.
+///
+/// This requires crate feature `mmap`.
+pub type MmapCowArray<A, D> = ArrayBase<MmapCowRepr<A>, D>;
+
+/// An error from mapping a file to an array.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum MmapError {
+    /// An I/O error from opening or mapping the file.
+    Io(io::Error),
+    /// The array could not be created from the mapped data, because the
+    /// file is too short for the shape, or the offset is not a multiple of
+    /// the alignment of the element type.
+    Shape(ShapeError),
+}
+
+impl fmt::Display for MmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MmapError::Io(err) => write!(f, "MmapError: {}", err),
+            MmapError::Shape(err) => write!(f, "MmapError: {}", err),
+        }
+    }
+}
+
+impl Error for MmapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MmapError::Io(err) => Some(err),
+            MmapError::Shape(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for MmapError {
+    fn from(err: io::Error) -> Self {
+        MmapError::Io(err)
+    }
+}
+
+impl From<ShapeError> for MmapError {
+    fn from(err: ShapeError) -> Self {
+        MmapError::Shape(err)
+    }
+}
+
+impl<A, D> MmapArray<A, D>
+where
+    D: Dimension,
+{
+    /// Map the file at `path` read-only, and create an array with the
+    /// elements starting `offset` bytes into the file.
+    ///
+    /// The shape can have custom strides or memory order, like for
+    /// [`Array::from_shape_vec()`](crate::Array::from_shape_vec). The file
+    /// may be longer than needed for the shape.
+    ///
+    /// **Errors** if the file can't be opened or mapped, if it's too short
+    /// for the shape, or if `offset` is not a multiple of the alignment of
+    /// `A`.
+    ///
+    /// **Panics** if `A` is zero-sized.
+    ///
+    /// # Safety
+    ///
+    /// * The file must not be modified or truncated, by this or any other
+    ///   process, while the array or any of its clones exist.
+    ///
+    /// * The mapped bytes must be valid values of type `A`. This is always
+    ///   the case for primitive integer and floating point types.
+    pub unsafe fn open_mmap<P, Sh>(path: P, offset: u64, shape: Sh) -> Result<Self, MmapError>
+    where
+        P: AsRef<Path>,
+        Sh: Into<StrideShape<D>>,
+    {
+        let file = File::open(path)?;
+        let map = MmapOptions::new().offset(offset).map(&file)?;
+        let ptr = map.as_ptr() as *mut A;
+        let len = map.len();
+        let data = MmapRepr {
+            map: Arc::new(map),
+            life: PhantomData,
+        };
+        from_mapped_data(data, ptr, len, shape.into())
+    }
+}
+
+impl<A, D> MmapCowArray<A, D>
+where
+    D: Dimension,
+{
+    /// Map the file at `path` copy on write, and create an array with the
+    /// elements starting `offset` bytes into the file.
+    ///
+    /// Modifications of the elements are private to the array and are not
+    /// written to the file; only the pages that are modified are copied.
+    /// See [`MmapArray::open_mmap()`] for the arguments and errors.
+    ///
+    /// **Panics** if `A` is zero-sized.
+    ///
+    /// # Safety
+    ///
+    /// * The file must not be modified or truncated, by this or any other
+    ///   process, while the array exists.
+    ///
+    /// * The mapped bytes must be valid values of type `A`. This is always
+    ///   the case for primitive integer and floating point types.
+    pub unsafe fn open_mmap_cow<P, Sh>(path: P, offset: u64, shape: Sh) -> Result<Self, MmapError>
+    where
+        P: AsRef<Path>,
+        Sh: Into<StrideShape<D>>,
+    {
+        let file = File::open(path)?;
+        let mut map = MmapOptions::new().offset(offset).map_copy(&file)?;
+        let ptr = map.as_mut_ptr() as *mut A;
+        let len = map.len();
+        let data = MmapCowRepr {
+            map,
+            life: PhantomData,
+        };
+        from_mapped_data(data, ptr, len, shape.into())
+    }
+}
+
+/// Create an array with storage `data`, of the `len` bytes starting at
+/// `ptr`, which are owned by `data`.
+unsafe fn from_mapped_data<S, A, D>(
+    data: S,
+    ptr: *mut A,
+    len: usize,
+    shape: StrideShape<D>,
+) -> Result<ArrayBase<S, D>, MmapError>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    assert!(
+        mem::size_of::<A>() != 0,
+        "memory-mapped arrays of zero-sized elements are not supported"
+    );
+    if !is_aligned(ptr) {
+        return Err(from_kind(ErrorKind::IncompatibleLayout).into());
+    }
+    // Only the length of the slice is used
+    let elements = slice::from_raw_parts(ptr as *const A, len / mem::size_of::<A>());
+    let dim = shape.dim;
+    dimension::can_index_slice_with_strides(elements, &dim, &shape.strides)?;
+    let strides = shape.strides.strides_for_dim(&dim);
+    let ptr = ptr.add(offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides));
+    Ok(ArrayBase::from_data_ptr(data, NonNull::new_unchecked(ptr)).with_strides_dim(strides, dim))
+}
+
+/// Return `true` if `ptr` is in the bytes of `map` or one past the end.
+fn is_pointer_in_map<A>(map: &[u8], ptr: *const A) -> bool {
+    let start = map.as_ptr() as usize;
+    let ptr = ptr as usize;
+    start <= ptr && ptr <= start + map.len()
+}
+
+unsafe impl<A> RawData for MmapRepr<A> {
+    type Elem = A;
+
+    #[inline]
+    fn _data_slice(&self) -> Option<&[A]> {
+        None
+    }
+
+    fn _is_pointer_inbounds(&self, ptr: *const Self::Elem) -> bool {
+        is_pointer_in_map(&self.map, ptr)
+    }
+
+    private_impl! {}
+}
+
+unsafe impl<A> Data for MmapRepr<A> {
+    fn into_owned<D>(self_: ArrayBase<Self, D>) -> ArrayBase<OwnedRepr<Self::Elem>, D>
+    where
+        Self::Elem: Clone,
+        D: Dimension,
+    {
+        self_.to_owned()
+    }
+}
+
+unsafe impl<A> RawDataClone for MmapRepr<A> {
+    unsafe fn clone_with_ptr(&self, ptr: NonNull<Self::Elem>) -> (Self, NonNull<Self::Elem>) {
+        let data = MmapRepr {
+            map: self.map.clone(),
+            life: PhantomData,
+        };
+        (data, ptr)
+    }
+}
+
+unsafe impl<A> RawData for MmapCowRepr<A> {
+    type Elem = A;
+
+    #[inline]
+    fn _data_slice(&self) -> Option<&[A]> {
+        None
+    }
+
+    fn _is_pointer_inbounds(&self, ptr: *const Self::Elem) -> bool {
+        is_pointer_in_map(&self.map, ptr)
+    }
+
+    private_impl! {}
+}
+
+unsafe impl<A> RawDataMut for MmapCowRepr<A> {
+    #[inline]
+    fn try_ensure_unique<D>(_: &mut ArrayBase<Self, D>)
+    where
+        Self: Sized,
+        D: Dimension,
+    {
+    }
+
+    #[inline]
+    fn try_is_unique(&mut self) -> Option<bool> {
+        Some(true)
+    }
+}
+
+unsafe impl<A> Data for MmapCowRepr<A> {
+    fn into_owned<D>(self_: ArrayBase<Self, D>) -> ArrayBase<OwnedRepr<Self::Elem>, D>
+    where
+        Self::Elem: Clone,
+        D: Dimension,
+    {
+        self_.to_owned()
+    }
+}
+
+unsafe impl<A> DataMut for MmapCowRepr<A> {}
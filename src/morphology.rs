@@ -0,0 +1,288 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::{IntoDimension, PadMode, Zip};
+
+/// The minimum length of the last axis for operating on bit-packed lanes
+const PACKED_MIN_LEN: usize = 64;
+
+/// The bits in a packed word
+const BITS: usize = 64;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Op {
+    Erode,
+    Dilate,
+}
+
+/// # Morphology
+///
+/// These methods compute the binary morphological operations of a boolean
+/// array with a structuring element, the boolean array `footprint` whose
+/// `true` elements are the neighborhood of an element. The neighborhood is
+/// centered on element `len / 2` of the footprint along each axis.
+///
+/// The elements outside of the array are taken from `boundary`, like
+/// [`.pad()`](ArrayBase::pad) does; usually this is
+/// `PadMode::Constant(false)`, or `PadMode::Constant(true)` for an erosion
+/// that doesn't shrink the shapes at the edges of the array.
+///
+/// The operations slide the footprint over the array. If the last axis is
+/// long, they operate on 64 elements at a time, packed in the bits of
+/// words.
+///
+/// **Panics** if the footprint doesn't have the same number of axes as the
+/// array or has an axis of length zero.
+impl<S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = bool>,
+    D: RemoveAxis,
+{
+    /// Return the erosion of the array by `footprint`: the elements whose
+    /// whole neighborhood is `true`.
+    ///
+    /// ```
+    /// use ndarray::{array, PadMode};
+    ///
+    /// let a = array![[false, true, true, true],
+    ///                [true, true, true, true],
+    ///                [false, true, true, true]];
+    /// let cross = array![[false, true, false],
+    ///                    [true, true, true],
+    ///                    [false, true, false]];
+    /// assert_eq!(
+    ///     a.binary_erode(&cross, &PadMode::Constant(false)),
+    ///     array![[false, false, false, false],
+    ///            [false, true, true, false],
+    ///            [false, false, false, false]]
+    /// );
+    /// assert_eq!(
+    ///     a.binary_erode(&cross, &PadMode::Constant(true)),
+    ///     array![[false, false, true, true],
+    ///            [false, true, true, true],
+    ///            [false, false, true, true]]
+    /// );
+    /// ```
+    pub fn binary_erode<S2>(&self, footprint: &ArrayBase<S2, D>, boundary: &PadMode<bool>) -> Array<bool, D>
+    where
+        S2: Data<Elem = bool>,
+    {
+        morphology(self.view(), footprint.view(), boundary, Op::Erode)
+    }
+
+    /// Return the dilation of the array by `footprint`: the elements whose
+    /// neighborhood, reflected through its center, has any `true` element.
+    ///
+    /// ```
+    /// use ndarray::{array, PadMode};
+    ///
+    /// let a = array![[false, false, false],
+    ///                [false, true, false],
+    ///                [false, false, false]];
+    /// // The element and the one to its right
+    /// let right = array![[false, true, true]];
+    /// assert_eq!(
+    ///     a.binary_dilate(&right, &PadMode::Constant(false)),
+    ///     array![[false, false, false],
+    ///            [false, true, true],
+    ///            [false, false, false]]
+    /// );
+    /// ```
+    pub fn binary_dilate<S2>(&self, footprint: &ArrayBase<S2, D>, boundary: &PadMode<bool>) -> Array<bool, D>
+    where
+        S2: Data<Elem = bool>,
+    {
+        morphology(self.view(), footprint.view(), boundary, Op::Dilate)
+    }
+
+    /// Return the opening of the array by `footprint`: the dilation of its
+    /// erosion, which removes the shapes and parts of shapes that the
+    /// footprint doesn't fit in.
+    ///
+    /// ```
+    /// use ndarray::{array, PadMode};
+    ///
+    /// // Remove the isolated element
+    /// let a = array![true, true, true, false, true, false];
+    /// assert_eq!(
+    ///     a.binary_opening(&array![true, true, true], &PadMode::Constant(false)),
+    ///     array![true, true, true, false, false, false]
+    /// );
+    /// ```
+    pub fn binary_opening<S2>(&self, footprint: &ArrayBase<S2, D>, boundary: &PadMode<bool>) -> Array<bool, D>
+    where
+        S2: Data<Elem = bool>,
+    {
+        self.binary_erode(footprint, boundary)
+            .binary_dilate(footprint, boundary)
+    }
+
+    /// Return the closing of the array by `footprint`: the erosion of its
+    /// dilation, which fills the holes and gaps that the footprint doesn't
+    /// fit in.
+    ///
+    /// ```
+    /// use ndarray::{array, PadMode};
+    ///
+    /// // Fill the gap
+    /// let a = array![false, true, true, false, true, false, false];
+    /// assert_eq!(
+    ///     a.binary_closing(&array![true, true, true], &PadMode::Constant(false)),
+    ///     array![false, true, true, true, true, false, false]
+    /// );
+    /// ```
+    pub fn binary_closing<S2>(&self, footprint: &ArrayBase<S2, D>, boundary: &PadMode<bool>) -> Array<bool, D>
+    where
+        S2: Data<Elem = bool>,
+    {
+        self.binary_dilate(footprint, boundary)
+            .binary_erode(footprint, boundary)
+    }
+}
+
+fn morphology<D>(a: ArrayView<'_, bool, D>, footprint: ArrayView<'_, bool, D>, boundary: &PadMode<bool>, op: Op)
+    -> Array<bool, D>
+where
+    D: RemoveAxis,
+{
+    assert_eq!(
+        footprint.ndim(),
+        a.ndim(),
+        "morphology: the footprint must have the same number of axes as the array"
+    );
+    assert!(
+        footprint.shape().iter().all(|&len| len > 0),
+        "morphology: the footprint of shape {:?} must not have axes of length zero",
+        footprint.shape()
+    );
+    // Dilation takes the reflected footprint. The array is padded so that
+    // each window of the padded array, the size of the footprint, is the
+    // neighborhood of an element of the result.
+    let mut footprint = footprint;
+    let mut pad_width = Vec::with_capacity(a.ndim());
+    for (axis, &len) in footprint.clone().shape().iter().enumerate() {
+        let center = len / 2;
+        if op == Op::Dilate {
+            footprint.invert_axis(Axis(axis));
+            pad_width.push((len - 1 - center, center));
+        } else {
+            pad_width.push((center, len - 1 - center));
+        }
+    }
+    if a.is_empty() {
+        return a.to_owned();
+    }
+    let padded = a.pad(&pad_width, boundary.clone());
+
+    let last = a.ndim().wrapping_sub(1);
+    if a.ndim() > 0 && a.len_of(Axis(last)) >= PACKED_MIN_LEN {
+        return morphology_packed(padded.view(), footprint, a.raw_dim(), op);
+    }
+    let mut out = Array::from_elem(a.raw_dim(), false);
+    Zip::from(&mut out)
+        .and(padded.windows(footprint.raw_dim()))
+        .for_each(|out, window| {
+            *out = match op {
+                Op::Erode => Zip::from(&window).and(&footprint).all(|&x, &f| x || !f),
+                Op::Dilate => !Zip::from(&window).and(&footprint).all(|&x, &f| !(x && f)),
+            };
+        });
+    out
+}
+
+/// Compute the morphology of `padded`, with the lanes along the last axis
+/// packed in the bits of words, into an array of shape `dim`.
+fn morphology_packed<D>(padded: ArrayView<'_, bool, D>, footprint: ArrayView<'_, bool, D>, dim: D, op: Op)
+    -> Array<bool, D>
+where
+    D: RemoveAxis,
+{
+    let last = Axis(dim.ndim() - 1);
+    let packed: Vec<Vec<u64>> = padded.lanes(last).into_iter().map(|lane| pack(&lane)).collect();
+
+    // The number of the lane of `padded` and the shift along it of each
+    // `true` element of the footprint, relative to the window at the start
+    let outer_shape = &padded.shape()[..last.index()];
+    let mut lane_strides = vec![1; outer_shape.len()];
+    for k in (1..outer_shape.len()).rev() {
+        lane_strides[k - 1] = lane_strides[k] * outer_shape[k];
+    }
+    let taps: Vec<(usize, usize)> = footprint
+        .indexed_iter()
+        .filter(|&(_, &f)| f)
+        .map(|(index, _)| {
+            let index = index.into_dimension();
+            let (outer, shift) = index.slice().split_at(last.index());
+            (outer.iter().zip(&lane_strides).map(|(i, s)| i * s).sum(), shift[0])
+        })
+        .collect();
+
+    let mut out = Array::from_elem(dim, false);
+    let outer_out_shape = out.shape()[..last.index()].to_vec();
+    let len = out.len_of(last);
+    let mut acc = vec![0u64; nwords(len)];
+    for (k, mut lane) in out.lanes_mut(last).into_iter().enumerate() {
+        // The number of the lane of `padded` at the start of the window
+        let mut rest = k;
+        let mut start = 0;
+        for (&n, &s) in outer_out_shape.iter().zip(&lane_strides).rev() {
+            start += rest % n * s;
+            rest /= n;
+        }
+        let fill = if op == Op::Erode { !0 } else { 0 };
+        acc.iter_mut().for_each(|w| *w = fill);
+        for &(lane_offset, shift) in &taps {
+            let src = &packed[start + lane_offset];
+            for (i, w) in acc.iter_mut().enumerate() {
+                let bits = shifted_word(src, i, shift);
+                match op {
+                    Op::Erode => *w &= bits,
+                    Op::Dilate => *w |= bits,
+                }
+            }
+        }
+        for (i, x) in lane.iter_mut().enumerate() {
+            *x = acc[i / BITS] >> (i % BITS) & 1 == 1;
+        }
+    }
+    out
+}
+
+/// Return the number of words for `len` bits.
+// usize::div_ceil needs a newer Rust than the MSRV
+#[allow(clippy::manual_div_ceil)]
+fn nwords(len: usize) -> usize {
+    (len + BITS - 1) / BITS
+}
+
+/// Pack the lane into words, with element `i` in bit `i % 64` of word
+/// `i / 64`.
+fn pack(lane: &ArrayView1<'_, bool>) -> Vec<u64> {
+    let mut words = vec![0u64; nwords(lane.len())];
+    for (i, &x) in lane.iter().enumerate() {
+        words[i / BITS] |= (x as u64) << (i % BITS);
+    }
+    words
+}
+
+/// Return word `i` of the packed bits `src` shifted down by `shift` bits,
+/// with zeros past the end.
+fn shifted_word(src: &[u64], i: usize, shift: usize) -> u64 {
+    let j = i + shift / BITS;
+    let r = shift % BITS;
+    let lo = src.get(j).copied().unwrap_or(0) >> r;
+    if r == 0 {
+        lo
+    } else {
+        lo | src.get(j + 1).copied().unwrap_or(0) << (BITS - r)
+    }
+}
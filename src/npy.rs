@@ -0,0 +1,592 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reading and writing NumPy's `.npy` and `.npz` files.
+//!
+//! [`write_npy`] writes an array to the [`.npy` format][npy], and
+//! [`read_npy`] reads it back into an [`ArrayD`]. Arrays in Fortran
+//! (column major) order are written and read in that order, without
+//! reordering the elements. The element type must implement [`NpyElement`],
+//! which covers `bool`, the primitive integer and floating point types and
+//! complex numbers of `f32` and `f64`.
+//!
+//! [`NpzWriter`] and [`NpzReader`] handle `.npz` archives of several named
+//! arrays, as written by `numpy.savez` and `numpy.savez_compressed`.
+//!
+//! This requires crate feature `npy`.
+//!
+//! ```
+//! use ndarray::array;
+//! use ndarray::npy::{read_npy, write_npy};
+//!
+//! let a = array![[1., 2., 3.], [4., 5., 6.]];
+//! let mut file = Vec::new();
+//! write_npy(&mut file, &a).unwrap();
+//!
+//! let b = read_npy::<_, f64>(&file[..]).unwrap();
+//! assert_eq!(b, a.into_dyn());
+//! ```
+//!
+//! [npy]: https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Seek, Write};
+use std::mem::size_of;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use num_complex::Complex;
+use zip_::result::ZipError;
+use zip_::write::FileOptions;
+use zip_::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::dimension;
+use crate::imp_prelude::*;
+use crate::{ShapeBuilder, ShapeError};
+
+/// The magic string at the start of every `.npy` file
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Size of the buffer used when writing the elements
+const WRITE_BUFFER_SIZE: usize = 1 << 13;
+
+/// An error from reading or writing `.npy` and `.npz` files.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum NpyError {
+    /// An I/O error.
+    Io(io::Error),
+    /// The data is not in a valid or supported `.npy` format.
+    Format(String),
+    /// The element type of the file is not the requested one.
+    DType {
+        /// The requested type, as a NumPy type string
+        expected: String,
+        /// The type in the file, as a NumPy type string
+        found: String,
+    },
+    /// The shape in the file doesn't match the data.
+    Shape(ShapeError),
+    /// There is no array with the given name in the `.npz` archive.
+    MissingArray(String),
+    /// The `.npz` archive is not a valid or supported zip archive.
+    Npz(String),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Io(err) => write!(f, "NpyError: {}", err),
+            NpyError::Format(msg) => write!(f, "NpyError: invalid npy format: {}", msg),
+            NpyError::DType { expected, found } => {
+                write!(f, "NpyError: expected element type {}, found {}", expected, found)
+            }
+            NpyError::Shape(err) => write!(f, "NpyError: {}", err),
+            NpyError::MissingArray(name) => write!(f, "NpyError: no array named {:?}", name),
+            NpyError::Npz(msg) => write!(f, "NpyError: invalid npz archive: {}", msg),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(err) => Some(err),
+            NpyError::Shape(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(err: io::Error) -> Self {
+        NpyError::Io(err)
+    }
+}
+
+impl From<ShapeError> for NpyError {
+    fn from(err: ShapeError) -> Self {
+        NpyError::Shape(err)
+    }
+}
+
+fn from_zip_error(err: ZipError) -> NpyError {
+    match err {
+        ZipError::Io(err) => NpyError::Io(err),
+        err => NpyError::Npz(err.to_string()),
+    }
+}
+
+/// An element type that can be stored in `.npy` files.
+///
+/// This trait is implemented for `bool`, the primitive integer and floating
+/// point types (except 128-bit integers) and `Complex<f32>` and
+/// `Complex<f64>`. It is sealed and can't be implemented outside of ndarray.
+pub trait NpyElement: Copy {
+    /// The NumPy type string without the byte order, for example `"f8"`.
+    const TYPE: &'static str;
+
+    #[doc(hidden)]
+    /// Read the element from `bytes`, which has the size of the element.
+    fn read_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    #[doc(hidden)]
+    /// Append the element in native byte order to `out`.
+    fn write_bytes(&self, out: &mut Vec<u8>);
+
+    private_decl! {}
+}
+
+macro_rules! impl_npy_element {
+    ($($elem:ty => $type_str:expr),*) => {
+        $(
+        impl NpyElement for $elem {
+            const TYPE: &'static str = $type_str;
+
+            fn read_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let mut buf = [0; size_of::<$elem>()];
+                buf.copy_from_slice(bytes);
+                if big_endian {
+                    <$elem>::from_be_bytes(buf)
+                } else {
+                    <$elem>::from_le_bytes(buf)
+                }
+            }
+
+            fn write_bytes(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_ne_bytes());
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+impl_npy_element!(i8 => "i1", i16 => "i2", i32 => "i4", i64 => "i8",
+                  u8 => "u1", u16 => "u2", u32 => "u4", u64 => "u8",
+                  f32 => "f4", f64 => "f8");
+
+impl NpyElement for bool {
+    const TYPE: &'static str = "b1";
+
+    fn read_bytes(bytes: &[u8], _big_endian: bool) -> Self {
+        bytes[0] != 0
+    }
+
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    private_impl! {}
+}
+
+macro_rules! impl_npy_complex {
+    ($($elem:ty => $type_str:expr),*) => {
+        $(
+        impl NpyElement for Complex<$elem> {
+            const TYPE: &'static str = $type_str;
+
+            fn read_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let (re, im) = bytes.split_at(size_of::<$elem>());
+                Complex::new(<$elem>::read_bytes(re, big_endian), <$elem>::read_bytes(im, big_endian))
+            }
+
+            fn write_bytes(&self, out: &mut Vec<u8>) {
+                self.re.write_bytes(out);
+                self.im.write_bytes(out);
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+impl_npy_complex!(f32 => "c8", f64 => "c16");
+
+/// Return the type string of `A` in native byte order, for example `"<f8"`.
+fn native_descr<A: NpyElement>() -> String {
+    let order = if size_of::<A>() == 1 {
+        '|'
+    } else if cfg!(target_endian = "big") {
+        '>'
+    } else {
+        '<'
+    };
+    format!("{}{}", order, A::TYPE)
+}
+
+/// Check that the type string `descr` is the one of `A` and return whether
+/// it is big endian.
+fn check_descr<A: NpyElement>(descr: &str) -> Result<bool, NpyError> {
+    let mismatch = || NpyError::DType {
+        expected: native_descr::<A>(),
+        found: descr.to_string(),
+    };
+    let mut chars = descr.chars();
+    let big_endian = match chars.next() {
+        Some('<') => false,
+        Some('>') => true,
+        Some('|') | Some('=') => cfg!(target_endian = "big"),
+        _ => return Err(mismatch()),
+    };
+    if chars.as_str() != A::TYPE {
+        return Err(mismatch());
+    }
+    Ok(big_endian)
+}
+
+/// The header of a `.npy` file.
+#[derive(Debug, PartialEq)]
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl Header {
+    /// Encode the header, including the magic string and version.
+    fn to_bytes(&self) -> Vec<u8> {
+        let shape = match &self.shape[..] {
+            [] => "()".to_string(),
+            [n] => format!("({},)", n),
+            shape => {
+                let dims = shape.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                format!("({})", dims.join(", "))
+            }
+        };
+        let dict = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+            self.descr,
+            if self.fortran_order { "True" } else { "False" },
+            shape
+        );
+        // The total header length, including the newline, is padded to a
+        // multiple of 64 bytes; format version 2.0 is only needed for long headers.
+        let padded_len = |prefix_len: usize| {
+            let len = prefix_len + dict.len() + 1;
+            len + (64 - len % 64) % 64 - prefix_len
+        };
+        let (version, prefix_len) = if padded_len(10) <= u16::MAX as usize { (1, 10) } else { (2, 12) };
+        let header_len = padded_len(prefix_len);
+        let mut out = Vec::with_capacity(prefix_len + header_len);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[version, 0]);
+        if version == 1 {
+            out.extend_from_slice(&(header_len as u16).to_le_bytes());
+        } else {
+            out.extend_from_slice(&(header_len as u32).to_le_bytes());
+        }
+        out.extend_from_slice(dict.as_bytes());
+        out.resize(prefix_len + header_len - 1, b' ');
+        out.push(b'\n');
+        out
+    }
+
+    /// Read and parse the header, leaving `reader` at the start of the data.
+    fn read<R: Read>(reader: &mut R) -> Result<Header, NpyError> {
+        let mut prefix = [0; 8];
+        reader.read_exact(&mut prefix)?;
+        if &prefix[..6] != MAGIC {
+            return Err(NpyError::Format("missing magic string".to_string()));
+        }
+        let header_len = match prefix[6] {
+            1 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            major => return Err(NpyError::Format(format!("unsupported version {}", major))),
+        };
+        let mut dict = Vec::new();
+        reader.take(header_len as u64).read_to_end(&mut dict)?;
+        if dict.len() != header_len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        let dict = String::from_utf8(dict)
+            .map_err(|_| NpyError::Format("header is not valid UTF-8".to_string()))?;
+        Header::parse(&dict)
+            .ok_or_else(|| NpyError::Format(format!("unsupported header {:?}", dict.trim_end())))
+    }
+
+    /// Parse the header dictionary, for example
+    /// `{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }`.
+    fn parse(dict: &str) -> Option<Header> {
+        let descr = dict_value(dict, "descr")?;
+        let quote = descr.chars().next().filter(|&c| c == '\'' || c == '"')?;
+        let descr = &descr[1..];
+        let descr = descr[..descr.find(quote)?].to_string();
+
+        let fortran_order = dict_value(dict, "fortran_order")?;
+        let fortran_order = if fortran_order.starts_with("True") {
+            true
+        } else if fortran_order.starts_with("False") {
+            false
+        } else {
+            return None;
+        };
+
+        let shape = dict_value(dict, "shape")?.strip_prefix('(')?;
+        let shape = shape[..shape.find(')')?]
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.trim_end_matches('L').parse().ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some(Header { descr, fortran_order, shape })
+    }
+}
+
+/// Return the text after `'key':` in the header dictionary.
+fn dict_value<'a>(dict: &'a str, key: &str) -> Option<&'a str> {
+    let start = dict
+        .find(&format!("'{}'", key))
+        .or_else(|| dict.find(&format!("\"{}\"", key)))?;
+    let rest = dict[start + key.len() + 2..].trim_start();
+    Some(rest.strip_prefix(':')?.trim_start())
+}
+
+/// Write the array to `writer` in `.npy` format.
+///
+/// The elements are written in native byte order. Arrays that are in Fortran
+/// (column major) layout are written in Fortran order; all other arrays are
+/// written in standard (row major) order.
+///
+/// **Errors** if writing fails.
+///
+/// ```no_run
+/// use ndarray::Array2;
+/// use ndarray::npy::write_npy;
+/// use std::fs::File;
+/// use std::io::BufWriter;
+///
+/// let a = Array2::<f32>::eye(3);
+/// let file = BufWriter::new(File::create("eye.npy")?);
+/// write_npy(file, &a)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn write_npy<W, A, S, D>(mut writer: W, array: &ArrayBase<S, D>) -> Result<(), NpyError>
+where
+    W: Write,
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let fortran_order = !array.is_standard_layout() && array.t().is_standard_layout();
+    let header = Header {
+        descr: native_descr::<A>(),
+        fortran_order,
+        shape: array.shape().to_vec(),
+    };
+    writer.write_all(&header.to_bytes())?;
+    let mut buf = Vec::with_capacity(WRITE_BUFFER_SIZE + size_of::<A>());
+    let elements = if fortran_order { array.t() } else { array.view() };
+    for elt in elements {
+        elt.write_bytes(&mut buf);
+        if buf.len() >= WRITE_BUFFER_SIZE {
+            writer.write_all(&buf)?;
+            buf.clear();
+        }
+    }
+    writer.write_all(&buf)?;
+    Ok(())
+}
+
+/// Read an array in `.npy` format from `reader`.
+///
+/// The element type of the file must be `A`, in either byte order. An array
+/// stored in Fortran order is returned in Fortran (column major) layout.
+///
+/// **Errors** if reading fails, if the data is not a valid `.npy` file or if
+/// its element type is not `A`.
+///
+/// ```no_run
+/// use ndarray::ArrayD;
+/// use ndarray::npy::read_npy;
+/// use std::fs::File;
+///
+/// let a: ArrayD<f32> = read_npy(File::open("eye.npy")?)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_npy<R, A>(mut reader: R) -> Result<ArrayD<A>, NpyError>
+where
+    R: Read,
+    A: NpyElement,
+{
+    let header = Header::read(&mut reader)?;
+    let big_endian = check_descr::<A>(&header.descr)?;
+    let shape = IxDyn(&header.shape);
+    let len = dimension::size_of_shape_checked(&shape)?;
+    let n_bytes = len
+        .checked_mul(size_of::<A>())
+        .ok_or_else(|| NpyError::Format("data size overflows usize".to_string()))?;
+    let mut bytes = Vec::new();
+    reader.take(n_bytes as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != n_bytes {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    let data = bytes
+        .chunks_exact(size_of::<A>())
+        .map(|elt| A::read_bytes(elt, big_endian))
+        .collect();
+    Ok(Array::from_shape_vec(shape.set_f(header.fortran_order), data)?)
+}
+
+/// Writer for `.npz` archives of named arrays.
+///
+/// The arrays are stored in `.npy` format, and can be read in Python with
+/// `numpy.load`.
+///
+/// This requires crate feature `npy`.
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use ndarray::npy::{NpzReader, NpzWriter};
+/// use std::io::Cursor;
+///
+/// let mut npz = NpzWriter::new_compressed(Cursor::new(Vec::new()));
+/// npz.add_array("a", &array![1, 2, 3]).unwrap();
+/// npz.add_array("b", &Array2::<f64>::zeros((2, 2))).unwrap();
+/// let file = npz.finish().unwrap();
+///
+/// let mut npz = NpzReader::new(file).unwrap();
+/// assert_eq!(npz.names().unwrap(), vec!["a", "b"]);
+/// assert_eq!(npz.by_name::<i32>("a").unwrap(), array![1, 2, 3].into_dyn());
+/// ```
+pub struct NpzWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    options: FileOptions,
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// Create a writer for an uncompressed archive, like `numpy.savez`.
+    pub fn new(writer: W) -> Self {
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Stored),
+        }
+    }
+
+    /// Create a writer for a compressed archive, like
+    /// `numpy.savez_compressed`.
+    pub fn new_compressed(writer: W) -> Self {
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Deflated),
+        }
+    }
+
+    /// Add an array with the given name to the archive.
+    ///
+    /// The array is stored as the file `name.npy`, see [`write_npy`].
+    ///
+    /// **Errors** if writing fails.
+    pub fn add_array<N, A, S, D>(&mut self, name: N, array: &ArrayBase<S, D>) -> Result<(), NpyError>
+    where
+        N: Into<String>,
+        A: NpyElement,
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let n_bytes = array.len().saturating_mul(size_of::<A>());
+        let options = self.options.large_file(n_bytes >= u32::MAX as usize / 2);
+        self.zip
+            .start_file(name.into() + ".npy", options)
+            .map_err(from_zip_error)?;
+        write_npy(&mut self.zip, array)
+    }
+
+    /// Finish writing the archive and return the underlying writer.
+    ///
+    /// **Errors** if writing fails.
+    pub fn finish(mut self) -> Result<W, NpyError> {
+        self.zip.finish().map_err(from_zip_error)
+    }
+}
+
+/// Reader for `.npz` archives of named arrays.
+///
+/// See [`NpzWriter`] for an example.
+///
+/// This requires crate feature `npy`.
+pub struct NpzReader<R: Read + Seek> {
+    zip: ZipArchive<R>,
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    /// Open the archive.
+    ///
+    /// **Errors** if reading fails or if it is not a zip archive.
+    pub fn new(reader: R) -> Result<Self, NpyError> {
+        Ok(NpzReader {
+            zip: ZipArchive::new(reader).map_err(from_zip_error)?,
+        })
+    }
+
+    /// Return the number of arrays in the archive.
+    pub fn len(&self) -> usize {
+        self.zip.len()
+    }
+
+    /// Return `true` if the archive contains no arrays.
+    pub fn is_empty(&self) -> bool {
+        self.zip.is_empty()
+    }
+
+    /// Return the names of the arrays in the archive, in archive order.
+    ///
+    /// **Errors** if reading fails.
+    pub fn names(&mut self) -> Result<Vec<String>, NpyError> {
+        (0..self.zip.len())
+            .map(|i| {
+                let file = self.zip.by_index(i).map_err(from_zip_error)?;
+                let name = file.name();
+                Ok(name.strip_suffix(".npy").unwrap_or(name).to_string())
+            })
+            .collect()
+    }
+
+    /// Read the array with the given name.
+    ///
+    /// **Errors** if there is no such array, if reading fails or if the
+    /// element type of the array is not `A`.
+    pub fn by_name<A: NpyElement>(&mut self, name: &str) -> Result<ArrayD<A>, NpyError> {
+        let file_name = if self.zip.file_names().any(|n| n == name) {
+            name.to_string()
+        } else {
+            format!("{}.npy", name)
+        };
+        match self.zip.by_name(&file_name) {
+            Ok(file) => read_npy(file),
+            Err(ZipError::FileNotFound) => Err(NpyError::MissingArray(name.to_string())),
+            Err(err) => Err(from_zip_error(err)),
+        }
+    }
+
+    /// Read the array at `index` in archive order.
+    ///
+    /// **Errors** if the index is out of bounds, if reading fails or if the
+    /// element type of the array is not `A`.
+    pub fn by_index<A: NpyElement>(&mut self, index: usize) -> Result<ArrayD<A>, NpyError> {
+        match self.zip.by_index(index) {
+            Ok(file) => read_npy(file),
+            Err(ZipError::FileNotFound) => Err(NpyError::MissingArray(index.to_string())),
+            Err(err) => Err(from_zip_error(err)),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, FloatConst};
+
+use crate::imp_prelude::*;
+
+/// # Angles
+///
+/// These methods require crate feature `std`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float + FloatConst,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return an array of the elements converted from degrees to radians.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use std::f64::consts::PI;
+    ///
+    /// let a = array![0., 90., -180.];
+    /// assert_eq!(a.deg2rad(), array![0., PI / 2., -PI]);
+    /// assert_eq!(a.deg2rad().rad2deg(), a);
+    /// ```
+    pub fn deg2rad(&self) -> Array<A, D> {
+        self.mapv(A::to_radians)
+    }
+
+    /// Return an array of the elements converted from radians to degrees.
+    pub fn rad2deg(&self) -> Array<A, D> {
+        self.mapv(A::to_degrees)
+    }
+
+    /// Unwrap the phase angles (in radians) along `axis`, by adding
+    /// multiples of 2π so that the difference between successive elements is
+    /// at most π.
+    ///
+    /// This is the same as NumPy's `unwrap` with the default discontinuity
+    /// and period: where successive elements differ by π or more, the
+    /// difference is replaced by the equivalent one between -π and π. The
+    /// first element of each lane is not changed.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use std::f64::consts::PI;
+    ///
+    /// // A phase that increases steadily, wrapped to [-π, π)
+    /// let wrapped = array![0., 0.5, 1., 1.5, 2., 2.5, 3., 3.5 - 2. * PI, 4. - 2. * PI];
+    /// let unwrapped = wrapped.unwrap_phase_axis(Axis(0));
+    /// assert!(unwrapped.iter().zip(&[0., 0.5, 1., 1.5, 2., 2.5, 3., 3.5, 4.])
+    ///     .all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn unwrap_phase_axis(&self, axis: Axis) -> Array<A, D> {
+        let mut out = self.to_owned();
+        out.map_axis_inplace(axis, unwrap_phase_lane);
+        out
+    }
+}
+
+/// Unwrap the phase angles in `lane`, carrying the correction from each
+/// element to the next.
+fn unwrap_phase_lane<A>(mut lane: ArrayViewMut1<'_, A>)
+where
+    A: Float + FloatConst,
+{
+    let pi = A::PI();
+    let two_pi = pi + pi;
+    let mut iter = lane.iter_mut();
+    let mut prev = match iter.next() {
+        Some(x) => *x,
+        None => return,
+    };
+    let mut correction = A::zero();
+    for x in iter {
+        let diff = *x - prev;
+        prev = *x;
+        if diff.abs() >= pi {
+            // The equivalent difference in [-π, π), but keep the sign of a
+            // difference of exactly π
+            let mut wrapped = (diff + pi) % two_pi;
+            if wrapped < A::zero() {
+                wrapped = wrapped + two_pi;
+            }
+            wrapped = wrapped - pi;
+            if wrapped == -pi && diff > A::zero() {
+                wrapped = pi;
+            }
+            correction = correction + wrapped - diff;
+        }
+        *x = *x + correction;
+    }
+}
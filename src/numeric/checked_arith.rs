@@ -0,0 +1,189 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use num_traits::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use num_traits::{WrappingAdd, WrappingMul, WrappingSub};
+
+use crate::error::OverflowError;
+use crate::imp_prelude::*;
+use crate::{DimMax, IntoDimension, Zip};
+
+type DimMaxOf<A, B> = <A as DimMax<B>>::Output;
+
+/// Apply the checked operation `f` to the elements of `lhs` and `rhs`, which
+/// have the same shape.
+fn checked_zip<A, D, F>(lhs: ArrayView<'_, A, D>, rhs: ArrayView<'_, A, D>, f: F)
+    -> Result<Array<A, D>, OverflowError>
+where
+    A: Clone,
+    D: Dimension,
+    F: Fn(&A, &A) -> Option<A>,
+{
+    let mut overflow = false;
+    let out = Zip::from(&lhs).and(&rhs).map_collect(|a, b| {
+        f(a, b).unwrap_or_else(|| {
+            overflow = true;
+            a.clone()
+        })
+    });
+    if !overflow {
+        return Ok(out);
+    }
+    // The traversal above is in memory order; find the first overflow in
+    // logical order.
+    let index = lhs
+        .indexed_iter()
+        .zip(rhs.iter())
+        .find(|((_, a), b)| f(a, b).is_none())
+        .map(|((index, _), _)| index.into_dimension())
+        .unwrap();
+    Err(OverflowError::at_index(index.slice()))
+}
+
+macro_rules! checked_method {
+    ($(#[$meta:meta])* $name:ident, $trt:ident) => {
+        $(#[$meta])*
+        pub fn $name<S2, E>(&self, rhs: &ArrayBase<S2, E>)
+            -> Result<Array<A, DimMaxOf<D, E>>, OverflowError>
+        where
+            A: $trt,
+            S2: Data<Elem = A>,
+            D: DimMax<E>,
+            E: Dimension,
+        {
+            let (lhs, rhs) = self.broadcast_with(rhs).unwrap();
+            checked_zip(lhs, rhs, A::$name)
+        }
+    };
+}
+
+macro_rules! total_method {
+    ($(#[$meta:meta])* $name:ident, $trt:ident) => {
+        $(#[$meta])*
+        pub fn $name<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, DimMaxOf<D, E>>
+        where
+            A: $trt,
+            S2: Data<Elem = A>,
+            D: DimMax<E>,
+            E: Dimension,
+        {
+            let (lhs, rhs) = self.broadcast_with(rhs).unwrap();
+            Zip::from(&lhs).and(&rhs).map_collect(|a, b| A::$name(a, b))
+        }
+    };
+}
+
+/// # Checked, Saturating and Wrapping Arithmetic
+///
+/// The arithmetic operators on integer arrays panic on overflow in debug
+/// builds and wrap around in release builds, like they do for the
+/// elements. These methods make the behavior explicit: the checked methods
+/// return an error that identifies the first element that overflows, the
+/// saturating methods clamp the results to the bounds of the element type,
+/// and the wrapping methods wrap around.
+///
+/// If their shapes disagree, `self` and `rhs` are broadcast to a common
+/// shape, like for the arithmetic operators. To use a scalar, broadcast a
+/// zero-dimensional array, like `&arr0(x)`.
+///
+/// **Panics** if broadcasting isn’t possible.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    checked_method! {
+        /// Return the elementwise sum of `self` and `rhs`.
+        ///
+        /// **Errors** if any of the sums overflows; the error holds the
+        /// index of the first one, in logical order.
+        ///
+        /// ```
+        /// use ndarray::{array, arr0};
+        ///
+        /// let a = array![[1u8, 2], [200, 100]];
+        /// assert_eq!(a.checked_add(&arr0(50)), Ok(array![[51, 52], [250, 150]]));
+        ///
+        /// let err = a.checked_add(&array![[0, 0], [100, 200]]).unwrap_err();
+        /// assert_eq!(err.index(), &[1, 0]);
+        /// ```
+        checked_add, CheckedAdd
+    }
+
+    checked_method! {
+        /// Return the elementwise difference of `self` and `rhs`.
+        ///
+        /// **Errors** if any of the differences overflows; the error holds
+        /// the index of the first one, in logical order.
+        checked_sub, CheckedSub
+    }
+
+    checked_method! {
+        /// Return the elementwise product of `self` and `rhs`.
+        ///
+        /// **Errors** if any of the products overflows; the error holds the
+        /// index of the first one, in logical order.
+        checked_mul, CheckedMul
+    }
+
+    checked_method! {
+        /// Return the elementwise quotient of `self` and `rhs`.
+        ///
+        /// **Errors** if any of the divisors is zero, or if any of the
+        /// quotients overflows; the error holds the index of the first one,
+        /// in logical order.
+        checked_div, CheckedDiv
+    }
+
+    total_method! {
+        /// Return the elementwise sum of `self` and `rhs`, saturating at the
+        /// bounds of the element type.
+        ///
+        /// ```
+        /// use ndarray::{array, arr0};
+        ///
+        /// let a = array![-100i8, 0, 100];
+        /// assert_eq!(a.saturating_add(&arr0(50)), array![-50, 50, 127]);
+        /// assert_eq!(a.saturating_sub(&arr0(50)), array![-128, -50, 50]);
+        /// assert_eq!(a.wrapping_add(&arr0(50)), array![-50, 50, -106]);
+        /// ```
+        saturating_add, SaturatingAdd
+    }
+
+    total_method! {
+        /// Return the elementwise difference of `self` and `rhs`, saturating
+        /// at the bounds of the element type.
+        saturating_sub, SaturatingSub
+    }
+
+    total_method! {
+        /// Return the elementwise product of `self` and `rhs`, saturating at
+        /// the bounds of the element type.
+        saturating_mul, SaturatingMul
+    }
+
+    total_method! {
+        /// Return the elementwise sum of `self` and `rhs`, wrapping around at
+        /// the bounds of the element type.
+        wrapping_add, WrappingAdd
+    }
+
+    total_method! {
+        /// Return the elementwise difference of `self` and `rhs`, wrapping
+        /// around at the bounds of the element type.
+        wrapping_sub, WrappingSub
+    }
+
+    total_method! {
+        /// Return the elementwise product of `self` and `rhs`, wrapping
+        /// around at the bounds of the element type.
+        wrapping_mul, WrappingMul
+    }
+}
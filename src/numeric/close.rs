@@ -0,0 +1,182 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Return `true` if `a` is close to the reference value `b`.
+fn is_close<A: Float>(a: A, b: A, rtol: A, atol: A) -> bool {
+    // Equal infinities are close, although their difference is NaN
+    a == b || (a - b).abs() <= atol + rtol * b.abs()
+}
+
+/// Return `true` if each element of `a` is close to the corresponding
+/// element of `b`.
+///
+/// The elements are checked in chunks without branches, which the compiler
+/// can vectorize, stopping after the first chunk with an element that is not
+/// close.
+fn slices_close<A: Float>(a: &[A], b: &[A], rtol: A, atol: A) -> bool {
+    const CHUNK: usize = 64;
+    a.chunks(CHUNK).zip(b.chunks(CHUNK)).all(|(a, b)| {
+        a.iter()
+            .zip(b)
+            .fold(true, |acc, (&a, &b)| acc & is_close(a, b, rtol, atol))
+    })
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return `true` if the arrays have the same shape and each element of
+    /// `self` is close to the corresponding element of `other`, like
+    /// NumPy's `allclose`.
+    ///
+    /// Elements `a` of `self` and `b` of `other` are close if
+    /// `|a - b| <= atol + rtol * |b|`, so `other` is the reference. Equal
+    /// infinities are close, and NaN is not close to anything.
+    ///
+    /// See also [`assert_all_close!`](crate::assert_all_close), which
+    /// reports the first element that is not close.
+    ///
+    /// This returns at the first element that is not close, so it's cheap
+    /// enough to check the convergence of an iterative solver after each
+    /// iteration. Arrays with the same memory layout are compared in memory
+    /// order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1e10, 1e-8];
+    /// assert!(a.all_close(&array![1.00001e10, 1e-9], 1e-5, 1e-8));
+    /// assert!(!a.all_close(&array![1.0001e10, 1e-9], 1e-5, 1e-8));
+    /// ```
+    pub fn all_close<S2>(&self, other: &ArrayBase<S2, D>, rtol: A, atol: A) -> bool
+    where
+        S2: Data<Elem = A>,
+    {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        if self.strides() == other.strides() {
+            if let (Some(a), Some(b)) = (self.as_slice_memory_order(), other.as_slice_memory_order()) {
+                return slices_close(a, b, rtol, atol);
+            }
+        }
+        Zip::from(self).and(other).all(|&a, &b| is_close(a, b, rtol, atol))
+    }
+
+    /// Return an array that is `true` where each element of the lane along
+    /// `axis` of `self` is close to the corresponding element of `other`,
+    /// for example to check the convergence of each of a batch of vectors.
+    ///
+    /// Closeness is defined as for [`.all_close()`](Self::all_close). Each
+    /// lane is checked until its first element that is not close.
+    ///
+    /// **Panics** if the shapes of `self` and `other` are not the same, or
+    /// if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let b = array![[1., 2.], [3.5, 4.]];
+    /// assert_eq!(a.all_close_axis(&b, Axis(1), 0., 0.1), array![true, false]);
+    /// assert_eq!(a.all_close_axis(&b, Axis(0), 0., 0.1), array![false, true]);
+    /// ```
+    pub fn all_close_axis<S2>(&self, other: &ArrayBase<S2, D>, axis: Axis, rtol: A, atol: A) -> Array<bool, D::Smaller>
+    where
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        assert_eq!(self.shape(), other.shape(), "all_close_axis: the shapes must be the same");
+        Zip::from(self.lanes(axis))
+            .and(other.lanes(axis))
+            .map_collect(|a, b| match (a.as_slice(), b.as_slice()) {
+                (Some(a), Some(b)) => slices_close(a, b, rtol, atol),
+                _ => Zip::from(&a).and(&b).all(|&a, &b| is_close(a, b, rtol, atol)),
+            })
+    }
+
+    /// Return the index and values of the first element of `self`, in
+    /// logical order, that is not close to the corresponding element of
+    /// `other`, or `None` if all elements are close.
+    ///
+    /// Closeness is defined as for [`.all_close()`](Self::all_close).
+    ///
+    /// **Panics** if the shapes of `self` and `other` are not the same.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let b = array![[1., 2.], [3.5, 4.5]];
+    /// assert_eq!(a.first_not_close(&b, 0., 0.1), Some(((1, 0), 3., 3.5)));
+    /// assert_eq!(a.first_not_close(&b, 0., 0.5), None);
+    /// ```
+    pub fn first_not_close<S2>(&self, other: &ArrayBase<S2, D>, rtol: A, atol: A) -> Option<(D::Pattern, A, A)>
+    where
+        S2: Data<Elem = A>,
+    {
+        assert_eq!(self.shape(), other.shape(), "first_not_close: the shapes must be the same");
+        self.indexed_iter()
+            .zip(other)
+            .find(|&((_, &a), &b)| !is_close(a, b, rtol, atol))
+            .map(|((index, &a), &b)| (index, a, b))
+    }
+}
+
+/// Assert that two floating point arrays are close, like
+/// [`.all_close()`](ArrayBase::all_close).
+///
+/// `assert_all_close!(actual, expected, rtol, atol)` compares `actual` to
+/// the reference `expected`.
+///
+/// **Panics** if the shapes of the arrays are not the same, or if any
+/// element is not close, with a message that shows the index and values of
+/// the first such element.
+///
+/// ```
+/// use ndarray::{array, assert_all_close};
+///
+/// let a = array![[0.1 + 0.2, 1. / 3.]];
+/// assert_all_close!(a, array![[0.3, 0.333333333]], 1e-6, 0.);
+/// ```
+///
+/// ```should_panic
+/// use ndarray::{array, assert_all_close};
+///
+/// // Panics with: assert_all_close!(array![1., 2.], array![1., 2.1], 0.0, 0.01) failed:
+/// // element at 1 is 2.0, expected 2.1
+/// assert_all_close!(array![1., 2.], array![1., 2.1], 0., 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_all_close {
+    ($actual:expr, $expected:expr, $rtol:expr, $atol:expr $(,)?) => {{
+        let (actual, expected) = (&$actual, &$expected);
+        let (rtol, atol) = ($rtol, $atol);
+        if actual.shape() != expected.shape() {
+            panic!(
+                "assert_all_close!({}, {}, {:?}, {:?}) failed: shape {:?}, expected {:?}",
+                stringify!($actual), stringify!($expected), rtol, atol,
+                actual.shape(), expected.shape()
+            );
+        }
+        if let Some((index, a, b)) = actual.first_not_close(expected, rtol, atol) {
+            panic!(
+                "assert_all_close!({}, {}, {:?}, {:?}) failed: element at {:?} is {:?}, expected {:?}",
+                stringify!($actual), stringify!($expected), rtol, atol, index, a, b
+            );
+        }
+    }};
+}
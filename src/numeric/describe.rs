@@ -0,0 +1,159 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::fmt;
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+
+/// Summary statistics of an array, returned by
+/// [`.describe()`](ArrayBase::describe).
+///
+/// NaN elements are not counted and don't contribute to the statistics. If
+/// there are no other elements, all statistics except `count` are NaN.
+///
+/// The `Display` implementation prints one statistic per line, applying the
+/// formatting parameters to each value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Summary<A> {
+    /// The number of elements that are not NaN.
+    pub count: usize,
+    /// The mean.
+    pub mean: A,
+    /// The sample standard deviation (with `ddof = 1`), which is NaN for a
+    /// single element.
+    pub std: A,
+    /// The minimum.
+    pub min: A,
+    /// The first quartile, the 25th percentile.
+    pub q25: A,
+    /// The median, the 50th percentile.
+    pub median: A,
+    /// The third quartile, the 75th percentile.
+    pub q75: A,
+    /// The maximum.
+    pub max: A,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return summary statistics of the elements: the count, mean, standard
+    /// deviation, minimum, quartiles and maximum; see [`Summary`].
+    ///
+    /// The mean and standard deviation are computed in one pass with the
+    /// Welford algorithm, as in [`.std()`](ArrayBase::std), while the
+    /// elements are copied; the quantiles are found by sorting the copy and
+    /// interpolating linearly between elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[4., 1.], [f64::NAN, 3.], [2., 5.]];
+    /// let summary = a.describe();
+    /// assert_eq!(summary.count, 5);
+    /// assert_eq!(summary.mean, 3.);
+    /// assert_eq!((summary.min, summary.median, summary.max), (1., 3., 5.));
+    /// assert_eq!((summary.q25, summary.q75), (2., 4.));
+    /// assert_eq!(
+    ///     format!("{:.3}", summary),
+    ///     "count  5\n\
+    ///      mean   3.000\n\
+    ///      std    1.581\n\
+    ///      min    1.000\n\
+    ///      25%    2.000\n\
+    ///      50%    3.000\n\
+    ///      75%    4.000\n\
+    ///      max    5.000"
+    /// );
+    /// ```
+    pub fn describe(&self) -> Summary<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mut sorted = Vec::with_capacity(self.len());
+        let mut mean = A::zero();
+        let mut sum_sq = A::zero();
+        self.for_each(|&x| {
+            if x.is_nan() {
+                return;
+            }
+            sorted.push(x);
+            let count =
+                A::from_usize(sorted.len()).expect("Converting length to `A` must not fail.");
+            let delta = x - mean;
+            mean = mean + delta / count;
+            sum_sq = (x - mean).mul_add(delta, sum_sq);
+        });
+        // There are no NaNs, so the comparison is a total order
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let count = sorted.len();
+        if count == 0 {
+            let nan = A::nan();
+            return Summary {
+                count,
+                mean: nan,
+                std: nan,
+                min: nan,
+                q25: nan,
+                median: nan,
+                q75: nan,
+                max: nan,
+            };
+        }
+        let dof = A::from_usize(count - 1).expect("Converting length to `A` must not fail.");
+        Summary {
+            count,
+            mean,
+            std: (sum_sq / dof).sqrt(),
+            min: sorted[0],
+            q25: quantile_of_sorted(&sorted, 0.25),
+            median: quantile_of_sorted(&sorted, 0.5),
+            q75: quantile_of_sorted(&sorted, 0.75),
+            max: sorted[count - 1],
+        }
+    }
+}
+
+/// Return the quantile `q` of the nonempty `sorted`, interpolating linearly
+/// between the closest elements.
+fn quantile_of_sorted<A>(sorted: &[A], q: f64) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    let frac = A::from_f64(pos - lo as f64).expect("Converting fraction to `A` must not fail.");
+    sorted[lo] + (sorted[hi] - sorted[lo]) * frac
+}
+
+impl<A: fmt::Display> fmt::Display for Summary<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "count  {}", self.count)?;
+        let rows = [
+            ("mean", &self.mean),
+            ("std", &self.std),
+            ("min", &self.min),
+            ("25%", &self.q25),
+            ("50%", &self.median),
+            ("75%", &self.q75),
+            ("max", &self.max),
+        ];
+        for &(name, value) in &rows {
+            write!(f, "\n{:<5}  ", name)?;
+            value.fmt(f)?;
+        }
+        Ok(())
+    }
+}
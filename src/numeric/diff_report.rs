@@ -0,0 +1,136 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::fmt;
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::IntoDimension;
+
+/// A report of where two arrays differ, returned by [`diff_report`].
+///
+/// Elements differ if they compare unequal, except that two NaNs are
+/// considered equal. Differences where exactly one of the elements is NaN
+/// are counted, but don't contribute to the maximum differences.
+///
+/// The `Display` implementation prints the counts and the maximum
+/// differences, applying the formatting parameters to the differences.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffReport<A, D: Dimension> {
+    /// The number of elements compared.
+    pub len: usize,
+    /// The number of elements that differ.
+    pub count: usize,
+    /// The maximum absolute difference, `|a - b|`, or zero if no elements
+    /// differ.
+    pub max_abs_diff: A,
+    /// The index of the maximum absolute difference.
+    pub max_abs_index: Option<D::Pattern>,
+    /// The maximum relative difference, `|a - b| / max(|a|, |b|)`, or zero if
+    /// no elements differ.
+    pub max_rel_diff: A,
+    /// The index of the maximum relative difference.
+    pub max_rel_index: Option<D::Pattern>,
+    /// The histogram of differences along each axis: `axis_counts[axis][i]`
+    /// is the number of elements that differ at index `i` along `axis`.
+    pub axis_counts: Vec<Vec<usize>>,
+}
+
+/// Return a report of where the arrays `a` and `b` differ: the number of
+/// differing elements, the maximum absolute and relative differences and
+/// their indices, and the number of differences at each index along each
+/// axis; see [`DiffReport`].
+///
+/// The arrays are compared in one pass.
+///
+/// **Panics** if the shapes of `a` and `b` are not the same.
+///
+/// ```
+/// use ndarray::{array, diff_report};
+///
+/// let expected = array![[1., 2., 3.], [4., 5., 6.]];
+/// let actual = array![[1., 2.5, 3.], [4., 5., 7.]];
+/// let report = diff_report(&expected, &actual);
+/// assert_eq!(report.count, 2);
+/// assert_eq!(report.max_abs_diff, 1.);
+/// assert_eq!(report.max_abs_index, Some((1, 2)));
+/// assert_eq!(report.max_rel_index, Some((0, 1)));
+/// // One difference in each row, and none in the first column
+/// assert_eq!(report.axis_counts, vec![vec![1, 1], vec![0, 1, 1]]);
+/// assert_eq!(
+///     format!("{:.3}", report),
+///     "2 of 6 elements differ\n\
+///      max abs diff 1.000 at (1, 2)\n\
+///      max rel diff 0.200 at (0, 1)"
+/// );
+/// ```
+pub fn diff_report<A, S1, S2, D>(a: &ArrayBase<S1, D>, b: &ArrayBase<S2, D>) -> DiffReport<A, D>
+where
+    A: Float,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut report = DiffReport {
+        len: a.len(),
+        count: 0,
+        max_abs_diff: A::zero(),
+        max_abs_index: None,
+        max_rel_diff: A::zero(),
+        max_rel_index: None,
+        axis_counts: a.shape().iter().map(|&len| vec![0; len]).collect(),
+    };
+    assert_eq!(a.shape(), b.shape(), "diff_report: the shapes must be the same");
+    for ((index, &x), &y) in a.indexed_iter().zip(b) {
+        if x == y || (x.is_nan() && y.is_nan()) {
+            continue;
+        }
+        report.count += 1;
+        let dim_index = index.clone().into_dimension();
+        for (counts, &i) in report.axis_counts.iter_mut().zip(dim_index.slice()) {
+            counts[i] += 1;
+        }
+        let abs_diff = (x - y).abs();
+        // Avoid inf / inf, which is NaN
+        let rel_diff = if abs_diff.is_infinite() {
+            A::one()
+        } else {
+            abs_diff / x.abs().max(y.abs())
+        };
+        if abs_diff > report.max_abs_diff {
+            report.max_abs_diff = abs_diff;
+            report.max_abs_index = Some(index.clone());
+        }
+        if rel_diff > report.max_rel_diff {
+            report.max_rel_diff = rel_diff;
+            report.max_rel_index = Some(index);
+        }
+    }
+    report
+}
+
+impl<A: fmt::Display, D: Dimension> fmt::Display for DiffReport<A, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {} elements differ", self.count, self.len)?;
+        let rows = [
+            ("abs", &self.max_abs_diff, &self.max_abs_index),
+            ("rel", &self.max_rel_diff, &self.max_rel_index),
+        ];
+        for &(name, value, index) in &rows {
+            if let Some(index) = index {
+                write!(f, "\nmax {} diff ", name)?;
+                value.fmt(f)?;
+                write!(f, " at {:?}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+
+/// # Entropy
+///
+/// These methods require crate feature `std`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the Shannon entropy of each lane along `axis`, treating the
+    /// lane as a probability distribution:
+    ///
+    /// ```text
+    /// H(p) = - ∑ pᵢ log(pᵢ)
+    /// ```
+    ///
+    /// with logarithms in base `base`, for example `2.` for bits or
+    /// `std::f64::consts::E` for nats. Zero probabilities contribute
+    /// nothing.
+    ///
+    /// If `normalize` is `true`, each lane is divided by its sum first, so
+    /// that lanes of counts or weights can be used directly; a lane that
+    /// sums to zero has entropy NaN. Otherwise the lanes are used as they
+    /// are, and should already sum to one.
+    ///
+    /// Lanes with negative elements have entropy NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let counts = array![[5f64, 5.], [10., 0.], [1., 3.]];
+    /// let bits = counts.entropy_axis(Axis(1), 2., true);
+    /// assert_eq!(bits[0], 1.);
+    /// assert_eq!(bits[1], 0.);
+    /// assert!((bits[2] - 0.811278).abs() < 1e-6);
+    /// ```
+    pub fn entropy_axis(&self, axis: Axis, base: A, normalize: bool) -> Array<A, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let ln_base = base.ln();
+        self.map_axis(axis, |lane| {
+            let total = if normalize {
+                lane.fold(A::zero(), |acc, &p| acc + p)
+            } else {
+                A::one()
+            };
+            let entropy = lane.fold(A::zero(), |acc, &p| {
+                let p = p / total;
+                if p == A::zero() {
+                    acc
+                } else {
+                    acc - p * p.ln()
+                }
+            });
+            entropy / ln_base
+        })
+    }
+}
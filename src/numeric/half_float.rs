@@ -0,0 +1,128 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use half::{bf16, f16};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Half precision floating point elements: [`f16`] and [`bf16`] from the
+/// `half` crate.
+///
+/// The generic numeric methods, like [`.sum()`](ArrayBase::sum) and
+/// [`.var_axis()`](ArrayBase::var_axis), work with these types too, but they
+/// accumulate in the element type, which has only 11 or 8 bits of precision.
+/// The methods [`.sum_f32()`](ArrayBase::sum_f32),
+/// [`.mean_f32()`](ArrayBase::mean_f32) and
+/// [`.var_axis_f32()`](ArrayBase::var_axis_f32) accumulate in `f32` instead.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+///
+/// This requires crate feature `half`.
+pub trait HalfFloat: Copy {
+    /// Convert the value to `f32`, which is exact.
+    fn to_f32(self) -> f32;
+
+    private_decl! {}
+}
+
+impl HalfFloat for f16 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+
+    private_impl! {}
+}
+
+impl HalfFloat for bf16 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+
+    private_impl! {}
+}
+
+/// # Numerical Methods for Half Precision Arrays
+///
+/// These methods require crate feature `half`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: HalfFloat,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the sum of all elements in the array, accumulated in `f32`.
+    ///
+    /// ```
+    /// use half::f16;
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_elem(2, f16::MAX);
+    /// assert_eq!(a.sum_f32(), 131008.);
+    /// // The sum in f16 overflows
+    /// assert_eq!(a.sum(), f16::INFINITY);
+    /// ```
+    pub fn sum_f32(&self) -> f32 {
+        self.fold(0., |acc, &x| acc + x.to_f32())
+    }
+
+    /// Return the arithmetic mean of all elements in the array, accumulated
+    /// in `f32`, or `None` if the array is empty.
+    pub fn mean_f32(&self) -> Option<f32> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.sum_f32() / self.len() as f32)
+        }
+    }
+
+    /// Return the variance along `axis`, accumulated in `f32`.
+    ///
+    /// This is computed like [`.var_axis()`](ArrayBase::var_axis), with the
+    /// Welford one-pass algorithm; `ddof` is the "delta degrees of freedom".
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// the axis, or if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use half::bf16;
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2.], [3., 4.], [5., 6.]].mapv(bf16::from_f64);
+    /// assert_eq!(a.var_axis_f32(Axis(0), 1.), array![4., 4.]);
+    /// ```
+    pub fn var_axis_f32(&self, axis: Axis, ddof: f32) -> Array<f32, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis) as f32;
+        assert!(
+            !(ddof < 0. || ddof > n),
+            "`ddof` must not be less than zero or greater than the length of \
+             the axis",
+        );
+        let dof = n - ddof;
+        let mut mean = Array::<f32, _>::zeros(self.dim.remove_axis(axis));
+        let mut sum_sq = Array::<f32, _>::zeros(self.dim.remove_axis(axis));
+        for (i, subview) in self.axis_iter(axis).enumerate() {
+            let count = (i + 1) as f32;
+            Zip::from(&mut mean)
+                .and(&mut sum_sq)
+                .and(&subview)
+                .for_each(|mean, sum_sq, &x| {
+                    let x = x.to_f32();
+                    let delta = x - *mean;
+                    *mean += delta / count;
+                    *sum_sq += (x - *mean) * delta;
+                });
+        }
+        sum_sq.mapv_into(|s| s / dof)
+    }
+}
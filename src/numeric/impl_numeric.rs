@@ -9,11 +9,15 @@
 #[cfg(feature = "std")]
 use num_traits::Float;
 use num_traits::{self, FromPrimitive, Zero};
-use std::ops::{Add, Div, Mul};
+use alloc::collections::VecDeque;
+use std::mem::MaybeUninit;
+use std::ops::{Add, Div, Mul, Sub};
 
 use crate::imp_prelude::*;
+use crate::dimension::IntoDimension;
 use crate::itertools::enumerate;
 use crate::numeric_util;
+use crate::{Slice, Zip};
 
 /// # Numerical Methods for Arrays
 impl<A, S, D> ArrayBase<S, D>
@@ -418,4 +422,466 @@ where
     {
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
+
+    /// Return the integral image (summed-area table) of the array.
+    ///
+    /// This is the cumulative sum along every axis: each element of the
+    /// result is the sum of the elements of `self` with indices less than or
+    /// equal to its own index along every axis.
+    ///
+    /// The sums are computed in the element type `B`, which can be wider than
+    /// `A` to avoid overflow, for example `u32` for an image of `u8`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1u8, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.integral_image::<u32>(), array![[1, 3, 6],
+    ///                                              [5, 12, 21]]);
+    /// ```
+    pub fn integral_image<B>(&self) -> Array<B, D>
+    where
+        A: Clone,
+        B: Clone + From<A> + Add<Output = B>,
+    {
+        let mut sums = self.map(|x| B::from(x.clone()));
+        for axis in 0..self.ndim() {
+            sums.accumulate_axis_inplace(Axis(axis), |prev, curr| {
+                *curr = curr.clone() + prev.clone();
+            });
+        }
+        sums
+    }
+
+    /// Return the mean of each window of size `window` in the array.
+    ///
+    /// The windows are the same as the ones of
+    /// [`.windows()`](ArrayBase::windows), so the result is shorter than
+    /// `self` by `window - 1` along each axis, or empty if the window is longer
+    /// than the array. The window sums are computed from the
+    /// [integral image](ArrayBase::integral_image), which takes constant time
+    /// per window regardless of the window size.
+    ///
+    /// The sums are computed in the element type `B`. Every intermediate
+    /// value is the sum of some of the elements of `self`, so it's enough that
+    /// the sum of all elements fits in `B`; in particular, there is no
+    /// intermediate underflow for unsigned `B`. Integer means are rounded
+    /// towards zero.
+    ///
+    /// **Panics** if the window has the wrong number of dimensions, if any
+    /// of its lengths is zero or if `B::from_usize()` fails for the number
+    /// of elements in the window.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1u8, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.box_filter::<f64, _>((2, 2)), array![[3., 4.]]);
+    /// assert_eq!(a.box_filter::<u32, _>((1, 3)), array![[2], [5]]);
+    /// ```
+    pub fn box_filter<B, E>(&self, window: E) -> Array<B, D>
+    where
+        A: Clone,
+        B: Clone + From<A> + FromPrimitive + Add<Output = B> + Sub<Output = B> + Div<Output = B>,
+        E: IntoDimension<Dim = D>,
+    {
+        let window = window.into_dimension();
+        ndassert!(
+            self.ndim() == window.ndim(),
+            concat!(
+                "Window dimension {} does not match array dimension {} ",
+                "(with array of shape {:?})"
+            ),
+            window.ndim(),
+            self.ndim(),
+            self.shape()
+        );
+        assert!(
+            window.slice().iter().all(|&w| w > 0),
+            "box_filter: window lengths must be non-zero"
+        );
+        let mut sums = self.integral_image::<B>();
+        for (axis, &w) in window.slice().iter().enumerate() {
+            sums = window_differences(sums.view(), Axis(axis), w);
+        }
+        let count = B::from_usize(window.size())
+            .expect("Converting window size to `B` must not fail.");
+        sums.mapv_into(|sum| sum / count.clone())
+    }
+
+    /// Return the sum of each window of length `window` along `axis`.
+    ///
+    /// The windows are the same as the ones of
+    /// [`.axis_windows()`](ArrayBase::axis_windows), so the result is shorter
+    /// than `self` by `window - 1` along `axis`, or empty if the window is
+    /// longer than the axis. The window sums are running sums along each
+    /// lane: the next sum subtracts the element that leaves the window and
+    /// adds the one that enters it, which takes constant time per window
+    /// regardless of the window size. The running sum stays within the range
+    /// of the window sums, but for floating point elements, the rounding
+    /// errors of a window sum carry over to the following ones.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3, 4],
+    ///                [5, 6, 7, 8]];
+    /// assert_eq!(a.rolling_sum(Axis(1), 3), array![[6, 9], [18, 21]]);
+    /// assert_eq!(a.rolling_sum(Axis(0), 2), array![[6, 8, 10, 12]]);
+    /// ```
+    pub fn rolling_sum(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Clone + Add<Output = A> + Sub<Output = A>,
+    {
+        assert!(window > 0, "rolling_sum: window length must be non-zero");
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = (self.len_of(axis) + 1).saturating_sub(window);
+        let mut out = Array::<A, D>::uninit(dim);
+        Zip::from(self.lanes(axis))
+            .and(out.lanes_mut(axis))
+            .for_each(|lane, mut out| {
+                if out.is_empty() {
+                    return;
+                }
+                let mut sum = lane.slice_axis(Axis(0), Slice::from(1..window))
+                    .fold(lane[0].clone(), |sum, x| sum + x.clone());
+                out[0] = MaybeUninit::new(sum.clone());
+                for i in 1..out.len() {
+                    // Subtract first, so that the running sum stays in the
+                    // range of the window sums
+                    sum = sum - lane[i - 1].clone() + lane[i + window - 1].clone();
+                    out[i] = MaybeUninit::new(sum.clone());
+                }
+            });
+        unsafe {
+            // Safe because every element was assigned above
+            out.assume_init()
+        }
+    }
+
+    /// Return the mean of each window of length `window` along `axis`.
+    ///
+    /// The windows and sums are the same as for
+    /// [`.rolling_sum()`](Self::rolling_sum).
+    ///
+    /// **Panics** if `axis` is out of bounds, if `window` is zero or if
+    /// `A::from_usize()` fails for `window`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 2., 3., 4., 5.];
+    /// assert_eq!(a.rolling_mean(Axis(0), 2), array![1.5, 2.5, 3.5, 4.5]);
+    /// ```
+    pub fn rolling_mean(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Sub<Output = A> + Div<Output = A>,
+    {
+        let count = A::from_usize(window).expect("Converting window length to `A` must not fail.");
+        self.rolling_sum(axis, window)
+            .mapv_into(|sum| sum / count.clone())
+    }
+
+    /// Return the minimum of each window of length `window` along `axis`.
+    ///
+    /// The windows are the same as for
+    /// [`.rolling_sum()`](Self::rolling_sum). The minimums are computed in
+    /// one pass along each lane, which takes amortized constant time per
+    /// window regardless of the window size. If the elements are not totally
+    /// ordered, for example if there are NaNs, the result is unspecified.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![3, 1, 4, 1, 5, 9, 2, 6];
+    /// assert_eq!(a.rolling_min(Axis(0), 3), array![1, 1, 1, 1, 2, 2]);
+    /// assert_eq!(a.rolling_max(Axis(0), 3), array![4, 4, 5, 9, 9, 9]);
+    /// ```
+    pub fn rolling_min(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+    {
+        assert!(window > 0, "rolling_min: window length must be non-zero");
+        rolling_extremum(self.view(), axis, window, |a, b| a <= b)
+    }
+
+    /// Return the maximum of each window of length `window` along `axis`.
+    ///
+    /// See [`.rolling_min()`](Self::rolling_min).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window` is zero.
+    pub fn rolling_max(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+    {
+        assert!(window > 0, "rolling_max: window length must be non-zero");
+        rolling_extremum(self.view(), axis, window, |a, b| a >= b)
+    }
+
+    /// Call `f` on each window of length `window` along `axis`, and return
+    /// an array of the results.
+    ///
+    /// The windows are the same as for
+    /// [`.rolling_sum()`](Self::rolling_sum); each is passed to `f` as a
+    /// one-dimensional view. This takes time proportional to the window size
+    /// per window, if `f` does; prefer the specialized rolling methods when
+    /// they apply.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // The range of each window of three elements
+    /// let a = array![[1, 5, 2, 8],
+    ///                [4, 4, 4, 0]];
+    /// let ranges = a.rolling_apply(Axis(1), 3, |w| {
+    ///     w.iter().max().unwrap() - w.iter().min().unwrap()
+    /// });
+    /// assert_eq!(ranges, array![[4, 6], [0, 4]]);
+    /// ```
+    pub fn rolling_apply<B, F>(&self, axis: Axis, window: usize, mut f: F) -> Array<B, D>
+    where
+        F: FnMut(ArrayView1<'_, A>) -> B,
+    {
+        assert!(window > 0, "rolling_apply: window length must be non-zero");
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = (self.len_of(axis) + 1).saturating_sub(window);
+        let mut out = Array::<B, D>::uninit(dim);
+        Zip::from(self.lanes(axis))
+            .and(out.lanes_mut(axis))
+            .for_each(|lane, mut out| {
+                for (i, out) in out.iter_mut().enumerate() {
+                    *out = MaybeUninit::new(f(lane.slice_axis(Axis(0), Slice::from(i..i + window))));
+                }
+            });
+        unsafe {
+            // Safe because every element was assigned above
+            out.assume_init()
+        }
+    }
+}
+
+/// # Parallel Numerical Methods
+///
+/// These methods require crate feature `rayon`.
+#[cfg(feature = "rayon")]
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+    A: Send + Sync,
+{
+    /// Parallel version of [`.sum_axis()`](Self::sum_axis).
+    ///
+    /// The array is split into pieces along the longest of the other axes,
+    /// and the pieces are summed along `axis` in parallel. Each element of the
+    /// result is computed in the same way as by `.sum_axis()`, so the results
+    /// are the same, also for floating point numbers.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::linspace(0., 1., 60_000).into_shape((300, 200)).unwrap();
+    /// assert_eq!(a.par_sum_axis(Axis(0)), a.sum_axis(Axis(0)));
+    /// ```
+    pub fn par_sum_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + Zero + Add<Output = A>,
+    {
+        let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+        par_reduce_axis(self.view(), res.view_mut(), axis, &|view, mut out| {
+            out.assign(&view.sum_axis(axis));
+        });
+        res
+    }
+
+    /// Parallel version of [`.mean_axis()`](Self::mean_axis).
+    ///
+    /// See [`.par_sum_axis()`](Self::par_sum_axis) for how the work is split;
+    /// the results are the same as the ones of `.mean_axis()`.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()`
+    /// fails for the axis length.
+    pub fn par_mean_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+    {
+        let axis_length = self.len_of(axis);
+        if axis_length == 0 {
+            None
+        } else {
+            let axis_length =
+                A::from_usize(axis_length).expect("Converting axis length to `A` must not fail.");
+            let sum = self.par_sum_axis(axis);
+            Some(sum / aview0(&axis_length))
+        }
+    }
+
+    /// Parallel version of [`.var_axis()`](Self::var_axis).
+    ///
+    /// See [`.par_sum_axis()`](Self::par_sum_axis) for how the work is split;
+    /// the results are the same as the ones of `.var_axis()`.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// the axis, if `axis` is out of bounds, or if `A::from_usize()` fails for
+    /// any of the numbers in the range `0..=n`.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::linspace(0., 1., 60_000).into_shape((300, 200)).unwrap();
+    /// assert_eq!(a.par_var_axis(Axis(1), 1.), a.var_axis(Axis(1), 1.));
+    /// ```
+    pub fn par_var_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+    {
+        let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
+        let n = A::from_usize(self.len_of(axis)).expect("Converting length to `A` must not fail.");
+        assert!(
+            !(ddof < zero || ddof > n),
+            "`ddof` must not be less than zero or greater than the length of \
+             the axis",
+        );
+        let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+        par_reduce_axis(self.view(), res.view_mut(), axis, &|view, mut out| {
+            out.assign(&view.var_axis(axis, ddof));
+        });
+        res
+    }
+
+    /// Parallel version of [`.std_axis()`](Self::std_axis).
+    ///
+    /// See [`.par_var_axis()`](Self::par_var_axis).
+    pub fn par_std_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.par_var_axis(axis, ddof).mapv_into(|x| x.sqrt())
+    }
+}
+
+/// Maximum number of elements of the input that `par_reduce_axis` reduces
+/// serially.
+#[cfg(feature = "rayon")]
+const PAR_REDUCE_AXIS_BLOCK_LEN: usize = 1 << 14;
+
+/// Reduce `view` along `axis` into `out`, which has the shape of `view` with
+/// `axis` removed, by calling `leaf` on pieces of both in parallel.
+///
+/// The pieces are made by splitting in halves along the longest axis other
+/// than `axis`, so each piece has the whole length of `axis` and each element
+/// of `out` is computed by a single call of `leaf`.
+#[cfg(feature = "rayon")]
+fn par_reduce_axis<A, D, L>(
+    view: ArrayView<'_, A, D>,
+    out: ArrayViewMut<'_, A, D::Smaller>,
+    axis: Axis,
+    leaf: &L,
+) where
+    A: Send + Sync,
+    D: RemoveAxis,
+    L: Fn(ArrayView<'_, A, D>, ArrayViewMut<'_, A, D::Smaller>) + Sync,
+{
+    let split = view
+        .shape()
+        .iter()
+        .enumerate()
+        .filter(|&(i, &len)| i != axis.index() && len > 1)
+        .max_by_key(|&(_, &len)| len)
+        .map(|(i, _)| i);
+    match split {
+        Some(split) if view.len() > PAR_REDUCE_AXIS_BLOCK_LEN => {
+            let out_split = if split > axis.index() { split - 1 } else { split };
+            let mid = view.len_of(Axis(split)) / 2;
+            let (left, right) = view.split_at(Axis(split), mid);
+            let (out_left, out_right) = out.split_at(Axis(out_split), mid);
+            rayon::join(
+                || par_reduce_axis(left, out_left, axis, leaf),
+                || par_reduce_axis(right, out_right, axis, leaf),
+            );
+        }
+        _ => leaf(view, out),
+    }
+}
+
+/// Return the extremum of each window of length `w` along `axis`, where
+/// `keeps(a, b)` is `true` if `a` is at least as extreme as `b`.
+///
+/// The indices of the candidates for the extremum of the current and later
+/// windows are kept in a deque, in order and with decreasing extremeness, so
+/// each element is pushed and popped at most once.
+fn rolling_extremum<A, D, F>(view: ArrayView<'_, A, D>, axis: Axis, w: usize, keeps: F) -> Array<A, D>
+where
+    A: Clone,
+    D: Dimension,
+    F: Fn(&A, &A) -> bool,
+{
+    let n = view.len_of(axis);
+    let mut dim = view.raw_dim();
+    dim[axis.index()] = (n + 1).saturating_sub(w);
+    let mut out = Array::<A, D>::uninit(dim);
+    let mut candidates = VecDeque::with_capacity(w.min(n));
+    Zip::from(view.lanes(axis))
+        .and(out.lanes_mut(axis))
+        .for_each(|lane, mut out| {
+            candidates.clear();
+            for (i, elt) in lane.iter().enumerate() {
+                while let Some(&j) = candidates.back() {
+                    if !keeps(elt, &lane[j]) {
+                        break;
+                    }
+                    candidates.pop_back();
+                }
+                candidates.push_back(i);
+                if candidates[0] + w <= i {
+                    candidates.pop_front();
+                }
+                if i + 1 >= w {
+                    out[i + 1 - w] = MaybeUninit::new(lane[candidates[0]].clone());
+                }
+            }
+        });
+    unsafe {
+        // Safe because every element was assigned above
+        out.assume_init()
+    }
+}
+
+/// Return the differences `sums[i + w - 1] - sums[i - 1]` along `axis`, where
+/// `sums[-1]` is taken to be zero.
+///
+/// Applied to cumulative sums, this gives the sums of windows of length `w`.
+fn window_differences<B, D>(sums: ArrayView<'_, B, D>, axis: Axis, w: usize) -> Array<B, D>
+where
+    B: Clone + Sub<Output = B>,
+    D: Dimension,
+{
+    let n = sums.len_of(axis);
+    let mut dim = sums.raw_dim();
+    dim[axis.index()] = (n + 1).saturating_sub(w);
+    let mut out = Array::<B, D>::uninit(dim);
+    if w <= n {
+        sums.slice_axis(axis, Slice::from(w - 1..w))
+            .assign_to(out.slice_axis_mut(axis, Slice::from(..1)));
+        Zip::from(out.slice_axis_mut(axis, Slice::from(1..)))
+            .and(sums.slice_axis(axis, Slice::from(w..)))
+            .and(sums.slice_axis(axis, Slice::from(..n - w)))
+            .for_each(|out, hi, lo| *out = MaybeUninit::new(hi.clone() - lo.clone()));
+    }
+    unsafe {
+        // Safe because every element was assigned above
+        out.assume_init()
+    }
 }
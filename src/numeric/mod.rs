@@ -1 +1,29 @@
+#[cfg(feature = "std")]
+mod angle;
+mod checked_arith;
+#[cfg(feature = "std")]
+mod close;
+#[cfg(feature = "std")]
+mod describe;
+#[cfg(feature = "std")]
+mod diff_report;
+#[cfg(feature = "std")]
+mod entropy;
+#[cfg(feature = "half")]
+mod half_float;
 mod impl_numeric;
+#[cfg(feature = "std")]
+mod quantile_sketch;
+#[cfg(feature = "std")]
+mod rounding;
+
+#[cfg(feature = "std")]
+pub use self::describe::Summary;
+#[cfg(feature = "std")]
+pub use self::diff_report::{diff_report, DiffReport};
+#[cfg(feature = "half")]
+pub use self::half_float::HalfFloat;
+#[cfg(feature = "std")]
+pub use self::quantile_sketch::QuantileSketch;
+#[cfg(feature = "std")]
+pub use self::rounding::Rounding;
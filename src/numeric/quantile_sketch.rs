@@ -0,0 +1,336 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::f64::consts::PI;
+use std::mem;
+
+use num_traits::ToPrimitive;
+
+use crate::imp_prelude::*;
+
+/// A bounded-memory accumulator of approximate quantiles, a merging
+/// t-digest.
+///
+/// Values are added one at a time, from arrays, or by merging other
+/// sketches, so the quantiles of data that doesn't fit in memory can be
+/// estimated chunk by chunk, and chunks can be processed in parallel and
+/// merged afterwards. The sketch keeps at most about `compression` clusters
+/// of values, plus a buffer of `5 * compression` values that are not
+/// clustered yet; quantiles near 0 and 1 are more accurate than those in
+/// the middle.
+///
+/// NaN values are ignored.
+///
+/// ```
+/// use ndarray::{process_chunks, Array};
+///
+/// // Estimate percentiles of data read in chunks
+/// let chunks = (0..100).map(|i| Array::range(i as f64 * 1000., (i + 1) as f64 * 1000., 1.));
+/// let sketch = process_chunks(
+///     chunks,
+///     || (),
+///     |_, chunk| chunk.quantile_sketch(100.),
+///     |mut a, b| {
+///         a.merge(&b);
+///         a
+///     },
+/// )
+/// .unwrap();
+/// assert_eq!(sketch.count(), 100_000);
+/// let median = sketch.quantile(0.5).unwrap();
+/// assert!((median - 50_000.).abs() < 500.);
+/// assert_eq!(sketch.quantile(1.), Some(99_999.));
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuantileSketch {
+    compression: f64,
+    /// Clusters sorted by mean
+    centroids: Vec<Centroid>,
+    /// Values and merged clusters that haven't been clustered yet
+    buffer: Vec<Centroid>,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl QuantileSketch {
+    /// Create an empty sketch that keeps about `compression` clusters.
+    ///
+    /// A larger compression gives more accurate quantiles and uses more
+    /// memory; 100 is a common choice.
+    ///
+    /// **Panics** if `compression` is less than 1 or not finite.
+    pub fn new(compression: f64) -> Self {
+        assert!(
+            compression.is_finite() && compression >= 1.,
+            "compression must be at least 1, got {}",
+            compression
+        );
+        QuantileSketch {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Return the number of values added to the sketch, not counting NaN.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return `true` if no values have been added to the sketch.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Return the smallest value added, or `None` if the sketch is empty.
+    pub fn min(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// Return the largest value added, or `None` if the sketch is empty.
+    pub fn max(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+
+    /// Add a value to the sketch. NaN is ignored.
+    pub fn insert(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        self.count += 1;
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+        self.push(Centroid {
+            mean: x,
+            weight: 1.,
+        });
+    }
+
+    /// Add all values of `other` to the sketch.
+    pub fn merge(&mut self, other: &QuantileSketch) {
+        if other.is_empty() {
+            return;
+        }
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        for &centroid in other.centroids.iter().chain(&other.buffer) {
+            self.push(centroid);
+        }
+    }
+
+    /// Return the estimated `q`th quantile of the values, or `None` if the
+    /// sketch is empty.
+    ///
+    /// The estimate interpolates linearly between the clusters. Until the
+    /// buffer is full for the first time, no values are clustered and the
+    /// result is exact, interpolating linearly between the sorted values.
+    ///
+    /// **Panics** if `q` is not between 0 and 1 (inclusive).
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        assert!(
+            (0. ..=1.).contains(&q),
+            "quantile must be between 0 and 1, got {}",
+            q
+        );
+        if self.is_empty() {
+            return None;
+        }
+        if self.buffer.is_empty() {
+            Some(self.interpolate(&self.centroids, q))
+        } else if self.centroids.is_empty() {
+            let mut buffer = self.buffer.clone();
+            sort_by_mean(&mut buffer);
+            Some(self.interpolate(&buffer, q))
+        } else {
+            let mut all = self.buffer.clone();
+            all.extend_from_slice(&self.centroids);
+            Some(self.interpolate(&self.cluster(all), q))
+        }
+    }
+
+    fn push(&mut self, centroid: Centroid) {
+        self.buffer.push(centroid);
+        if self.buffer.len() as f64 >= 5. * self.compression {
+            let mut all = mem::take(&mut self.buffer);
+            all.append(&mut self.centroids);
+            self.centroids = self.cluster(all);
+        }
+    }
+
+    /// Sort the clusters and merge neighbours, keeping the size of each
+    /// cluster within the limit of the t-digest scale function.
+    fn cluster(&self, mut all: Vec<Centroid>) -> Vec<Centroid> {
+        sort_by_mean(&mut all);
+        // Not always the count, since a merge adds the other's count first
+        let total = all.iter().map(|c| c.weight).sum::<f64>();
+        let mut merged = Vec::with_capacity(self.compression as usize);
+        let mut all = all.into_iter();
+        let mut current = match all.next() {
+            Some(c) => c,
+            None => return merged,
+        };
+        let mut weight_before = 0.;
+        let mut limit = total * self.quantile_limit(0.);
+        for next in all {
+            if weight_before + current.weight + next.weight <= limit {
+                current.weight += next.weight;
+                current.mean += (next.mean - current.mean) * next.weight / current.weight;
+            } else {
+                weight_before += current.weight;
+                merged.push(current);
+                limit = total * self.quantile_limit(weight_before / total);
+                current = next;
+            }
+        }
+        merged.push(current);
+        merged
+    }
+
+    /// Return the largest quantile that a cluster starting at quantile `q`
+    /// can extend to, which is one step of the scale function
+    /// `k(q) = δ / 2π · asin(2q - 1)`.
+    fn quantile_limit(&self, q: f64) -> f64 {
+        let delta = self.compression;
+        let k = delta / (2. * PI) * (2. * q - 1.).asin();
+        let k = (k + 1.).min(delta / 4.);
+        ((k * 2. * PI / delta).sin() + 1.) / 2.
+    }
+
+    /// Interpolate linearly between the cluster centers, where the minimum
+    /// and the maximum are the centers of the first and last values.
+    fn interpolate(&self, centroids: &[Centroid], q: f64) -> f64 {
+        // The clusters around the ends can overlap, so check those first
+        if q <= 0. {
+            return self.min;
+        } else if q >= 1. {
+            return self.max;
+        }
+        let total = self.count as f64;
+        // With unit weights, value i is centered at i + 0.5
+        let target = q * (total - 1.) + 0.5;
+        let mut prev = (0.5, self.min);
+        let mut center = 0.;
+        let points = centroids
+            .iter()
+            .map(|c| {
+                let point = (center + c.weight / 2., c.mean);
+                center += c.weight;
+                point
+            })
+            .chain(Some((total - 0.5, self.max)));
+        for (pos, value) in points {
+            if target <= pos {
+                if pos <= prev.0 {
+                    return value;
+                }
+                return prev.1 + (value - prev.1) * (target - prev.0) / (pos - prev.0);
+            }
+            prev = (pos, value);
+        }
+        self.max
+    }
+}
+
+fn sort_by_mean(centroids: &mut [Centroid]) {
+    // NaN is never inserted
+    centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap());
+}
+
+impl Default for QuantileSketch {
+    /// Create an empty sketch with compression 100.
+    fn default() -> Self {
+        QuantileSketch::new(100.)
+    }
+}
+
+impl Extend<f64> for QuantileSketch {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.insert(x);
+        }
+    }
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a [`QuantileSketch`] of the elements, for estimating their
+    /// quantiles in bounded memory.
+    ///
+    /// Elements that can't be converted to `f64` are ignored, like NaN.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_shape_fn((100, 100), |(i, j)| (i * 100 + j) as u32);
+    /// let sketch = a.quantile_sketch(100.);
+    /// let p99 = sketch.quantile(0.99).unwrap();
+    /// assert!((p99 - 9899.).abs() < 10.);
+    /// ```
+    pub fn quantile_sketch(&self, compression: f64) -> QuantileSketch
+    where
+        A: ToPrimitive,
+    {
+        let mut sketch = QuantileSketch::new(compression);
+        self.for_each(|x| {
+            if let Some(x) = x.to_f64() {
+                sketch.insert(x);
+            }
+        });
+        sketch
+    }
+
+    /// Return a [`QuantileSketch`] of each lane along `axis`.
+    ///
+    /// Sketches of successive chunks along `axis` can be merged elementwise
+    /// with [`.merge()`](QuantileSketch::merge).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 5., 2.], [4., 3., 6.]];
+    /// let sketches = a.quantile_sketch_axis(Axis(1), 100.);
+    /// let medians = sketches.map(|s| s.quantile(0.5).unwrap());
+    /// assert_eq!(medians, array![2., 4.]);
+    /// ```
+    pub fn quantile_sketch_axis(
+        &self,
+        axis: Axis,
+        compression: f64,
+    ) -> Array<QuantileSketch, D::Smaller>
+    where
+        A: ToPrimitive,
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.quantile_sketch(compression))
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Bounded, Float, NumCast, Zero};
+
+use crate::imp_prelude::*;
+
+/// How to round floating point numbers to integral values, used by
+/// [`.round_to_int()`](ArrayBase::round_to_int).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Rounding {
+    /// Round to the nearest integer, and halfway cases away from zero, like
+    /// [`f64::round`].
+    HalfAwayFromZero,
+    /// Round to the nearest integer, and halfway cases to the even integer
+    /// (banker's rounding).
+    HalfToEven,
+    /// Round down, like [`f64::floor`].
+    Down,
+    /// Round up, like [`f64::ceil`].
+    Up,
+    /// Round toward zero, like [`f64::trunc`].
+    TowardZero,
+}
+
+impl Rounding {
+    fn apply<A: Float>(self, x: A) -> A {
+        match self {
+            Rounding::HalfAwayFromZero => x.round(),
+            Rounding::HalfToEven => {
+                let r = x.round();
+                if (r - x).abs() == A::from(0.5).unwrap() {
+                    // A tie, which round() rounded away from zero
+                    let two = A::one() + A::one();
+                    (x / two).round() * two
+                } else {
+                    r
+                }
+            }
+            Rounding::Down => x.floor(),
+            Rounding::Up => x.ceil(),
+            Rounding::TowardZero => x.trunc(),
+        }
+    }
+}
+
+/// # Rounding
+///
+/// These methods require crate feature `std`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return an array of the elements rounded to the nearest integer, with
+    /// halfway cases rounded away from zero, like [`f64::round`].
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-1.5, -0.2, 0.5, 2.7];
+    /// assert_eq!(a.round(), array![-2., -0., 1., 3.]);
+    /// assert_eq!(a.floor(), array![-2., -1., 0., 2.]);
+    /// assert_eq!(a.ceil(), array![-1., -0., 1., 3.]);
+    /// assert_eq!(a.trunc(), array![-1., -0., 0., 2.]);
+    /// ```
+    pub fn round(&self) -> Array<A, D> {
+        self.mapv(A::round)
+    }
+
+    /// Return an array of the elements rounded down, like [`f64::floor`].
+    pub fn floor(&self) -> Array<A, D> {
+        self.mapv(A::floor)
+    }
+
+    /// Return an array of the elements rounded up, like [`f64::ceil`].
+    pub fn ceil(&self) -> Array<A, D> {
+        self.mapv(A::ceil)
+    }
+
+    /// Return an array of the integer parts of the elements, rounded toward
+    /// zero, like [`f64::trunc`].
+    pub fn trunc(&self) -> Array<A, D> {
+        self.mapv(A::trunc)
+    }
+
+    /// Round the elements with `rounding` and convert them to the integer
+    /// type `I`.
+    ///
+    /// Return `None` if any element is NaN, or if a rounded element is out
+    /// of the range of `I`.
+    ///
+    /// ```
+    /// use ndarray::{array, Rounding};
+    ///
+    /// let a = array![0.5, 1.5, 2.5, -2.5];
+    /// assert_eq!(a.round_to_int::<i32>(Rounding::HalfAwayFromZero), Some(array![1, 2, 3, -3]));
+    /// assert_eq!(a.round_to_int::<i32>(Rounding::HalfToEven), Some(array![0, 2, 2, -2]));
+    /// assert_eq!(a.round_to_int::<u8>(Rounding::Down), None);
+    /// ```
+    pub fn round_to_int<I>(&self, rounding: Rounding) -> Option<Array<I, D>>
+    where
+        I: NumCast,
+    {
+        // Check all elements before allocating the result
+        if self.iter().any(|&x| I::from(rounding.apply(x)).is_none()) {
+            return None;
+        }
+        Some(self.mapv(|x| I::from(rounding.apply(x)).unwrap()))
+    }
+
+    /// Round the elements with `rounding` and convert them to the integer
+    /// type `I`, saturating at the bounds of `I`.
+    ///
+    /// Rounded elements out of the range of `I`, including infinities, are
+    /// converted to the nearest bound of `I`, and NaN is converted to zero,
+    /// like the `as` operator does for primitive types.
+    ///
+    /// ```
+    /// use ndarray::{array, Rounding};
+    ///
+    /// let a = array![-3.7, 0.5, 254.5, 1e10, f64::NAN];
+    /// assert_eq!(
+    ///     a.round_to_int_saturating::<u8>(Rounding::HalfToEven),
+    ///     array![0, 0, 254, 255, 0]
+    /// );
+    /// ```
+    pub fn round_to_int_saturating<I>(&self, rounding: Rounding) -> Array<I, D>
+    where
+        I: NumCast + Bounded + Zero,
+    {
+        self.mapv(|x| {
+            let r = rounding.apply(x);
+            match I::from(r) {
+                Some(i) => i,
+                None if r.is_nan() => I::zero(),
+                None if r < A::zero() => I::min_value(),
+                None => I::max_value(),
+            }
+        })
+    }
+}
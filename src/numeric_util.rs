@@ -108,41 +108,3 @@ where
     }
     sum
 }
-
-/// Compute pairwise equality
-///
-/// `xs` and `ys` must be the same length
-pub fn unrolled_eq<A, B>(xs: &[A], ys: &[B]) -> bool
-where
-    A: PartialEq<B>,
-{
-    debug_assert_eq!(xs.len(), ys.len());
-    // eightfold unrolled for performance (this is not done by llvm automatically)
-    let len = cmp::min(xs.len(), ys.len());
-    let mut xs = &xs[..len];
-    let mut ys = &ys[..len];
-
-    while xs.len() >= 8 {
-        if (xs[0] != ys[0])
-            | (xs[1] != ys[1])
-            | (xs[2] != ys[2])
-            | (xs[3] != ys[3])
-            | (xs[4] != ys[4])
-            | (xs[5] != ys[5])
-            | (xs[6] != ys[6])
-            | (xs[7] != ys[7])
-        {
-            return false;
-        }
-        xs = &xs[8..];
-        ys = &ys[8..];
-    }
-
-    for i in 0..xs.len() {
-        if xs[i] != ys[i] {
-            return false;
-        }
-    }
-
-    true
-}
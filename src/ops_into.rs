@@ -0,0 +1,91 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Apply `f` to the elements of `a` and `b` elementwise, writing the results into `out`.
+///
+/// This does not allocate, so it can be used in loops with preallocated outputs. If their shapes
+/// disagree, `a` and `b` are broadcast to the shape of `out`.
+///
+/// **Panics** if broadcasting isn’t possible.
+///
+/// ```
+/// use ndarray::{add_into, array, binary_op_into, mul_into, Array2};
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let b = array![10., 20.];
+/// let mut out = Array2::zeros((2, 2));
+/// binary_op_into(|x: &f64, y: &f64| x.max(*y / 4.), &a, &b, &mut out);
+/// assert_eq!(out, array![[2.5, 5.], [3., 5.]]);
+///
+/// // The arithmetic operators have their own functions
+/// for _ in 0..10 {
+///     add_into(&a, &b, &mut out);
+///     assert_eq!(out, &a + &b);
+///     mul_into(&a, &b, &mut out);
+///     assert_eq!(out, &a * &b);
+/// }
+/// ```
+pub fn binary_op_into<A, B, C, S1, S2, S3, D1, D2, D3, F>(
+    mut f: F,
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    out: &mut ArrayBase<S3, D3>,
+) where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = B>,
+    S3: DataMut<Elem = C>,
+    D1: Dimension,
+    D2: Dimension,
+    D3: Dimension,
+    F: FnMut(&A, &B) -> C,
+{
+    let dim = out.raw_dim();
+    let a = a.broadcast_unwrap(dim.clone());
+    let b = b.broadcast_unwrap(dim);
+    Zip::from(out).and(a).and(b).for_each(|out, a, b| *out = f(a, b));
+}
+
+macro_rules! impl_op_into {
+    ($name:ident, $trt:ident, $operator:tt, $doc:expr) => {
+        /// Perform elementwise
+        #[doc=$doc]
+        /// between `a` and `b`, writing the results into `out`.
+        ///
+        /// This is the version of the arithmetic operator that does not allocate. If their
+        /// shapes disagree, `a` and `b` are broadcast to the shape of `out`. See
+        /// [`binary_op_into`] for an example, and for other operations.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        pub fn $name<A, B, C, S1, S2, S3, D1, D2, D3>(
+            a: &ArrayBase<S1, D1>,
+            b: &ArrayBase<S2, D2>,
+            out: &mut ArrayBase<S3, D3>,
+        ) where
+            A: Clone + $trt<B, Output = C>,
+            B: Clone,
+            S1: Data<Elem = A>,
+            S2: Data<Elem = B>,
+            S3: DataMut<Elem = C>,
+            D1: Dimension,
+            D2: Dimension,
+            D3: Dimension,
+        {
+            binary_op_into(|x: &A, y: &B| x.clone() $operator y.clone(), a, b, out)
+        }
+    };
+}
+
+impl_op_into!(add_into, Add, +, "addition");
+impl_op_into!(sub_into, Sub, -, "subtraction");
+impl_op_into!(mul_into, Mul, *, "multiplication");
+impl_op_into!(div_into, Div, /, "division");
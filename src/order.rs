@@ -81,3 +81,84 @@ impl Order {
         }
     }
 }
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    // A const initializer needs a newer Rust than the MSRV
+    #[allow(clippy::missing_const_for_thread_local)]
+    static DEFAULT_ORDER: std::cell::Cell<Order> = std::cell::Cell::new(Order::RowMajor);
+}
+
+/// Call `f` with `order` as the default memory order of new arrays on the
+/// current thread, and return its result.
+///
+/// Within `f`, the constructors that don't take elements in a particular
+/// order use `order` for shapes without an explicit memory order, like
+/// `(2, 3)` (in contrast to `(2, 3).f()` or `(2, 3).set_f(false)`). These
+/// are [`zeros`](crate::ArrayBase::zeros), [`ones`](crate::ArrayBase::ones),
+/// [`from_elem`](crate::ArrayBase::from_elem),
+/// [`default`](crate::ArrayBase::default),
+/// [`from_shape_fn`](crate::ArrayBase::from_shape_fn) and
+/// [`from_shape_simple_fn`](crate::ArrayBase::from_shape_simple_fn).
+/// [`Zip::map_collect`](crate::Zip::map_collect) uses `order` for its
+/// result when the inputs have no preferred memory order.
+///
+/// Constructors from a vector or iterator, like
+/// [`from_shape_vec`](crate::ArrayBase::from_shape_vec), are not affected,
+/// since the memory order determines where their elements go; the shape
+/// of the result and its elements are the same in any case.
+///
+/// The previous default order is restored when `f` returns or panics. The
+/// setting is per thread, so it doesn't apply to work that `f` spawns on
+/// other threads.
+///
+/// This function requires crate feature `std`.
+///
+/// ```
+/// use ndarray::{Array, Array2, Order, ShapeBuilder};
+///
+/// let a = ndarray::with_default_order(Order::ColumnMajor, || {
+///     let a = Array2::<f64>::zeros((2, 3));
+///     assert_eq!(a.strides(), &[1, 2]);
+///
+///     // An explicit order is kept
+///     let b = Array2::<f64>::zeros((2, 3).set_f(false));
+///     assert_eq!(b.strides(), &[3, 1]);
+///     Array::from_shape_fn((2, 3), |(i, j)| i * 3 + j)
+/// });
+/// assert_eq!(a.strides(), &[1, 2]);
+/// assert_eq!(a, Array::from_shape_vec((2, 3), (0..6).collect()).unwrap());
+/// assert_eq!(ndarray::default_order(), Order::RowMajor);
+/// ```
+#[cfg(feature = "std")]
+pub fn with_default_order<F, R>(order: Order, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Restore(Order);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DEFAULT_ORDER.with(|default| default.set(self.0));
+        }
+    }
+
+    let _restore = Restore(DEFAULT_ORDER.with(|default| default.replace(order)));
+    f()
+}
+
+/// Return the default memory order of new arrays on the current thread,
+/// which is row major unless it is set with [`with_default_order`].
+///
+/// This function requires crate feature `std`.
+#[cfg(feature = "std")]
+pub fn default_order() -> Order {
+    DEFAULT_ORDER.with(|default| default.get())
+}
+
+/// Return the default memory order of new arrays, which is always row major
+/// without crate feature `std`.
+#[cfg(not(feature = "std"))]
+pub(crate) fn default_order() -> Order {
+    Order::RowMajor
+}
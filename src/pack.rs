@@ -0,0 +1,117 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::SliceArg;
+
+/// # Packing Slices
+///
+/// These methods copy a slice of the array to and from a contiguous buffer,
+/// for example to send it in a message. The elements of the slice are
+/// packed in logical order (row major order of the slice), regardless of the
+/// memory layout of the array, so the sender and receiver of a packed slice
+/// only need to agree on the shape of the slice.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Copy the elements of the slice `info` to the start of `buf`, in
+    /// logical order.
+    ///
+    /// Return the number of elements copied, which is the number of
+    /// elements in the slice.
+    ///
+    /// **Panics** if the slice is out of bounds, or if `buf` is shorter than
+    /// the slice.
+    ///
+    /// ```
+    /// use ndarray::{s, Array};
+    ///
+    /// let a = Array::from_shape_fn((4, 4), |(i, j)| 10 * i + j);
+    /// let mut buf = [0; 16];
+    ///
+    /// // The last column
+    /// let n = a.pack_slice(s![.., -1], &mut buf);
+    /// assert_eq!(&buf[..n], &[3, 13, 23, 33]);
+    ///
+    /// // The interior
+    /// let n = a.pack_slice(s![1..3, 1..3], &mut buf);
+    /// assert_eq!(&buf[..n], &[11, 12, 21, 22]);
+    /// ```
+    pub fn pack_slice<I>(&self, info: I, buf: &mut [A]) -> usize
+    where
+        I: SliceArg<D>,
+        S: Data,
+        A: Clone,
+    {
+        let view = self.slice(info);
+        let len = view.len();
+        assert!(
+            buf.len() >= len,
+            "pack_slice: buffer of length {} is shorter than the slice of length {}",
+            buf.len(),
+            len
+        );
+        let buf = &mut buf[..len];
+        if let Some(slc) = view.as_slice() {
+            buf.clone_from_slice(slc);
+        } else {
+            for (dst, src) in buf.iter_mut().zip(view.iter()) {
+                *dst = src.clone();
+            }
+        }
+        len
+    }
+
+    /// Copy the elements from the start of `buf` to the slice `info`, in
+    /// logical order. This is the inverse of
+    /// [`.pack_slice()`](Self::pack_slice).
+    ///
+    /// Return the number of elements copied, which is the number of
+    /// elements in the slice.
+    ///
+    /// **Panics** if the slice is out of bounds, or if `buf` is shorter than
+    /// the slice.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Array2};
+    ///
+    /// // Fill the halo of width one of a 4 × 4 array from received edges
+    /// let mut a = Array2::zeros((4, 4));
+    /// a.unpack_slice(s![0, ..], &[1; 4]);
+    /// a.unpack_slice(s![-1, ..], &[2; 4]);
+    /// a.unpack_slice(s![1..-1, 0], &[3; 2]);
+    /// a.unpack_slice(s![1..-1, -1], &[4; 2]);
+    /// assert_eq!(a, array![[1, 1, 1, 1], [3, 0, 0, 4], [3, 0, 0, 4], [2, 2, 2, 2]]);
+    /// ```
+    pub fn unpack_slice<I>(&mut self, info: I, buf: &[A]) -> usize
+    where
+        I: SliceArg<D>,
+        S: DataMut,
+        A: Clone,
+    {
+        let mut view = self.slice_mut(info);
+        let len = view.len();
+        assert!(
+            buf.len() >= len,
+            "unpack_slice: buffer of length {} is shorter than the slice of length {}",
+            buf.len(),
+            len
+        );
+        let buf = &buf[..len];
+        if let Some(slc) = view.as_slice_mut() {
+            slc.clone_from_slice(buf);
+        } else {
+            for (dst, src) in view.iter_mut().zip(buf) {
+                *dst = src.clone();
+            }
+        }
+        len
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::Slice;
+
+/// How to fill the padding, in [`.pad()`](ArrayBase::pad).
+///
+/// The examples show a one-dimensional array `[1, 2, 3]` padded by 4
+/// elements before and 2 elements after.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PadMode<A> {
+    /// Fill with a constant value: `[0, 0, 0, 0, 1, 2, 3, 0, 0]` for `Constant(0)`
+    Constant(A),
+    /// Repeat the edge element: `[1, 1, 1, 1, 1, 2, 3, 3, 3]`
+    Edge,
+    /// Mirror the elements, without repeating the edge element:
+    /// `[1, 2, 3, 2, 1, 2, 3, 2, 1]`
+    Reflect,
+    /// Wrap around to the other end: `[3, 1, 2, 3, 1, 2, 3, 1, 2]`
+    Wrap,
+}
+
+impl<A> PadMode<A> {
+    /// Return the index in `0..len` for the index `i` in the padded axis,
+    /// where `i` is relative to the start of the array (negative before it).
+    pub(crate) fn source_index(&self, i: isize, len: usize) -> usize {
+        let n = len as isize;
+        let i = match *self {
+            PadMode::Constant(_) => unreachable!(),
+            PadMode::Edge => i.max(0).min(n - 1),
+            PadMode::Reflect if n == 1 => 0,
+            PadMode::Reflect => {
+                let period = 2 * (n - 1);
+                let k = i.rem_euclid(period);
+                if k < n {
+                    k
+                } else {
+                    period - k
+                }
+            }
+            PadMode::Wrap => i.rem_euclid(n),
+        };
+        i as usize
+    }
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a new array with padding added to each axis.
+    ///
+    /// `pad_width` has a pair `(before, after)` of padding lengths for each
+    /// axis, and `mode` is how to fill the padding; see [`PadMode`]. The
+    /// padding can be longer than the axis for all modes. The result has
+    /// the same element order as `self`, in standard layout.
+    ///
+    /// **Panics** if the length of `pad_width` is not the number of axes,
+    /// or if an axis of length zero is padded with a mode other than
+    /// `Constant`.
+    ///
+    /// ```
+    /// use ndarray::{array, PadMode};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(
+    ///     a.pad(&[(1, 1), (0, 1)], PadMode::Constant(0)),
+    ///     array![[0, 0, 0], [1, 2, 0], [3, 4, 0], [0, 0, 0]]
+    /// );
+    /// assert_eq!(
+    ///     a.pad(&[(0, 0), (2, 1)], PadMode::Edge),
+    ///     array![[1, 1, 1, 2, 2], [3, 3, 3, 4, 4]]
+    /// );
+    /// assert_eq!(
+    ///     a.pad(&[(1, 0), (1, 1)], PadMode::Wrap),
+    ///     array![[4, 3, 4, 3], [2, 1, 2, 1], [4, 3, 4, 3]]
+    /// );
+    /// ```
+    pub fn pad(&self, pad_width: &[(usize, usize)], mode: PadMode<A>) -> Array<A, D>
+    where
+        A: Clone,
+        D: RemoveAxis,
+    {
+        assert_eq!(
+            pad_width.len(),
+            self.ndim(),
+            "pad: expected padding for {} axes, got {}",
+            self.ndim(),
+            pad_width.len()
+        );
+        if let PadMode::Constant(value) = mode {
+            let mut dim = self.raw_dim();
+            for (len, &(before, after)) in dim.slice_mut().iter_mut().zip(pad_width) {
+                *len += before + after;
+            }
+            let mut padded = Array::from_elem(dim, value);
+            padded
+                .slice_each_axis_mut(|ax| {
+                    let before = pad_width[ax.axis.index()].0;
+                    Slice::from(before..before + self.len_of(ax.axis))
+                })
+                .assign(self);
+            return padded;
+        }
+
+        // Pad one axis at a time, selecting the source index for every index
+        // of the padded axis
+        let mut padded = self.to_owned();
+        for (axis, &(before, after)) in pad_width.iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+            let len = self.len_of(Axis(axis));
+            assert!(
+                len > 0,
+                "pad: can't pad axis {} of length zero except with a constant",
+                axis
+            );
+            let indices: Vec<usize> = (-(before as isize)..(len + after) as isize)
+                .map(|i| mode.source_index(i, len))
+                .collect();
+            padded = padded.select(Axis(axis), &indices);
+        }
+        // The input and the concatenation in select can have other layouts
+        if padded.is_standard_layout() {
+            padded
+        } else {
+            padded.as_standard_layout().into_owned()
+        }
+    }
+}
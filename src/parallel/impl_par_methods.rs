@@ -1,11 +1,27 @@
-use crate::{Array, ArrayBase, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
-use crate::AssignElem;
+use crate::{Array, ArrayBase, ArrayView, Axis, Data, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{AssignElem, RemoveAxis};
+use crate::iter::{AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut};
 
 use crate::parallel::prelude::*;
-use crate::parallel::par::ParallelSplits;
+use crate::parallel::par::{GrainSplits, Parallel, ParallelSplits};
 use super::send_producer::SendProducer;
 
 use crate::partial::Partial;
+use crate::sort::sort_lane_by;
+use crate::ArrayViewMut;
+
+use num_traits::Zero;
+use rayon::slice::ParallelSliceMut;
+use std::cmp::Ordering;
+use std::ops::Add;
+use std::time::Instant;
+
+/// Number of elements the adaptive methods run serially to estimate the cost of the closure.
+const GRAIN_PROBE_LEN: usize = 64;
+/// Approximate amount of work (in nanoseconds) the adaptive methods aim for in each task.
+const GRAIN_TASK_NANOS: u128 = 50_000;
+/// Maximum number of elements the deterministic reductions fold serially.
+const REDUCE_BLOCK_LEN: usize = 1 << 12;
 
 /// # Parallel methods
 ///
@@ -43,6 +59,379 @@ where
             .into_par_iter()
             .for_each(move |x| *x = f(x.clone()))
     }
+
+    /// Parallel version of `map_inplace`, with adaptive grain size.
+    ///
+    /// Modify the array in place by calling `f` by mutable reference on each element.
+    ///
+    /// The cost of `f` is estimated by timing it on a small part of the array first.
+    /// The rest of the array is then split into pieces that are just large enough to be
+    /// worth running as separate tasks: a cheap closure on a small array runs serially,
+    /// while an expensive closure is split all the way down to single elements if needed.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let mut a = Array2::<f64>::zeros((64, 64));
+    /// a.par_for_each_mut(|x| *x += 1.);
+    /// assert_eq!(a.sum(), 64. * 64.);
+    /// ```
+    pub fn par_for_each_mut<F>(&mut self, f: F)
+    where
+        F: Fn(&mut A) + Sync + Send,
+    {
+        // Split off a small leading piece of the array to use as the probe; the remaining
+        // pieces are halves, quarters, etc. of the array.
+        let mut rest = Vec::new();
+        let mut probe = self.view_mut();
+        while probe.len() > GRAIN_PROBE_LEN {
+            let axis = probe.max_stride_axis();
+            let mid = probe.len_of(axis) / 2;
+            let (head, tail) = probe.split_at(axis, mid);
+            probe = head;
+            rest.push(tail);
+        }
+        let probe_len = probe.len();
+        let start = Instant::now();
+        probe.map_inplace(&f);
+        let elapsed = Ord::max(start.elapsed().as_nanos(), 1);
+
+        let rest_len = rest.iter().map(|v| v.len()).sum::<usize>();
+        let grain = GRAIN_TASK_NANOS * probe_len as u128 / elapsed;
+        let grain = Ord::max(grain.min(usize::MAX as u128) as usize, 1);
+        if grain >= rest_len {
+            for mut view in rest {
+                view.map_inplace(&f);
+            }
+        } else {
+            rest.into_par_iter()
+                .for_each(|view| GrainSplits { view, grain }.for_each(&f));
+        }
+    }
+
+    /// Return a parallel iterator over the subviews along `axis`, with
+    /// mutable access.
+    ///
+    /// This is the same as `.axis_iter_mut(axis).into_par_iter()`; see
+    /// [`.axis_iter_mut()`](Self::axis_iter_mut). The parallel iterator is
+    /// indexed, so it can be zipped with other indexed parallel iterators.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array2, Axis};
+    /// use ndarray::parallel::prelude::*;
+    ///
+    /// let mut a = Array2::<f64>::zeros((4, 3));
+    /// a.par_axis_iter_mut(Axis(0))
+    ///     .enumerate()
+    ///     .for_each(|(i, mut row)| row.fill(i as f64));
+    /// assert_eq!(a.sum(), 3. * 6.);
+    /// ```
+    pub fn par_axis_iter_mut(&mut self, axis: Axis) -> Parallel<AxisIterMut<'_, A, D::Smaller>>
+    where
+        D: RemoveAxis,
+    {
+        self.axis_iter_mut(axis).into_par_iter()
+    }
+
+    /// Return a parallel iterator over chunks of `size` along `axis`, with
+    /// mutable access.
+    ///
+    /// This is the same as `.axis_chunks_iter_mut(axis, size).into_par_iter()`;
+    /// see [`.axis_chunks_iter_mut()`](Self::axis_chunks_iter_mut).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `size` is zero.
+    pub fn par_axis_chunks_iter_mut(
+        &mut self,
+        axis: Axis,
+        size: usize,
+    ) -> Parallel<AxisChunksIterMut<'_, A, D>> {
+        self.axis_chunks_iter_mut(axis, size).into_par_iter()
+    }
+
+    /// Parallel version of [`.sort_axis_by()`](Self::sort_axis_by).
+    ///
+    /// Sort each lane along `axis` with the comparison function `compare`.
+    /// The sort is stable.
+    ///
+    /// The lanes are sorted in parallel. If there is only one lane, it is
+    /// sorted with a parallel merge sort instead.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let mut a = Array::from_shape_fn((100, 64), |(i, j)| (i * 7 + j * 13) % 64);
+    /// a.par_sort_axis_by(Axis(1), |x, y| x.cmp(y));
+    /// for row in a.rows() {
+    ///     assert!(row.iter().zip(row.iter().skip(1)).all(|(x, y)| x <= y));
+    /// }
+    /// ```
+    pub fn par_sort_axis_by<F>(&mut self, axis: Axis, compare: F)
+    where
+        A: Clone,
+        F: Fn(&A, &A) -> Ordering + Sync,
+    {
+        par_sort_axis_by(self.view_mut(), axis, true, &compare)
+    }
+
+    /// Parallel version of
+    /// [`.sort_unstable_axis_by()`](Self::sort_unstable_axis_by).
+    ///
+    /// Sort each lane along `axis` with the comparison function `compare`,
+    /// without preserving the order of equal elements. See
+    /// [`.par_sort_axis_by()`](Self::par_sort_axis_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_sort_unstable_axis_by<F>(&mut self, axis: Axis, compare: F)
+    where
+        A: Clone,
+        F: Fn(&A, &A) -> Ordering + Sync,
+    {
+        par_sort_axis_by(self.view_mut(), axis, false, &compare)
+    }
+}
+
+fn par_sort_axis_by<A, D, F>(mut view: ArrayViewMut<'_, A, D>, axis: Axis, stable: bool, compare: &F)
+where
+    A: Clone + Send + Sync,
+    D: Dimension,
+    F: Fn(&A, &A) -> Ordering + Sync,
+{
+    let sort_slice = |slc: &mut [A]| {
+        if stable {
+            slc.par_sort_by(compare)
+        } else {
+            slc.par_sort_unstable_by(compare)
+        }
+    };
+    if view.len() > 1 && view.len() == view.len_of(axis) {
+        // A single lane: sort its elements in parallel
+        let mut lane = view.lanes_mut(axis).into_iter().next().unwrap();
+        if let Some(slc) = lane.as_slice_mut() {
+            sort_slice(slc);
+        } else {
+            let mut buffer = lane.to_vec();
+            sort_slice(&mut buffer);
+            lane.assign(&ArrayView::from(&buffer));
+        }
+    } else {
+        Zip::from(view.lanes_mut(axis)).par_for_each(|lane| sort_lane_by(lane, stable, compare));
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: Send + Sync,
+{
+    /// Return a parallel iterator over the subviews along `axis`.
+    ///
+    /// This is the same as `.axis_iter(axis).into_par_iter()`; see
+    /// [`.axis_iter()`](Self::axis_iter). The parallel iterator is indexed,
+    /// so it can be zipped with other indexed parallel iterators.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    /// use ndarray::parallel::prelude::*;
+    ///
+    /// let a = Array::linspace(0., 1., 1200).into_shape((40, 30)).unwrap();
+    /// let row_max = a.par_axis_iter(Axis(0))
+    ///     .map(|row| row.fold(f64::NEG_INFINITY, |m, &x| m.max(x)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(row_max.len(), 40);
+    /// assert_eq!(row_max[39], 1.);
+    /// ```
+    pub fn par_axis_iter(&self, axis: Axis) -> Parallel<AxisIter<'_, A, D::Smaller>>
+    where
+        D: RemoveAxis,
+    {
+        self.axis_iter(axis).into_par_iter()
+    }
+
+    /// Return a parallel iterator over chunks of `size` along `axis`.
+    ///
+    /// This is the same as `.axis_chunks_iter(axis, size).into_par_iter()`;
+    /// see [`.axis_chunks_iter()`](Self::axis_chunks_iter).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `size` is zero.
+    pub fn par_axis_chunks_iter(&self, axis: Axis, size: usize) -> Parallel<AxisChunksIter<'_, A, D>> {
+        self.axis_chunks_iter(axis, size).into_par_iter()
+    }
+
+    /// Parallel reduction of the elements with a fixed reduction tree.
+    ///
+    /// The array is split in halves along its outermost axis that is longer
+    /// than one, recursively, until the pieces have at most 4096 elements.
+    /// Each piece is folded serially in logical order, starting from
+    /// `identity()`, and the results are then combined in pairs with `op`
+    /// following the same tree.
+    ///
+    /// The tree depends only on the shape of the array, not on the number of
+    /// threads or how the tasks are scheduled. The result is therefore always
+    /// the same for the same input, bitwise even for floating point numbers,
+    /// which is not the case for rayon's `reduce`.
+    ///
+    /// `op` should be associative and `identity()` an identity element for it.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::linspace(0., 1., 100_000);
+    /// let max = a.par_reduce_deterministic(|| f64::NEG_INFINITY, f64::max);
+    /// assert_eq!(max, 1.);
+    /// ```
+    pub fn par_reduce_deterministic<ID, F>(&self, identity: ID, op: F) -> A
+    where
+        A: Clone,
+        ID: Fn() -> A + Sync,
+        F: Fn(A, A) -> A + Sync,
+    {
+        fixed_tree_reduce(
+            self.view(),
+            &|piece| piece.iter().fold(identity(), |acc, elt| op(acc, elt.clone())),
+            &op,
+        )
+    }
+
+    /// Return the sum of all elements, computed in parallel with a fixed
+    /// reduction tree.
+    ///
+    /// The result is always the same for the same array, bitwise even for
+    /// floating point numbers; see
+    /// [`.par_reduce_deterministic()`](Self::par_reduce_deterministic) for
+    /// the details. The pieces are summed like [`.sum()`](Self::sum).
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::linspace(0., 1., 100_000).mapv(f64::sin);
+    /// assert_eq!(a.par_sum_deterministic(), a.par_sum_deterministic());
+    /// ```
+    pub fn par_sum_deterministic(&self) -> A
+    where
+        A: Clone + Add<Output = A> + Zero,
+    {
+        fixed_tree_reduce(self.view(), &|piece| piece.sum(), &A::add)
+    }
+
+    /// Parallel version of [`.process_chunks()`](Self::process_chunks).
+    ///
+    /// Map-reduce over the array in chunks of `chunk_len` along `axis`, with
+    /// the chunks processed in parallel. `init()` is called to create the
+    /// scratch value once for each rayon task, which processes one or more
+    /// chunks. The results are still combined in order, so `reduce` needs to
+    /// be associative but not commutative.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `chunk_len` is zero.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((1000, 3), |(i, j)| (i * 3 + j) as u64);
+    /// let sum = a.par_process_chunks(Axis(0), 64, || (), |_, chunk| chunk.sum(), |x, y| x + y);
+    /// assert_eq!(sum, Some(a.sum()));
+    /// ```
+    pub fn par_process_chunks<B, T, F, M, R>(
+        &self,
+        axis: Axis,
+        chunk_len: usize,
+        init: F,
+        map: M,
+        reduce: R,
+    ) -> Option<T>
+    where
+        T: Send,
+        F: Fn() -> B + Sync + Send,
+        M: Fn(&mut B, ArrayView<'_, A, D>) -> T + Sync + Send,
+        R: Fn(T, T) -> T + Sync + Send,
+    {
+        self.axis_chunks_iter(axis, chunk_len)
+            .into_par_iter()
+            .map_init(init, map)
+            .reduce_with(reduce)
+    }
+}
+
+/// Parallel version of [`process_chunks`](crate::process_chunks).
+///
+/// Map-reduce over a sequence of chunks, processing them in parallel. The
+/// chunks are taken from `chunks` in batches of as many chunks as there are
+/// threads in the rayon thread pool, and each batch is processed before the
+/// next one is taken, so that only one batch of chunks is alive at a time.
+///
+/// `init()` is called to create the scratch value once for each rayon task,
+/// which processes one or more chunks. The results are combined in order, so
+/// `reduce` needs to be associative but not commutative. Returns `None` if
+/// there are no chunks.
+///
+/// Requires crate feature `rayon`.
+///
+/// ```
+/// use ndarray::Array1;
+/// use ndarray::parallel::par_process_chunks;
+///
+/// let chunks = (0..10).map(|i| Array1::from_elem(100, i as f64));
+/// let total = par_process_chunks(chunks, || (), |_, chunk| chunk.sum(), |a, b| a + b);
+/// assert_eq!(total, Some(4500.));
+/// ```
+pub fn par_process_chunks<I, B, T, F, M, R>(chunks: I, init: F, map: M, reduce: R) -> Option<T>
+where
+    I: IntoIterator,
+    I::Item: Send,
+    T: Send,
+    F: Fn() -> B + Sync + Send,
+    M: Fn(&mut B, I::Item) -> T + Sync + Send,
+    R: Fn(T, T) -> T + Sync + Send,
+{
+    let batch_len = rayon::current_num_threads();
+    let mut chunks = chunks.into_iter();
+    let mut result = None;
+    loop {
+        let batch = chunks.by_ref().take(batch_len).collect::<Vec<_>>();
+        if batch.is_empty() {
+            return result;
+        }
+        let batch_result = batch.into_par_iter().map_init(&init, &map).reduce_with(&reduce);
+        result = match (result, batch_result) {
+            (Some(x), Some(y)) => Some(reduce(x, y)),
+            (x, y) => x.or(y),
+        };
+    }
+}
+
+/// Reduce `view` with `leaf` on pieces of at most `REDUCE_BLOCK_LEN` elements and `combine`
+/// on the partial results, using a tree that depends only on the shape of `view`.
+fn fixed_tree_reduce<A, D, R, L, C>(view: ArrayView<'_, A, D>, leaf: &L, combine: &C) -> R
+where
+    A: Sync,
+    D: Dimension,
+    R: Send,
+    L: Fn(ArrayView<'_, A, D>) -> R + Sync,
+    C: Fn(R, R) -> R + Sync,
+{
+    if view.len() <= REDUCE_BLOCK_LEN {
+        return leaf(view);
+    }
+    // Not empty, so there is an axis longer than one
+    let axis = view.shape().iter().position(|&len| len > 1).unwrap();
+    let mid = view.len_of(Axis(axis)) / 2;
+    let (left, right) = view.split_at(Axis(axis), mid);
+    let (left, right) = rayon::join(
+        || fixed_tree_reduce(left, leaf, combine),
+        || fixed_tree_reduce(right, leaf, combine),
+    );
+    combine(left, right)
 }
 
 // Zip
@@ -71,6 +460,44 @@ macro_rules! zip_impl {
                 self.into_par_iter().for_each(move |($($p,)*)| function($($p),*))
             }
 
+            /// The `par_for_each` method for `Zip`, with a scratch value for each
+            /// rayon task.
+            ///
+            /// `init()` is called to create the scratch value once for each rayon task,
+            /// which visits one or more items, and `function` gets it by mutable reference
+            /// together with the items. Use it for buffers, random number generators or
+            /// other state that is expensive to create for every item.
+            ///
+            /// This is a shorthand for using `.into_par_iter().for_each_init()` on
+            /// `Zip`.
+            ///
+            /// Requires crate feature `rayon`.
+            ///
+            /// ```
+            /// use ndarray::{Array2, Zip};
+            ///
+            /// let a = Array2::from_shape_fn((64, 8), |(i, j)| ((i * 8 + j) % 5) as f64);
+            /// let mut medians = Array2::zeros((64, 1));
+            ///
+            /// // Reuse one sort buffer per task instead of allocating one per row
+            /// Zip::from(a.rows())
+            ///     .and(medians.rows_mut())
+            ///     .par_for_each_init(Vec::new, |buffer, row, mut median| {
+            ///         buffer.clear();
+            ///         buffer.extend(row.iter().cloned());
+            ///         buffer.sort_by(|x, y| x.partial_cmp(y).unwrap());
+            ///         median[0] = buffer[buffer.len() / 2];
+            ///     });
+            /// assert_eq!(medians[[0, 0]], 2.);
+            /// ```
+            pub fn par_for_each_init<T, INIT, F>(self, init: INIT, function: F)
+                where INIT: Fn() -> T + Sync + Send,
+                      F: Fn(&mut T, $($p::Item),*) + Sync + Send
+            {
+                self.into_par_iter()
+                    .for_each_init(init, move |scratch, ($($p,)*)| function(scratch, $($p),*))
+            }
+
             /// The `par_apply` method for `Zip`.
             ///
             /// This is a shorthand for using `.into_par_iter().for_each()` on
@@ -189,5 +616,11 @@ zip_impl! {
     [true P1 P2 P3],
     [true P1 P2 P3 P4],
     [true P1 P2 P3 P4 P5],
-    [false P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6 P7],
+    [true P1 P2 P3 P4 P5 P6 P7 P8],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11],
+    [false P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12],
 }
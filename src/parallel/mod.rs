@@ -13,14 +13,24 @@
 //! - [`Array`], [`ArcArray`] `.par_iter()` and `.par_iter_mut()`
 //! - [`ArrayView`] `.into_par_iter()`
 //! - [`ArrayViewMut`] `.into_par_iter()`
-//! - [`AxisIter`], [`AxisIterMut`] `.into_par_iter()`
-//! - [`AxisChunksIter`], [`AxisChunksIterMut`] `.into_par_iter()`
+//! - [`AxisIter`], [`AxisIterMut`] `.into_par_iter()`, or directly from an
+//!   array with [`.par_axis_iter()`](ArrayBase::par_axis_iter) and
+//!   [`.par_axis_iter_mut()`](ArrayBase::par_axis_iter_mut)
+//! - [`AxisChunksIter`], [`AxisChunksIterMut`] `.into_par_iter()`, or directly
+//!   from an array with [`.par_axis_chunks_iter()`](ArrayBase::par_axis_chunks_iter)
+//!   and [`.par_axis_chunks_iter_mut()`](ArrayBase::par_axis_chunks_iter_mut)
 //! - [`Zip`] `.into_par_iter()`
 //!
 //! The following other parallelized methods exist:
 //!
 //! - [`ArrayBase::par_map_inplace()`]
 //! - [`ArrayBase::par_mapv_inplace()`]
+//! - [`ArrayBase::par_for_each_mut()`]
+//! - [`ArrayBase::par_reduce_deterministic()`]
+//! - [`ArrayBase::par_sum_deterministic()`]
+//! - [`ArrayBase::par_process_chunks()`] and [`par_process_chunks()`]
+//! - [`ArrayBase::par_sum_axis()`], [`ArrayBase::par_mean_axis()`],
+//!   [`ArrayBase::par_var_axis()`] and [`ArrayBase::par_std_axis()`]
 //! - [`Zip::par_for_each()`] (all arities)
 //! - [`Zip::par_map_collect()`] (all arities)
 //! - [`Zip::par_map_assign_into()`] (all arities)
@@ -149,6 +159,7 @@ pub mod prelude {
     pub use super::par_azip;
 }
 
+pub use self::impl_par_methods::par_process_chunks;
 pub use self::par::Parallel;
 pub use crate::par_azip;
 
@@ -282,6 +282,12 @@ zip_impl! {
     [P1 P2 P3 P4],
     [P1 P2 P3 P4 P5],
     [P1 P2 P3 P4 P5 P6],
+    [P1 P2 P3 P4 P5 P6 P7],
+    [P1 P2 P3 P4 P5 P6 P7 P8],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9 P10],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12],
 }
 
 /// A parallel iterator (unindexed) that produces the splits of the array
@@ -333,3 +339,58 @@ impl<P> UnindexedProducer for ParallelSplits<P>
         folder.consume(self.iter)
     }
 }
+
+/// An unindexed parallel producer of the elements of an array view, which
+/// stops splitting once pieces are smaller than the given grain size.
+pub(crate) struct GrainSplits<'a, A, D> {
+    pub(crate) view: ArrayViewMut<'a, A, D>,
+    pub(crate) grain: usize,
+}
+
+impl<'a, A, D> ParallelIterator for GrainSplits<'a, A, D>
+    where D: Dimension,
+          A: Send + Sync,
+{
+    type Item = &'a mut A;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge_unindexed(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<'a, A, D> UnindexedProducer for GrainSplits<'a, A, D>
+    where D: Dimension,
+          A: Send + Sync,
+{
+    type Item = &'a mut A;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if self.view.len() <= 1 || self.view.len() < 2 * self.grain {
+            return (self, None)
+        }
+        let grain = self.grain;
+        let axis = self.view.max_stride_axis();
+        let mid = self.view.len_of(axis) / 2;
+        let (a, b) = self.view.split_at(axis, mid);
+        (GrainSplits { view: a, grain }, Some(GrainSplits { view: b, grain }))
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>,
+    {
+        Zip::from(self.view).fold_while(folder, |mut folder, elt| {
+            folder = folder.consume(elt);
+            if folder.full() {
+                FoldWhile::Done(folder)
+            } else {
+                FoldWhile::Continue(folder)
+            }
+        }).into_inner()
+    }
+}
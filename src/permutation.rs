@@ -0,0 +1,203 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+
+/// A permutation of the indices `0..len`, to reorder the subviews along an
+/// axis of an array.
+///
+/// The permutation is a list of indices in which each index from `0` to
+/// `len - 1` appears once. Applying it to an axis with
+/// [`.permute_axis()`](ArrayBase::permute_axis) moves subview `indices[i]`
+/// to position `i`.
+///
+/// Permutations can be inverted and composed, so that the order of
+/// shuffled or sorted data can be restored:
+///
+/// ```
+/// use ndarray::{array, Axis, Permutation};
+///
+/// let scores = array![0.5, 0.9, 0.1];
+/// let rows = array![[1, 1], [2, 2], [3, 3]];
+///
+/// let by_score = Permutation::from_sort_of(&scores);
+/// assert_eq!(by_score.indices(), &[2, 0, 1]);
+/// let sorted = rows.permute_axis(Axis(0), &by_score);
+/// assert_eq!(sorted, array![[3, 3], [1, 1], [2, 2]]);
+///
+/// // Unsort
+/// assert_eq!(sorted.permute_axis(Axis(0), &by_score.inverse()), rows);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// Return the identity permutation of `len` indices, which doesn't move
+    /// anything.
+    pub fn identity(len: usize) -> Self {
+        Permutation {
+            indices: (0..len).collect(),
+        }
+    }
+
+    /// Return the permutation with the list of indices `indices`, or `None`
+    /// if it doesn't have each index from `0` to `indices.len() - 1` once.
+    ///
+    /// ```
+    /// use ndarray::Permutation;
+    ///
+    /// assert!(Permutation::from_indices(vec![2, 0, 1]).is_some());
+    /// assert!(Permutation::from_indices(vec![2, 0, 2]).is_none());
+    /// assert!(Permutation::from_indices(vec![3, 0, 1]).is_none());
+    /// ```
+    pub fn from_indices(indices: Vec<usize>) -> Option<Self> {
+        let mut seen = vec![false; indices.len()];
+        for &i in &indices {
+            match seen.get_mut(i) {
+                Some(s) if !*s => *s = true,
+                _ => return None,
+            }
+        }
+        Some(Permutation { indices })
+    }
+
+    /// Return the permutation that sorts `keys` in ascending order.
+    ///
+    /// The sort is stable: equal keys keep their order. Keys that don't
+    /// compare, like NaN, are treated as equal to all other keys, so the
+    /// order is unspecified if there are any.
+    pub fn from_sort_of<A, S>(keys: &ArrayBase<S, Ix1>) -> Self
+    where
+        A: PartialOrd,
+        S: Data<Elem = A>,
+    {
+        let mut indices: Vec<usize> = (0..keys.len()).collect();
+        indices.sort_by(|&i, &j| keys[i].partial_cmp(&keys[j]).unwrap_or(Ordering::Equal));
+        Permutation { indices }
+    }
+
+    /// Return a uniformly random permutation of `len` indices, drawn from
+    /// `rng`. The permutation is determined by the state of `rng`, so a
+    /// seeded generator gives the same permutation each time.
+    ///
+    /// **Requires crate feature `"rand"`**
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, Permutation};
+    /// use ndarray::rand::rngs::mock::StepRng;
+    ///
+    /// let shuffle = Permutation::random(5, &mut StepRng::new(7, 11));
+    /// assert_eq!(shuffle, Permutation::random(5, &mut StepRng::new(7, 11)));
+    ///
+    /// let batch = array![10, 11, 12, 13, 14];
+    /// let shuffled = batch.permute_axis(Axis(0), &shuffle);
+    /// assert_eq!(shuffled.permute_axis(Axis(0), &shuffle.inverse()), batch);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random<R>(len: usize, rng: &mut R) -> Self
+    where
+        R: rand_::Rng + ?Sized,
+    {
+        use rand_::seq::SliceRandom;
+        let mut perm = Self::identity(len);
+        perm.indices.shuffle(rng);
+        perm
+    }
+
+    /// Return the number of indices.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Return `true` if the permutation has no indices.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Return the list of indices.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Return the list of indices.
+    pub fn into_indices(self) -> Vec<usize> {
+        self.indices
+    }
+
+    /// Return `true` if this is the identity permutation.
+    pub fn is_identity(&self) -> bool {
+        self.indices.iter().enumerate().all(|(i, &j)| i == j)
+    }
+
+    /// Return the inverse permutation, which undoes this one.
+    pub fn inverse(&self) -> Self {
+        let mut indices = vec![0; self.len()];
+        for (i, &j) in self.indices.iter().enumerate() {
+            indices[j] = i;
+        }
+        Permutation { indices }
+    }
+
+    /// Return the permutation that applies this one and then `next`.
+    ///
+    /// **Panics** if the permutations don't have the same length.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, Permutation};
+    ///
+    /// let a = array![0, 1, 2];
+    /// let p = Permutation::from_indices(vec![1, 2, 0]).unwrap();
+    /// let q = Permutation::from_indices(vec![0, 2, 1]).unwrap();
+    /// assert_eq!(
+    ///     a.permute_axis(Axis(0), &p.then(&q)),
+    ///     a.permute_axis(Axis(0), &p).permute_axis(Axis(0), &q)
+    /// );
+    /// assert!(p.then(&p.inverse()).is_identity());
+    /// ```
+    pub fn then(&self, next: &Permutation) -> Self {
+        assert_eq!(
+            self.len(),
+            next.len(),
+            "Permutation::then: the permutations must have the same length"
+        );
+        Permutation {
+            indices: next.indices.iter().map(|&i| self.indices[i]).collect(),
+        }
+    }
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a copy of the array with the subviews along `axis` reordered by
+    /// `perm`: subview `i` of the result is subview `perm.indices()[i]` of
+    /// the array.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the length of `perm` is
+    /// not the length of the axis.
+    pub fn permute_axis(&self, axis: Axis, perm: &Permutation) -> Array<A, D>
+    where
+        A: Clone,
+        D: RemoveAxis,
+    {
+        assert_eq!(
+            perm.len(),
+            self.len_of(axis),
+            "permute_axis: the permutation must have the length of the axis"
+        );
+        self.select(axis, perm.indices())
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::IntoDimension;
+
+/// # Searching
+///
+/// These methods return the indices of the elements that satisfy a
+/// predicate, stopping at the first match(es) instead of visiting the whole
+/// array. The predicate is called on the elements in order until the search
+/// is done.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the index of the first element, in logical order, for which
+    /// `pred` returns `true`, or `None` if there is none.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let residuals = array![[0.1, 0.002], [0.5, 0.001]];
+    /// assert_eq!(residuals.position_where(|&r| r < 0.01), Some((0, 1)));
+    /// assert_eq!(residuals.position_where(|&r| r > 1.), None);
+    /// ```
+    pub fn position_where<F>(&self, mut pred: F) -> Option<D::Pattern>
+    where
+        F: FnMut(&A) -> bool,
+    {
+        if let Some(slice) = self.as_slice() {
+            let i = slice.iter().position(pred)?;
+            return Some(self.logical_index(i).into_pattern());
+        }
+        self.indexed_iter().find(|(_, x)| pred(x)).map(|(index, _)| index)
+    }
+
+    /// Return the index of the last element, in logical order, for which
+    /// `pred` returns `true`, or `None` if there is none.
+    ///
+    /// The elements are visited in reverse logical order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.rposition_where(|&x| x % 2 == 1), Some((1, 1)));
+    /// assert_eq!(a.t().rposition_where(|&x| x % 2 == 1), Some((2, 0)));
+    /// ```
+    pub fn rposition_where<F>(&self, mut pred: F) -> Option<D::Pattern>
+    where
+        F: FnMut(&A) -> bool,
+    {
+        if let Some(slice) = self.as_slice() {
+            let i = slice.iter().rposition(pred)?;
+            return Some(self.logical_index(i).into_pattern());
+        }
+        // Search the array with all axes inverted, which reverses the
+        // logical order, and invert the index back
+        let mut reversed = self.view();
+        for k in 0..reversed.ndim() {
+            reversed.invert_axis(Axis(k));
+        }
+        let (index, _) = reversed.indexed_iter().find(|(_, x)| pred(x))?;
+        let mut index = index.into_dimension();
+        for (i, &len) in index.slice_mut().iter_mut().zip(self.shape()) {
+            *i = len - 1 - *i;
+        }
+        Some(index.into_pattern())
+    }
+
+    /// Return the indices of the first `k` elements, in logical order, for
+    /// which `pred` returns `true`, or of all of them if there are fewer.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, -2, 3], [-4, 5, -6]];
+    /// assert_eq!(a.positions_where(2, |&x| x < 0), vec![(0, 1), (1, 0)]);
+    /// assert_eq!(a.positions_where(5, |&x| x < 0).len(), 3);
+    /// ```
+    pub fn positions_where<F>(&self, k: usize, mut pred: F) -> Vec<D::Pattern>
+    where
+        F: FnMut(&A) -> bool,
+    {
+        if let Some(slice) = self.as_slice() {
+            return slice
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| pred(x))
+                .take(k)
+                .map(|(i, _)| self.logical_index(i).into_pattern())
+                .collect();
+        }
+        self.indexed_iter()
+            .filter(|(_, x)| pred(x))
+            .take(k)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Return the index of an element for which `pred` returns `true`, or
+    /// `None` if there is none, visiting the elements in the fastest order.
+    ///
+    /// If the array is contiguous, this is the first such element in the
+    /// order of the elements in memory, whatever the memory layout of the
+    /// array; otherwise this is the first in logical order, like
+    /// [`.position_where()`](Self::position_where).
+    ///
+    /// ```
+    /// use ndarray::{array, Array, ShapeBuilder};
+    ///
+    /// let a = Array::from_shape_vec((2, 2).f(), vec![0, 1, 2, 3]).unwrap();
+    /// assert_eq!(a, array![[0, 2], [1, 3]]);
+    /// // Element 1 comes before element 2 in memory
+    /// assert_eq!(a.position_where_memory_order(|&x| x > 0), Some((1, 0)));
+    /// assert_eq!(a.position_where(|&x| x > 0), Some((0, 1)));
+    /// ```
+    pub fn position_where_memory_order<F>(&self, pred: F) -> Option<D::Pattern>
+    where
+        F: FnMut(&A) -> bool,
+    {
+        if let Some(slice) = self.as_slice_memory_order() {
+            let offset = slice.iter().position(pred)?;
+            return Some(self.memory_index(offset).into_pattern());
+        }
+        self.position_where(pred)
+    }
+
+    /// Return the index of element `i` in logical order.
+    fn logical_index(&self, mut i: usize) -> D {
+        let mut index = self.raw_dim();
+        for (x, &len) in index.slice_mut().iter_mut().zip(self.shape()).rev() {
+            *x = i % len;
+            i /= len;
+        }
+        index
+    }
+
+    /// Return the index of the element at `offset` elements from the lowest
+    /// address of the contiguous array.
+    fn memory_index(&self, mut offset: usize) -> D {
+        let mut index = D::zeros(self.ndim());
+        // Unravel the offset from the axis of the largest stride to the
+        // smallest; axes of length one have any stride and index zero.
+        let mut axes: Vec<usize> = (0..self.ndim()).filter(|&k| self.shape()[k] > 1).collect();
+        axes.sort_by_key(|&k| std::cmp::Reverse(self.strides()[k].unsigned_abs()));
+        for k in axes {
+            let stride = self.strides()[k];
+            let step = stride.unsigned_abs();
+            let i = offset / step;
+            offset %= step;
+            index[k] = if stride < 0 { self.shape()[k] - 1 - i } else { i };
+        }
+        index
+    }
+}
@@ -11,6 +11,16 @@
 //! This module contains the most used types, type aliases, traits, functions,
 //! and macros that you can import easily as a group.
 //!
+//! Besides the arrays and their constructors, it contains the main types of
+//! the other parts of the crate that are enabled, by crate feature:
+//!
+//! - always: [`ArrayBuilder`], [`Order`], [`Zip`], [`Permutation`] and
+//!   [`zip_axis_iter!`]
+//! - `std`: the statistics types [`Summary`] and [`QuantileSketch`], and the
+//!   error of the [`io::snapshot`](crate::io::snapshot) format
+//! - `linalg`: [`LU`] and [`LinalgError`]
+//! - `npy`, `csv`: the errors and options of the file formats
+//!
 //! ```
 //! use ndarray::prelude::*;
 //!
@@ -45,7 +55,7 @@ pub use crate::{Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
 #[doc(no_inline)]
 pub use crate::{arr0, arr1, arr2, aview0, aview1, aview2, aview_mut1};
 
-pub use crate::{array, azip, s};
+pub use crate::{array, azip, s, zip_axis_iter};
 
 #[doc(no_inline)]
 pub use crate::ShapeBuilder;
@@ -59,3 +69,26 @@ pub use crate::AsArray;
 #[doc(no_inline)]
 #[cfg(feature = "std")]
 pub use crate::NdFloat;
+
+#[doc(no_inline)]
+pub use crate::{ArrayBuilder, Order, Permutation, Zip};
+
+#[doc(no_inline)]
+#[cfg(feature = "std")]
+pub use crate::{QuantileSketch, Summary};
+
+#[doc(no_inline)]
+#[cfg(feature = "std")]
+pub use crate::io::snapshot::SnapshotError;
+
+#[doc(no_inline)]
+#[cfg(feature = "linalg")]
+pub use crate::linalg::solve::{LinalgError, LU};
+
+#[doc(no_inline)]
+#[cfg(feature = "npy")]
+pub use crate::npy::NpyError;
+
+#[doc(no_inline)]
+#[cfg(feature = "csv")]
+pub use crate::csv::{CsvError, CsvOptions};
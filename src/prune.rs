@@ -0,0 +1,160 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use num_traits::{Signed, Zero};
+
+use crate::imp_prelude::*;
+use crate::IntoDimension;
+
+/// Return the indices of the `true` elements of `mask`, one row for each
+/// element, like NumPy's `argwhere`.
+///
+/// The result has shape `(count, ndim)`: the rows are the indices of the
+/// `true` elements, in logical order. The elements are counted first, so
+/// that the result is allocated once.
+///
+/// ```
+/// use ndarray::{argwhere, array};
+///
+/// let a = array![[0, 3, 0], [4, 0, 5]];
+/// let hits = argwhere(&a.mapv(|x| x > 0));
+/// assert_eq!(hits, array![[0, 1], [1, 0], [1, 2]]);
+///
+/// // The rows index the array
+/// for index in hits.rows() {
+///     assert!(a[[index[0], index[1]]] > 0);
+/// }
+/// ```
+pub fn argwhere<S, D>(mask: &ArrayBase<S, D>) -> Array2<usize>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    let count = mask.iter().filter(|&&m| m).count();
+    let mut indices = Array2::zeros((count, mask.ndim()));
+    let hits = mask.indexed_iter().filter(|&(_, &m)| m);
+    for (mut row, (index, _)) in indices.rows_mut().into_iter().zip(hits) {
+        let index = index.into_dimension();
+        row.assign(&ArrayView1::from(index.slice()));
+    }
+    indices
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a copy of the array where the elements for which `pred`
+    /// returns `true` are replaced by `fill`, and the indices of the replaced
+    /// elements, in logical order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let weights = array![[0.5, -0.01], [0.02, -2.]];
+    /// let (pruned, indices) = weights.prune(|x: &f64| x.abs() < 0.1, 0.);
+    /// assert_eq!(pruned, array![[0.5, 0.], [0., -2.]]);
+    /// assert_eq!(indices, vec![(0, 1), (1, 0)]);
+    /// ```
+    pub fn prune<F>(&self, mut pred: F, fill: A) -> (Array<A, D>, Vec<D::Pattern>)
+    where
+        A: Clone,
+        F: FnMut(&A) -> bool,
+    {
+        let mut indices = Vec::new();
+        let mut pruned = self.to_owned();
+        for (index, elt) in pruned.indexed_iter_mut() {
+            if pred(elt) {
+                *elt = fill.clone();
+                indices.push(index);
+            }
+        }
+        (pruned, indices)
+    }
+
+    /// Return a mask that is `true` for the elements whose absolute value is
+    /// greater than `threshold`, the elements that are kept when the array is
+    /// made sparse.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0.5, -0.01], [0.1, -2.]];
+    /// assert_eq!(a.to_sparse_mask(0.1), array![[true, false], [false, true]]);
+    /// ```
+    pub fn to_sparse_mask(&self, threshold: A) -> Array<bool, D>
+    where
+        A: Signed + PartialOrd,
+    {
+        self.map(|x| x.abs() > threshold)
+    }
+
+    /// Return the indices of the elements that are not zero, with one array
+    /// of indices for each axis, like NumPy's `nonzero`.
+    ///
+    /// The elements are in logical order. The `i`-th element is at the
+    /// index given by the `i`-th element of each of the arrays.
+    ///
+    /// ```
+    /// use ndarray::{arr1, array};
+    ///
+    /// let a = array![[0, 3, 0], [4, 0, 5]];
+    /// let indices = a.nonzero();
+    /// assert_eq!(indices, vec![arr1(&[0, 1, 1]), arr1(&[1, 0, 2])]);
+    /// ```
+    pub fn nonzero(&self) -> Vec<Array1<usize>>
+    where
+        A: Zero + PartialEq,
+    {
+        let ndim = self.ndim();
+        let count = self.iter().filter(|x| !x.is_zero()).count();
+        let mut indices = (0..ndim)
+            .map(|_| Vec::with_capacity(count))
+            .collect::<Vec<_>>();
+        for (index, elt) in self.indexed_iter() {
+            if !elt.is_zero() {
+                let index = index.into_dimension();
+                for (axis_indices, &i) in indices.iter_mut().zip(index.slice()) {
+                    axis_indices.push(i);
+                }
+            }
+        }
+        indices.into_iter().map(Array1::from).collect()
+    }
+
+    /// Return the indices of the elements that are not zero in the
+    /// flattened array, like NumPy's `flatnonzero`.
+    ///
+    /// The indices are positions in the logical order of the elements, the
+    /// order of [`.iter()`](ArrayBase::iter), whatever the memory layout.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0, 3, 0], [4, 0, 5]];
+    /// assert_eq!(a.flatnonzero(), array![1, 3, 5]);
+    /// assert_eq!(a.t().flatnonzero(), array![1, 2, 5]);
+    /// ```
+    pub fn flatnonzero(&self) -> Array1<usize>
+    where
+        A: Zero + PartialEq,
+    {
+        let count = self.iter().filter(|x| !x.is_zero()).count();
+        let mut indices = Vec::with_capacity(count);
+        indices.extend(
+            self.iter()
+                .enumerate()
+                .filter(|(_, x)| !x.is_zero())
+                .map(|(i, _)| i),
+        );
+        Array1::from(indices)
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::ops::{Add, Div};
+
+use num_traits::FromPrimitive;
+
+use crate::imp_prelude::*;
+use crate::{Slice, Zip};
+
+/// How to combine each block of elements into one, in
+/// [`.build_pyramid()`](ArrayBase::build_pyramid).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Aggregate {
+    /// The mean of the block. Integer means are rounded towards zero.
+    Mean,
+    /// The largest element of the block.
+    Max,
+    /// The smallest element of the block.
+    Min,
+}
+
+impl Aggregate {
+    /// Combine `x` into the aggregate `acc`; means are divided afterwards.
+    fn combine<A, B>(self, acc: &mut B, x: &A)
+    where
+        A: Clone,
+        B: Clone + PartialOrd + Add<Output = B> + From<A>,
+    {
+        let x = B::from(x.clone());
+        match self {
+            Aggregate::Mean => *acc = acc.clone() + x,
+            Aggregate::Max => {
+                if x > *acc {
+                    *acc = x;
+                }
+            }
+            Aggregate::Min => {
+                if x < *acc {
+                    *acc = x;
+                }
+            }
+        }
+    }
+}
+
+/// # Pyramids
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return `levels` successively downsampled copies of the array, each
+    /// half the length of the previous one along every axis.
+    ///
+    /// This is [`.build_pyramid_axes()`](Self::build_pyramid_axes) with all
+    /// axes.
+    ///
+    /// ```
+    /// use ndarray::{array, Aggregate};
+    ///
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [5., 6., 7., 8.],
+    ///                [9., 10., 11., 12.]];
+    /// let pyramid = a.build_pyramid::<f64>(2, Aggregate::Mean);
+    /// assert_eq!(pyramid[0], array![[3.5, 5.5], [9.5, 11.5]]);
+    /// assert_eq!(pyramid[1], array![[7.5]]);
+    ///
+    /// let pyramid = a.build_pyramid::<f64>(1, Aggregate::Max);
+    /// assert_eq!(pyramid[0], array![[6., 8.], [10., 12.]]);
+    /// ```
+    pub fn build_pyramid<B>(&self, levels: usize, aggregate: Aggregate) -> Vec<Array<B, D>>
+    where
+        A: Clone,
+        B: Clone + PartialOrd + FromPrimitive + Add<Output = B> + Div<Output = B> + From<A>,
+    {
+        let axes: Vec<Axis> = (0..self.ndim()).map(Axis).collect();
+        self.build_pyramid_axes(&axes, levels, aggregate)
+    }
+
+    /// Return `levels` successively downsampled copies of the array, each
+    /// half the length of the previous one along `axes`, for example for
+    /// displaying a huge array at several zoom levels, or for multiscale
+    /// algorithms.
+    ///
+    /// Level `k` of the pyramid is downsampled by a factor of `2^(k + 1)`;
+    /// the array itself is not included. Each element of a level aggregates
+    /// a block of two elements along each of `axes` of the previous level,
+    /// with `aggregate`. If the previous level has an odd length along one
+    /// of the axes, the last blocks along that axis have only one element
+    /// along it, so the length of the next level is `(len + 1) / 2`.
+    ///
+    /// The levels have the element type `B`, in which the sums of the means
+    /// are computed, so it must hold the sum of a block of two elements
+    /// along each of `axes`. It can be wider than `A` to avoid overflow, for
+    /// example `u16` for an image of `u8`.
+    ///
+    /// Each level is computed from the previous one in `2^axes.len()`
+    /// strided passes over it, one for each position in the blocks, without
+    /// buffers other than the levels themselves.
+    ///
+    /// **Panics** if any of `axes` is out of bounds or repeated, or if
+    /// `B::from_usize()` fails for the number of elements of a block.
+    ///
+    /// ```
+    /// use ndarray::{array, Aggregate, Axis};
+    ///
+    /// // Downsample a series of frames in space, but not in time
+    /// let frames = array![[[0, 1], [2, 3]],
+    ///                     [[4, 5], [6, 7]]];
+    /// let pyramid = frames.build_pyramid_axes::<i32>(&[Axis(1), Axis(2)], 1, Aggregate::Max);
+    /// assert_eq!(pyramid[0], array![[[3]], [[7]]]);
+    ///
+    /// // The sum of a block of 200u8 overflows u8, but not u16
+    /// let image = array![[200u8, 200], [200, 200]];
+    /// let pyramid = image.build_pyramid_axes::<u16>(&[Axis(0), Axis(1)], 1, Aggregate::Mean);
+    /// assert_eq!(pyramid[0], array![[200]]);
+    /// ```
+    pub fn build_pyramid_axes<B>(&self, axes: &[Axis], levels: usize, aggregate: Aggregate) -> Vec<Array<B, D>>
+    where
+        A: Clone,
+        B: Clone + PartialOrd + FromPrimitive + Add<Output = B> + Div<Output = B> + From<A>,
+    {
+        for (i, axis) in axes.iter().enumerate() {
+            assert!(
+                axis.index() < self.ndim(),
+                "build_pyramid: axis {} is out of bounds for an array with {} axes",
+                axis.index(),
+                self.ndim()
+            );
+            assert!(
+                !axes[..i].contains(axis),
+                "build_pyramid: axis {} is repeated",
+                axis.index()
+            );
+        }
+        let mut pyramid: Vec<Array<B, D>> = Vec::with_capacity(levels);
+        for _ in 0..levels {
+            let level = match pyramid.last() {
+                Some(prev) => downsample(prev.view(), axes, aggregate),
+                None => downsample(self.view(), axes, aggregate),
+            };
+            pyramid.push(level);
+        }
+        pyramid
+    }
+}
+
+/// Return the array of the aggregates of the blocks of two elements along
+/// each of `axes` of `view`, in the element type `B`.
+fn downsample<A, B, D>(view: ArrayView<'_, A, D>, axes: &[Axis], aggregate: Aggregate) -> Array<B, D>
+where
+    A: Clone,
+    B: Clone + PartialOrd + FromPrimitive + Add<Output = B> + Div<Output = B> + From<A>,
+    D: Dimension,
+{
+    // The elements at the same position in each block: bit `i` of `offsets`
+    // is the offset along `axes[i]`
+    let block_elements = |offsets: usize| {
+        let mut part = view.clone();
+        for (i, &axis) in axes.iter().enumerate() {
+            part.slice_axis_inplace(axis, Slice::new(((offsets >> i) & 1) as isize, None, 2));
+        }
+        part
+    };
+    let mut out = block_elements(0).map(|x| B::from(x.clone()));
+    if out.is_empty() {
+        return out;
+    }
+    for offsets in 1..1 << axes.len() {
+        let part = block_elements(offsets);
+        // The last blocks along axes of odd length are missing this part
+        let mut out = out.slice_each_axis_mut(|ax| Slice::from(..part.len_of(ax.axis)));
+        Zip::from(&mut out)
+            .and(&part)
+            .for_each(|acc, x| aggregate.combine(acc, x));
+    }
+
+    if aggregate == Aggregate::Mean {
+        // Divide each region of blocks with the same number of elements by
+        // that number. Bit `i` of `edges` selects the last, partial blocks
+        // along the `i`-th axis of odd length, instead of the full blocks.
+        let odd_axes: Vec<Axis> = axes
+            .iter()
+            .copied()
+            .filter(|&axis| view.len_of(axis) % 2 == 1)
+            .collect();
+        for edges in 0..1usize << odd_axes.len() {
+            let mut region = out.view_mut();
+            for (i, &axis) in odd_axes.iter().enumerate() {
+                let slice = if (edges >> i) & 1 == 1 { Slice::from(-1..) } else { Slice::from(..-1) };
+                region.slice_axis_inplace(axis, slice);
+            }
+            let count = B::from_usize(1 << (axes.len() - edges.count_ones() as usize))
+                .expect("Converting block size to `A` must not fail.");
+            region.mapv_inplace(|sum| sum / count.clone());
+        }
+    }
+    out
+}
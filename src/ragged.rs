@@ -0,0 +1,265 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::slice;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+
+/// A sequence of one-dimensional rows of different lengths, stored
+/// contiguously.
+///
+/// The elements of all rows are stored in one vector, `values`, and row `i`
+/// is `values[offsets[i]..offsets[i + 1]]`. This is the usual storage of
+/// variable-length sequences, like sentences of tokens or time series of
+/// different durations: the elements can be processed all at once through
+/// [`.values()`](RaggedArray::values), and the rows are array views.
+///
+/// Use [`.to_dense()`](RaggedArray::to_dense) to pad the rows to a matrix.
+///
+/// ```
+/// use ndarray::{array, RaggedArray};
+///
+/// let mut ragged = RaggedArray::from_rows(vec![vec![1, 2, 3], vec![4]]);
+/// ragged.push_row(vec![5, 6]);
+/// assert_eq!(ragged.nrows(), 3);
+/// assert_eq!(ragged.row(2), array![5, 6]);
+/// assert_eq!(ragged.rows().map(|row| row.sum()).collect::<Vec<_>>(), vec![6, 4, 11]);
+///
+/// let (dense, mask) = ragged.to_dense(0);
+/// assert_eq!(dense, array![[1, 2, 3], [4, 0, 0], [5, 6, 0]]);
+/// assert_eq!(mask, array![[true, true, true], [true, false, false], [true, true, false]]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RaggedArray<A> {
+    values: Vec<A>,
+    /// The start of each row and the end of the last row
+    offsets: Vec<usize>,
+}
+
+impl<A> RaggedArray<A> {
+    /// Create a ragged array without rows.
+    pub fn new() -> Self {
+        RaggedArray {
+            values: Vec::new(),
+            offsets: vec![0],
+        }
+    }
+
+    /// Create a ragged array from the elements of each row.
+    pub fn from_rows<I, R>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = A>,
+    {
+        let mut ragged = Self::new();
+        for row in rows {
+            ragged.push_row(row);
+        }
+        ragged
+    }
+
+    /// Create a ragged array from the elements of all rows and the offsets
+    /// of the rows, where row `i` is `values[offsets[i]..offsets[i + 1]]`.
+    ///
+    /// ***Errors*** if `offsets` is empty, doesn't start with zero, is
+    /// decreasing anywhere or doesn't end with `values.len()`.
+    pub fn from_parts(values: Vec<A>, offsets: Vec<usize>) -> Result<Self, ShapeError> {
+        let valid = offsets.first() == Some(&0)
+            && offsets.last() == Some(&values.len())
+            && offsets.windows(2).all(|w| w[0] <= w[1]);
+        if !valid {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        Ok(RaggedArray { values, offsets })
+    }
+
+    /// Return the elements of all rows and the offsets of the rows; see
+    /// [`from_parts`](RaggedArray::from_parts).
+    pub fn into_parts(self) -> (Vec<A>, Vec<usize>) {
+        (self.values, self.offsets)
+    }
+
+    /// Append a row at the end.
+    pub fn push_row<I>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        self.values.extend(row);
+        self.offsets.push(self.values.len());
+    }
+
+    /// Return the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Return the number of elements in all rows.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Return `true` if there are no elements in any row.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Return the length of the longest row, or zero if there are no rows.
+    pub fn max_row_len(&self) -> usize {
+        self.offsets
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the length of row `index`.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn row_len(&self, index: usize) -> usize {
+        self.offsets[index + 1] - self.offsets[index]
+    }
+
+    /// Return a view of row `index`.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn row(&self, index: usize) -> ArrayView1<'_, A> {
+        ArrayView1::from(&self.values[self.offsets[index]..self.offsets[index + 1]])
+    }
+
+    /// Return a mutable view of row `index`.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn row_mut(&mut self, index: usize) -> ArrayViewMut1<'_, A> {
+        ArrayViewMut1::from(&mut self.values[self.offsets[index]..self.offsets[index + 1]])
+    }
+
+    /// Return an iterator of views of the rows.
+    pub fn rows(&self) -> RaggedRows<'_, A> {
+        RaggedRows {
+            values: &self.values,
+            offsets: self.offsets.windows(2),
+        }
+    }
+
+    /// Return a view of the elements of all rows, one row after another.
+    pub fn values(&self) -> ArrayView1<'_, A> {
+        ArrayView1::from(&self.values)
+    }
+
+    /// Return a mutable view of the elements of all rows, one row after
+    /// another.
+    pub fn values_mut(&mut self) -> ArrayViewMut1<'_, A> {
+        ArrayViewMut1::from(&mut self.values)
+    }
+
+    /// Return the offsets of the rows, where row `i` is
+    /// `values[offsets[i]..offsets[i + 1]]`.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Return a ragged array with the same row lengths, where the elements
+    /// are the results of `f` applied to each element.
+    pub fn map<B, F>(&self, f: F) -> RaggedArray<B>
+    where
+        F: FnMut(&A) -> B,
+    {
+        RaggedArray {
+            values: self.values.iter().map(f).collect(),
+            offsets: self.offsets.clone(),
+        }
+    }
+
+    /// Return the rows padded with `fill` to the length of the longest row,
+    /// as a matrix, and a mask that is `true` for the elements that are in
+    /// the rows.
+    pub fn to_dense(&self, fill: A) -> (Array2<A>, Array2<bool>)
+    where
+        A: Clone,
+    {
+        let shape = (self.nrows(), self.max_row_len());
+        let mut dense = Array2::from_elem(shape, fill);
+        let mut mask = Array2::from_elem(shape, false);
+        for (i, row) in self.rows().enumerate() {
+            let len = row.len();
+            dense.row_mut(i).slice_mut(s![..len]).assign(&row);
+            mask.row_mut(i).slice_mut(s![..len]).fill(true);
+        }
+        (dense, mask)
+    }
+
+    /// Create a ragged array from the first `lengths[i]` elements of each
+    /// row `i` of a padded matrix.
+    ///
+    /// **Panics** if the length of `lengths` is not the number of rows, or
+    /// if any of the lengths is larger than the number of columns.
+    pub fn from_dense<S>(dense: &ArrayBase<S, Ix2>, lengths: &[usize]) -> Self
+    where
+        A: Clone,
+        S: Data<Elem = A>,
+    {
+        assert_eq!(
+            lengths.len(),
+            dense.nrows(),
+            "RaggedArray::from_dense: expected {} lengths, got {}",
+            dense.nrows(),
+            lengths.len()
+        );
+        Self::from_rows(
+            dense
+                .rows()
+                .into_iter()
+                .zip(lengths)
+                .map(|(row, &len)| row.slice_move(s![..len]).into_iter().cloned()),
+        )
+    }
+}
+
+impl<A> Default for RaggedArray<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator of views of the rows of a [`RaggedArray`].
+///
+/// Created by [`RaggedArray::rows`].
+#[derive(Clone, Debug)]
+pub struct RaggedRows<'a, A> {
+    values: &'a [A],
+    offsets: slice::Windows<'a, usize>,
+}
+
+impl<'a, A> Iterator for RaggedRows<'a, A> {
+    type Item = ArrayView1<'a, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.values;
+        self.offsets
+            .next()
+            .map(|w| ArrayView1::from(&values[w[0]..w[1]]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<'a, A> DoubleEndedIterator for RaggedRows<'a, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let values = self.values;
+        self.offsets
+            .next_back()
+            .map(|w| ArrayView1::from(&values[w[0]..w[1]]))
+    }
+}
+
+impl<'a, A> ExactSizeIterator for RaggedRows<'a, A> {}
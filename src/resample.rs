@@ -0,0 +1,267 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use num_traits::{Float, FromPrimitive};
+use num_traits::{Num, NumCast, ToPrimitive};
+
+use crate::imp_prelude::*;
+use crate::Slice;
+
+/// How to divide the timestamps into bins, in
+/// [`.resample_axis()`](ArrayBase::resample_axis).
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BinSpec<T> {
+    /// Bins of the given width, starting at the first timestamp: bin `i`
+    /// is `[t₀ + i·width, t₀ + (i + 1)·width)`.
+    Width(T),
+    /// Bins between consecutive edges: bin `i` is
+    /// `[edges[i], edges[i + 1])`. Samples outside of all bins are dropped.
+    Edges(Vec<T>),
+}
+
+/// How to aggregate the samples in a bin, in
+/// [`.resample_axis()`](ArrayBase::resample_axis).
+///
+/// Empty bins are NaN, except for `Sum` and `Count`, which are zero.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Reducer {
+    /// The mean of the samples.
+    Mean,
+    /// The sum of the samples.
+    Sum,
+    /// The smallest sample.
+    Min,
+    /// The largest sample.
+    Max,
+    /// The first sample.
+    First,
+    /// The last sample.
+    Last,
+    /// The number of samples.
+    Count,
+}
+
+#[cfg(feature = "std")]
+impl Reducer {
+    fn reduce<A, D>(self, samples: ArrayView<'_, A, D>, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let n = samples.len_of(axis);
+        if n == 0 {
+            let empty = match self {
+                Reducer::Sum | Reducer::Count => A::zero(),
+                _ => A::nan(),
+            };
+            return Array::from_elem(samples.raw_dim().remove_axis(axis), empty);
+        }
+        match self {
+            Reducer::Mean => samples.mean_axis(axis).unwrap(),
+            Reducer::Sum => samples.sum_axis(axis),
+            Reducer::Min => samples.fold_axis(axis, A::infinity(), |acc, &x| acc.min(x)),
+            Reducer::Max => samples.fold_axis(axis, A::neg_infinity(), |acc, &x| acc.max(x)),
+            Reducer::First => samples.index_axis(axis, 0).to_owned(),
+            Reducer::Last => samples.index_axis(axis, n - 1).to_owned(),
+            Reducer::Count => Array::from_elem(
+                samples.raw_dim().remove_axis(axis),
+                A::from_usize(n).expect("Converting count to `A` must not fail."),
+            ),
+        }
+    }
+}
+
+/// Return the start of each bin and the range of samples in it, for sorted
+/// `timestamps`.
+fn bin_ranges<T>(timestamps: &[T], bins: &BinSpec<T>) -> (Vec<T>, Vec<(usize, usize)>)
+where
+    T: Copy + PartialOrd + Num + NumCast + ToPrimitive,
+{
+    let mut starts = Vec::new();
+    let mut ranges = Vec::new();
+    match *bins {
+        BinSpec::Width(width) => {
+            assert!(
+                width > T::zero(),
+                "resample_axis: the bin width must be positive"
+            );
+            let origin = match timestamps.first() {
+                Some(&t) => t,
+                None => return (starts, ranges),
+            };
+            let mut start = 0;
+            while start < timestamps.len() {
+                let bin = ((timestamps[start] - origin) / width)
+                    .to_usize()
+                    .expect("resample_axis: too many bins");
+                // Add the empty bins before this one
+                while starts.len() < bin {
+                    ranges.push((start, start));
+                    starts.push(origin + width * bin_index::<T>(starts.len()));
+                }
+                let bin_end = origin + width * bin_index::<T>(bin + 1);
+                let mut end = start + 1;
+                while end < timestamps.len() && timestamps[end] < bin_end {
+                    end += 1;
+                }
+                ranges.push((start, end));
+                starts.push(origin + width * bin_index::<T>(bin));
+                start = end;
+            }
+        }
+        BinSpec::Edges(ref edges) => {
+            assert!(
+                edges.len() >= 2 && edges.windows(2).all(|w| w[0] < w[1]),
+                "resample_axis: the bin edges must be increasing, with at least two edges"
+            );
+            let position = |edge: T| timestamps.iter().take_while(|&&t| t < edge).count();
+            let mut start = position(edges[0]);
+            for w in edges.windows(2) {
+                let end = start
+                    + timestamps[start..]
+                        .iter()
+                        .take_while(|&&t| t < w[1])
+                        .count();
+                ranges.push((start, end));
+                starts.push(w[0]);
+                start = end;
+            }
+        }
+    }
+    (starts, ranges)
+}
+
+fn bin_index<T: NumCast>(i: usize) -> T {
+    T::from(i).expect("resample_axis: too many bins")
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    /// Aggregate the samples along `axis` into time bins, using `reducer`.
+    ///
+    /// `timestamps` has the time of each sample along `axis`, in ascending
+    /// order. The timestamps can be any numeric type, for example seconds
+    /// as `f64` or nanoseconds since an epoch as `i64`. `bins` divides the
+    /// time into bins; see [`BinSpec`].
+    ///
+    /// Return the start time of each bin, and the array with the aggregated
+    /// samples, which has one element along `axis` for each bin.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `timestamps`
+    /// is not the length of `axis`, if the timestamps are not sorted, or if
+    /// the bin width is not positive or the bin edges are not increasing.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, BinSpec, Reducer};
+    ///
+    /// // Two channels, sampled at irregular times in seconds
+    /// let times = array![0., 0.4, 1.1, 1.5, 1.9, 3.2];
+    /// let samples = array![[1f64, 2., 3., 4., 5., 6.], [0., 0., 1., 1., 1., 0.]];
+    ///
+    /// let (starts, means) = samples.resample_axis(Axis(1), &times, BinSpec::Width(1.), Reducer::Mean);
+    /// assert_eq!(starts, array![0., 1., 2., 3.]);
+    /// assert!(means[[0, 2]].is_nan()); // No samples in [2, 3)
+    /// assert_eq!(means.column(1), array![4., 1.]);
+    ///
+    /// let edges = BinSpec::Edges(vec![1., 2., 4.]);
+    /// let (_, counts) = samples.resample_axis(Axis(1), &times, edges, Reducer::Count);
+    /// assert_eq!(counts, array![[3., 1.], [3., 1.]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn resample_axis<T, S2>(
+        &self,
+        axis: Axis,
+        timestamps: &ArrayBase<S2, Ix1>,
+        bins: BinSpec<T>,
+        reducer: Reducer,
+    ) -> (Array1<T>, Array<A, D>)
+    where
+        A: Float + FromPrimitive,
+        T: Copy + PartialOrd + Num + NumCast + ToPrimitive,
+        S2: Data<Elem = T>,
+    {
+        self.resample_axis_with(axis, timestamps, bins, |samples| {
+            reducer.reduce(samples, axis)
+        })
+    }
+
+    /// Aggregate the samples along `axis` into time bins, using `reduce`.
+    ///
+    /// This is like [`.resample_axis()`](ArrayBase::resample_axis), but
+    /// `reduce` is called with a view of the samples of each bin, which may
+    /// be empty, and returns the aggregate, with `axis` removed.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `timestamps`
+    /// is not the length of `axis`, if the timestamps are not sorted, if
+    /// the bin width is not positive or the bin edges are not increasing, or
+    /// if `reduce` returns an array of the wrong shape.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, BinSpec};
+    ///
+    /// // Nanosecond timestamps, binned by the microsecond
+    /// let times = array![0i64, 300, 900, 1200, 3000];
+    /// let samples = array![5, 1, 3, 2, 7];
+    /// let (starts, ranges) = samples.resample_axis_with(Axis(0), &times, BinSpec::Width(1000), |bin| {
+    ///     let max = bin.iter().max().copied().unwrap_or(0);
+    ///     let min = bin.iter().min().copied().unwrap_or(0);
+    ///     ndarray::arr0(max - min)
+    /// });
+    /// assert_eq!(starts, array![0, 1000, 2000, 3000]);
+    /// assert_eq!(ranges, array![4, 0, 0, 0]);
+    /// ```
+    pub fn resample_axis_with<T, S2, B, F>(
+        &self,
+        axis: Axis,
+        timestamps: &ArrayBase<S2, Ix1>,
+        bins: BinSpec<T>,
+        mut reduce: F,
+    ) -> (Array1<T>, Array<B, D>)
+    where
+        T: Copy + PartialOrd + Num + NumCast + ToPrimitive,
+        S2: Data<Elem = T>,
+        B: Clone,
+        F: FnMut(ArrayView<'_, A, D>) -> Array<B, D::Smaller>,
+    {
+        assert_eq!(
+            timestamps.len(),
+            self.len_of(axis),
+            "resample_axis: expected {} timestamps, got {}",
+            self.len_of(axis),
+            timestamps.len()
+        );
+        let timestamps = timestamps.to_vec();
+        assert!(
+            timestamps.windows(2).all(|w| w[0] <= w[1]),
+            "resample_axis: the timestamps must be sorted"
+        );
+        let (starts, ranges) = bin_ranges(&timestamps, &bins);
+
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = ranges.len();
+        let mut out = Array::uninit(dim);
+        for (i, &(start, end)) in ranges.iter().enumerate() {
+            reduce(self.slice_axis(axis, Slice::from(start..end)))
+                .assign_to(out.index_axis_mut(axis, i));
+        }
+        let out = unsafe {
+            // Safe because every element was assigned above
+            out.assume_init()
+        };
+        (Array1::from(starts), out)
+    }
+}
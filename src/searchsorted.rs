@@ -0,0 +1,143 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Which insertion index to return for values equal to elements of the
+/// sorted array, in [`.searchsorted()`](ArrayBase::searchsorted).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSide {
+    /// The index of the first equal element, so that the value is inserted
+    /// before all equal elements.
+    Left,
+    /// The index after the last equal element, so that the value is
+    /// inserted after all equal elements.
+    Right,
+}
+
+/// Return the insertion index of `value` in the sorted `lane`.
+fn search_lane<A: PartialOrd>(lane: &ArrayView1<'_, A>, value: &A, side: SearchSide) -> usize {
+    let mut lo = 0;
+    let mut hi = lane.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let elt = &lane[mid];
+        let before = match side {
+            SearchSide::Left => elt < value,
+            SearchSide::Right => elt <= value,
+        };
+        if before {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+impl<A, S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the indices where `values` would be inserted into the array
+    /// to keep it sorted.
+    ///
+    /// The array must be sorted in ascending order. For each value, the
+    /// index `i` is such that all elements before `i` are less than the
+    /// value (less than or equal to for `SearchSide::Right`), and all
+    /// elements from `i` on are not. The result has the shape of `values`.
+    ///
+    /// The result is unspecified, but the call doesn't panic, if the array
+    /// isn't sorted or contains elements that don't compare, like NaN.
+    ///
+    /// ```
+    /// use ndarray::{array, SearchSide};
+    ///
+    /// let bins = array![0., 1., 2., 5.];
+    /// let samples = array![[-1., 0.], [1.5, 7.]];
+    /// assert_eq!(
+    ///     bins.searchsorted(&samples, SearchSide::Right),
+    ///     array![[0, 1], [2, 4]]
+    /// );
+    /// assert_eq!(bins.searchsorted(&array![1., 2.5], SearchSide::Left), array![1, 3]);
+    /// ```
+    pub fn searchsorted<S2, E>(
+        &self,
+        values: &ArrayBase<S2, E>,
+        side: SearchSide,
+    ) -> Array<usize, E>
+    where
+        A: PartialOrd,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let lane = self.view();
+        values.map(|value| search_lane(&lane, value, side))
+    }
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the indices where `values` would be inserted into each lane
+    /// along `axis` to keep it sorted.
+    ///
+    /// Each lane of the array along `axis` must be sorted in ascending
+    /// order; each lane of `values` along `axis` is searched in the
+    /// corresponding lane of the array, as in
+    /// [`.searchsorted()`](ArrayBase::searchsorted). `values` must have the
+    /// same shape as the array except along `axis`, and the result has the
+    /// shape of `values`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the shapes don't match.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, SearchSide};
+    ///
+    /// let sorted = array![[1, 3, 5], [2, 4, 6]];
+    /// let values = array![[3, 6], [0, 3]];
+    /// assert_eq!(
+    ///     sorted.searchsorted_axis(Axis(1), &values, SearchSide::Left),
+    ///     array![[1, 3], [0, 1]]
+    /// );
+    /// ```
+    pub fn searchsorted_axis<S2>(
+        &self,
+        axis: Axis,
+        values: &ArrayBase<S2, D>,
+        side: SearchSide,
+    ) -> Array<usize, D>
+    where
+        A: PartialOrd,
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        let mut lanes_dim = values.raw_dim();
+        lanes_dim[axis.index()] = self.len_of(axis);
+        assert!(
+            lanes_dim == self.raw_dim(),
+            "searchsorted_axis: values of shape {:?} don't match the array of shape {:?} along axis {}",
+            values.shape(),
+            self.shape(),
+            axis.index()
+        );
+        let mut indices = Array::zeros(values.raw_dim());
+        Zip::from(self.lanes(axis))
+            .and(values.lanes(axis))
+            .and(indices.lanes_mut(axis))
+            .for_each(|lane, values, mut indices| {
+                Zip::from(&mut indices)
+                    .and(&values)
+                    .for_each(|index, value| *index = search_lane(&lane, value, side));
+            });
+        indices
+    }
+}
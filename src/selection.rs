@@ -0,0 +1,268 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{NdIndex, ShapeBuilder, SliceArg, Zip};
+
+/// A set of element indices of an array of a given shape.
+///
+/// A selection is built from slices, lists of indices along an axis or of
+/// elements, and boolean masks, and combined with the set operations
+/// [`.union()`](Self::union), [`.intersection()`](Self::intersection),
+/// [`.difference()`](Self::difference) and
+/// [`.complement()`](Self::complement). It can then be applied to any array
+/// of the same shape with [`.select_with()`](ArrayBase::select_with) and
+/// [`.assign_with()`](ArrayBase::assign_with).
+///
+/// ```
+/// use ndarray::{array, s, Axis, Selection};
+///
+/// let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+///
+/// // The first row, and the last column
+/// let row = Selection::from_slice((3, 3), s![0, ..]);
+/// let col = Selection::from_indices((3, 3), Axis(1), &[2]);
+/// let sel = row.union(&col);
+/// assert_eq!(sel.len(), 5);
+/// assert_eq!(a.select_with(&sel), array![1, 2, 3, 6, 9]);
+///
+/// // ... except the even elements
+/// let sel = sel.difference(&Selection::from_mask(&a.mapv(|x| x % 2 == 0)));
+/// assert_eq!(a.select_with(&sel), array![1, 3, 9]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selection<D: Dimension> {
+    mask: Array<bool, D>,
+}
+
+impl<D: Dimension> Selection<D> {
+    /// Create a selection of all the elements of an array of shape `shape`.
+    pub fn all<Sh>(shape: Sh) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        Selection {
+            mask: Array::from_elem(shape, true),
+        }
+    }
+
+    /// Create a selection of no elements of an array of shape `shape`.
+    pub fn none<Sh>(shape: Sh) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        Selection {
+            mask: Array::from_elem(shape, false),
+        }
+    }
+
+    /// Create a selection of the elements where `mask` is `true`.
+    ///
+    /// The selection has the shape of `mask`.
+    pub fn from_mask<S>(mask: &ArrayBase<S, D>) -> Self
+    where
+        S: Data<Elem = bool>,
+    {
+        Selection {
+            mask: mask.to_owned(),
+        }
+    }
+
+    /// Create a selection of the elements of the slice `info` of an array of
+    /// shape `shape`.
+    ///
+    /// **Panics** if the slice is out of bounds, like
+    /// [`.slice()`](ArrayBase::slice).
+    pub fn from_slice<Sh, I>(shape: Sh, info: I) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        I: SliceArg<D>,
+    {
+        let mut sel = Self::none(shape);
+        sel.mask.slice_mut(info).fill(true);
+        sel
+    }
+
+    /// Create a selection of the subviews at `indices` along `axis` of an
+    /// array of shape `shape`.
+    ///
+    /// **Panics** if `axis` or any of the indices is out of bounds.
+    pub fn from_indices<Sh>(shape: Sh, axis: Axis, indices: &[Ix]) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        D: RemoveAxis,
+    {
+        let mut sel = Self::none(shape);
+        for &index in indices {
+            sel.mask.index_axis_mut(axis, index).fill(true);
+        }
+        sel
+    }
+
+    /// Create a selection of the elements at `points` of an array of shape
+    /// `shape`.
+    ///
+    /// **Panics** if any of the points is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Selection};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// let diag = Selection::from_points((2, 2), vec![(0, 0), (1, 1)]);
+    /// assert_eq!(a.select_with(&diag), array![1, 4]);
+    /// ```
+    pub fn from_points<Sh, I, P>(shape: Sh, points: I) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        I: IntoIterator<Item = P>,
+        P: NdIndex<D>,
+    {
+        let mut sel = Self::none(shape);
+        for point in points {
+            match sel.mask.get_mut(point) {
+                Some(m) => *m = true,
+                None => panic!("Selection::from_points: point out of bounds"),
+            }
+        }
+        sel
+    }
+
+    /// Return the shape of the arrays the selection applies to.
+    pub fn shape(&self) -> &[usize] {
+        self.mask.shape()
+    }
+
+    /// Return the number of selected elements.
+    pub fn len(&self) -> usize {
+        self.mask.iter().filter(|&&m| m).count()
+    }
+
+    /// Return `true` if no elements are selected.
+    pub fn is_empty(&self) -> bool {
+        !self.mask.iter().any(|&m| m)
+    }
+
+    /// Return `true` if the element at `index` is selected, and `false` if it
+    /// is not selected or out of bounds.
+    pub fn contains<I>(&self, index: I) -> bool
+    where
+        I: NdIndex<D>,
+    {
+        self.mask.get(index).copied().unwrap_or(false)
+    }
+
+    /// Return a view of the selection as a boolean mask.
+    pub fn mask(&self) -> ArrayView<'_, bool, D> {
+        self.mask.view()
+    }
+
+    /// Return the selection as a boolean mask.
+    pub fn into_mask(self) -> Array<bool, D> {
+        self.mask
+    }
+
+    /// Return the elements selected in `self` or `other`, or both.
+    ///
+    /// **Panics** if the shapes of the selections disagree.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Return the elements selected in both `self` and `other`.
+    ///
+    /// **Panics** if the shapes of the selections disagree.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Return the elements selected in `self` but not in `other`.
+    ///
+    /// **Panics** if the shapes of the selections disagree.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Return the elements that are not selected in `self`.
+    pub fn complement(&self) -> Self {
+        Selection {
+            mask: self.mask.mapv(|m| !m),
+        }
+    }
+
+    fn combine<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(bool, bool) -> bool,
+    {
+        assert_eq!(
+            self.shape(),
+            other.shape(),
+            "Selection: the shapes of the selections disagree"
+        );
+        Selection {
+            mask: Zip::from(&self.mask)
+                .and(&other.mask)
+                .map_collect(|&a, &b| f(a, b)),
+        }
+    }
+}
+
+/// # Selections
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Return a one-dimensional array of the elements in the selection
+    /// `sel`, in logical order.
+    ///
+    /// **Panics** if the shape of the selection is not the shape of `self`.
+    pub fn select_with(&self, sel: &Selection<D>) -> Array<A, Ix1>
+    where
+        S: Data,
+        A: Clone,
+    {
+        assert_eq!(
+            self.shape(),
+            sel.shape(),
+            "select_with: the shape of the selection is not the shape of the array"
+        );
+        self.select_where(&sel.mask)
+    }
+
+    /// Perform an elementwise assigment to `self` from `rhs`, for the
+    /// elements in the selection `sel`.
+    ///
+    /// Return the number of elements that were assigned. If their shapes
+    /// disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if the shape of the selection is not the shape of `self`,
+    /// or if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Selection};
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// let border = Selection::from_slice((2, 3), s![.., 1..2]).complement();
+    /// assert_eq!(a.assign_with(&border, &array![0]), 4);
+    /// assert_eq!(a, array![[0, 2, 0], [0, 5, 0]]);
+    /// ```
+    pub fn assign_with<S2, E>(&mut self, sel: &Selection<D>, rhs: &ArrayBase<S2, E>) -> usize
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        assert_eq!(
+            self.shape(),
+            sel.shape(),
+            "assign_with: the shape of the selection is not the shape of the array"
+        );
+        self.assign_where(&sel.mask, rhs)
+    }
+}
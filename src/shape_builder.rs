@@ -1,6 +1,6 @@
 use crate::dimension::IntoDimension;
 use crate::Dimension;
-use crate::order::Order;
+use crate::order::{self, Order};
 
 /// A contiguous array shape of n dimensions.
 ///
@@ -11,6 +11,8 @@ pub struct Shape<D> {
     pub(crate) dim: D,
     /// Strides can only be C or F here
     pub(crate) strides: Strides<Contiguous>,
+    /// Whether the order is the default, rather than given explicitly
+    pub(crate) default_order: bool,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -20,6 +22,17 @@ impl<D> Shape<D> {
     pub(crate) fn is_c(&self) -> bool {
         matches!(self.strides, Strides::C)
     }
+
+    /// Use the current default memory order (see [`with_default_order`]) if
+    /// no order was given explicitly.
+    ///
+    /// [`with_default_order`]: crate::with_default_order
+    pub(crate) fn with_current_default_order(mut self) -> Self {
+        if self.default_order && order::default_order().is_column_major() {
+            self.strides = Strides::F;
+        }
+        self
+    }
 }
 
 /// An array shape of n dimensions in c-order, f-order or custom strides.
@@ -131,6 +144,7 @@ where
         Shape {
             dim: self.into_dimension(),
             strides: Strides::C,
+            default_order: true,
         }
     }
     fn f(self) -> Shape<Self::Dim> {
@@ -161,6 +175,7 @@ where
 
     fn set_f(mut self, is_f: bool) -> Self {
         self.strides = if !is_f { Strides::C } else { Strides::F };
+        self.default_order = false;
         self
     }
 
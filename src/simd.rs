@@ -0,0 +1,845 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Explicitly vectorized reductions.
+//!
+//! The regular [`.sum()`](ArrayBase::sum) and [`.product()`](ArrayBase::product)
+//! are generic over the element type and rely on the compiler to vectorize
+//! them, which works for sums and products of contiguous arrays but not for
+//! minimum and maximum, NaN-aware reductions or strided arrays. The methods
+//! [`.simd_sum()`](ArrayBase::simd_sum), [`.simd_product()`](ArrayBase::simd_product),
+//! [`.simd_min()`](ArrayBase::simd_min) and [`.simd_max()`](ArrayBase::simd_max)
+//! are implemented with SIMD instructions for the element types `f32`, `f64`
+//! and `i32` (see [`SimdElement`]).
+//!
+//! The array is processed in contiguous pieces: the whole array if it is
+//! contiguous, otherwise its lanes along the axis with the smallest stride,
+//! which are copied to a buffer first if they are not contiguous either.
+//!
+//! On x86-64, the kernels use SSE2 intrinsics, which are always available on
+//! that target. On other targets, they are written with independent
+//! accumulator lanes that the compiler vectorizes.
+//!
+//! Other computations are vectorized with [`Pack`], a fixed number of
+//! elements whose arithmetic uses the same instructions. The closures of
+//! [`.simd_fold()`](ArrayBase::simd_fold) and
+//! [`Zip::simd_for_each`](crate::Zip::simd_for_each) take packs instead of
+//! single elements, so that they are vectorized regardless of the compiler,
+//! like the explicit versions of [`.fold()`](ArrayBase::fold) and
+//! [`Zip::for_each`](crate::Zip::for_each).
+//!
+//! This requires crate feature `simd`.
+//!
+//! ```
+//! use ndarray::Array;
+//!
+//! let a = Array::linspace(-1f32, 1., 1001);
+//! assert_eq!(a.simd_min(), Some(-1.));
+//! assert_eq!(a.simd_max(), Some(1.));
+//! assert!(a.simd_sum().abs() < 1e-4);
+//! ```
+
+use alloc::vec::Vec;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Elements with explicitly vectorized reductions: `f32`, `f64` and `i32`.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+///
+/// This requires crate feature `simd`.
+pub trait SimdElement: Copy + PartialOrd {
+    /// Return `acc` plus the sum of `xs`.
+    #[doc(hidden)]
+    fn sum_slice(acc: Self, xs: &[Self]) -> Self;
+    /// Return `acc` times the product of `xs`.
+    #[doc(hidden)]
+    fn product_slice(acc: Self, xs: &[Self]) -> Self;
+    /// Return the minimum of `xs`, ignoring NaN
+    #[doc(hidden)]
+    fn min_slice(xs: &[Self]) -> Option<Self>;
+    /// Return the maximum of `xs`, ignoring NaN
+    #[doc(hidden)]
+    fn max_slice(xs: &[Self]) -> Option<Self>;
+    /// Return the lanewise sum of `a` and `b`
+    #[doc(hidden)]
+    fn add_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self>;
+    /// Return the lanewise difference of `a` and `b`
+    #[doc(hidden)]
+    fn sub_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self>;
+    /// Return the lanewise product of `a` and `b`
+    #[doc(hidden)]
+    fn mul_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self>;
+    /// Return the lanewise quotient of `a` and `b`
+    #[doc(hidden)]
+    fn div_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self>;
+    /// Return the lanewise `min_op(a, b)`
+    #[doc(hidden)]
+    fn min_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self>;
+    /// Return the lanewise `max_op(a, b)`
+    #[doc(hidden)]
+    fn max_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self>;
+    /// The identity of `sum_slice`
+    #[doc(hidden)]
+    const ZERO: Self;
+    /// The identity of `product_slice`
+    #[doc(hidden)]
+    const ONE: Self;
+
+    private_decl! {}
+}
+
+/// Number of independent accumulators in the portable kernels
+const LANES: usize = 8;
+
+/// The number of elements in a [`Pack`].
+pub const PACK_LEN: usize = 8;
+
+/// A pack of [`PACK_LEN`] elements, whose operators and methods work on all
+/// lanes at once with SIMD instructions.
+///
+/// Packs are the arguments of the closures of
+/// [`.simd_fold()`](ArrayBase::simd_fold) and
+/// [`Zip::simd_for_each`](crate::Zip::simd_for_each). The operators `+`, `-`,
+/// `*` and `/` are applied lane by lane; integer operations other than
+/// division wrap around on overflow.
+///
+/// ```
+/// use ndarray::simd::Pack;
+///
+/// let a = Pack([1., 2., 3., 4., 5., 6., 7., 8.]);
+/// let b = a * Pack::splat(2.) - Pack::splat(5.);
+/// assert_eq!(b.0, [-3., -1., 1., 3., 5., 7., 9., 11.]);
+/// assert_eq!(a.min(b).0, [-3., -1., 1., 3., 5., 6., 7., 8.]);
+/// ```
+///
+/// This requires crate feature `simd`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pack<A>(pub [A; PACK_LEN]);
+
+impl<A> Pack<A>
+where
+    A: SimdElement,
+{
+    /// Return a pack with `x` in every lane.
+    #[inline]
+    pub fn splat(x: A) -> Self {
+        Pack([x; PACK_LEN])
+    }
+
+    /// Return the lanewise minimum of `self` and `other`; a lane is the one
+    /// of `self` if the one of `other` is NaN.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        A::min_pack(self, other)
+    }
+
+    /// Return the lanewise maximum of `self` and `other`; a lane is the one
+    /// of `self` if the one of `other` is NaN.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        A::max_pack(self, other)
+    }
+
+    /// Return a pack of the first elements of `xs`, which must not be empty;
+    /// if `xs` is shorter than a pack, the lanes after its end repeat its
+    /// last element, so that they are valid operands wherever the elements
+    /// are.
+    #[inline]
+    fn from_slice(xs: &[A]) -> Self {
+        if xs.len() >= PACK_LEN {
+            let mut pack = Pack::splat(xs[0]);
+            pack.0.copy_from_slice(&xs[..PACK_LEN]);
+            pack
+        } else {
+            let mut pack = Pack::splat(xs[xs.len() - 1]);
+            pack.0[..xs.len()].copy_from_slice(xs);
+            pack
+        }
+    }
+}
+
+macro_rules! impl_pack_op {
+    ($trt:ident, $method:ident, $pack:ident) => {
+        impl<A> $trt for Pack<A>
+        where
+            A: SimdElement,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                A::$pack(self, rhs)
+            }
+        }
+    };
+}
+
+impl_pack_op!(Add, add, add_pack);
+impl_pack_op!(Sub, sub, sub_pack);
+impl_pack_op!(Mul, mul, mul_pack);
+impl_pack_op!(Div, div, div_pack);
+
+/// Return the pack of `f` applied to each lane of `a` and `b`.
+#[inline]
+fn map_pack<A, F>(a: Pack<A>, b: Pack<A>, f: F) -> Pack<A>
+where
+    A: Copy,
+    F: Fn(A, A) -> A,
+{
+    let mut out = a;
+    for (x, &y) in out.0.iter_mut().zip(&b.0) {
+        *x = f(*x, y);
+    }
+    out
+}
+
+/// Fold `xs` with `f`, using independent accumulators that can be vectorized.
+#[inline]
+fn lanes_fold<A, F>(xs: &[A], init: A, f: F) -> A
+where
+    A: Copy,
+    F: Fn(A, A) -> A,
+{
+    let mut acc = [init; LANES];
+    let mut chunks = xs.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        for (a, &x) in acc.iter_mut().zip(chunk) {
+            *a = f(*a, x);
+        }
+    }
+    let mut res = init;
+    for &a in &acc {
+        res = f(res, a);
+    }
+    for &x in chunks.remainder() {
+        res = f(res, x);
+    }
+    res
+}
+
+/// Return the smaller of `acc` and `x`, or `acc` if `x` is NaN.
+#[inline]
+fn min_op<A: PartialOrd>(acc: A, x: A) -> A {
+    if x < acc { x } else { acc }
+}
+
+/// Return the larger of `acc` and `x`, or `acc` if `x` is NaN.
+#[inline]
+fn max_op<A: PartialOrd>(acc: A, x: A) -> A {
+    if x > acc { x } else { acc }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sse2 {
+    use core::arch::x86_64::*;
+
+    use super::{Pack, PACK_LEN};
+
+    /// Define a function that folds a slice with an SSE2 operation.
+    ///
+    /// The slice is processed with four vector accumulators initialized to
+    /// `$init`; these are combined and the remainder is folded with `$scalar`.
+    macro_rules! sse_fold {
+        ($name:ident, $elem:ty, $vec:ty, $width:expr, $set1:ident, $loadu:ident,
+         $storeu:ident, $op:ident, $scalar:expr) => {
+            #[inline]
+            pub(super) fn $name(xs: &[$elem], init: $elem) -> $elem {
+                const CHUNK: usize = 4 * $width;
+                let scalar = $scalar;
+                let mut chunks = xs.chunks_exact(CHUNK);
+                let mut lanes = [init; $width];
+                // Safe because SSE2 is always available on x86-64, and every
+                // load is within a chunk of length CHUNK
+                unsafe {
+                    let mut a0: $vec = $set1(init);
+                    let (mut a1, mut a2, mut a3) = (a0, a0, a0);
+                    for chunk in &mut chunks {
+                        let p = chunk.as_ptr();
+                        // The operand order matters for min and max: the
+                        // result is the second operand if the first is NaN
+                        a0 = $op($loadu(p), a0);
+                        a1 = $op($loadu(p.add($width)), a1);
+                        a2 = $op($loadu(p.add(2 * $width)), a2);
+                        a3 = $op($loadu(p.add(3 * $width)), a3);
+                    }
+                    let acc = $op($op(a0, a1), $op(a2, a3));
+                    $storeu(lanes.as_mut_ptr(), acc);
+                }
+                let mut res = init;
+                for &x in &lanes {
+                    res = scalar(res, x);
+                }
+                for &x in chunks.remainder() {
+                    res = scalar(res, x);
+                }
+                res
+            }
+        };
+    }
+
+    /// Define a function that combines two packs with an SSE2 operation,
+    /// `$width` lanes at a time.
+    macro_rules! sse_map {
+        ($name:ident, $elem:ty, $width:expr, $loadu:ident, $storeu:ident, $op:ident) => {
+            #[inline]
+            pub(super) fn $name(a: Pack<$elem>, b: Pack<$elem>) -> Pack<$elem> {
+                let mut out = a;
+                // Safe because SSE2 is always available on x86-64, and every
+                // load and store is within the packs
+                unsafe {
+                    let mut i = 0;
+                    while i < PACK_LEN {
+                        let x = $op($loadu(a.0.as_ptr().add(i)), $loadu(b.0.as_ptr().add(i)));
+                        $storeu(out.0.as_mut_ptr().add(i), x);
+                        i += $width;
+                    }
+                }
+                out
+            }
+        };
+    }
+
+    sse_fold!(sum_f32, f32, __m128, 4, _mm_set1_ps, _mm_loadu_ps, _mm_storeu_ps,
+              _mm_add_ps, |a: f32, b| a + b);
+    sse_fold!(product_f32, f32, __m128, 4, _mm_set1_ps, _mm_loadu_ps, _mm_storeu_ps,
+              _mm_mul_ps, |a: f32, b| a * b);
+    sse_fold!(min_f32, f32, __m128, 4, _mm_set1_ps, _mm_loadu_ps, _mm_storeu_ps,
+              _mm_min_ps, super::min_op);
+    sse_fold!(max_f32, f32, __m128, 4, _mm_set1_ps, _mm_loadu_ps, _mm_storeu_ps,
+              _mm_max_ps, super::max_op);
+    sse_fold!(sum_f64, f64, __m128d, 2, _mm_set1_pd, _mm_loadu_pd, _mm_storeu_pd,
+              _mm_add_pd, |a: f64, b| a + b);
+    sse_fold!(product_f64, f64, __m128d, 2, _mm_set1_pd, _mm_loadu_pd, _mm_storeu_pd,
+              _mm_mul_pd, |a: f64, b| a * b);
+    sse_fold!(min_f64, f64, __m128d, 2, _mm_set1_pd, _mm_loadu_pd, _mm_storeu_pd,
+              _mm_min_pd, super::min_op);
+    sse_fold!(max_f64, f64, __m128d, 2, _mm_set1_pd, _mm_loadu_pd, _mm_storeu_pd,
+              _mm_max_pd, super::max_op);
+
+    #[inline]
+    unsafe fn set1_epi32(x: i32) -> __m128i {
+        _mm_set1_epi32(x)
+    }
+
+    #[inline]
+    unsafe fn loadu_epi32(p: *const i32) -> __m128i {
+        _mm_loadu_si128(p as *const __m128i)
+    }
+
+    #[inline]
+    unsafe fn storeu_epi32(p: *mut i32, x: __m128i) {
+        _mm_storeu_si128(p as *mut __m128i, x)
+    }
+
+    sse_fold!(sum_i32, i32, __m128i, 4, set1_epi32, loadu_epi32, storeu_epi32,
+              _mm_add_epi32, i32::wrapping_add);
+
+    // The operand order matters for min and max, see sse_fold; the pack
+    // functions take the accumulator first, so these are called with the
+    // operands swapped
+    sse_map!(add_pack_f32, f32, 4, _mm_loadu_ps, _mm_storeu_ps, _mm_add_ps);
+    sse_map!(sub_pack_f32, f32, 4, _mm_loadu_ps, _mm_storeu_ps, _mm_sub_ps);
+    sse_map!(mul_pack_f32, f32, 4, _mm_loadu_ps, _mm_storeu_ps, _mm_mul_ps);
+    sse_map!(div_pack_f32, f32, 4, _mm_loadu_ps, _mm_storeu_ps, _mm_div_ps);
+    sse_map!(min_pack_f32, f32, 4, _mm_loadu_ps, _mm_storeu_ps, _mm_min_ps);
+    sse_map!(max_pack_f32, f32, 4, _mm_loadu_ps, _mm_storeu_ps, _mm_max_ps);
+    sse_map!(add_pack_f64, f64, 2, _mm_loadu_pd, _mm_storeu_pd, _mm_add_pd);
+    sse_map!(sub_pack_f64, f64, 2, _mm_loadu_pd, _mm_storeu_pd, _mm_sub_pd);
+    sse_map!(mul_pack_f64, f64, 2, _mm_loadu_pd, _mm_storeu_pd, _mm_mul_pd);
+    sse_map!(div_pack_f64, f64, 2, _mm_loadu_pd, _mm_storeu_pd, _mm_div_pd);
+    sse_map!(min_pack_f64, f64, 2, _mm_loadu_pd, _mm_storeu_pd, _mm_min_pd);
+    sse_map!(max_pack_f64, f64, 2, _mm_loadu_pd, _mm_storeu_pd, _mm_max_pd);
+    sse_map!(add_pack_i32, i32, 4, loadu_epi32, storeu_epi32, _mm_add_epi32);
+    sse_map!(sub_pack_i32, i32, 4, loadu_epi32, storeu_epi32, _mm_sub_epi32);
+}
+
+macro_rules! impl_simd_float {
+    ($elem:ty, $sum:ident, $product:ident, $min:ident, $max:ident,
+     $add_pack:ident, $sub_pack:ident, $mul_pack:ident, $div_pack:ident,
+     $min_pack:ident, $max_pack:ident) => {
+        impl SimdElement for $elem {
+            fn sum_slice(acc: Self, xs: &[Self]) -> Self {
+                #[cfg(target_arch = "x86_64")]
+                let sum = sse2::$sum(xs, 0.);
+                #[cfg(not(target_arch = "x86_64"))]
+                let sum = lanes_fold(xs, 0., |a, b| a + b);
+                acc + sum
+            }
+
+            fn product_slice(acc: Self, xs: &[Self]) -> Self {
+                #[cfg(target_arch = "x86_64")]
+                let product = sse2::$product(xs, 1.);
+                #[cfg(not(target_arch = "x86_64"))]
+                let product = lanes_fold(xs, 1., |a, b| a * b);
+                acc * product
+            }
+
+            fn min_slice(xs: &[Self]) -> Option<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let min = sse2::$min(xs, <$elem>::INFINITY);
+                #[cfg(not(target_arch = "x86_64"))]
+                let min = lanes_fold(xs, <$elem>::INFINITY, min_op);
+                // The initial value is returned if all elements are NaN
+                if min == <$elem>::INFINITY && !xs.contains(&min) {
+                    None
+                } else {
+                    Some(min)
+                }
+            }
+
+            fn max_slice(xs: &[Self]) -> Option<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let max = sse2::$max(xs, <$elem>::NEG_INFINITY);
+                #[cfg(not(target_arch = "x86_64"))]
+                let max = lanes_fold(xs, <$elem>::NEG_INFINITY, max_op);
+                if max == <$elem>::NEG_INFINITY && !xs.contains(&max) {
+                    None
+                } else {
+                    Some(max)
+                }
+            }
+
+            #[inline]
+            fn add_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let pack = sse2::$add_pack(a, b);
+                #[cfg(not(target_arch = "x86_64"))]
+                let pack = map_pack(a, b, |x, y| x + y);
+                pack
+            }
+
+            #[inline]
+            fn sub_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let pack = sse2::$sub_pack(a, b);
+                #[cfg(not(target_arch = "x86_64"))]
+                let pack = map_pack(a, b, |x, y| x - y);
+                pack
+            }
+
+            #[inline]
+            fn mul_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let pack = sse2::$mul_pack(a, b);
+                #[cfg(not(target_arch = "x86_64"))]
+                let pack = map_pack(a, b, |x, y| x * y);
+                pack
+            }
+
+            #[inline]
+            fn div_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let pack = sse2::$div_pack(a, b);
+                #[cfg(not(target_arch = "x86_64"))]
+                let pack = map_pack(a, b, |x, y| x / y);
+                pack
+            }
+
+            #[inline]
+            fn min_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let pack = sse2::$min_pack(b, a);
+                #[cfg(not(target_arch = "x86_64"))]
+                let pack = map_pack(a, b, min_op);
+                pack
+            }
+
+            #[inline]
+            fn max_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+                #[cfg(target_arch = "x86_64")]
+                let pack = sse2::$max_pack(b, a);
+                #[cfg(not(target_arch = "x86_64"))]
+                let pack = map_pack(a, b, max_op);
+                pack
+            }
+
+            const ZERO: Self = 0.;
+            const ONE: Self = 1.;
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_simd_float!(f32, sum_f32, product_f32, min_f32, max_f32,
+                 add_pack_f32, sub_pack_f32, mul_pack_f32, div_pack_f32, min_pack_f32, max_pack_f32);
+impl_simd_float!(f64, sum_f64, product_f64, min_f64, max_f64,
+                 add_pack_f64, sub_pack_f64, mul_pack_f64, div_pack_f64, min_pack_f64, max_pack_f64);
+
+impl SimdElement for i32 {
+    fn sum_slice(acc: Self, xs: &[Self]) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        let sum = sse2::sum_i32(xs, 0);
+        #[cfg(not(target_arch = "x86_64"))]
+        let sum = lanes_fold(xs, 0, i32::wrapping_add);
+        acc.wrapping_add(sum)
+    }
+
+    fn product_slice(acc: Self, xs: &[Self]) -> Self {
+        // SSE2 has no 32-bit multiplication; this vectorizes well anyway
+        acc.wrapping_mul(lanes_fold(xs, 1, i32::wrapping_mul))
+    }
+
+    fn min_slice(xs: &[Self]) -> Option<Self> {
+        if xs.is_empty() {
+            None
+        } else {
+            Some(lanes_fold(xs, i32::MAX, min_op))
+        }
+    }
+
+    fn max_slice(xs: &[Self]) -> Option<Self> {
+        if xs.is_empty() {
+            None
+        } else {
+            Some(lanes_fold(xs, i32::MIN, max_op))
+        }
+    }
+
+    #[inline]
+    fn add_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+        #[cfg(target_arch = "x86_64")]
+        let pack = sse2::add_pack_i32(a, b);
+        #[cfg(not(target_arch = "x86_64"))]
+        let pack = map_pack(a, b, i32::wrapping_add);
+        pack
+    }
+
+    #[inline]
+    fn sub_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+        #[cfg(target_arch = "x86_64")]
+        let pack = sse2::sub_pack_i32(a, b);
+        #[cfg(not(target_arch = "x86_64"))]
+        let pack = map_pack(a, b, i32::wrapping_sub);
+        pack
+    }
+
+    // SSE2 has no 32-bit multiplication, division, minimum or maximum;
+    // these vectorize well anyway, except division
+
+    #[inline]
+    fn mul_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+        map_pack(a, b, i32::wrapping_mul)
+    }
+
+    #[inline]
+    fn div_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+        map_pack(a, b, |x, y| x / y)
+    }
+
+    #[inline]
+    fn min_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+        map_pack(a, b, min_op)
+    }
+
+    #[inline]
+    fn max_pack(a: Pack<Self>, b: Pack<Self>) -> Pack<Self> {
+        map_pack(a, b, max_op)
+    }
+
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    private_impl! {}
+}
+
+/// # Vectorized Reductions
+///
+/// These methods require crate feature `simd`.
+/// See also the [`simd`](crate::simd) module.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: SimdElement,
+{
+    /// Return the sum of all elements in the array.
+    ///
+    /// The summation order differs from [`.sum()`](Self::sum), so floating
+    /// point results can differ slightly. Integer sums wrap around on
+    /// overflow.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.simd_sum(), 10.);
+    /// assert_eq!(a.t().simd_sum(), 10.);
+    /// ```
+    pub fn simd_sum(&self) -> A {
+        let mut sum = A::ZERO;
+        self.for_each_slice(|xs| sum = A::sum_slice(sum, xs));
+        sum
+    }
+
+    /// Return the product of all elements in the array.
+    ///
+    /// The multiplication order differs from [`.product()`](Self::product),
+    /// so floating point results can differ slightly. Integer products wrap
+    /// around on overflow.
+    pub fn simd_product(&self) -> A {
+        let mut product = A::ONE;
+        self.for_each_slice(|xs| product = A::product_slice(product, xs));
+        product
+    }
+
+    /// Return the smallest element of the array, ignoring NaN.
+    ///
+    /// Return `None` if the array is empty or if all elements are NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3., f64::NAN], [-1., 2.]];
+    /// assert_eq!(a.simd_min(), Some(-1.));
+    /// assert_eq!(array![f64::NAN].simd_min(), None);
+    /// ```
+    pub fn simd_min(&self) -> Option<A> {
+        let mut min = None;
+        self.for_each_slice(|xs| {
+            if let Some(x) = A::min_slice(xs) {
+                min = Some(min.map_or(x, |m| min_op(m, x)));
+            }
+        });
+        min
+    }
+
+    /// Return the largest element of the array, ignoring NaN.
+    ///
+    /// Return `None` if the array is empty or if all elements are NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 7], [-1, 2]];
+    /// assert_eq!(a.simd_max(), Some(7));
+    /// ```
+    pub fn simd_max(&self) -> Option<A> {
+        let mut max = None;
+        self.for_each_slice(|xs| {
+            if let Some(x) = A::max_slice(xs) {
+                max = Some(max.map_or(x, |m| max_op(m, x)));
+            }
+        });
+        max
+    }
+
+    /// Fold the elements of the array a pack at a time, and return the result.
+    ///
+    /// This is a vectorized [`.fold()`](Self::fold): the accumulator is a
+    /// [`Pack`] whose lanes start at `init`, and `fold` combines it with
+    /// packs of elements, lane by lane. At the end, the lanes of the
+    /// accumulator are combined with `reduce`, starting with `init`.
+    ///
+    /// Each lane folds a subset of the elements in unspecified order, so the
+    /// result must not depend on the order: `init` must be an identity of
+    /// `reduce`, like `0.` for sums. Lanes after the last element of a pack
+    /// repeat that element, and are not kept in the accumulator.
+    ///
+    /// The array is processed in contiguous pieces, like in
+    /// [`.simd_sum()`](Self::simd_sum).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::simd::Pack;
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// // The sum of squares
+    /// assert_eq!(a.simd_fold(0., |acc, x| acc + x * x, |x, y| x + y), 91.);
+    /// // The largest absolute value
+    /// let max_abs = a.simd_fold(0., |acc, x| acc.max(x.max(Pack::splat(0.) - x)), f64::max);
+    /// assert_eq!(max_abs, 6.);
+    /// ```
+    pub fn simd_fold<F, G>(&self, init: A, mut fold: F, mut reduce: G) -> A
+    where
+        F: FnMut(Pack<A>, Pack<A>) -> Pack<A>,
+        G: FnMut(A, A) -> A,
+    {
+        let mut acc = Pack::splat(init);
+        self.for_each_slice(|xs| {
+            let mut chunks = xs.chunks_exact(PACK_LEN);
+            for chunk in &mut chunks {
+                acc = fold(acc, Pack::from_slice(chunk));
+            }
+            let rest = chunks.remainder();
+            if !rest.is_empty() {
+                let next = fold(acc, Pack::from_slice(rest));
+                acc.0[..rest.len()].copy_from_slice(&next.0[..rest.len()]);
+            }
+        });
+        acc.0.iter().fold(init, |x, &y| reduce(x, y))
+    }
+
+    /// Call `f` on contiguous slices that together hold all elements of the
+    /// array, in unspecified order.
+    fn for_each_slice<F>(&self, mut f: F)
+    where
+        F: FnMut(&[A]),
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            f(slc);
+            return;
+        }
+        let axis = self.dim.min_stride_axis(&self.strides);
+        let mut buffer = Vec::new();
+        for lane in self.lanes(axis) {
+            if let Some(slc) = lane.as_slice_memory_order() {
+                f(slc);
+            } else {
+                buffer.resize(lane.len(), A::ZERO);
+                ArrayViewMut::from(&mut buffer[..]).assign(&lane);
+                f(&buffer);
+            }
+        }
+    }
+}
+
+/// Call `f` on the packs of the elements of `xs` and the index of their
+/// first element; the elements are replaced by the lanes of the pack after
+/// the call.
+#[inline]
+fn for_each_pack<A, F>(xs: &mut [A], mut f: F)
+where
+    A: SimdElement,
+    F: FnMut(&mut Pack<A>, usize),
+{
+    let mut chunks = xs.chunks_exact_mut(PACK_LEN);
+    let mut i = 0;
+    for chunk in &mut chunks {
+        let mut pack = Pack::from_slice(chunk);
+        f(&mut pack, i);
+        chunk.copy_from_slice(&pack.0);
+        i += PACK_LEN;
+    }
+    let rest = chunks.into_remainder();
+    if !rest.is_empty() {
+        let mut pack = Pack::from_slice(rest);
+        f(&mut pack, i);
+        let n = rest.len();
+        rest.copy_from_slice(&pack.0[..n]);
+    }
+}
+
+/// # Vectorized Zip
+///
+/// These methods require crate feature `simd`.
+/// See also the [`simd`](crate::simd) module.
+impl<'a, A, D> Zip<(ArrayViewMut<'a, A, D>,), D>
+where
+    A: SimdElement,
+    D: Dimension,
+{
+    /// Call `f` on packs of the elements, to modify them.
+    ///
+    /// This is a vectorized [`.for_each()`](Zip::for_each): `f` is called
+    /// with a [`Pack`] of up to [`PACK_LEN`] elements at a time, and the
+    /// elements are replaced by the lanes of the pack afterwards. Lanes after
+    /// the last element repeat that element, and are discarded.
+    ///
+    /// The elements are processed a pack at a time if the array is
+    /// contiguous, and one at a time otherwise, with every lane of the pack
+    /// equal to the element.
+    ///
+    /// ```
+    /// use ndarray::{array, Zip};
+    /// use ndarray::simd::Pack;
+    ///
+    /// let mut a = array![-1., 2., -3., 4.];
+    /// Zip::from(&mut a).simd_for_each(|x| *x = x.max(Pack::splat(0.)));
+    /// assert_eq!(a, array![0., 2., 0., 4.]);
+    /// ```
+    pub fn simd_for_each<F>(self, mut f: F)
+    where
+        F: FnMut(&mut Pack<A>),
+    {
+        let (mut a,) = self.into_parts();
+        if let Some(xs) = a.as_slice_memory_order_mut() {
+            for_each_pack(xs, |x, _| f(x));
+        } else {
+            a.map_inplace(move |x| for_each_pack(std::slice::from_mut(x), |x, _| f(x)));
+        }
+    }
+}
+
+impl<'a, 'b, A, D> Zip<(ArrayViewMut<'a, A, D>, ArrayView<'b, A, D>), D>
+where
+    A: SimdElement,
+    D: Dimension,
+{
+    /// Call `f` on packs of the elements of the two arrays, to modify the
+    /// elements of the first one.
+    ///
+    /// The packs are the ones of [`.simd_for_each()`](#method.simd_for_each)
+    /// for one array: the elements are processed a pack at a time if both
+    /// arrays are contiguous with the same memory layout, and one at a time
+    /// otherwise.
+    ///
+    /// ```
+    /// use ndarray::{Array, Zip};
+    /// use ndarray::simd::Pack;
+    ///
+    /// // y = 2 x + y
+    /// let x = Array::linspace(0f32, 1., 101);
+    /// let mut y = Array::ones(101);
+    /// Zip::from(&mut y).and(&x).simd_for_each(|y, x| *y = Pack::splat(2.) * x + *y);
+    /// assert_eq!(y[100], 3.);
+    /// ```
+    pub fn simd_for_each<F>(self, mut f: F)
+    where
+        F: FnMut(&mut Pack<A>, Pack<A>),
+    {
+        let (mut a, b) = self.into_parts();
+        if a.dim.strides_equivalent(&a.strides, &b.strides) {
+            if let (Some(xs), Some(ys)) = (a.as_slice_memory_order_mut(), b.as_slice_memory_order()) {
+                for_each_pack(xs, |x, i| f(x, Pack::from_slice(&ys[i..])));
+                return;
+            }
+        }
+        Zip::from(a).and(b).for_each(move |x, &y| {
+            for_each_pack(std::slice::from_mut(x), |x, _| f(x, Pack::splat(y)));
+        });
+    }
+}
+
+impl<'a, 'b, 'c, A, D> Zip<(ArrayViewMut<'a, A, D>, ArrayView<'b, A, D>, ArrayView<'c, A, D>), D>
+where
+    A: SimdElement,
+    D: Dimension,
+{
+    /// Call `f` on packs of the elements of the three arrays, to modify the
+    /// elements of the first one.
+    ///
+    /// The packs are the ones of [`.simd_for_each()`](#method.simd_for_each)
+    /// for one array: the elements are processed a pack at a time if the
+    /// arrays are contiguous with the same memory layout, and one at a time
+    /// otherwise.
+    pub fn simd_for_each<F>(self, mut f: F)
+    where
+        F: FnMut(&mut Pack<A>, Pack<A>, Pack<A>),
+    {
+        let (mut a, b, c) = self.into_parts();
+        if a.dim.strides_equivalent(&a.strides, &b.strides) &&
+            a.dim.strides_equivalent(&a.strides, &c.strides)
+        {
+            if let (Some(xs), Some(ys), Some(zs)) =
+                (a.as_slice_memory_order_mut(), b.as_slice_memory_order(), c.as_slice_memory_order())
+            {
+                for_each_pack(xs, |x, i| f(x, Pack::from_slice(&ys[i..]), Pack::from_slice(&zs[i..])));
+                return;
+            }
+        }
+        Zip::from(a).and(b).and(c).for_each(move |x, &y, &z| {
+            for_each_pack(std::slice::from_mut(x), |x, _| f(x, Pack::splat(y), Pack::splat(z)));
+        });
+    }
+}
@@ -7,7 +7,7 @@
 // except according to those terms.
 use crate::dimension::slices_intersect;
 use crate::error::{ErrorKind, ShapeError};
-use crate::{ArrayViewMut, DimAdd, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
+use crate::{ArrayBase, ArrayViewMut, Data, DimAdd, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
 use alloc::vec::Vec;
 use std::convert::TryFrom;
 use std::fmt;
@@ -289,6 +289,71 @@ impl From<NewAxis> for SliceInfoElem {
     }
 }
 
+/// A slice or a list of indices for one axis, used by the
+/// [`gather![]`](macro.gather!.html) macro and
+/// [`.gather()`](crate::ArrayBase::gather).
+///
+/// ## Examples
+///
+/// `GatherElem::from(a..b)` is the range `a..b` of the axis, like `s![a..b]`.
+///
+/// `GatherElem::from(vec![3, 17, 42])` selects the indices 3, 17 and 42 of
+/// the axis, in that order. It can also be created from a slice, an array or
+/// a one-dimensional `ndarray` array of indices.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GatherElem {
+    /// A range with step size; see [`Slice`].
+    Slice(Slice),
+    /// A list of indices, which are selected in order and may repeat.
+    Indices(Vec<usize>),
+}
+
+impl<T> From<T> for GatherElem
+where
+    Slice: From<T>,
+{
+    #[inline]
+    fn from(r: T) -> GatherElem {
+        GatherElem::Slice(Slice::from(r))
+    }
+}
+
+impl From<Vec<usize>> for GatherElem {
+    fn from(indices: Vec<usize>) -> GatherElem {
+        GatherElem::Indices(indices)
+    }
+}
+
+impl<'a> From<&'a [usize]> for GatherElem {
+    fn from(indices: &'a [usize]) -> GatherElem {
+        GatherElem::Indices(indices.to_vec())
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for GatherElem {
+    fn from(indices: [usize; N]) -> GatherElem {
+        GatherElem::Indices(indices.to_vec())
+    }
+}
+
+impl<S> From<ArrayBase<S, Ix1>> for GatherElem
+where
+    S: Data<Elem = usize>,
+{
+    fn from(indices: ArrayBase<S, Ix1>) -> GatherElem {
+        GatherElem::Indices(indices.to_vec())
+    }
+}
+
+impl<'a, S> From<&'a ArrayBase<S, Ix1>> for GatherElem
+where
+    S: Data<Elem = usize>,
+{
+    fn from(indices: &'a ArrayBase<S, Ix1>) -> GatherElem {
+        GatherElem::Indices(indices.to_vec())
+    }
+}
+
 /// A type that can slice an array of dimension `D`.
 ///
 /// This trait is unsafe to implement because the implementation must ensure
@@ -727,7 +792,8 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 /// [`.slice_move()`]: struct.ArrayBase.html#method.slice_move
 /// [`.slice_collapse()`]: struct.ArrayBase.html#method.slice_collapse
 ///
-/// See also [*Slicing*](struct.ArrayBase.html#slicing).
+/// See also [*Slicing*](struct.ArrayBase.html#slicing), and
+/// [`gather![]`](macro.gather!.html) to select lists of indices.
 ///
 /// # Example
 ///
@@ -878,6 +944,40 @@ macro_rules! s(
     };
 );
 
+/// Gather argument constructor, for [`.gather()`](crate::ArrayBase::gather).
+///
+/// `gather![]` takes a list of ranges/slices and lists of indices, one for
+/// each axis, separated by comma. Ranges and slices are written like in
+/// [`s![]`](macro.s!.html), with optional step sizes that are separated from
+/// the range by a semicolon; indices and new axes are not supported. A list
+/// of indices is a `Vec<usize>`, `&[usize]`, `[usize; n]` or a
+/// one-dimensional array of `usize`, and selects those indices of the axis
+/// in order. It is converted into an array of [`GatherElem`].
+///
+/// ```
+/// use ndarray::{array, gather};
+///
+/// let a = array![[0, 1, 2, 3], [10, 11, 12, 13], [20, 21, 22, 23]];
+/// let rows = vec![2, 0];
+/// assert_eq!(a.gather(&gather![rows, 1..;2]), array![[21, 23], [1, 3]]);
+/// ```
+#[macro_export]
+macro_rules! gather(
+    // convert range/slice/index list into GatherElem
+    (@convert $r:expr) => {
+        <$crate::GatherElem as ::std::convert::From<_>>::from($r)
+    };
+    // convert range/slice and step into GatherElem
+    (@convert $r:expr, $s:expr) => {
+        <$crate::GatherElem as ::std::convert::From<_>>::from(
+            <$crate::Slice as ::std::convert::From<_>>::from($r).step_by($s as isize)
+        )
+    };
+    ($($r:expr $(;$s:expr)?),* $(,)?) => {
+        [$($crate::gather!(@convert $r $(, $s)?)),*]
+    };
+);
+
 /// Slicing information describing multiple mutable, disjoint slices.
 ///
 /// It's unfortunate that we need `'a` and `A` to be parameters of the trait,
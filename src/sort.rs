@@ -0,0 +1,250 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::imp_prelude::*;
+
+/// Direction of a sort: ascending or descending values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Smallest value first
+    Ascending,
+    /// Largest value first
+    Descending,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    /// Sort each lane along `axis` in ascending order.
+    ///
+    /// The sort is stable. Lanes that are contiguous in memory are sorted in
+    /// place, other lanes are sorted in a temporary buffer.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[3, 1, 2], [1, 0, 5]];
+    /// a.sort_axis(Axis(1));
+    /// assert_eq!(a, array![[1, 2, 3], [0, 1, 5]]);
+    /// a.sort_axis(Axis(0));
+    /// assert_eq!(a, array![[0, 1, 3], [1, 2, 5]]);
+    /// ```
+    pub fn sort_axis(&mut self, axis: Axis)
+    where
+        A: Ord + Clone,
+    {
+        self.sort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Sort each lane along `axis` with the comparison function `compare`.
+    ///
+    /// The sort is stable. See [`.sort_axis()`](ArrayBase::sort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[0.5, -1., 2.], [3., 0., 1.]];
+    /// a.sort_axis_by(Axis(1), |x, y| y.partial_cmp(x).unwrap());
+    /// assert_eq!(a, array![[2., 0.5, -1.], [3., 1., 0.]]);
+    /// ```
+    pub fn sort_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        for lane in self.lanes_mut(axis) {
+            sort_lane_by(lane, true, &mut compare);
+        }
+    }
+
+    /// Sort each lane along `axis` with the comparison function `compare`,
+    /// without preserving the order of equal elements.
+    ///
+    /// See [`.sort_axis()`](ArrayBase::sort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_unstable_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        for lane in self.lanes_mut(axis) {
+            sort_lane_by(lane, false, &mut compare);
+        }
+    }
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return an iterator of indices and elements in order of the elements'
+    /// values.
+    ///
+    /// The iterator yields `(index, &element)` pairs, where `index` is the
+    /// index pattern of the element, smallest value first for
+    /// `SortOrder::Ascending` and largest first for `SortOrder::Descending`.
+    /// Equal elements are yielded in logical order.
+    ///
+    /// The elements are not sorted up front: creating the iterator builds a
+    /// heap of references in *O(n)* time, and each step takes *O(log n)*
+    /// time, so taking the `k` largest elements costs *O(n + k log n)*.
+    ///
+    /// Elements that can't be compared, like NaN, are treated as equal to
+    /// everything, so the order of the other elements is unspecified when
+    /// there are any.
+    ///
+    /// ```
+    /// use ndarray::{array, SortOrder};
+    ///
+    /// let a = array![[0.5, 3., 1.], [2., -1., 4.]];
+    /// let largest = a
+    ///     .sorted_indices_iter(SortOrder::Descending)
+    ///     .take(2)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(largest, vec![((1, 2), &4.), ((0, 1), &3.)]);
+    /// ```
+    pub fn sorted_indices_iter(&self, order: SortOrder) -> SortedIndicesIter<'_, A, D>
+    where
+        A: PartialOrd,
+    {
+        let descending = order == SortOrder::Descending;
+        let entries = self
+            .indexed_iter()
+            .enumerate()
+            .map(|(position, (index, value))| HeapEntry {
+                value,
+                position,
+                index,
+                descending,
+            })
+            .collect::<Vec<_>>();
+        SortedIndicesIter {
+            heap: BinaryHeap::from(entries),
+        }
+    }
+}
+
+/// An iterator of indices and elements in order of the elements' values.
+///
+/// See [`.sorted_indices_iter()`](ArrayBase::sorted_indices_iter) for more
+/// information.
+pub struct SortedIndicesIter<'a, A, D: Dimension> {
+    heap: BinaryHeap<HeapEntry<'a, A, D::Pattern>>,
+}
+
+impl<'a, A, D> Iterator for SortedIndicesIter<'a, A, D>
+where
+    A: PartialOrd,
+    D: Dimension,
+{
+    type Item = (D::Pattern, &'a A);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop().map(|entry| (entry.index, entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+
+impl<'a, A, D> ExactSizeIterator for SortedIndicesIter<'a, A, D>
+where
+    A: PartialOrd,
+    D: Dimension,
+{
+}
+
+impl<'a, A, D> fmt::Debug for SortedIndicesIter<'a, A, D>
+where
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedIndicesIter")
+            .field("len", &self.heap.len())
+            .finish()
+    }
+}
+
+/// An element in the heap of `SortedIndicesIter`. The greatest entry is the
+/// next one to yield.
+struct HeapEntry<'a, A, I> {
+    value: &'a A,
+    /// Position in logical order, to keep equal elements in order
+    position: usize,
+    index: I,
+    descending: bool,
+}
+
+impl<'a, A: PartialOrd, I> Ord for HeapEntry<'a, A, I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by_value = self
+            .value
+            .partial_cmp(other.value)
+            .unwrap_or(Ordering::Equal);
+        let by_value = if self.descending {
+            by_value
+        } else {
+            by_value.reverse()
+        };
+        by_value.then_with(|| other.position.cmp(&self.position))
+    }
+}
+
+impl<'a, A: PartialOrd, I> PartialOrd for HeapEntry<'a, A, I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, A: PartialOrd, I> PartialEq for HeapEntry<'a, A, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, A: PartialOrd, I> Eq for HeapEntry<'a, A, I> {}
+
+/// Sort the lane in place if it's contiguous, or else in a temporary buffer.
+pub(crate) fn sort_lane_by<A, F>(mut lane: ArrayViewMut1<'_, A>, stable: bool, compare: F)
+where
+    A: Clone,
+    F: FnMut(&A, &A) -> Ordering,
+{
+    if let Some(slc) = lane.as_slice_mut() {
+        sort_slice_by(slc, stable, compare);
+    } else {
+        let mut buffer = lane.to_vec();
+        sort_slice_by(&mut buffer, stable, compare);
+        lane.assign(&ArrayView1::from(&buffer));
+    }
+}
+
+fn sort_slice_by<A, F>(slc: &mut [A], stable: bool, compare: F)
+where
+    F: FnMut(&A, &A) -> Ordering,
+{
+    if stable {
+        slc.sort_by(compare);
+    } else {
+        slc.sort_unstable_by(compare);
+    }
+}
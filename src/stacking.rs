@@ -7,10 +7,12 @@
 // except according to those terms.
 
 use alloc::vec::Vec;
+use core::fmt;
 
 use crate::dimension;
 use crate::error::{from_kind, ErrorKind, ShapeError};
 use crate::imp_prelude::*;
+use crate::Slice;
 
 /// Stack arrays along the new axis.
 ///
@@ -49,6 +51,43 @@ where
     stack_new_axis(axis, arrays)
 }
 
+/// Stack arrays from an iterator along the new axis.
+///
+/// This is like [`stack`], but takes the views from any iterator, so they
+/// don't need to be collected first. The result grows as the arrays are
+/// appended, with amortized reallocation like `Vec::push`.
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty, if `axis` is out of bounds,
+/// if the result is larger than is possible to represent.
+///
+/// ```
+/// use ndarray::{arr1, arr2, stack_iter, Axis};
+///
+/// let rows = (0..3).map(|i| arr1(&[i, 10 * i]));
+/// let owned = rows.collect::<Vec<_>>();
+/// let res = stack_iter(Axis(0), owned.iter().map(|row| row.view()));
+/// assert_eq!(res, Ok(arr2(&[[0, 0], [1, 10], [2, 20]])));
+/// ```
+pub fn stack_iter<'a, A, D, I>(axis: Axis, arrays: I) -> Result<Array<A, D::Larger>, ShapeError>
+where
+    A: Clone + 'a,
+    D: Dimension,
+    D::Larger: RemoveAxis,
+    I: IntoIterator<Item = ArrayView<'a, A, D>>,
+{
+    let mut arrays = arrays.into_iter().peekable();
+    let first_dim = arrays
+        .peek()
+        .ok_or_else(|| from_kind(ErrorKind::Unsupported))?
+        .raw_dim();
+    // Avoid panic on `insert_axis` call, return an Err instead of it.
+    if axis.index() > first_dim.ndim() {
+        return Err(from_kind(ErrorKind::OutOfBounds));
+    }
+    concatenate_iter(axis, arrays.map(|array| array.insert_axis(axis)))
+}
+
 /// Concatenate arrays along the given axis.
 ///
 /// ***Errors*** if the arrays have mismatching shapes, apart from along `axis`.
@@ -108,6 +147,264 @@ where
     Ok(res)
 }
 
+/// Concatenate arrays from an iterator along the given axis.
+///
+/// This is like [`concatenate`], but takes the views from any iterator, so
+/// they don't need to be collected first. The result grows as the arrays
+/// are appended, with amortized reallocation like `Vec::push`.
+///
+/// ***Errors*** if the arrays have mismatching shapes, apart from along `axis`.<br>
+/// ***Errors*** if `arrays` is empty, if `axis` is out of bounds,
+/// if the result is larger than is possible to represent.
+///
+/// ```
+/// use ndarray::{arr2, concatenate_iter, Axis};
+///
+/// let a = arr2(&[[1, 2, 3],
+///                [4, 5, 6]]);
+/// // Concatenate the rows as 1 × 3 arrays, without collecting the views
+/// let res = concatenate_iter(Axis(0), a.rows().into_iter().map(|row| {
+///     row.into_shape((1, 3)).unwrap()
+/// }));
+/// assert_eq!(res, Ok(a.clone()));
+/// ```
+pub fn concatenate_iter<'a, A, D, I>(axis: Axis, arrays: I) -> Result<Array<A, D>, ShapeError>
+where
+    A: Clone + 'a,
+    D: RemoveAxis,
+    I: IntoIterator<Item = ArrayView<'a, A, D>>,
+{
+    let mut arrays = arrays.into_iter();
+    let first = arrays.next().ok_or_else(|| from_kind(ErrorKind::Unsupported))?;
+    if axis.index() >= first.ndim() {
+        return Err(from_kind(ErrorKind::OutOfBounds));
+    }
+
+    // start with an empty array with room for as many arrays like the first
+    // as the iterator promises; append checks the shapes and grows it
+    let mut res_dim = first.raw_dim();
+    res_dim.set_axis(axis, 0);
+    let capacity = first.len().saturating_mul(arrays.size_hint().0.saturating_add(1));
+    let mut res = unsafe {
+        // Safety: dimension is size 0 and vec is empty
+        Array::from_shape_vec_unchecked(res_dim, Vec::with_capacity(capacity))
+    };
+    res.append(axis, first)?;
+    for array in arrays {
+        res.append(axis, array)?;
+    }
+    Ok(res)
+}
+
+/// Concatenate matrices with a fixed number of columns along their rows.
+///
+/// Each block is a slice of rows of `N` elements. Since the number of columns
+/// is part of the type, blocks with different numbers of columns are a
+/// compile time error, and the concatenation itself can't fail.
+///
+/// **Panics** if the number of rows overflows `isize`. (This can only occur
+/// if `A` is zero-sized or if `N` is zero.)
+///
+/// ```
+/// use ndarray::{arr2, concatenate_fixed};
+///
+/// let a = [[1, 2], [3, 4]];
+/// let b = [[5, 6]];
+/// assert_eq!(concatenate_fixed(&[&a, &b]), arr2(&[[1, 2], [3, 4], [5, 6]]));
+/// ```
+pub fn concatenate_fixed<A, const N: usize>(blocks: &[&[[A; N]]]) -> Array2<A>
+where
+    A: Clone,
+{
+    let rows = blocks.iter().map(|block| block.len()).sum::<usize>();
+    let mut data = Vec::with_capacity(rows * N);
+    for row in blocks.iter().flat_map(|block| block.iter()) {
+        data.extend_from_slice(row);
+    }
+    Array::from_shape_vec((rows, N), data)
+        .expect("Product of non-zero axis lengths must not overflow isize.")
+}
+
+/// An error from [`concatenate_checked`], describing which inputs are invalid.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConcatError {
+    /// No arrays were given.
+    Empty,
+    /// The axis is out of bounds for the first array.
+    AxisOutOfBounds {
+        /// The axis to concatenate along
+        axis: usize,
+        /// The number of dimensions of the first array
+        ndim: usize,
+    },
+    /// Some arrays don't have the same shape as the first array, apart from
+    /// along the axis.
+    ShapeMismatch {
+        /// The shape of the first array
+        expected: Vec<usize>,
+        /// The index in the input and shape of each mismatching array
+        mismatches: Vec<(usize, Vec<usize>)>,
+    },
+    /// The result would be larger than is possible to represent.
+    Overflow,
+}
+
+impl fmt::Display for ConcatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConcatError::Empty => write!(f, "ConcatError: no arrays to concatenate"),
+            ConcatError::AxisOutOfBounds { axis, ndim } => write!(
+                f,
+                "ConcatError: axis {} is out of bounds for arrays of dimension {}",
+                axis, ndim
+            ),
+            ConcatError::ShapeMismatch { expected, mismatches } => {
+                write!(f, "ConcatError: expected arrays of shape {:?} apart from the axis;", expected)?;
+                for (index, shape) in mismatches {
+                    write!(f, " array {} has shape {:?}", index, shape)?;
+                }
+                Ok(())
+            }
+            ConcatError::Overflow => write!(f, "ConcatError: the result is too large"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConcatError {}
+
+/// Concatenate arrays along the given axis, checking all inputs first.
+///
+/// This is like [`concatenate`], but all arrays are validated before
+/// anything is copied, and an error lists every input that has the wrong
+/// shape. The result is allocated once, with its exact size.
+///
+/// ***Errors*** if `arrays` is empty, if `axis` is out of bounds, if any of
+/// the arrays has a shape that differs from the first array (apart from
+/// along `axis`), or if the result is larger than is possible to represent.
+///
+/// ```
+/// use ndarray::{concatenate_checked, Array2, Axis, ConcatError};
+///
+/// let a = Array2::<f64>::zeros((2, 3));
+/// let b = Array2::<f64>::ones((1, 3));
+/// let c = Array2::<f64>::ones((1, 4));
+/// let res = concatenate_checked(Axis(0), &[a.view(), b.view()]).unwrap();
+/// assert_eq!(res.shape(), &[3, 3]);
+///
+/// let err = concatenate_checked(Axis(0), &[a.view(), b.view(), c.view()]);
+/// assert_eq!(err, Err(ConcatError::ShapeMismatch {
+///     expected: vec![2, 3],
+///     mismatches: vec![(2, vec![1, 4])],
+/// }));
+/// ```
+pub fn concatenate_checked<A, D>(axis: Axis, arrays: &[ArrayView<A, D>]) -> Result<Array<A, D>, ConcatError>
+where
+    A: Clone,
+    D: RemoveAxis,
+{
+    let first = arrays.first().ok_or(ConcatError::Empty)?;
+    let ndim = first.ndim();
+    if axis.index() >= ndim {
+        return Err(ConcatError::AxisOutOfBounds { axis: axis.index(), ndim });
+    }
+    let matches = |a: &ArrayView<A, D>| {
+        a.ndim() == ndim
+            && a.shape().iter().zip(first.shape()).enumerate()
+                .all(|(i, (x, y))| i == axis.index() || x == y)
+    };
+    let mismatches = arrays
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| !matches(a))
+        .map(|(index, a)| (index, a.shape().to_vec()))
+        .collect::<Vec<_>>();
+    if !mismatches.is_empty() {
+        return Err(ConcatError::ShapeMismatch { expected: first.shape().to_vec(), mismatches });
+    }
+
+    let stacked_len = arrays
+        .iter()
+        .try_fold(0usize, |acc, a| acc.checked_add(a.len_of(axis)))
+        .ok_or(ConcatError::Overflow)?;
+    let mut res_dim = first.raw_dim();
+    res_dim.set_axis(axis, stacked_len);
+    dimension::size_of_shape_checked(&res_dim).map_err(|_| ConcatError::Overflow)?;
+
+    let mut res = Array::uninit(res_dim);
+    let mut start = 0;
+    for array in arrays {
+        let end = start + array.len_of(axis);
+        array.assign_to(res.slice_axis_mut(axis, Slice::from(start..end)));
+        start = end;
+    }
+    unsafe {
+        // Safe because the parts along the axis cover the whole array
+        Ok(res.assume_init())
+    }
+}
+
+/// Interleave arrays along an existing axis.
+///
+/// With `n` arrays, element `i` along `axis` of array `k` is at position
+/// `i * n + k` along `axis` of the result, for example for packing real and
+/// imaginary parts, or even and odd samples. Each array is copied in one
+/// strided pass. Use [`stack`] with the axis after `axis` instead to
+/// interleave along a new axis, and
+/// [`.deinterleave()`](ArrayBase::deinterleave) for the inverse.
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty, if `axis` is out of bounds,
+/// if the result is larger than is possible to represent.
+///
+/// ```
+/// use ndarray::{arr1, arr2, interleave, Axis};
+///
+/// let even = arr1(&[0, 2, 4]);
+/// let odd = arr1(&[1, 3, 5]);
+/// assert_eq!(interleave(Axis(0), &[even.view(), odd.view()]), Ok(arr1(&[0, 1, 2, 3, 4, 5])));
+///
+/// let re = arr2(&[[1., 2.], [3., 4.]]);
+/// let im = arr2(&[[0.5, 0.], [0., -1.]]);
+/// let packed = interleave(Axis(1), &[re.view(), im.view()]).unwrap();
+/// assert_eq!(packed, arr2(&[[1., 0.5, 2., 0.], [3., 0., 4., -1.]]));
+/// ```
+pub fn interleave<A, D>(axis: Axis, arrays: &[ArrayView<A, D>]) -> Result<Array<A, D>, ShapeError>
+where
+    A: Clone,
+    D: Dimension,
+{
+    if arrays.is_empty() {
+        return Err(from_kind(ErrorKind::Unsupported));
+    }
+    let common_dim = arrays[0].raw_dim();
+    if axis.index() >= common_dim.ndim() {
+        return Err(from_kind(ErrorKind::OutOfBounds));
+    }
+    if arrays.iter().any(|a| a.raw_dim() != common_dim) {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    let n = arrays.len();
+    let mut res_dim = common_dim;
+    res_dim[axis.index()] = res_dim[axis.index()]
+        .checked_mul(n)
+        .ok_or_else(|| from_kind(ErrorKind::Overflow))?;
+    dimension::size_of_shape_checked(&res_dim)?;
+
+    let mut res = Array::uninit(res_dim);
+    for (k, array) in arrays.iter().enumerate() {
+        array.assign_to(res.slice_axis_mut(axis, Slice::new(k as isize, None, n as isize)));
+    }
+    unsafe {
+        // Safe because the arrays are assigned to every n-th position from
+        // each offset 0..n, which covers the whole array
+        Ok(res.assume_init())
+    }
+}
+
 #[deprecated(note="Use under the name stack instead.", since="0.15.0")]
 /// Stack arrays along the new axis.
 ///
@@ -0,0 +1,166 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::format;
+use alloc::string::String;
+use std::fmt;
+
+use crate::imp_prelude::*;
+
+/// The markup language of a table, for
+/// [`.display_as()`](ArrayBase::display_as).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TableFormat {
+    /// A Markdown (GitHub flavored) table with an empty header row.
+    Markdown,
+    /// A LaTeX `bmatrix` environment.
+    Latex,
+    /// An HTML `<table>`.
+    Html,
+}
+
+/// Displays a one- or two-dimensional array as a table, created by
+/// [`.display_as()`](ArrayBase::display_as).
+///
+/// The formatting parameters, like the precision, are applied to each
+/// element.
+#[derive(Debug)]
+pub struct TableDisplay<'a, A> {
+    view: ArrayView2<'a, A>,
+    format: TableFormat,
+}
+
+impl<A, S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    /// Return an adapter that displays the array as a table with one row,
+    /// in the markup language `format`.
+    ///
+    /// See the two-dimensional [`.display_as()`](ArrayBase::display_as)
+    /// for an example.
+    pub fn display_as(&self, format: TableFormat) -> TableDisplay<'_, A> {
+        TableDisplay {
+            view: self.view().insert_axis(Axis(0)),
+            format,
+        }
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return an adapter that displays the matrix as a table in the markup
+    /// language `format`.
+    ///
+    /// The elements are formatted with `Display`, using the formatting
+    /// parameters of the table, like the precision. Characters with a
+    /// special meaning in Markdown and HTML tables are escaped; LaTeX
+    /// output is not escaped.
+    ///
+    /// ```
+    /// use ndarray::{array, TableFormat};
+    ///
+    /// let a = array![[1., 2.5], [-3., 4.125]];
+    /// assert_eq!(
+    ///     format!("{:.2}", a.display_as(TableFormat::Markdown)),
+    ///     "|  |  |\n\
+    ///      |---|---|\n\
+    ///      | 1.00 | 2.50 |\n\
+    ///      | -3.00 | 4.12 |\n"
+    /// );
+    /// assert_eq!(
+    ///     a.display_as(TableFormat::Latex).to_string(),
+    ///     "\\begin{bmatrix}\n1 & 2.5 \\\\\n-3 & 4.125\n\\end{bmatrix}\n"
+    /// );
+    /// assert_eq!(
+    ///     format!("{:.1}", a.row(0).display_as(TableFormat::Html)),
+    ///     "<table>\n<tr><td>1.0</td><td>2.5</td></tr>\n</table>\n"
+    /// );
+    /// ```
+    pub fn display_as(&self, format: TableFormat) -> TableDisplay<'_, A> {
+        TableDisplay {
+            view: self.view(),
+            format,
+        }
+    }
+}
+
+/// Format `elt` with the precision of `f`, if any.
+fn format_elem<A: fmt::Display>(elt: &A, f: &fmt::Formatter<'_>) -> String {
+    match f.precision() {
+        Some(precision) => format!("{:.*}", precision, elt),
+        None => format!("{}", elt),
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl<'a, A: fmt::Display> fmt::Display for TableDisplay<'a, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            TableFormat::Markdown => {
+                let ncols = self.view.ncols();
+                writeln!(f, "|{}", "  |".repeat(ncols))?;
+                writeln!(f, "|{}", "---|".repeat(ncols))?;
+                for row in self.view.rows() {
+                    f.write_str("|")?;
+                    for elt in row {
+                        let cell = format_elem(elt, f).replace('|', "\\|");
+                        write!(f, " {} |", cell)?;
+                    }
+                    f.write_str("\n")?;
+                }
+            }
+            TableFormat::Latex => {
+                f.write_str("\\begin{bmatrix}\n")?;
+                for (i, row) in self.view.rows().into_iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" \\\\\n")?;
+                    }
+                    for (j, elt) in row.iter().enumerate() {
+                        if j > 0 {
+                            f.write_str(" & ")?;
+                        }
+                        f.write_str(&format_elem(elt, f))?;
+                    }
+                }
+                if self.view.nrows() > 0 {
+                    f.write_str("\n")?;
+                }
+                f.write_str("\\end{bmatrix}\n")?;
+            }
+            TableFormat::Html => {
+                f.write_str("<table>\n")?;
+                for row in self.view.rows() {
+                    f.write_str("<tr>")?;
+                    for elt in row {
+                        write!(f, "<td>{}</td>", escape_html(&format_elem(elt, f)))?;
+                    }
+                    f.write_str("</tr>\n")?;
+                }
+                f.write_str("</table>\n")?;
+            }
+        }
+        Ok(())
+    }
+}
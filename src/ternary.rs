@@ -0,0 +1,71 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension::broadcast::co_broadcast;
+use crate::imp_prelude::*;
+use crate::{DimMax, Zip};
+
+type DimMax3<D1, D2, D3> = <<D1 as DimMax<D2>>::Output as DimMax<D3>>::Output;
+
+/// Return a new array with the elements of `then` where `cond` is `true`,
+/// and the elements of `otherwise` where it is `false`.
+///
+/// The three arrays are broadcast together to a common shape, like the
+/// arithmetic operators do, which is the shape of the result. See
+/// [`.assign_if()`](ArrayBase::assign_if) for the in-place version.
+///
+/// **Panics** if broadcasting isn’t possible.
+///
+/// ```
+/// use ndarray::{array, where_};
+///
+/// let a = array![[1, 5], [7, 2]];
+/// let b = array![[4, 3], [6, 8]];
+///
+/// // Elementwise maximum
+/// assert_eq!(where_(&a.mapv(|x| x > 4), &a, &b), array![[4, 5], [7, 8]]);
+///
+/// // Condition per column, broadcast against a scalar
+/// let clipped = where_(&array![true, false], &a, &array![0]);
+/// assert_eq!(clipped, array![[1, 0], [7, 0]]);
+/// ```
+pub fn where_<A, S1, D1, S2, D2, S3, D3>(
+    cond: &ArrayBase<S1, D1>,
+    then: &ArrayBase<S2, D2>,
+    otherwise: &ArrayBase<S3, D3>,
+) -> Array<A, DimMax3<D1, D2, D3>>
+where
+    A: Clone,
+    S1: Data<Elem = bool>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    D1: Dimension + DimMax<D2>,
+    D2: Dimension,
+    D3: Dimension,
+    <D1 as DimMax<D2>>::Output: DimMax<D3>,
+{
+    let shape = co_broadcast::<_, _, <D1 as DimMax<D2>>::Output>(&cond.raw_dim(), &then.raw_dim())
+        .and_then(|shape| co_broadcast::<_, _, DimMax3<D1, D2, D3>>(&shape, &otherwise.raw_dim()));
+    let shape = match shape {
+        Ok(shape) => shape,
+        Err(_) => panic!(
+            "ndarray: could not broadcast arrays of shape {:?}, {:?} and {:?} together",
+            cond.shape(),
+            then.shape(),
+            otherwise.shape()
+        ),
+    };
+    // Broadcasting to the common shape always succeeds
+    let cond = cond.broadcast(shape.clone()).unwrap();
+    let then = then.broadcast(shape.clone()).unwrap();
+    let otherwise = otherwise.broadcast(shape).unwrap();
+    Zip::from(cond)
+        .and(then)
+        .and(otherwise)
+        .map_collect(|&c, t, e| if c { t.clone() } else { e.clone() })
+}
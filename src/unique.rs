@@ -0,0 +1,215 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+use crate::imp_prelude::*;
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the distinct elements of the array, in sorted order.
+    ///
+    /// Use [`.unique_by()`](ArrayBase::unique_by) for elements that are not
+    /// `Ord`, like floating point numbers.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 1, 3], [2, 1, 1]];
+    /// assert_eq!(a.unique(), array![1, 2, 3]);
+    /// ```
+    pub fn unique(&self) -> Array1<A>
+    where
+        A: Ord + Clone,
+    {
+        let mut values = self.iter().cloned().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        Array1::from(values)
+    }
+
+    /// Return the distinct elements of the array, in sorted order, and the
+    /// number of times each of them occurs.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 1, 3], [2, 1, 1]];
+    /// let (values, counts) = a.unique_counts();
+    /// assert_eq!(values, array![1, 2, 3]);
+    /// assert_eq!(counts, array![3, 1, 2]);
+    /// ```
+    pub fn unique_counts(&self) -> (Array1<A>, Array1<usize>)
+    where
+        A: Ord + Clone,
+    {
+        let (values, counts, _) = self.unique_groups(Ord::cmp);
+        (values, counts)
+    }
+
+    /// Return the distinct elements of the array, in sorted order, and for
+    /// each element of the array the index of its value in the distinct
+    /// elements.
+    ///
+    /// The indices have the shape of the array. This is a label encoding of
+    /// the array: indexing the distinct elements with the indices gives
+    /// back the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array!["cat", "dog", "cat", "bird"];
+    /// let (classes, codes) = labels.unique_inverse();
+    /// assert_eq!(classes, array!["bird", "cat", "dog"]);
+    /// assert_eq!(codes, array![1, 2, 1, 0]);
+    /// assert_eq!(codes.map(|&i| classes[i]), labels);
+    /// ```
+    pub fn unique_inverse(&self) -> (Array1<A>, Array<usize, D>)
+    where
+        A: Ord + Clone,
+    {
+        let (values, _, inverse) = self.unique_groups(Ord::cmp);
+        (values, inverse)
+    }
+
+    /// Return the number of distinct elements in each lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 3], [2, 2, 2]];
+    /// assert_eq!(a.count_distinct_axis(Axis(1)), array![2, 1]);
+    /// assert_eq!(a.count_distinct_axis(Axis(0)), array![2, 2, 2]);
+    /// ```
+    pub fn count_distinct_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: Ord,
+        D: RemoveAxis,
+    {
+        let mut lane_elements = Vec::with_capacity(self.len_of(axis));
+        self.map_axis(axis, |lane| {
+            lane_elements.clear();
+            lane_elements.extend(lane);
+            lane_elements.sort_unstable();
+            lane_elements.dedup();
+            lane_elements.len()
+        })
+    }
+
+    /// Return the distinct elements of the array, sorted by `compare`, with
+    /// the number of times each of them occurs and the inverse indices, as
+    /// in [`.unique_counts()`](ArrayBase::unique_counts) and
+    /// [`.unique_inverse()`](ArrayBase::unique_inverse).
+    ///
+    /// Elements that compare equal are considered the same; the first of
+    /// them in logical order is kept. `compare` must be a total order, for
+    /// example the comparison of floats that can't be NaN, or a total order
+    /// of floats like `f64::total_cmp`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0.5, -1., 0.5, 2.];
+    /// let (values, counts, inverse) = a.unique_by(|x, y| x.partial_cmp(y).unwrap());
+    /// assert_eq!(values, array![-1., 0.5, 2.]);
+    /// assert_eq!(counts, array![1, 2, 1]);
+    /// assert_eq!(inverse, array![1, 0, 1, 2]);
+    /// ```
+    pub fn unique_by<F>(&self, compare: F) -> (Array1<A>, Array1<usize>, Array<usize, D>)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        self.unique_groups(compare)
+    }
+
+    /// Encode the elements as integer codes: return for each element the
+    /// index of its value in the distinct values, and the distinct values in
+    /// the order of their first occurrence in logical order.
+    ///
+    /// The codes have the shape of the array, and indexing the distinct
+    /// values with them gives back the array. Unlike
+    /// [`.unique_inverse()`](ArrayBase::unique_inverse), the elements only
+    /// need to be hashable, like strings and enums, and the array is
+    /// traversed once, cloning only the distinct values.
+    ///
+    /// This method requires crate feature `std`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use std::sync::Arc;
+    ///
+    /// let labels = array!["dog", "cat", "dog", "bird"].map(|&s| Arc::<str>::from(s));
+    /// let (codes, uniques) = labels.factorize();
+    /// assert_eq!(codes, array![0, 1, 0, 2]);
+    /// assert_eq!(uniques, array!["dog", "cat", "bird"].map(|&s| Arc::<str>::from(s)));
+    /// assert_eq!(codes.map(|&i| uniques[i].clone()), labels);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn factorize(&self) -> (Array<usize, D>, Array1<A>)
+    where
+        A: Hash + Eq + Clone,
+    {
+        let mut index = HashMap::<&A, usize>::new();
+        let mut uniques = Vec::new();
+        let codes = self
+            .iter()
+            .map(|elt| {
+                *index.entry(elt).or_insert_with(|| {
+                    uniques.push(elt.clone());
+                    uniques.len() - 1
+                })
+            })
+            .collect();
+        let codes = Array::from_shape_vec(self.raw_dim(), codes).unwrap();
+        (codes, Array1::from(uniques))
+    }
+
+    fn unique_groups<F>(&self, mut compare: F) -> (Array1<A>, Array1<usize>, Array<usize, D>)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let elements = self.iter().collect::<Vec<_>>();
+        let mut order = (0..elements.len()).collect::<Vec<_>>();
+        // A stable sort, so that the first equal element in logical order is kept
+        order.sort_by(|&i, &j| compare(elements[i], elements[j]));
+
+        let mut values = Vec::new();
+        let mut counts = Vec::new();
+        let mut inverse = vec![0; elements.len()];
+        let mut last: Option<&A> = None;
+        for i in order {
+            let elt = elements[i];
+            match last {
+                Some(prev) if compare(prev, elt) == Ordering::Equal => {
+                    *counts.last_mut().unwrap() += 1;
+                }
+                _ => {
+                    values.push(elt.clone());
+                    counts.push(1);
+                    last = Some(elt);
+                }
+            }
+            inverse[i] = values.len() - 1;
+        }
+        let inverse = Array::from_shape_vec(self.raw_dim(), inverse).unwrap();
+        (Array1::from(values), Array1::from(counts), inverse)
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between arrays and JavaScript typed arrays, for use with
+//! `wasm-bindgen` on the `wasm32` target.
+//!
+//! Each element type in [`TypedArrayElement`] corresponds to a typed array
+//! of the `js-sys` crate in [`JsTypedArray`], for example `f32` to
+//! `Float32Array`. The typed array of an *n*-dimensional array holds its
+//! elements in logical order, that is, row major order.
+//!
+//! - [`.to_typed_array()`](ArrayBase::to_typed_array) copies the elements
+//!   into a new typed array.
+//! - [`.typed_array_view()`](ArrayBase::typed_array_view) creates a typed
+//!   array over the memory of an array in standard layout, without
+//!   copying. It is `unsafe`, because the view is not tied to the lifetime
+//!   of the array, see its documentation.
+//! - [`Array1::from_typed_array`] and [`Array::from_shape_typed_array`]
+//!   copy the elements of a typed array into a new array. The memory of a
+//!   typed array is outside of the WebAssembly memory, so it can't be viewed
+//!   without copying.
+//!
+//! This requires crate feature `wasm`. The functions of `js-sys` can only
+//! be called when compiled for `wasm32`; on other targets they panic.
+//!
+//! ```no_run
+//! use js_sys::Float32Array;
+//! use ndarray::{array, Array};
+//!
+//! let a = array![[1f32, 2., 3.], [4., 5., 6.]];
+//! let typed: Float32Array = a.to_typed_array();
+//! assert_eq!(typed.length(), 6);
+//!
+//! let b = Array::from_shape_typed_array((2, 3), &typed).unwrap();
+//! assert_eq!(a, b);
+//! ```
+
+use alloc::vec::Vec;
+
+use js_sys::{
+    Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, Uint16Array, Uint32Array,
+    Uint8Array,
+};
+use wasm_bindgen::JsCast;
+
+use crate::imp_prelude::*;
+use crate::{ShapeError, StrideShape};
+
+/// Elements that have a JavaScript typed array: `i8`, `i16`, `i32`, `u8`,
+/// `u16`, `u32`, `f32` and `f64`.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+///
+/// This requires crate feature `wasm`.
+pub trait TypedArrayElement: Copy {
+    /// The typed array of this element type, for example `Float32Array` for
+    /// `f32`.
+    type TypedArray: JsTypedArray<Elem = Self>;
+
+    /// Return a new typed array with a copy of `xs`.
+    #[doc(hidden)]
+    fn copy_to_typed_array(xs: &[Self]) -> Self::TypedArray;
+    /// Return a typed array that views the memory of `xs`.
+    #[doc(hidden)]
+    unsafe fn view_as_typed_array(xs: &[Self]) -> Self::TypedArray;
+
+    private_decl! {}
+}
+
+/// The JavaScript typed arrays of the [`TypedArrayElement`] types, like
+/// `Float32Array`.
+///
+/// This trait is sealed and can't be implemented outside of ndarray.
+///
+/// This requires crate feature `wasm`.
+pub trait JsTypedArray: JsCast {
+    /// The element type of the typed array.
+    type Elem: TypedArrayElement<TypedArray = Self>;
+
+    /// Return a copy of the elements.
+    #[doc(hidden)]
+    fn copy_to_vec(&self) -> Vec<Self::Elem>;
+
+    private_decl! {}
+}
+
+macro_rules! impl_typed_array_element {
+    ($($elem:ty => $typed:ident),* $(,)?) => {
+        $(
+            impl TypedArrayElement for $elem {
+                type TypedArray = $typed;
+
+                fn copy_to_typed_array(xs: &[Self]) -> $typed {
+                    $typed::from(xs)
+                }
+
+                unsafe fn view_as_typed_array(xs: &[Self]) -> $typed {
+                    $typed::view(xs)
+                }
+
+                private_impl! {}
+            }
+
+            impl JsTypedArray for $typed {
+                type Elem = $elem;
+
+                fn copy_to_vec(&self) -> Vec<$elem> {
+                    self.to_vec()
+                }
+
+                private_impl! {}
+            }
+        )*
+    };
+}
+
+impl_typed_array_element! {
+    i8 => Int8Array,
+    i16 => Int16Array,
+    i32 => Int32Array,
+    u8 => Uint8Array,
+    u16 => Uint16Array,
+    u32 => Uint32Array,
+    f32 => Float32Array,
+    f64 => Float64Array,
+}
+
+/// # Conversions to JavaScript Typed Arrays
+///
+/// These methods require crate feature `wasm`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: TypedArrayElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a new JavaScript typed array with a copy of the elements, in
+    /// logical order.
+    ///
+    /// The elements are copied directly if the array is in standard layout,
+    /// and collected into a buffer first otherwise.
+    ///
+    /// This requires crate feature `wasm`.
+    pub fn to_typed_array(&self) -> A::TypedArray {
+        match self.as_slice() {
+            Some(xs) => A::copy_to_typed_array(xs),
+            None => A::copy_to_typed_array(&self.iter().cloned().collect::<Vec<_>>()),
+        }
+    }
+
+    /// Return a JavaScript typed array that views the elements of the array
+    /// in the WebAssembly memory, without copying; or `None` if the array is
+    /// not in standard layout.
+    ///
+    /// This requires crate feature `wasm`.
+    ///
+    /// # Safety
+    ///
+    /// The typed array is not tied to the lifetime of the array, and the
+    /// caller must ensure that:
+    ///
+    /// - it is only used while the array is alive and not modified;
+    /// - it is not written to, because the array is borrowed immutably;
+    /// - no memory is allocated in the WebAssembly module while it is in
+    ///   use: if the memory grows, the view refers to a detached buffer.
+    ///
+    /// The usual pattern is to pass the view to a JavaScript function that
+    /// copies or consumes it before returning, like `WebGL`'s `bufferData`.
+    pub unsafe fn typed_array_view(&self) -> Option<A::TypedArray> {
+        self.as_slice().map(|xs| A::view_as_typed_array(xs))
+    }
+}
+
+impl<A> Array1<A>
+where
+    A: TypedArrayElement,
+{
+    /// Create an array with a copy of the elements of the JavaScript typed
+    /// array `array`.
+    ///
+    /// This requires crate feature `wasm`.
+    pub fn from_typed_array<T>(array: &T) -> Self
+    where
+        T: JsTypedArray<Elem = A>,
+    {
+        Array1::from(array.copy_to_vec())
+    }
+}
+
+impl<A, D> Array<A, D>
+where
+    A: TypedArrayElement,
+    D: Dimension,
+{
+    /// Create an array of shape `shape` with a copy of the elements of the
+    /// JavaScript typed array `array`, like
+    /// [`Array::from_shape_vec`].
+    ///
+    /// **Errors** if the shape does not correspond to the number of elements
+    /// of `array`.
+    ///
+    /// This requires crate feature `wasm`.
+    pub fn from_shape_typed_array<Sh, T>(shape: Sh, array: &T) -> Result<Self, ShapeError>
+    where
+        Sh: Into<StrideShape<D>>,
+        T: JsTypedArray<Elem = A>,
+    {
+        Array::from_shape_vec(shape, array.copy_to_vec())
+    }
+}
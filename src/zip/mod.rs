@@ -14,7 +14,8 @@ mod ndproducer;
 use std::mem::MaybeUninit;
 
 use crate::imp_prelude::*;
-use crate::AssignElem;
+use crate::iter::AxisIter;
+use crate::{AsArray, AssignElem};
 use crate::IntoDimension;
 use crate::Layout;
 use crate::partial::Partial;
@@ -22,6 +23,10 @@ use crate::partial::Partial;
 use crate::indexes::{indices, Indices};
 use crate::split_at::{SplitPreference, SplitAt};
 use crate::dimension;
+use crate::dimension::broadcast::co_broadcast;
+use crate::error::ShapeError;
+use crate::DimMax;
+use crate::order;
 
 pub use self::ndproducer::{NdProducer, IntoNdProducer, Offset};
 
@@ -131,6 +136,9 @@ trait ZippableTuple: Sized {
 /// The order elements are visited is not specified. The producers don’t have to
 /// have the same item type.
 ///
+/// A zip can hold up to 12 producers. Methods that add an output array, like
+/// `map_collect`, can be used with up to 11.
+///
 /// The `Zip` has two methods for function application: `for_each` and
 /// `fold_while`. The zip object can be split, which allows parallelization.
 /// A read-only zip object (no mutable producers) can be cloned.
@@ -259,6 +267,32 @@ where
     );
 }
 
+/// Return the axis iterator of an array in `zip_axis_iter!`.
+///
+/// **Panics** if `axis` is out of bounds, or if the length along `axis` is
+/// not `len`, if given.
+#[doc(hidden)]
+pub fn zip_axis_iter_part<'a, A, D, V>(
+    array: V,
+    axis: Axis,
+    len: Option<usize>,
+) -> AxisIter<'a, A, D::Smaller>
+where
+    A: 'a,
+    D: RemoveAxis,
+    V: AsArray<'a, A, D>,
+{
+    let view = array.into();
+    if let Some(len) = len {
+        assert_eq!(
+            view.len_of(axis),
+            len,
+            "zip_axis_iter: the arrays must have the same length along axis {}",
+            axis.index()
+        );
+    }
+    AxisIter::new(view, axis)
+}
 
 impl<Parts, D> Zip<Parts, D>
 where
@@ -269,6 +303,12 @@ where
         self.dimension.size()
     }
 
+    /// Return the producers of the Zip
+    #[cfg(feature = "simd")]
+    pub(crate) fn into_parts(self) -> Parts {
+        self.parts
+    }
+
     /// Return the length of `axis`
     ///
     /// ***Panics*** if `axis` is out of bounds.
@@ -281,6 +321,16 @@ where
             (self.layout.is(Layout::FORDER) || self.layout_tendency < 0)
     }
 
+    /// Whether to collect the output in f-order: as the inputs prefer, or in
+    /// the default memory order if they have no preference.
+    fn prefer_f_for_output(&self) -> bool {
+        if self.layout_tendency == 0 && !self.layout.is(Layout::CORDER | Layout::FORDER) {
+            order::default_order().is_column_major()
+        } else {
+            self.prefer_f()
+        }
+    }
+
     /// Return an *approximation* to the max stride axis; if
     /// component arrays disagree, there may be no choice better than the
     /// others.
@@ -431,7 +481,7 @@ where
     #[cfg(feature = "rayon")]
     pub(crate) fn uninitalized_for_current_layout<T>(&self) -> Array<MaybeUninit<T>, D>
     {
-        let is_f = self.prefer_f();
+        let is_f = self.prefer_f_for_output();
         Array::uninit(self.dimension.clone().set_f(is_f))
     }
 }
@@ -506,6 +556,12 @@ offset_impl! {
     [A B C D][ a b c d],
     [A B C D E][ a b c d e],
     [A B C D E F][ a b c d e f],
+    [A B C D E F G][ a b c d e f g],
+    [A B C D E F G H][ a b c d e f g h],
+    [A B C D E F G H I][ a b c d e f g h i],
+    [A B C D E F G H I J][ a b c d e f g h i j],
+    [A B C D E F G H I J K][ a b c d e f g h i j k],
+    [A B C D E F G H I J K L][ a b c d e f g h i j k l],
 }
 
 macro_rules! zipt_impl {
@@ -565,6 +621,12 @@ zipt_impl! {
     [A B C D][ a b c d],
     [A B C D E][ a b c d e],
     [A B C D E F][ a b c d e f],
+    [A B C D E F G][ a b c d e f g],
+    [A B C D E F G H][ a b c d e f g h],
+    [A B C D E F G H I][ a b c d e f g h i],
+    [A B C D E F G H I J][ a b c d e f g h i j],
+    [A B C D E F G H I J K][ a b c d e f g h i j k],
+    [A B C D E F G H I J K L][ a b c d e f g h i j k l],
 }
 
 macro_rules! map_impl {
@@ -746,7 +808,7 @@ macro_rules! map_impl {
             {
                 // safe because: all elements are written before the array is completed
 
-                let shape = self.dimension.clone().set_f(self.prefer_f());
+                let shape = self.dimension.clone().set_f(self.prefer_f_for_output());
                 let output = <ArrayBase<S, D>>::build_uninit(shape, |output| {
                     // Use partial to count the number of filled elements, and can drop the right
                     // number of elements on unwinding (if it happens during apply/collect).
@@ -762,6 +824,56 @@ macro_rules! map_impl {
                 }
             }
 
+            /// Map and collect the results into a new array, which has the same size as the
+            /// inputs, stopping at the first error.
+            ///
+            /// The closure returns a `Result`; if it returns `Err`, no further elements are
+            /// visited, the elements collected so far are dropped and the error is returned.
+            /// For a closure that returns an `Option`, use `.ok_or(())` on the closure's
+            /// result and `.ok()` on the collected result.
+            ///
+            /// If all inputs are c- or f-order respectively, that is preserved in the output.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            /// use std::convert::TryFrom;
+            ///
+            /// let a = array![[1, 2], [3, 4]];
+            /// let b = array![[10, 20], [30, 40i32]];
+            ///
+            /// let c = Zip::from(&a).and(&b).try_map_collect(|&a, &b| u8::try_from(a * b));
+            /// assert_eq!(c, Ok(array![[10, 40], [90, 160]]));
+            ///
+            /// let d = Zip::from(&a).and(&b).try_map_collect(|&a, &b| u8::try_from(a * b * 2));
+            /// assert!(d.is_err());
+            ///
+            /// let e = Zip::from(&a).and(&b).try_map_collect(|&a, &b| b.checked_div(a - 1).ok_or(()));
+            /// assert_eq!(e.ok(), None);
+            /// ```
+            pub fn try_map_collect<R, E>(self, f: impl FnMut($($p::Item,)* ) -> Result<R, E>)
+                -> Result<Array<R, D>, E>
+            {
+                let shape = self.dimension.clone().set_f(self.prefer_f_for_output());
+                let mut result = Ok(());
+                let output = <Array<R, D>>::build_uninit(shape, |output| {
+                    // Use partial to count the number of filled elements, and drop them if
+                    // there is an error (or if we unwind).
+                    unsafe {
+                        let output_view = output.into_raw_view_mut().cast::<R>();
+                        let (partial, res) = self.and(output_view).try_collect_with_partial(f);
+                        match res {
+                            Ok(()) => { partial.release_ownership(); }
+                            Err(e) => {
+                                drop(partial);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                });
+                // safe because: all elements are written if there is no error
+                result.map(|()| unsafe { output.assume_init() })
+            }
+
             /// Map and collect the results into a new array, which has the same size as the
             /// inputs.
             ///
@@ -868,6 +980,40 @@ macro_rules! map_impl {
 
                     partial
                 }
+
+                /// The inner workings of try_map_collect
+                ///
+                /// Like `collect_with_partial`, but stops at the first error, which is returned
+                /// together with the Partial of the elements written up to that point.
+                ///
+                /// ## Safety
+                ///
+                /// See `collect_with_partial`.
+                pub(crate) unsafe fn try_collect_with_partial<F, E>(self, mut f: F)
+                    -> (Partial<R>, Result<(), E>)
+                    where F: FnMut($($p::Item,)* ) -> Result<R, E>
+                {
+                    let (.., ref output) = &self.parts;
+                    let mut partial = Partial::new(output.as_ptr());
+
+                    // Elements are visited in the memory order of the output, so the elements
+                    // written before an error are a contiguous prefix that Partial can drop.
+                    let partial_len = &mut partial.len;
+                    let result = self.fold_while(Ok(()), move |_, $($p,)* output_elem: *mut R| {
+                        match f($($p),*) {
+                            Ok(elem) => {
+                                output_elem.write(elem);
+                                if std::mem::needs_drop::<R>() {
+                                    *partial_len += 1;
+                                }
+                                FoldWhile::Continue(Ok(()))
+                            }
+                            Err(e) => FoldWhile::Done(Err(e)),
+                        }
+                    }).into_inner();
+
+                    (partial, result)
+                }
             }
         );
 
@@ -918,7 +1064,82 @@ map_impl! {
     [true P1 P2 P3],
     [true P1 P2 P3 P4],
     [true P1 P2 P3 P4 P5],
-    [false P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6 P7],
+    [true P1 P2 P3 P4 P5 P6 P7 P8],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11],
+    [false P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12],
+}
+
+macro_rules! co_broadcast_impl {
+    ($([$p1:ident $a1:ident $($p:ident $a:ident)*],)+) => {
+        $(
+        #[allow(non_snake_case)]
+        impl<'a, D, $a1, $($a),*> Zip<(ArrayView<'a, $a1, D>, $(ArrayView<'a, $a, D>,)*), D>
+            where D: Dimension,
+        {
+            /// Include the read-only producer `p` in the Zip, broadcasting it
+            /// and all the producers already in the Zip to their common shape.
+            ///
+            /// Unlike [`.and_broadcast()`](Zip::and_broadcast), where `p` is
+            /// broadcast to the shape of the Zip, the shape of the result is
+            /// the co-broadcast shape of all the operands, so `p` may also be
+            /// larger than the producers before it. This requires that all
+            /// the producers in the Zip are array views.
+            ///
+            /// ***Errors*** if the shapes can't be broadcast together.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![[1], [2], [3]];
+            /// let b = array![10, 20];
+            /// let c = array![[100, 200]];
+            /// let sum = Zip::from(&a)
+            ///     .and_co_broadcast(&b).unwrap()
+            ///     .and_co_broadcast(&c).unwrap()
+            ///     .map_collect(|&a, &b, &c| a + b + c);
+            /// assert_eq!(sum, array![[111, 221], [112, 222], [113, 223]]);
+            ///
+            /// assert!(Zip::from(&a).and_co_broadcast(&array![[1, 2, 3], [4, 5, 6]]).is_err());
+            /// ```
+            pub fn and_co_broadcast<P, E, Elem>(self, p: P)
+                -> Result<Zip<(ArrayView<'a, $a1, <D as DimMax<E>>::Output>,
+                               $(ArrayView<'a, $a, <D as DimMax<E>>::Output>,)*
+                               ArrayView<'a, Elem, <D as DimMax<E>>::Output>, ),
+                              <D as DimMax<E>>::Output>,
+                          ShapeError>
+                where P: IntoNdProducer<Dim=E, Output=ArrayView<'a, Elem, E>, Item=&'a Elem>,
+                      E: Dimension,
+                      D: DimMax<E>,
+            {
+                let part = p.into_producer();
+                let shape = co_broadcast::<D, E, <D as DimMax<E>>::Output>(
+                    &self.dimension, &part.raw_dim())?;
+                let ($p1, $($p,)*) = self.parts;
+                Ok(Zip::from($p1.broadcast_unwrap(shape.clone()))
+                    $(.and($p.broadcast_unwrap(shape.clone())))*
+                    .and(part.broadcast_unwrap(shape)))
+            }
+        }
+        )+
+    }
+}
+
+co_broadcast_impl! {
+    [P1 A1],
+    [P1 A1 P2 A2],
+    [P1 A1 P2 A2 P3 A3],
+    [P1 A1 P2 A2 P3 A3 P4 A4],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5 P6 A6],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5 P6 A6 P7 A7],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5 P6 A6 P7 A7 P8 A8],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5 P6 A6 P7 A7 P8 A8 P9 A9],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5 P6 A6 P7 A7 P8 A8 P9 A9 P10 A10],
+    [P1 A1 P2 A2 P3 A3 P4 A4 P5 A5 P6 A6 P7 A7 P8 A8 P9 A9 P10 A10 P11 A11],
 }
 
 /// Value controlling the execution of `.fold_while` on `Zip`.
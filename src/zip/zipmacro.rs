@@ -132,3 +132,66 @@ macro_rules! azip {
         $crate::azip!(@build for_each $($t)*)
     };
 }
+
+/// Axis iterator zip macro: iterate over the subviews along an axis of
+/// several arrays in lock step.
+///
+/// `zip_axis_iter!((a, b, c), axis)` is an iterator of tuples `(a_i, b_i,
+/// c_i)` of the subviews at each index `i` along `axis`, as from
+/// [`.axis_iter()`](crate::ArrayBase::axis_iter) of each array. The arrays are
+/// given as references to arrays or as array views, for example `&a` or
+/// `a.t()`; their other axes don't need to match.
+///
+/// The iterator is an `ExactSizeIterator` and a `DoubleEndedIterator`.
+/// Unlike zipping the axis iterators, which stops silently at the end of
+/// the shortest, the lengths along `axis` are checked when the iterator is
+/// created.
+///
+/// **Panics** if `axis` is out of bounds for any of the arrays, or if their
+/// lengths along `axis` are not the same.
+///
+/// ```
+/// use ndarray::{array, zip_axis_iter, Axis};
+///
+/// let features = array![[1., 2.], [3., 4.], [5., 6.]];
+/// let labels = array![0, 1, 1];
+/// let weights = array![0.5, 1., 2.];
+///
+/// let mut total = 0.;
+/// for (x, y, w) in zip_axis_iter!((&features, &labels, &weights), Axis(0)) {
+///     total += w[()] * x.sum() * y[()] as f64;
+/// }
+/// assert_eq!(total, 7. + 2. * 11.);
+/// assert_eq!(zip_axis_iter!((&features, &labels), Axis(0)).len(), 3);
+/// ```
+///
+/// ```should_panic
+/// use ndarray::{Array1, Array2, zip_axis_iter, Axis};
+///
+/// let features = Array2::<f64>::zeros((3, 2));
+/// let labels = Array1::<u8>::zeros(4);
+/// // Panics: 3 samples but 4 labels
+/// let _ = zip_axis_iter!((&features, &labels), Axis(0));
+/// ```
+#[macro_export]
+macro_rules! zip_axis_iter {
+    // The closure that flattens the nested pairs from `Iterator::zip`; each
+    // `b` is a distinct identifier by macro hygiene.
+    (@closure $p:pat => $tup:expr) => {
+        |$p| $tup
+    };
+    (@closure $p:pat => ($($tup:tt)*), $_iter:expr $(, $tail:expr)*) => {
+        $crate::zip_axis_iter!(@closure ($p, b) => ($($tup)*, b) $(, $tail)*)
+    };
+    (($first:expr $(,)?), $axis:expr $(,)?) => {
+        $crate::zip_axis_iter_part($first, $axis, None).map(|a| (a,))
+    };
+    (($first:expr $(, $rest:expr)+ $(,)?), $axis:expr $(,)?) => {{
+        let axis: $crate::Axis = $axis;
+        let first = $crate::zip_axis_iter_part($first, axis, None);
+        let len = first.len();
+        first
+            $(.zip($crate::zip_axis_iter_part($rest, axis, Some(len))))+
+            .map($crate::zip_axis_iter!(@closure a => (a) $(, $rest)+))
+    }};
+}
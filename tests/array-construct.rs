@@ -157,6 +157,37 @@ fn deny_wraparound_from_vec() {
     assert!(six.is_err());
 }
 
+#[test]
+fn test_from_vec_of_arrays() {
+    let points = vec![[1., 2., 3.], [4., 5., 6.]];
+    let ptr = points.as_ptr() as *const f64;
+    let a = Array2::from(points);
+    assert_eq!(a, array![[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(a.as_ptr(), ptr);
+
+    let wide = vec![[7u8; 40]; 3];
+    let b = Array2::from(wide);
+    assert_eq!(b.dim(), (3, 40));
+    assert!(b.iter().all(|&x| x == 7));
+}
+
+#[test]
+fn test_view_from_slice_of_arrays() {
+    let mut pixels = [[0u8, 1, 2]; 4];
+    {
+        let v = ArrayView2::from(&pixels[..]);
+        assert_eq!(v.dim(), (4, 3));
+        assert_eq!(v.column(2).sum(), 8);
+        assert_eq!(v.as_ptr(), pixels.as_ptr() as *const u8);
+    }
+    let mut v = ArrayViewMut2::from(&mut pixels[..]);
+    v.column_mut(0).fill(9);
+    assert_eq!(pixels[3], [9, 1, 2]);
+
+    let empty: &[[f32; 20]] = &[];
+    assert_eq!(ArrayView2::from(empty).dim(), (0, 20));
+}
+
 #[test]
 fn test_ones() {
     let mut a = Array::<f32, _>::zeros((2, 3, 4));
@@ -286,3 +317,112 @@ fn maybe_uninit_1() {
 
     }
 }
+
+#[test]
+fn from_rows_and_columns() {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let a = Array2::from_rows(&rows).unwrap();
+    assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    assert!(a.is_standard_layout());
+
+    let b = Array2::from_columns(a.rows()).unwrap();
+    assert_eq!(b, a.t());
+    assert!(b.t().is_standard_layout());
+
+    let c = Array2::from_rows(a.columns()).unwrap();
+    assert_eq!(c, b);
+
+    let empty = Array2::<i32>::from_rows(&Vec::<Vec<i32>>::new()).unwrap();
+    assert_eq!(empty.shape(), &[0, 0]);
+    let empty_rows = Array2::<i32>::from_rows(vec![&[][..], &[]]).unwrap();
+    assert_eq!(empty_rows.shape(), &[2, 0]);
+    let empty_columns = Array2::<i32>::from_columns(vec![&[][..], &[]]).unwrap();
+    assert_eq!(empty_columns.shape(), &[0, 2]);
+
+    let ragged = vec![vec![1, 2], vec![3, 4], vec![5]];
+    assert!(Array2::from_rows(&ragged).is_err());
+    assert!(Array2::from_columns(&ragged).is_err());
+
+    // An iterator whose size hint overstates the number of lanes
+    struct Overstated<I>(I);
+    impl<I: Iterator> Iterator for Overstated<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (usize::MAX, Some(usize::MAX))
+        }
+    }
+    assert_eq!(Array2::from_rows(Overstated(rows.iter())).unwrap(), a);
+}
+
+#[test]
+fn from_iter_with_shape() {
+    let a = Array::from_iter_with_shape((2, 3, 4), 0..24).unwrap();
+    assert_eq!(a, Array::from_shape_vec((2, 3, 4), (0..24).collect()).unwrap());
+    assert!(a.is_standard_layout());
+
+    let b = Array::from_iter_with_shape((2, 3, 4).f(), 0..24).unwrap();
+    assert_eq!(b, Array::from_shape_vec((2, 3, 4).f(), (0..24).collect()).unwrap());
+    assert!(b.t().is_standard_layout());
+
+    let c = Array::from_iter_with_shape(IxDyn(&[2, 0, 3]), Vec::<f32>::new()).unwrap();
+    assert_eq!(c.shape(), &[2, 0, 3]);
+
+    assert!(Array::from_iter_with_shape((2, 3), 0..5).is_err());
+    assert!(Array::from_iter_with_shape((2, 3), 0..7).is_err());
+    // The iterator is read no further than one element after the shape is full
+    let mut read = 0;
+    let res = Array::from_iter_with_shape(4, (0..).inspect(|_| read += 1));
+    assert!(res.is_err());
+    assert_eq!(read, 5);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn scoped_default_order() {
+    use ndarray::{with_default_order, Order};
+
+    let (a, b, c) = with_default_order(Order::F, || {
+        let a = Array3::<u8>::zeros((2, 3, 4));
+        let b = Array::from_shape_fn((2, 3), |(i, j)| i * 3 + j);
+        let x = Array::from_shape_vec((2, 3), (0..6).collect()).unwrap();
+        let c = Zip::from(&x).and(&b.t().to_owned().reversed_axes()).map_collect(|&x, &y| x + y);
+        assert_eq!(x.strides(), &[3, 1]);
+        (a, b, c)
+    });
+    assert_eq!(a.strides(), &[1, 2, 6]);
+    assert_eq!(b.strides(), &[1, 2]);
+    assert_eq!(b, arr2(&[[0, 1, 2], [3, 4, 5]]));
+    assert_eq!(c.strides(), &[1, 2]);
+    assert_eq!(c, &b * 2);
+
+    let r = std::panic::catch_unwind(|| with_default_order(Order::F, || panic!()));
+    assert!(r.is_err());
+    assert_eq!(ndarray::default_order(), Order::C);
+    assert_eq!(Array2::<u8>::zeros((2, 3)).strides(), &[3, 1]);
+}
+
+#[test]
+fn array_builder() {
+    let a = ArrayBuilder::shape((2, 3)).zeros::<f64>();
+    assert_eq!(a, Array2::zeros((2, 3)));
+    assert!(a.is_standard_layout());
+
+    let b = ArrayBuilder::shape((2, 3)).order(Order::ColumnMajor).ones::<i32>();
+    assert_eq!(b, Array2::ones((2, 3)));
+    assert_eq!(b.strides(), &[1, 2]);
+
+    // An explicit order overrides the order of the shape
+    let c = ArrayBuilder::shape((2, 3).f()).order(Order::RowMajor).fill_with(|(i, j)| i + j);
+    assert_eq!(c, Array::from_shape_fn((2, 3), |(i, j)| i + j));
+    assert_eq!(c.strides(), &[3, 1]);
+
+    let d = ArrayBuilder::shape(IxDyn(&[2, 1, 2])).order(Order::F).with_vec(vec![1, 2, 3, 4]);
+    assert_eq!(d.unwrap(), Array::from_shape_vec(IxDyn(&[2, 1, 2]).f(), vec![1, 2, 3, 4]).unwrap());
+    assert!(ArrayBuilder::shape(3).with_vec(vec![1, 2]).is_err());
+
+    let e = ndarray::with_default_order(Order::ColumnMajor, || ArrayBuilder::shape((2, 2)).fill(0));
+    assert_eq!(e.strides(), &[1, 2]);
+}
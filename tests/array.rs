@@ -12,6 +12,7 @@ use itertools::{zip, Itertools};
 use ndarray::prelude::*;
 use ndarray::{arr3, rcarr2};
 use ndarray::indices;
+use ndarray::PadMode;
 use ndarray::{Slice, SliceInfo, SliceInfoElem};
 use std::convert::TryFrom;
 
@@ -722,6 +723,145 @@ fn test_select_1d() {
     assert_eq!(r3, arr1(&[]));
 }
 
+#[test]
+fn test_select_where() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let mask = a.mapv(|x| x % 3 == 0);
+    assert_eq!(a.select_where(&mask), arr1(&[0, 3, 6, 9]));
+    // logical order, also for other memory layouts
+    let t = a.t();
+    assert_eq!(t.select_where(&mask.t()), arr1(&[0, 9, 6, 3]));
+    // broadcast mask
+    assert_eq!(a.select_where(&arr2(&[[false], [true], [false]])), arr1(&[4, 5, 6, 7]));
+    assert_eq!(a.select_where(&arr0(false)), arr1(&[]));
+}
+
+#[test]
+#[should_panic]
+fn test_select_where_broadcast_fail() {
+    let a = Array2::<f32>::zeros((3, 4));
+    a.select_where(&arr1(&[true, false, true]));
+}
+
+#[test]
+fn take_put_along_axis() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| ((i * 7 + j * 5) % 11) as i32);
+    // sort each row and each column using argsort indices
+    for &axis in &[Axis(0), Axis(1)] {
+        let mut indices = Array::zeros(a.raw_dim());
+        for (mut idx, lane) in indices.lanes_mut(axis).into_iter().zip(a.lanes(axis)) {
+            let mut order = (0..lane.len()).collect::<Vec<_>>();
+            order.sort_by_key(|&i| lane[i]);
+            idx.assign(&aview1(&order));
+        }
+        let sorted = a.take_along_axis(axis, &indices);
+        for lane in sorted.lanes(axis) {
+            assert!(lane.iter().tuple_windows().all(|(x, y)| x <= y));
+        }
+        // scattering the sorted values back restores the array
+        let mut b = Array::zeros(a.raw_dim());
+        b.put_along_axis(axis, &indices, &sorted);
+        assert_eq!(b, a);
+    }
+
+    // broadcasting
+    let b = a.take_along_axis(Axis(0), &arr2(&[[2], [0]]));
+    assert_eq!(b, ndarray::stack![Axis(0), a.row(2), a.row(0)]);
+    let c = a.slice(s![..1, ..]).take_along_axis(Axis(1), &arr2(&[[3, 3], [1, 0]]));
+    assert_eq!(c, arr2(&[[a[[0, 3]], a[[0, 3]]], [a[[0, 1]], a[[0, 0]]]]));
+    let mut d = Array::zeros((2, 3));
+    d.put_along_axis(Axis(1), &arr2(&[[2], [0]]), &arr0(1));
+    assert_eq!(d, arr2(&[[0, 0, 1], [1, 0, 0]]));
+
+    // empty indices
+    let e = a.take_along_axis(Axis(1), &Array2::zeros((3, 0)));
+    assert_eq!(e.shape(), &[3, 0]);
+}
+
+#[test]
+#[should_panic]
+fn take_along_axis_out_of_bounds() {
+    let a = Array2::<f32>::zeros((3, 4));
+    a.take_along_axis(Axis(0), &arr2(&[[0, 1, 2, 3]]));
+}
+
+#[test]
+#[should_panic]
+fn put_along_axis_out_of_bounds() {
+    let mut a = Array2::<f32>::zeros((3, 4));
+    a.put_along_axis(Axis(1), &arr2(&[[4], [0], [0]]), &arr0(1.));
+}
+
+#[test]
+fn test_assign_where() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    let mask = a.mapv(|x| x >= 6.);
+    let mut b = a.clone();
+    b.fill_where(&mask, -1.);
+    assert_eq!(b.select_where(&mask), Array1::from_elem(6, -1.));
+    assert_eq!(b.slice(s![0, ..]), a.slice(s![0, ..]));
+
+    a.slice_mut(s![.., ..;-1])
+        .assign_where(&arr1(&[true, false, false, true]), &arr2(&[[10.], [20.], [30.]]));
+    assert_eq!(a.column(0), arr1(&[10., 20., 30.]));
+    assert_eq!(a.column(3), arr1(&[10., 20., 30.]));
+    assert_eq!(a.column(1), arr1(&[1., 5., 9.]));
+
+    let mut c = Array2::<i32>::zeros((2, 3));
+    c.zip_mut_with_where(&arr2(&[[true, false, true], [false, true, false]]), &arr1(&[1, 2, 3]), |x, &y| *x += y);
+    assert_eq!(c, arr2(&[[1, 0, 3], [0, 2, 0]]));
+}
+
+#[test]
+fn test_replace_where() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    a[[1, 1]] = f64::NAN;
+    a[[2, 3]] = f64::NAN;
+    assert_eq!(a.replace_where(|x| x.is_nan(), 0.), 2);
+    assert_eq!(a[[1, 1]], 0.);
+    assert_eq!(a[[2, 3]], 0.);
+    assert_eq!(a.replace_where(|x| x.is_nan(), 0.), 0);
+
+    let mut b = a.t().to_owned();
+    let n = b.replace_where_from(|&x| x > 5., &arr1(&[-1., -2., -3.]));
+    assert_eq!(n, 5);
+    assert_eq!(b.column(2), arr1(&[-3., -3., -3., 0.]));
+    assert_eq!(b.column(1), arr1(&[4., 0., -2., -2.]));
+    // Only the elements that differ from their replacement count
+    assert_eq!(b.replace_where_from(|&x| x < 0., &arr1(&[-1., -2., 0.])), 3);
+    assert_eq!(b.column(2), arr1(&[0., 0., 0., 0.]));
+
+    let mut c = Array2::from_elem((2, 2), 1);
+    assert_eq!(c.fill_where(&arr1(&[true, false]), 0), 2);
+    assert_eq!(c.assign_where(&arr2(&[[true], [false]]), &arr0(2)), 2);
+    assert_eq!(c, arr2(&[[2, 2], [0, 1]]));
+}
+
+#[test]
+fn test_where_assign_if() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let cond = a.mapv(|x| x % 3 == 0);
+    let w = ndarray::where_(&cond, &a, &arr0(-1));
+    assert_eq!(w, a.mapv(|x| if x % 3 == 0 { x } else { -1 }));
+
+    // Broadcast to a shape that none of the inputs has
+    let w = ndarray::where_(&arr1(&[true, false, true]), &arr2(&[[1], [2]]), &arr2(&[[0], [0]]));
+    assert_eq!(w, arr2(&[[1, 0, 1], [2, 0, 2]]));
+    let w = ndarray::where_(&arr2(&[[true], [false]]), &arr1(&[1, 2, 3]), &ArrayD::from_elem(IxDyn(&[1, 1, 3]), 9));
+    assert_eq!(w.shape(), &[1, 2, 3]);
+    assert_eq!(w, arr3(&[[[1, 2, 3], [9, 9, 9]]]).into_dyn());
+
+    let mut b = Array2::zeros((3, 4));
+    b.slice_mut(s![.., ..;-1]).assign_if(&cond.slice(s![.., ..;-1]), &a.slice(s![.., ..;-1]), &arr1(&[-1]));
+    assert_eq!(b, ndarray::where_(&cond, &a, &arr0(-1)));
+}
+
+#[test]
+#[should_panic]
+fn test_where_broadcast_fail() {
+    ndarray::where_(&arr1(&[true, false]), &arr1(&[1, 2, 3]), &arr0(0));
+}
+
 #[test]
 fn diag() {
     let d = arr2(&[[1., 2., 3.0f32]]).into_diag();
@@ -735,6 +875,99 @@ fn diag() {
     assert_eq!(d.dim(), 1);
 }
 
+#[test]
+fn soa_split_join() {
+    let a = Array::from_shape_fn((3, 2), |(i, j)| (i as f32, (10 * i + j) as u8));
+    let (x, y) = a.soa_split();
+    assert_eq!(x, arr2(&[[0., 0.], [1., 1.], [2., 2.]]));
+    assert_eq!(y, arr2(&[[0, 1], [10, 11], [20, 21]]));
+    assert_eq!(Array::soa_join(&x, &y), a);
+
+    let t = a.t();
+    let (xv, yv) = t.soa_views().unwrap();
+    assert_eq!(xv, x.t());
+    assert_eq!(yv, y.t());
+
+    let mut b = a.clone();
+    {
+        let mut every_other = b.slice_mut(s![..;-2, ..]);
+        let (mut xv, mut yv) = every_other.soa_views_mut().unwrap();
+        xv.fill(-1.);
+        yv += 100;
+    }
+    assert_eq!(b[[0, 1]], (-1., 101));
+    assert_eq!(b[[1, 1]], (1., 11));
+    assert_eq!(b[[2, 0]], (-1., 120));
+
+    // Component size doesn't divide the element size
+    let c = Array1::from(vec![([0u8; 3], 1u32); 4]);
+    assert!(c.soa_views().is_none());
+    let empty = Array2::<(u16, u64)>::from_elem((0, 3), (0, 0));
+    let (e1, e2) = empty.soa_views().unwrap();
+    assert_eq!(e1.shape(), &[0, 3]);
+    assert_eq!(e2.shape(), &[0, 3]);
+}
+
+#[test]
+fn diag_offset() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| 1 + 4 * i + j);
+    assert_eq!(a.diag_offset(0), a.diag());
+    assert_eq!(a.diag_offset(1), arr1(&[2, 7, 12]));
+    assert_eq!(a.diag_offset(3), arr1(&[4]));
+    assert_eq!(a.diag_offset(4).len(), 0);
+    assert_eq!(a.diag_offset(-2), arr1(&[9]));
+    assert_eq!(a.diag_offset(-3).len(), 0);
+    assert_eq!(a.diag_offset(isize::MIN).len(), 0);
+    assert_eq!(a.slice(s![..;-1, ..]).diag_offset(1), arr1(&[10, 7, 4]));
+
+    let mut b = a.clone();
+    b.diag_offset_mut(-1).fill(0);
+    assert_eq!(b[[1, 0]], 0);
+    assert_eq!(b[[2, 1]], 0);
+    assert_eq!(b.sum(), a.sum() - 5 - 10);
+
+    assert_eq!(a.trace(0), 1 + 6 + 11);
+    assert_eq!(a.trace(2), 3 + 8);
+    assert_eq!(a.trace(-5), 0);
+
+    let diagonals = a.diagonals_iter().collect::<Vec<_>>();
+    assert_eq!(diagonals.len(), 6);
+    assert_eq!(a.diagonals_iter().len(), 6);
+    for (d, k) in diagonals.iter().zip(-2..) {
+        assert_eq!(d, &a.diag_offset(k));
+    }
+    let rev = a.diagonals_iter().rev().map(|d| d[0]).collect::<Vec<_>>();
+    assert_eq!(rev, vec![4, 3, 2, 1, 5, 9]);
+    assert_eq!(Array2::<f32>::zeros((0, 3)).diagonals_iter().len(), 0);
+}
+
+#[test]
+fn triu_tril() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| 1 + 4 * i + j);
+    assert_eq!(a.triu(0), arr2(&[[1, 2, 3, 4], [0, 6, 7, 8], [0, 0, 11, 12]]));
+    assert_eq!(a.triu(2), arr2(&[[0, 0, 3, 4], [0, 0, 0, 8], [0, 0, 0, 0]]));
+    assert_eq!(a.triu(-2), arr2(&[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]));
+    assert_eq!(a.tril(0), arr2(&[[1, 0, 0, 0], [5, 6, 0, 0], [9, 10, 11, 0]]));
+    assert_eq!(a.tril(-1), arr2(&[[0, 0, 0, 0], [5, 0, 0, 0], [9, 10, 0, 0]]));
+    assert_eq!(a.tril(10), a);
+    assert_eq!(a.triu(isize::MIN), a);
+    assert_eq!(a.tril(isize::MIN), Array::zeros((3, 4)));
+    assert_eq!(a.band(0, 0), arr2(&[[1, 0, 0, 0], [0, 6, 0, 0], [0, 0, 11, 0]]));
+    assert_eq!(a.band(1, 1), arr2(&[[1, 2, 0, 0], [5, 6, 7, 0], [0, 10, 11, 12]]));
+    assert_eq!(a.band(usize::MAX, usize::MAX), a);
+
+    // In place on a transposed view
+    let mut b = a.clone();
+    b.view_mut().reversed_axes().triu_inplace(0);
+    assert_eq!(b, a.tril(0));
+    let mut b = a.clone();
+    b.slice_mut(s![.., ..;-1]).tril_inplace(-1);
+    assert_eq!(b, arr2(&[[0, 0, 0, 0], [0, 0, 0, 8], [0, 0, 11, 12]]));
+    let mut b = a.clone();
+    b.slice_mut(s![.., ..;-1]).tril_inplace(1);
+    assert_eq!(b, arr2(&[[0, 0, 3, 4], [0, 6, 7, 8], [9, 10, 11, 12]]));
+}
+
 /// Check that the merged shape is correct.
 ///
 /// Note that this does not check the strides in the "merged" case!
@@ -827,6 +1060,344 @@ fn merge_axes() {
     assert_merged!(a, s![.., .., ..;2, ..], 1, 3);
 }
 
+#[test]
+fn rotate_axis() {
+    let a = Array::from_shape_fn((4, 7, 3), |(i, j, k)| i * 100 + j * 10 + k);
+    for &axis in &[Axis(0), Axis(1), Axis(2)] {
+        let n = a.len_of(axis);
+        for mid in 0..=n {
+            let mut b = a.clone();
+            b.rotate_axis_left(axis, mid);
+            let expected = ndarray::concatenate(
+                axis,
+                &[a.slice_axis(axis, Slice::from(mid..)), a.slice_axis(axis, Slice::from(..mid))],
+            )
+            .unwrap();
+            assert_eq!(b, expected);
+            b.rotate_axis_right(axis, mid);
+            assert_eq!(b, a);
+
+            // Negative strides
+            let mut c = a.clone();
+            c.slice_mut(s![..;-1, .., ..;-1]).rotate_axis_left(axis, mid);
+            let mut expected = expected;
+            if axis != Axis(1) {
+                expected = a.clone();
+                expected.invert_axis(axis);
+                expected.rotate_axis_left(axis, mid);
+                expected.invert_axis(axis);
+            }
+            assert_eq!(c, expected);
+        }
+    }
+}
+
+#[test]
+fn roll_axis() {
+    let a = Array::from_shape_fn((3, 5), |(i, j)| i * 10 + j);
+    for shift in -12..12 {
+        let rolled = a.roll_axis_by(Axis(1), shift);
+        for ((i, j), &x) in rolled.indexed_iter() {
+            assert_eq!(x, a[(i, (j as isize - shift).rem_euclid(5) as usize)]);
+        }
+        let mut b = a.clone();
+        b.slice_mut(s![.., ..;-1]).roll_axis_inplace(Axis(1), -shift);
+        assert_eq!(b, rolled);
+        assert_eq!(a.t().roll_axis_by(Axis(0), shift), rolled.t());
+    }
+    let empty = Array2::<u8>::zeros((0, 3));
+    assert_eq!(empty.roll_axis_by(Axis(0), -1), empty);
+}
+
+#[test]
+#[should_panic]
+fn rotate_axis_mid_out_of_bounds() {
+    Array2::<u8>::zeros((2, 3)).rotate_axis_left(Axis(1), 4);
+}
+
+#[test]
+fn swap_many() {
+    let mut a = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    a.swap_many(&[((0, 0), (1, 2)), ((0, 1), (0, 1)), ((1, 0), (0, 0))]);
+    assert_eq!(a, arr2(&[[3, 1, 2], [5, 4, 0]]));
+    let mut b = a.clone();
+    b.swap_many::<(usize, usize)>(&[]);
+    assert_eq!(a, b);
+}
+
+#[test]
+#[should_panic]
+fn swap_many_out_of_bounds() {
+    Array2::<u8>::zeros((2, 3)).swap_many(&[([0, 0], [1, 1]), ([0, 0], [2, 0])]);
+}
+
+#[test]
+fn transpose_inplace() {
+    for &(m, n) in &[(0, 3), (1, 1), (1, 5), (4, 4), (3, 7), (7, 3), (40, 70), (70, 70)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| (i * 1000 + j).to_string());
+        let mut b = a.clone();
+        b.transpose_inplace();
+        assert_eq!(b, a.t());
+        assert!(b.is_standard_layout());
+
+        let mut f = Array::from_shape_fn((m, n).f(), |(i, j)| (i * 1000 + j).to_string());
+        f.transpose_inplace();
+        assert_eq!(f, a.t());
+        if m == n {
+            assert!(f.t().is_standard_layout());
+        } else {
+            assert!(f.is_standard_layout());
+        }
+
+        let mut c = Array::from_shape_fn((m, n + 1), |(i, j)| (i * 1000 + j).to_string());
+        c.slice_collapse(s![.., ..n]);
+        c.transpose_inplace();
+        assert_eq!(c, a.t());
+    }
+}
+
+#[test]
+fn pad() {
+    let a = arr1(&[1, 2, 3]);
+    assert_eq!(a.pad(&[(4, 2)], PadMode::Constant(0)), arr1(&[0, 0, 0, 0, 1, 2, 3, 0, 0]));
+    assert_eq!(a.pad(&[(4, 2)], PadMode::Edge), arr1(&[1, 1, 1, 1, 1, 2, 3, 3, 3]));
+    assert_eq!(a.pad(&[(4, 2)], PadMode::Reflect), arr1(&[1, 2, 3, 2, 1, 2, 3, 2, 1]));
+    assert_eq!(a.pad(&[(4, 2)], PadMode::Wrap), arr1(&[3, 1, 2, 3, 1, 2, 3, 1, 2]));
+    assert_eq!(arr1(&[7]).pad(&[(2, 1)], PadMode::Reflect), arr1(&[7; 4]));
+
+    // Each mode is the same as padding with the index mapping along each axis
+    let b = Array::from_shape_fn((3, 4, 2), |(i, j, k)| i * 100 + j * 10 + k);
+    let b = b.slice(s![..;-1, 1.., ..]);
+    let width = [(2, 1), (0, 4), (5, 5)];
+    let wrap = b.pad(&width, PadMode::Wrap);
+    let edge = b.pad(&width, PadMode::Edge);
+    let reflect = b.pad(&width, PadMode::Reflect);
+    let constant = b.pad(&width, PadMode::Constant(0));
+    assert_eq!(wrap.shape(), &[6, 7, 12]);
+    for (ix, &x) in wrap.indexed_iter() {
+        let (i, j, k) = (ix.0 as isize - 2, ix.1 as isize, ix.2 as isize - 5);
+        assert_eq!(x, b[(i.rem_euclid(3) as usize, j.rem_euclid(3) as usize, k.rem_euclid(2) as usize)]);
+        let clamp = |i: isize, n: isize| i.max(0).min(n - 1) as usize;
+        assert_eq!(edge[ix], b[(clamp(i, 3), clamp(j, 3), clamp(k, 2))]);
+        let mirror = |i: isize, n: isize| {
+            let k = i.rem_euclid(2 * (n - 1));
+            (if k < n { k } else { 2 * (n - 1) - k }) as usize
+        };
+        assert_eq!(reflect[ix], b[(mirror(i, 3), mirror(j, 3), mirror(k, 2))]);
+        let inside = (0..3).contains(&i) && (0..3).contains(&j) && (0..2).contains(&k);
+        assert_eq!(constant[ix], if inside { x } else { 0 });
+    }
+
+    let empty = Array2::<f32>::zeros((0, 2)).into_dyn();
+    assert_eq!(empty.pad(&[(1, 1), (0, 0)], PadMode::Constant(1.)), ArrayD::from_elem(vec![2, 2], 1.));
+    assert_eq!(empty.pad(&[(0, 0), (1, 1)], PadMode::Edge).shape(), &[0, 4]);
+
+    // The result is in standard layout, also without padding or for
+    // padding along the last axis
+    let f = Array::from_shape_vec((2, 3).f(), vec![1, 4, 2, 5, 3, 6]).unwrap();
+    for &width in &[[(0, 0), (0, 0)], [(0, 0), (1, 0)], [(1, 1), (0, 0)]] {
+        for mode in vec![PadMode::Edge, PadMode::Wrap, PadMode::Reflect, PadMode::Constant(0)] {
+            let padded = f.pad(&width, mode);
+            assert!(padded.is_standard_layout());
+            assert_eq!(padded.slice(s![width[0].0..width[0].0 + 2, width[1].0..width[1].0 + 3]), f);
+        }
+        assert!(f.as_standard_layout().pad(&width, PadMode::Edge).is_standard_layout());
+    }
+}
+
+#[test]
+#[should_panic]
+fn pad_empty_axis() {
+    Array2::<f32>::zeros((0, 2)).pad(&[(1, 0), (0, 0)], PadMode::Reflect);
+}
+
+#[test]
+#[should_panic]
+fn pad_wrong_number_of_axes() {
+    Array2::<f32>::zeros((3, 2)).pad(&[(1, 1)], PadMode::Edge);
+}
+
+#[test]
+fn flipped() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+    assert_eq!(a.view().flipped(Axis(1)), a.slice(s![.., ..;-1, ..]));
+    assert_eq!(a.view().flipped(Axis(2)).flipped(Axis(2)), a);
+    assert_eq!(a.view().flipped_all(), a.slice(s![..;-1, ..;-1, ..;-1]));
+    let ptr = a.as_ptr();
+    let b = a.flipped(Axis(0));
+    assert_eq!(b[[0, 0, 0]], 100);
+    assert_eq!(b.flipped(Axis(0)).as_ptr(), ptr);
+    assert_eq!(arr0(1).flipped_all(), arr0(1));
+}
+
+#[test]
+fn repeat_axis_tile() {
+    let a = Array::from_shape_fn((2, 3, 2), |(i, j, k)| i * 100 + j * 10 + k);
+    let v = a.slice(s![.., ..;-1, ..]);
+    let r = v.repeat_axis(Axis(1), 2);
+    assert_eq!(r.shape(), &[2, 6, 2]);
+    for ((i, j, k), &x) in r.indexed_iter() {
+        assert_eq!(x, v[(i, j / 2, k)]);
+    }
+    assert_eq!(v.repeat_axis(Axis(2), 0).shape(), &[2, 3, 0]);
+
+    let t = v.tile(&[2, 1, 3]);
+    assert_eq!(t.shape(), &[4, 3, 6]);
+    for ((i, j, k), &x) in t.indexed_iter() {
+        assert_eq!(x, v[(i % 2, j, k % 2)]);
+    }
+    assert_eq!(v.tile(&[1, 1, 1]), v);
+    assert_eq!(v.into_dyn().tile(&[0, 2, 1]).shape(), &[0, 6, 2]);
+}
+
+#[test]
+#[should_panic]
+fn tile_wrong_number_of_axes() {
+    arr2(&[[1, 2]]).tile(&[2]);
+}
+
+#[test]
+fn searchsorted() {
+    use ndarray::SearchSide;
+    let a = arr1(&[1, 2, 2, 2, 5]);
+    let values = arr1(&[0, 1, 2, 3, 5, 6]);
+    assert_eq!(a.searchsorted(&values, SearchSide::Left), arr1(&[0, 0, 1, 4, 4, 5]));
+    assert_eq!(a.searchsorted(&values, SearchSide::Right), arr1(&[0, 1, 4, 4, 5, 5]));
+    assert_eq!(
+        a.slice(s![..0]).searchsorted(&values, SearchSide::Left),
+        Array1::<usize>::zeros(6)
+    );
+
+    let sorted = arr2(&[[1, 4], [2, 5], [3, 6]]);
+    let values = arr2(&[[2, 6], [2, 6], [4, 0], [3, 3]]);
+    assert_eq!(
+        sorted.searchsorted_axis(Axis(0), &values, SearchSide::Left),
+        arr2(&[[1, 2], [1, 2], [3, 0], [2, 0]])
+    );
+    assert_eq!(
+        sorted.searchsorted_axis(Axis(0), &values, SearchSide::Right),
+        arr2(&[[2, 3], [2, 3], [3, 0], [3, 0]])
+    );
+}
+
+#[test]
+fn unique() {
+    let a = arr2(&[[4, 1, 4], [0, 1, 9]]);
+    let t = a.t();
+    assert_eq!(t.unique(), arr1(&[0, 1, 4, 9]));
+    let (values, counts) = t.unique_counts();
+    assert_eq!(values, arr1(&[0, 1, 4, 9]));
+    assert_eq!(counts, arr1(&[1, 2, 2, 1]));
+    let (values, inverse) = t.unique_inverse();
+    assert_eq!(inverse, arr2(&[[2, 0], [1, 1], [2, 3]]));
+    assert_eq!(inverse.map(|&i| values[i]), t);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    let (values, counts, inverse) = empty.unique_by(Ord::cmp);
+    assert_eq!(values.len(), 0);
+    assert_eq!(counts.len(), 0);
+    assert_eq!(inverse.shape(), &[0, 3]);
+}
+
+#[test]
+fn factorize() {
+    let a = arr2(&[["b", "a", "b"], ["c", "a", "a"]]).map(|s| s.to_string());
+    let t = a.t();
+    let (codes, uniques) = t.factorize();
+    assert_eq!(uniques, arr1(&["b", "c", "a"]).map(|s| s.to_string()));
+    assert_eq!(codes, arr2(&[[0, 1], [2, 2], [0, 2]]));
+    assert_eq!(codes.map(|&i| uniques[i].clone()), t);
+    assert_eq!(t.eq_value("a"), arr2(&[[false, false], [true, true], [false, true]]));
+
+    let (codes, uniques) = Array2::<String>::default((0, 3)).factorize();
+    assert_eq!(codes.shape(), &[0, 3]);
+    assert_eq!(uniques.len(), 0);
+}
+
+#[test]
+fn duplicate_rows() {
+    use ndarray::Norm;
+    let a = arr2(&[[1, 2], [3, 4], [1, 2], [3, 5]]);
+    assert_eq!(a.duplicate_rows(), vec![vec![0, 2]]);
+    assert_eq!(a.t().duplicate_rows(), Vec::<Vec<usize>>::new());
+
+    let b = arr2(&[[0., 1.], [f64::NAN, 1.], [0.5, 1.5], [1., 2.], [0., 1.]]);
+    assert_eq!(b.near_duplicate_rows(0., Norm::L2), vec![vec![0, 4]]);
+    // Connected through row 2
+    assert_eq!(b.near_duplicate_rows(0.5, Norm::Max), vec![vec![0, 2, 3, 4]]);
+    assert_eq!(b.near_duplicate_rows(0.5, Norm::L1), vec![vec![0, 4]]);
+    assert_eq!(b.near_duplicate_rows(1., Norm::L1), vec![vec![0, 2, 3, 4]]);
+
+    let no_columns = Array2::<f64>::zeros((3, 0));
+    let no_int_columns = Array2::<i32>::zeros((3, 0));
+    assert_eq!(no_columns.near_duplicate_rows(0., Norm::L1), vec![vec![0, 1, 2]]);
+    assert_eq!(no_int_columns.duplicate_rows(), vec![vec![0, 1, 2]]);
+}
+
+#[test]
+#[should_panic]
+fn searchsorted_axis_shape_mismatch() {
+    use ndarray::SearchSide;
+    let sorted = arr2(&[[1, 4], [2, 5], [3, 6]]);
+    sorted.searchsorted_axis(Axis(1), &arr2(&[[1, 2]]), SearchSide::Left);
+}
+
+#[test]
+fn split_axis() {
+    let mut a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    let parts = a.split_axis(Axis(1), 3);
+    assert_eq!(parts.iter().map(|p| p.ncols()).collect::<Vec<_>>(), vec![2, 1, 1]);
+    assert_eq!(parts[1], arr2(&[[2], [6], [10]]));
+    let parts = a.split_axis(Axis(0), 5);
+    assert_eq!(parts.iter().map(|p| p.nrows()).collect::<Vec<_>>(), vec![1, 1, 1, 0, 0]);
+    assert_eq!(a.split_axis(Axis(0), 1)[0], a);
+
+    for (i, mut part) in a.split_axis_mut(Axis(0), 2).into_iter().enumerate() {
+        part.fill(i);
+    }
+    assert_eq!(a, arr2(&[[0; 4], [0; 4], [1; 4]]));
+}
+
+#[test]
+#[should_panic]
+fn split_axis_zero_parts() {
+    arr1(&[1, 2]).split_axis(Axis(0), 0);
+}
+
+#[test]
+fn sort_axis() {
+    let mut a = arr2(&[[3, 1, 2], [1, 0, 5]]);
+    a.slice_mut(s![.., ..;-1]).sort_axis(Axis(1));
+    assert_eq!(a, arr2(&[[3, 2, 1], [5, 1, 0]]));
+    a.sort_unstable_axis_by(Axis(0), |x, y| y.cmp(x));
+    assert_eq!(a, arr2(&[[5, 2, 1], [3, 1, 0]]));
+
+    // The sort is stable
+    let mut pairs = arr2(&[[(1, 0), (0, 1)], [(1, 2), (0, 3)], [(0, 4), (1, 5)]]);
+    pairs.sort_axis_by(Axis(0), |x, y| x.0.cmp(&y.0));
+    assert_eq!(pairs, arr2(&[[(0, 4), (0, 1)], [(1, 0), (0, 3)], [(1, 2), (1, 5)]]));
+}
+
+#[test]
+fn prune_and_nonzero() {
+    let a = arr3(&[[[1i32, 0], [0, -2]], [[3, 0], [0, 0]]]);
+    let (pruned, indices) = a.prune(|&x| x < 2, 7);
+    assert_eq!(pruned, arr3(&[[[7, 7], [7, 7]], [[3, 7], [7, 7]]]));
+    assert_eq!(indices.len(), 7);
+    assert_eq!(indices[0], (0, 0, 0));
+    assert_eq!(indices[6], (1, 1, 1));
+
+    assert_eq!(a.to_sparse_mask(1), a.mapv(|x| x.abs() > 1));
+    let nonzero = a.t().nonzero();
+    assert_eq!(nonzero, vec![arr1(&[0, 0, 1]), arr1(&[0, 0, 1]), arr1(&[0, 1, 0])]);
+    for i in 0..3 {
+        assert_ne!(a.t()[[nonzero[0][i], nonzero[1][i], nonzero[2][i]]], 0);
+    }
+
+    assert_eq!(arr0(5).nonzero(), Vec::<Array1<usize>>::new());
+    assert_eq!(Array2::<f32>::zeros((2, 3)).nonzero(), vec![arr1(&[]), arr1(&[])]);
+}
+
 #[test]
 fn swapaxes() {
     let mut a = arr2(&[[1., 2.], [3., 4.0f32]]);
@@ -2501,3 +3072,475 @@ fn test_remove_index_oob3() {
     let mut a = array![[10], [4], [1]];
     a.remove_index(Axis(2), 0);
 }
+
+#[test]
+fn test_swap_remove_index() {
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (i * 10 + j).to_string());
+    a.swap_remove_index(Axis(0), 1);
+    assert_eq!(a, arr2(&[["0", "1", "2"], ["30", "31", "32"], ["20", "21", "22"]]).mapv(String::from));
+    a.swap_remove_index(Axis(1), 2);
+    assert_eq!(a.shape(), &[3, 2]);
+    assert_eq!(a.column(1), arr1(&["1", "31", "21"]).mapv(String::from));
+    a.swap_remove_index(Axis(1), 0);
+    assert_eq!(a, arr2(&[["1"], ["31"], ["21"]]).mapv(String::from));
+
+    let mut b = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    b.invert_axis(Axis(1));
+    b.swap_remove_index(Axis(1), 0);
+    assert_eq!(b, arr2(&[[0, 1], [3, 4]]));
+    b.swap_remove_index(Axis(0), 1);
+    b.swap_remove_index(Axis(0), 0);
+    assert_eq!(b.shape(), &[0, 2]);
+}
+
+#[should_panic(expected="must be less")]
+#[test]
+fn test_swap_remove_index_oob() {
+    let mut a = array![[10], [4], [1]];
+    a.swap_remove_index(Axis(0), 3);
+}
+
+#[test]
+fn to_planar_interleaved() {
+    let hwc = Array::from_iter(0..70 * 45 * 3).into_shape((70, 45, 3)).unwrap();
+    let chw = hwc.to_planar(Axis(2));
+    assert!(chw.is_standard_layout());
+    assert_eq!(chw, hwc.view().permuted_axes([2, 0, 1]));
+    let back = chw.to_interleaved(Axis(0));
+    assert!(back.is_standard_layout());
+    assert_eq!(back, hwc);
+
+    // Non-contiguous input and the channel axis in the middle
+    let v = hwc.slice(s![..;-3, 1.., ..]);
+    assert_eq!(v.to_planar(Axis(1)), v.permuted_axes([1, 0, 2]));
+    assert_eq!(v.to_interleaved(Axis(1)), v.permuted_axes([0, 2, 1]));
+
+    // Dynamic dimension and degenerate cases
+    let d = hwc.clone().into_dyn();
+    assert_eq!(d.to_planar(Axis(2)), chw.clone().into_dyn());
+    assert_eq!(hwc.to_planar(Axis(0)), hwc);
+    let e = Array3::<i32>::zeros((0, 4, 3));
+    assert_eq!(e.to_planar(Axis(2)).shape(), &[3, 0, 4]);
+    assert_eq!(arr1(&[1, 2]).to_interleaved(Axis(0)), arr1(&[1, 2]));
+}
+
+#[test]
+fn process_chunks() {
+    let a = Array::from_shape_fn((10, 3), |(i, j)| i * 3 + j);
+    // Non-commutative reduction: the chunks are combined in order
+    let firsts = a.process_chunks(Axis(0), 4, || (), |_, chunk| vec![chunk[[0, 0]]], |mut x, y| {
+        x.extend(y);
+        x
+    });
+    assert_eq!(firsts, Some(vec![0, 12, 24]));
+    assert_eq!(a.slice(s![..0, ..]).process_chunks(Axis(0), 4, || (), |_, c| c.sum(), |x, y| x + y), None);
+
+    // The scratch value is created once and reused
+    let mut inits = 0;
+    let n = ndarray::process_chunks(a.rows(), || { inits += 1; 0 }, |count, _row| { *count += 1; *count }, usize::max);
+    assert_eq!((n, inits), (Some(10), 1));
+}
+
+#[test]
+fn expect_shape() {
+    use ndarray::{ErrorKind, ExpectedShape, ShapeError};
+
+    let a = Array::<f32, _>::zeros((2, 3, 4));
+    assert!(a.expect_shape((2, 3, 4)).is_ok());
+    assert!(a.expect_shape((None, 3, None)).is_ok());
+    assert!(a.expect_shape([Some(2), None, Some(4)]).is_ok());
+    assert!(a.expect_shape(&[2, 3, 4][..]).is_ok());
+    let err = a.expect_shape((2, 3)).unwrap_err();
+    assert_eq!(err.expected(), &ExpectedShape::Pattern(vec![Some(2), Some(3)]));
+    assert_eq!(err.found(), &[2, 3, 4]);
+    assert_eq!(err.context(), None);
+    assert!(a.expect_shape((2, None, 5)).is_err());
+
+    // chained checks on a dynamic dimension array
+    let d = Array::<f32, _>::zeros(vec![3, 3]);
+    let view = d.expect_ndim(2).and_then(|d| d.expect_square()).unwrap().view();
+    assert_eq!(view.shape(), &[3, 3]);
+    assert!(d.expect_shape(3).is_err());
+    assert!(aview1(&[1, 2, 3]).expect_shape(3).is_ok());
+    assert!(arr0(1).expect_shape(()).is_ok());
+
+    let err = a.expect_square().unwrap_err().with_context("input");
+    assert_eq!(err.expected(), &ExpectedShape::Square);
+    assert_eq!(err.context(), Some("input"));
+    assert_eq!(
+        a.expect_ndim(2).unwrap_err().to_string(),
+        "ExpectShapeError: expected 2 dimensions, found shape [2, 3, 4]"
+    );
+    let shape_err: ShapeError = err.into();
+    assert_eq!(shape_err.kind(), ErrorKind::IncompatibleShape);
+}
+
+#[test]
+fn indexed_fold_and_reduce_axis() {
+    let a = array![[3, 1, 4], [1, 5, 9], [2, 6, 5]];
+    // Index of the maximum along each column
+    let argmax = a.indexed_fold_axis(Axis(0), (0, i32::MIN), |&(imax, max), i, &x| {
+        if x > max { (i, x) } else { (imax, max) }
+    });
+    assert_eq!(argmax, array![(0, 3), (2, 6), (1, 9)]);
+
+    // Non-commutative: a left fold of subtraction
+    let diff = a.reduce_axis(Axis(1), |&x, &y| x - y).unwrap();
+    assert_eq!(diff, array![-2, -13, -9]);
+    let diff = a.reduce_axis(Axis(0), |&x, &y| x - y).unwrap();
+    assert_eq!(diff, array![0, -10, -10]);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.reduce_axis(Axis(0), |&x, &y| x + y), None);
+    assert_eq!(empty.reduce_axis(Axis(1), |&x, &y| x + y), Some(Array1::zeros(0)));
+    assert_eq!(empty.indexed_fold_axis(Axis(0), 7, |&x, _, _| x), array![7, 7, 7]);
+}
+
+#[test]
+fn sorted_indices_iter() {
+    use ndarray::SortOrder;
+
+    let a = array![[3, 1, 4], [1, 5, 9], [2, 6, 5]];
+    let ascending = a.sorted_indices_iter(SortOrder::Ascending).collect::<Vec<_>>();
+    assert_eq!(
+        ascending,
+        vec![
+            ((0, 1), &1),
+            ((1, 0), &1),
+            ((2, 0), &2),
+            ((0, 0), &3),
+            ((0, 2), &4),
+            ((1, 1), &5),
+            ((2, 2), &5),
+            ((2, 1), &6),
+            ((1, 2), &9),
+        ]
+    );
+    let at = a.t();
+    let mut descending = at.sorted_indices_iter(SortOrder::Descending);
+    assert_eq!(descending.len(), 9);
+    assert_eq!(descending.next(), Some(((2, 1), &9)));
+    assert_eq!(descending.next(), Some(((1, 2), &6)));
+    assert_eq!(descending.next(), Some(((1, 1), &5)));
+    assert_eq!(descending.next(), Some(((2, 2), &5)));
+    assert_eq!(descending.len(), 5);
+
+    let empty = Array3::<f32>::zeros((2, 0, 3));
+    assert_eq!(empty.sorted_indices_iter(SortOrder::Ascending).next(), None);
+}
+
+#[test]
+fn map_axis_inplace() {
+    let mut a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| (i * 8 + j * 2 + k) as i32);
+    let expected = a.map_axis(Axis(1), |lane| lane.sum());
+    a.slice_mut(s![.., ..;-1, ..]).map_axis_inplace(Axis(1), |mut lane| {
+        let mut acc = 0;
+        lane.iter_mut().for_each(|x| {
+            acc += *x;
+            *x = acc;
+        });
+    });
+    // Cumulative sums in reverse order along axis 1
+    assert_eq!(a.index_axis(Axis(1), 0), expected);
+    assert_eq!(a[[0, 3, 0]], 6);
+    assert_eq!(a[[2, 2, 1]], 23 + 21);
+
+    let mut empty = Array2::<i32>::zeros((0, 3));
+    empty.map_axis_inplace(Axis(1), |_| unreachable!());
+}
+
+#[test]
+fn scan_axis() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i64);
+    let mut cumsum = a.clone();
+    cumsum.accumulate_axis_inplace(Axis(1), |&prev, curr| *curr += prev);
+    assert_eq!(a.scan_axis(Axis(1), 0, |&acc, &x| acc + x), cumsum);
+
+    // Reversed axis, and an accumulator of a different type
+    let v = a.slice(s![.., ..;-1, ..]);
+    let counts = v.scan_axis(Axis(1), (0usize, 0i64), |&(n, sum), &x| (n + 1, sum + x));
+    assert_eq!(counts.shape(), &[2, 3, 4]);
+    assert_eq!(counts[[0, 0, 0]], (1, 8));
+    assert_eq!(counts[[1, 2, 3]], (3, 15 + 19 + 23));
+
+    let empty = Array2::<i64>::zeros((0, 3));
+    assert_eq!(empty.scan_axis(Axis(0), 0, |&a, &b| a + b).shape(), &[0, 3]);
+    assert_eq!(empty.scan_axis(Axis(1), 0, |&a, &b| a + b).shape(), &[0, 3]);
+}
+
+#[test]
+fn unchecked_axis_views() {
+    let mut a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as i32);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for i in 0..a.len_of(axis) {
+            assert_eq!(unsafe { a.index_axis_unchecked(axis, i) }, a.index_axis(axis, i));
+        }
+        let len = a.len_of(axis);
+        for &(start, end) in &[(0, len), (1, len), (0, 0), (len, len), (1, 2)] {
+            assert_eq!(
+                unsafe { a.slice_axis_unchecked(axis, start..end) },
+                a.slice_axis(axis, Slice::from(start..end))
+            );
+        }
+    }
+
+    let mut v = a.slice_mut(s![..;-1, .., 1..;2]);
+    unsafe { v.index_axis_unchecked_mut(Axis(0), 2) }.fill(-1);
+    unsafe { v.slice_axis_unchecked_mut(Axis(2), 1..2) }.fill(-2);
+    assert_eq!(a[[0, 0, 1]], -1);
+    assert_eq!(a[[0, 3, 3]], -2);
+    assert_eq!(a[[2, 1, 3]], -2);
+    assert_eq!(a[[0, 0, 0]], 0);
+    assert_eq!(a[[1, 0, 1]], 21);
+}
+
+#[test]
+fn transpose_option_result() {
+    use std::rc::Rc;
+
+    let a = Array::from_shape_fn((3, 4).f(), |(i, j)| Some(i * 4 + j));
+    let b = a.transpose_option().unwrap();
+    assert_eq!(b, Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j));
+    assert_eq!(b.strides(), &[1, 3]);
+
+    let a = Array::from_shape_fn((3, 4), |(i, j)| if i * j == 2 { Err((i, j)) } else { Ok(i + j) });
+    assert_eq!(a.clone().transpose_result(), Err((1, 2)));
+    assert_eq!(a.slice_move(s![.., ..;-3]).transpose_result(), Ok(array![[3, 0], [4, 1], [5, 2]]));
+
+    // Elements are moved and dropped once, also those outside a slice
+    let rc = Rc::new(());
+    let a = Array::from_elem((4, 3), Some(rc.clone())).slice_move(s![1..3, ..;2]);
+    let b = a.transpose_option().unwrap();
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(b);
+    let mut a = Array::from_elem((4, 3), Some(rc.clone()));
+    a[[1, 1]] = None;
+    assert!(a.transpose_option().is_none());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn fallible_methods() {
+    use ndarray::{AxisError, ErrorKind};
+
+    let mut a = Array::from_shape_fn((2, 3), |(i, j)| i * 3 + j);
+    assert_eq!(a.try_index_axis(Axis(1), 2).unwrap(), arr1(&[2, 5]));
+    assert_eq!(
+        a.try_index_axis_mut(Axis(0), 2).unwrap_err(),
+        AxisError::IndexOutOfBounds { axis: 0, index: 2, len: 2 }
+    );
+    assert_eq!(
+        a.view().try_insert_axis(Axis(3)).unwrap_err(),
+        AxisError::AxisOutOfBounds { axis: 3, ndim: 2 }
+    );
+    assert_eq!(a.view().try_insert_axis(Axis(2)).unwrap().shape(), &[2, 3, 1]);
+    assert_eq!(a.try_sum_axis(Axis(0)), Ok(arr1(&[3, 5, 7])));
+    assert!(a.try_sum_axis(Axis(2)).is_err());
+    assert_eq!(
+        a.try_select(Axis(1), &[0, 3, 4]).unwrap_err(),
+        AxisError::IndexOutOfBounds { axis: 1, index: 3, len: 3 }
+    );
+    assert_eq!(a.try_select(Axis(1), &[2, 0]), Ok(arr2(&[[2, 0], [5, 3]])));
+
+    let err = a.try_broadcast((3, 3)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.shapes(), Some((&[2, 3][..], &[3, 3][..])));
+    let err = a.t().broadcast_with(&a).unwrap_err();
+    assert_eq!(err.shapes(), Some((&[3, 2][..], &[2, 3][..])));
+    let err = Array::from_shape_vec((2, 2), vec![1, 2, 3, 4, 5]).unwrap_err();
+    assert_eq!(err.shapes(), Some((&[5][..], &[2, 2][..])));
+    let err = a.into_shape((4, 2)).unwrap_err();
+    assert_eq!(err.to_string(), "ShapeError/IncompatibleShape: incompatible shapes [2, 3] and [4, 2]");
+}
+
+#[test]
+fn selection() {
+    use ndarray::Selection;
+
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let rows = Selection::from_indices((3, 4), Axis(0), &[0, 2]);
+    let cols = Selection::from_slice((3, 4), s![.., 1..;2]);
+    let both = rows.intersection(&cols);
+    assert_eq!(both.len(), 4);
+    assert!(both.contains((2, 3)));
+    assert!(!both.contains((1, 1)));
+    assert!(!both.contains((5, 5)));
+    assert_eq!(a.select_with(&both), arr1(&[1, 3, 9, 11]));
+    assert_eq!(rows.union(&cols).len(), 10);
+    assert_eq!(rows.difference(&cols), Selection::from_points((3, 4), vec![(0, 0), (0, 2), (2, 0), (2, 2)]));
+    assert_eq!(rows.complement(), Selection::from_indices((3, 4), Axis(0), &[1]));
+    assert!(Selection::<Ix2>::none((3, 4)).is_empty());
+    assert_eq!(Selection::all((3, 4)).complement().mask(), Array::from_elem((3, 4), false));
+
+    let small = Selection::from_mask(&a.mapv(|x| x < 3));
+    assert_eq!(a.assign_with(&small, &arr1(&[-1])), 3);
+    assert_eq!(a.row(0), arr1(&[-1, -1, -1, 3]));
+    let fortran = Array::from_shape_fn((3, 4).f(), |(i, j)| (i * 4 + j) as i32);
+    assert_eq!(fortran.select_with(&both), arr1(&[1, 3, 9, 11]));
+}
+
+#[test]
+#[should_panic]
+fn selection_shape_mismatch() {
+    let a = Array::<i32, _>::zeros((2, 3));
+    a.select_with(&ndarray::Selection::all((3, 2)));
+}
+
+#[test]
+fn pack_unpack_slice() {
+    fn check<I: ndarray::SliceArg<Ix3> + Copy>(info: I) {
+        let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| 100 * i + 10 * j + k);
+        let af = Array::from_shape_fn((3, 4, 5).f(), |(i, j, k)| 100 * i + 10 * j + k);
+        let mut buf = vec![0; 60];
+        let mut buf_f = vec![0; 60];
+        let n = a.pack_slice(info, &mut buf);
+        assert_eq!(af.pack_slice(info, &mut buf_f), n);
+        assert_eq!(buf[..n], buf_f[..n]);
+        assert_eq!(&buf[..n], a.slice(info).iter().cloned().collect::<Vec<_>>());
+
+        let mut b = Array::zeros(a.raw_dim());
+        assert_eq!(b.unpack_slice(info, &buf), n);
+        assert_eq!(b.slice(info), a.slice(info));
+        let mut bf = Array::zeros(af.raw_dim().f());
+        bf.unpack_slice(info, &buf);
+        assert_eq!(bf, b);
+    }
+    check(s![.., 1, ..;2]);
+    check(s![1..3, ..;-1, 4..5]);
+    check(s![.., .., ..]);
+}
+
+#[test]
+#[should_panic]
+fn pack_slice_short_buffer() {
+    let a = Array::<i32, _>::zeros((3, 4));
+    a.pack_slice(s![.., 1..], &mut [0; 8]);
+}
+
+#[test]
+fn exchange_ghosts_matches_pad() {
+    use ndarray::exchange_ghosts;
+
+    let domain = Array::from_shape_fn((6, 8), |(i, j)| (10 * i + j) as i32);
+    // Uneven tiles: rows 0..3, 3..6 and columns 0..3, 3..5, 5..8
+    let rows = [0, 3, 6];
+    let cols = [0, 3, 5, 8];
+    let w = 2;
+    for mode in [PadMode::Constant(-1), PadMode::Edge, PadMode::Reflect, PadMode::Wrap].iter() {
+        let mut tiles = Array::from_shape_fn((2, 3), |(ti, tj)| {
+            let (h, c) = (rows[ti + 1] - rows[ti], cols[tj + 1] - cols[tj]);
+            let mut tile = Array::zeros((h + 2 * w, c + 2 * w));
+            tile.slice_mut(s![w..w + h, w..w + c])
+                .assign(&domain.slice(s![rows[ti]..rows[ti + 1], cols[tj]..cols[tj + 1]]));
+            tile
+        });
+        exchange_ghosts(&mut tiles, w, mode);
+        let padded = domain.pad(&[(w, w), (w, w)], mode.clone());
+        for ((ti, tj), tile) in tiles.indexed_iter() {
+            let expected = padded.slice(s![rows[ti]..rows[ti + 1] + 2 * w, cols[tj]..cols[tj + 1] + 2 * w]);
+            assert_eq!(tile, &expected, "{:?} tile {:?}", mode, (ti, tj));
+        }
+    }
+
+    // Tiles can be views, here of the parts of one array, with ghost cells
+    // of width 1 between them
+    let mut data = Array::from_shape_fn(8, |i| i as i32);
+    let (left, right) = data.view_mut().split_at(Axis(0), 4);
+    let mut tiles = Array::from(vec![left, right]);
+    exchange_ghosts(&mut tiles, 1, &PadMode::Wrap);
+    drop(tiles);
+    assert_eq!(data, arr1(&[6, 1, 2, 5, 2, 5, 6, 1]));
+}
+
+#[test]
+fn gather_indices() {
+    use ndarray::{gather, GatherElem};
+
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| 100 * i + 10 * j + k);
+    assert_eq!(a.gather(&gather![.., .., ..]), a);
+    assert_eq!(a.gather(&gather![1.., ..;2, -1..]), a.slice(s![1.., ..;2, -1..]));
+
+    let rows = arr1(&[3, 0, 3]);
+    let b = a.gather(&gather![&rows, 1..3, vec![5, 0]]);
+    assert_eq!(b.shape(), &[3, 2, 2]);
+    for (i, &r) in rows.iter().enumerate() {
+        for (j, c) in (1..3).enumerate() {
+            for (k, &d) in [5, 0].iter().enumerate() {
+                assert_eq!(b[[i, j, k]], a[[r, c, d]]);
+            }
+        }
+    }
+    assert_eq!(
+        a.gather(&gather![rows.view(), ..;-1, ..]),
+        a.select(Axis(0), &[3, 0, 3]).slice(s![.., ..;-1, ..])
+    );
+    assert_eq!(a.gather(&gather![&[][..], .., ..]).shape(), &[0, 5, 6]);
+
+    let info = [GatherElem::from(Slice::from(1..)), GatherElem::from(vec![2])];
+    assert_eq!(a.index_axis(Axis(0), 0).gather(&info), arr2(&[[12], [22], [32], [42]]));
+}
+
+#[test]
+#[should_panic]
+fn gather_index_out_of_bounds() {
+    let a = Array::<i32, _>::zeros((2, 3));
+    a.gather(&ndarray::gather![.., [0, 3]]);
+}
+
+#[test]
+fn expand_to_pattern() {
+    use ndarray::{ErrorKind, ExpandAxis, Keep};
+
+    let a = Array::from_shape_fn((2, 3), |(i, j)| 10 * i + j);
+    let b = a.expand_to((4, Keep, 1, Keep, 2)).unwrap();
+    assert_eq!(b.shape(), &[4, 2, 1, 3, 2]);
+    assert_eq!(b.strides(), &[0, 3, 0, 1, 0]);
+    for ((n, i, _, j, m), &x) in b.indexed_iter() {
+        assert_eq!(x, a[[i, j]], "at {:?}", (n, i, j, m));
+    }
+    let expected = a.view().insert_axis(Axis(0)).insert_axis(Axis(2)).insert_axis(Axis(4));
+    assert_eq!(b, expected.broadcast((4, 2, 1, 3, 2)).unwrap());
+
+    // Dynamic patterns, a transposed array and an empty new axis
+    let pattern = vec![ExpandAxis::Keep, ExpandAxis::New(0), ExpandAxis::Keep];
+    let at = a.t();
+    let c = at.expand_to(pattern).unwrap();
+    assert_eq!(c.shape(), &[3, 0, 2]);
+    let c = at.expand_to(&[ExpandAxis::Keep, ExpandAxis::Keep, ExpandAxis::New(2)][..]).unwrap();
+    assert_eq!(c.index_axis(Axis(2), 1), at.into_dyn());
+
+    assert!(a.expand_to((Keep, 2)).is_err());
+    assert!(a.expand_to((Keep, Keep, Keep)).is_err());
+    assert_eq!(arr0(5).expand_to((3,)).unwrap(), arr1(&[5, 5, 5]));
+
+    let err = a.expand_to((Keep, usize::MAX, Keep)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+    assert!(a.expand_to((Keep, isize::MAX as usize / 6, Keep)).is_ok());
+}
+
+#[test]
+fn eq_and_bit_eq_layouts() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as u8);
+    let af = Array::from_shape_fn((3, 4).f(), |(i, j)| (i * 4 + j) as u8);
+    assert_eq!(a, af);
+    assert_eq!(a.t(), af.t());
+    assert_eq!(af.t(), af.t().to_owned());
+    let mut b = af.clone();
+    b[[2, 3]] = 0;
+    assert_ne!(af, b);
+    assert!(!af.bit_eq(&b));
+    assert!(a.bit_eq(&af) && af.bit_eq(&af.clone()));
+    assert!(a.slice(s![..;-1, ..]).bit_eq(&a.slice(s![..;-1, ..]).to_owned()));
+    assert!(!a.bit_eq(&a.slice(s![..;-1, ..])));
+    assert!(!a.bit_eq(&a.slice(s![.., ..2])));
+
+    let x = Array::from_shape_vec((2, 2).f(), vec![f64::NAN, 1., -0., 2.]).unwrap();
+    assert_ne!(x, x);
+    assert!(x.bit_eq(&x.clone()));
+    assert!(x.t().bit_eq(&x.t().to_owned()));
+    let mut y = x.clone();
+    y[[0, 1]] = 0.;
+    assert!(!x.bit_eq(&y));
+    assert!(x.bit_eq(&x.mapv(|v| v)));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray::ArrayPool;
+
+#[test]
+fn pool_reuses_buffers() {
+    let pool = ArrayPool::new();
+    let ptr = {
+        let mut a = pool.take::<f64, _>((10, 20));
+        a.fill(1.);
+        a.as_ptr()
+    };
+    assert_eq!(pool.len(), 1);
+
+    // A smaller array of the same type reuses the buffer, and is zeroed
+    let b = pool.take::<f64, _>((5, 3).f());
+    assert_eq!(b.as_ptr(), ptr);
+    assert_eq!(*b, Array2::zeros((5, 3)));
+    assert!(b.t().is_standard_layout());
+    assert!(pool.is_empty());
+
+    // Other element types and larger arrays allocate
+    let c = pool.take_elem(IxDyn(&[2, 2]), String::from("x"));
+    let d = pool.take::<f64, _>(1000);
+    assert_ne!(d.as_ptr(), ptr);
+    assert_eq!(c[[1, 1]], "x");
+    drop((b, c, d));
+    assert_eq!(pool.len(), 3);
+
+    // The smallest buffer that is large enough is used
+    let e = pool.take::<f64, _>(100);
+    assert_eq!(e.as_ptr(), ptr);
+    let owned: Array1<f64> = e.into_inner();
+    assert_eq!(owned.len(), 100);
+    assert_eq!(pool.len(), 2);
+
+    pool.clear();
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn pool_is_shared_between_threads() {
+    use std::sync::Arc;
+    use std::thread;
+
+    let pool = Arc::new(ArrayPool::new());
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            let pool = pool.clone();
+            thread::spawn(move || {
+                let a = pool.take_elem(8, i);
+                assert_eq!(a.sum(), 8 * i);
+            })
+        })
+        .collect();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert!(pool.len() >= 1 && pool.len() <= 4);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "arrow")]
+
+use std::sync::Arc;
+
+use arrow_array::types::{Float64Type, Int32Type, UInt8Type};
+use arrow_array::{Array as _, FixedSizeListArray, Float64Array, Int32Array};
+use arrow_schema::{DataType, Field};
+use ndarray::arrow::FromArrowError;
+use ndarray::prelude::*;
+
+#[test]
+fn arrow_view_is_zero_copy() {
+    let column = Float64Array::from(vec![1., 2., 3., 4.]);
+    let sliced = column.slice(1, 2);
+    let view = ArrayView1::from_arrow(&sliced).unwrap();
+    assert_eq!(view, array![2., 3.]);
+    assert_eq!(view.as_ptr(), sliced.values().as_ptr());
+}
+
+#[test]
+fn arrow_nulls() {
+    let column = Int32Array::from(vec![Some(1), None, Some(3)]);
+    assert_eq!(ArrayView1::from_arrow(&column), Err(FromArrowError::Nulls));
+    assert_eq!(Array1::from_arrow(column), Err(FromArrowError::Nulls));
+}
+
+#[test]
+fn arrow_roundtrip_reuses_allocation() {
+    let a = Array1::from(vec![1i32, 2, 3]);
+    let ptr = a.as_ptr();
+    let column: Int32Array = a.into_arrow();
+    assert_eq!(column.values().as_ptr(), ptr);
+    let b = Array1::from_arrow(column).unwrap();
+    assert_eq!(b, array![1, 2, 3]);
+    assert_eq!(b.as_ptr(), ptr);
+
+    // A shared buffer is copied
+    let column = Int32Array::from(vec![4, 5, 6]);
+    let b = Array1::from_arrow(column.slice(1, 2)).unwrap();
+    assert_eq!(b, array![5, 6]);
+    assert_eq!(column.len(), 3);
+
+    // Not in standard layout
+    let a = array![1., 2., 3., 4.].slice_move(s![..;-2]);
+    let column: Float64Array = a.into_arrow();
+    assert_eq!(column.values(), &[4., 2.]);
+}
+
+#[test]
+fn arrow_fixed_size_list() {
+    let a = Array::from_shape_fn((4, 3), |(i, j)| (i * 3 + j) as f64);
+    let lists = a.clone().reversed_axes().into_arrow_fixed_size_list::<Float64Type>();
+    assert_eq!((lists.len(), lists.value_length()), (3, 4));
+    let view = ArrayView2::from_arrow_fixed_size_list::<Float64Type>(&lists).unwrap();
+    assert_eq!(view, a.t());
+
+    let sliced = lists.slice(1, 2);
+    let view = ArrayView2::from_arrow_fixed_size_list::<Float64Type>(&sliced).unwrap();
+    assert_eq!(view, a.t().slice(s![1.., ..]));
+
+    assert_eq!(
+        ArrayView2::from_arrow_fixed_size_list::<UInt8Type>(&lists),
+        Err(FromArrowError::DataType {
+            expected: DataType::UInt8,
+            found: DataType::Float64,
+        })
+    );
+
+    let field = Arc::new(Field::new("item", DataType::Int32, true));
+    let values = Arc::new(Int32Array::from(vec![Some(1), None]));
+    let lists = FixedSizeListArray::new(field, 1, values, None);
+    assert_eq!(
+        ArrayView2::from_arrow_fixed_size_list::<Int32Type>(&lists),
+        Err(FromArrowError::Nulls)
+    );
+}
@@ -179,6 +179,51 @@ fn test_zip_collect_drop() {
 }
 
 
+#[test]
+fn test_zip_try_collect() {
+    use std::cell::RefCell;
+
+    struct Recorddrop<'a>((usize, usize), &'a RefCell<Vec<(usize, usize)>>);
+
+    impl<'a> Drop for Recorddrop<'a> {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    for &(a_is_f, b_is_f) in &[(false, false), (false, true), (true, true)] {
+        for &fail in &[true, false] {
+            let mut inserts = RefCell::new(Vec::new());
+            let mut drops = RefCell::new(Vec::new());
+            let a = Array::from_shape_fn((5, 10).set_f(a_is_f), |idx| idx);
+            let b = Array::from_shape_fn((5, 10).set_f(b_is_f), |(i, j)| i + j);
+
+            let result = Zip::from(&a).and(&b).try_map_collect(|&elt, &sum| {
+                if fail && sum == 7 {
+                    return Err(elt);
+                }
+                inserts.borrow_mut().push(elt);
+                Ok(Recorddrop(elt, &drops))
+            });
+            assert_eq!(result.is_err(), fail);
+            if let Ok(c) = &result {
+                assert_eq!(c.is_standard_layout(), !a_is_f);
+            }
+            drop(result);
+
+            // Elements collected before the error are dropped, in order
+            assert_eq!(inserts.get_mut(), drops.get_mut());
+            assert_eq!(inserts.get_mut().len() < 50, fail);
+        }
+    }
+
+    let a = array![[1, 2], [3, 4]];
+    let ok = Zip::from(&a).try_map_collect(|&x| if x > 0 { Ok(x * 2) } else { Err(x) });
+    assert_eq!(ok, Ok(array![[2, 4], [6, 8]]));
+    let err = Zip::from(&a).and(&a.t()).try_map_collect(|&x, &y| if x == y { Ok(x) } else { Err((x, y)) });
+    assert_eq!(err, Err((2, 3)));
+}
+
 #[test]
 fn test_azip_syntax_trailing_comma() {
     let mut b = Array::<i32, _>::zeros((5, 5));
@@ -455,3 +500,91 @@ fn test_zip_all_empty_array() {
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| true));
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| false));
 }
+
+#[test]
+fn test_zip_twelve_producers() {
+    let inputs = (0..11)
+        .map(|i| Array::from_shape_fn((4, 5), |(r, c)| (i * 100 + r * 10 + c) as i32))
+        .collect::<Vec<_>>();
+    let (a, b, c, d, e, f) = (&inputs[0], &inputs[1], &inputs[2], &inputs[3], &inputs[4], &inputs[5]);
+    let (g, h, i, j, k) = (&inputs[6], &inputs[7], &inputs[8], &inputs[9], &inputs[10]);
+    let expected = inputs.iter().fold(Array2::zeros((4, 5)), |acc, x| acc + x);
+
+    let sum = Zip::from(a)
+        .and(b)
+        .and(c)
+        .and(d)
+        .and(e)
+        .and(f)
+        .and(g)
+        .and(h)
+        .and(i)
+        .and(j)
+        .and(k)
+        .map_collect(|&a, &b, &c, &d, &e, &f, &g, &h, &i, &j, &k| {
+            a + b + c + d + e + f + g + h + i + j + k
+        });
+    assert_eq!(sum, expected);
+
+    let mut out = Array2::zeros((4, 5));
+    azip!((out in &mut out, &a in a, &b in b, &c in c, &d in d, &e in e, &f in f,
+           &g in g, &h in h, &i in i, &j in j, &k in k)
+        *out = a + b + c + d + e + f + g + h + i + j + k);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_zip_co_broadcast() {
+    let a = Array::from_shape_fn((4, 1, 3), |(i, _, k)| (i * 10 + k) as i32);
+    let b = Array::from_shape_fn((2, 1), |(j, _)| j as i32 * 100);
+    let c = Array::from_elem(3, 1000);
+    let zip = Zip::from(&a)
+        .and_co_broadcast(&b)
+        .unwrap()
+        .and_co_broadcast(&c)
+        .unwrap();
+    assert_eq!(zip.size(), 4 * 2 * 3);
+    let res = zip.map_collect(|&a, &b, &c| a + b + c);
+    assert_eq!(res.shape(), &[4, 2, 3]);
+    let expected = Array::from_shape_fn((4, 2, 3), |(i, j, k)| a[[i, 0, k]] + b[[j, 0]] + 1000);
+    assert_eq!(res, expected);
+
+    // A dynamic dimension and a fixed one give a dynamic dimension
+    let d = a.clone().into_dyn();
+    let res = Zip::from(&d).and_co_broadcast(&b).unwrap().map_collect(|&a, &b| a + b);
+    assert_eq!(res, expected.mapv(|x| x - 1000).into_dyn());
+
+    assert!(Zip::from(&a).and_co_broadcast(&Array1::<i32>::zeros(4)).is_err());
+}
+
+#[test]
+fn test_zip_axis_iter() {
+    let a = Array::from_shape_fn((3, 2), |(i, j)| i * 10 + j);
+    let b = Array::from_shape_fn((3, 3, 4), |(i, j, k)| i + 2 * j + k);
+    let c = arr1(&[1, 2, 3]);
+
+    let mut iter = ndarray::zip_axis_iter!((&a, b.view(), &c), Axis(0));
+    assert_eq!(iter.len(), 3);
+    let (row, _, elt) = iter.next_back().unwrap();
+    assert_eq!(row, arr1(&[20, 21]));
+    assert_eq!(elt[()], 3);
+    assert_eq!(iter.len(), 2);
+
+    let rows: Vec<_> = ndarray::zip_axis_iter!((a.t(), &b), Axis(1)).collect();
+    assert_eq!(rows.len(), 3);
+    for (i, (col, plane)) in rows.into_iter().enumerate() {
+        assert_eq!(col, a.row(i));
+        assert_eq!(plane, b.index_axis(Axis(1), i));
+    }
+
+    let single: Vec<_> = ndarray::zip_axis_iter!((&c,), Axis(0)).map(|(x,)| x[()]).collect();
+    assert_eq!(single, vec![1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_zip_axis_iter_mismatch() {
+    let a = Array2::<f64>::zeros((3, 2));
+    let b = Array1::<f64>::zeros(2);
+    let _ = ndarray::zip_axis_iter!((&a, &b), Axis(0));
+}
@@ -81,3 +81,19 @@ fn test_broadcast_1d() {
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn co_broadcast_binary_ops() {
+    let a = array![[1], [2], [3]];
+    let b = array![10, 20, 30, 40];
+    let expected = Array::from_shape_fn((3, 4), |(i, j)| (i + 1) as i32 + 10 * (j + 1) as i32);
+    assert_eq!(&a + &b, expected);
+    assert_eq!(a.try_add(&b).unwrap(), expected);
+    assert_eq!(b.try_sub(&a).unwrap(), -&a + &b);
+    assert_eq!(a.try_mul(&b).unwrap().dim(), (3, 4));
+
+    let c = array![1, 2];
+    let err = a.try_add(&c.broadcast((2, 2)).unwrap()).unwrap_err();
+    assert_eq!(err.shapes(), Some((&[3, 1][..], &[2, 2][..])));
+    assert!(c.try_div(&b).is_err());
+}
@@ -1,5 +1,5 @@
 use ndarray::Array;
-use ndarray::{arr1, arr2, Axis};
+use ndarray::{arr1, arr2, s, Axis};
 use num_complex::Complex;
 use num_traits::Num;
 
@@ -21,3 +21,55 @@ fn complex_mat_mul() {
         arr1(&[c(1.5, 1.), c(2.5, 0.)])
     );
 }
+
+#[test]
+fn complex_split_views() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| c(i as f64, j as f64));
+    a.slice_collapse(s![..;-1, 1..;2]);
+    let Complex { re, im } = a.view_split_complex();
+    assert_eq!(re, arr2(&[[2., 2.], [1., 1.], [0., 0.]]));
+    assert_eq!(im, arr2(&[[1., 3.], [1., 3.], [1., 3.]]));
+
+    let Complex { mut re, im } = a.view_split_complex_mut();
+    re += &im;
+    assert_eq!(a[[0, 1]], c(5., 3.));
+
+    let empty = Array::<Complex<f32>, _>::zeros((0, 3));
+    assert_eq!(empty.view_split_complex().im.shape(), &[0, 3]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn complex_polar() {
+    let a = arr2(&[[c(1f64, 1.), c(-2., 0.)], [c(0., -3.), c(0., 0.)]]);
+    let (r, theta) = a.t().to_polar();
+    assert_eq!(r.t(), a.map(|z| z.norm_sqr().sqrt()));
+    assert_eq!(theta.t(), a.map(|z| z.im.atan2(z.re)));
+    let b = Array::from_polar(&r, &theta);
+    for (x, y) in b.t().iter().zip(&a) {
+        assert!((x - y).norm_sqr() < 1e-24);
+    }
+}
+
+#[test]
+fn complex_conj_and_parts() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| c(i as f64, j as f64 - 1.));
+    a.slice_collapse(s![.., ..;-2]);
+    assert_eq!(a.real(), arr2(&[[0., 0.], [1., 1.], [2., 2.]]));
+    assert_eq!(a.imag(), arr2(&[[2., 0.], [2., 0.], [2., 0.]]));
+    let conj = a.conj();
+    a.conj_inplace();
+    assert_eq!(a, conj);
+    assert_eq!(a.imag(), arr2(&[[-2., -0.], [-2., -0.], [-2., -0.]]));
+    a.real_mut().fill(3.);
+    assert_eq!(a.norm_sqr(), arr2(&[[13., 9.], [13., 9.], [13., 9.]]));
+}
+
+#[test]
+fn complex_var_axis() {
+    let a = Array::from_shape_fn((5, 3), |(i, j)| c(i as f64 * j as f64, (i * i) as f64));
+    let var = a.var_axis_complex(Axis(0), 1.);
+    let expected = &a.real().var_axis(Axis(0), 1.) + &a.imag().var_axis(Axis(0), 1.);
+    assert!(var.iter().zip(&expected).all(|(x, y)| (x - y).abs() < 1e-12));
+    assert_eq!(a.norm_l2(), a.norm_sqr().sum().sqrt());
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "csv")]
+
+use ndarray::csv::{CsvError, CsvOptions};
+use ndarray::prelude::*;
+
+#[test]
+fn csv_roundtrip() {
+    let a = Array::from_shape_fn((4, 3), |(i, j)| (i as f64) * 1.5 - j as f64);
+    for &delimiter in &[b',', b'\t', b';'] {
+        let options = CsvOptions::new().delimiter(delimiter);
+        let mut text = Vec::new();
+        a.write_csv(&mut text, &options).unwrap();
+        assert_eq!(text.iter().filter(|&&c| c == b'\n').count(), 4);
+        let b = Array2::<f64>::from_csv_reader(&text[..], &options).unwrap();
+        assert_eq!(a, b);
+        // Non-contiguous arrays are written in logical order
+        let mut text_t = Vec::new();
+        a.t().write_csv(&mut text_t, &options).unwrap();
+        assert_eq!(Array2::<f64>::from_csv_reader(&text_t[..], &options).unwrap(), a.t());
+    }
+}
+
+#[test]
+fn csv_header() {
+    let a = array![[1, 2], [3, 4]];
+    let mut text = Vec::new();
+    a.write_csv(&mut text, &CsvOptions::new().header(vec!["first", "second"])).unwrap();
+    assert_eq!(text, b"first,second\n1,2\n3,4\n");
+    let options = CsvOptions::new().has_header(true);
+    assert_eq!(Array2::<i32>::from_csv_reader(&text[..], &options).unwrap(), a);
+    // Without has_header, the header can't be parsed
+    assert!(matches!(
+        Array2::<i32>::from_csv_reader(&text[..], &CsvOptions::new()),
+        Err(CsvError::Parse { row: 0, column: 0, .. })
+    ));
+    assert!(matches!(
+        a.write_csv(Vec::new(), &CsvOptions::new().header(&["x"])),
+        Err(CsvError::HeaderLength { expected: 2, found: 1 })
+    ));
+}
+
+#[test]
+fn csv_errors() {
+    let text = "1, 2, 3\n4, 5, x6\n";
+    match Array2::<u8>::from_csv_reader(text.as_bytes(), &CsvOptions::new()) {
+        Err(CsvError::Parse { row, column, field, .. }) => {
+            assert_eq!((row, column), (1, 2));
+            assert_eq!(field, "x6");
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    let text = "1,2,3\n4,5\n";
+    assert!(matches!(
+        Array2::<u8>::from_csv_reader(text.as_bytes(), &CsvOptions::new()),
+        Err(CsvError::RowLength { row: 1, expected: 3, found: 2 })
+    ));
+    let a = Array2::<u8>::from_csv_reader(&b""[..], &CsvOptions::new()).unwrap();
+    assert_eq!(a.shape(), &[0, 0]);
+}
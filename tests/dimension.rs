@@ -2,7 +2,7 @@
 
 use defmac::defmac;
 
-use ndarray::{arr2, ArcArray, Array, Axis, Dim, Dimension, IxDyn, RemoveAxis};
+use ndarray::{arr1, arr2, ArcArray, Array, Axis, Dim, Dimension, IxDyn, RemoveAxis};
 
 use std::hash::{Hash, Hasher};
 
@@ -340,3 +340,33 @@ fn test_all_ndindex() {
     ndindex!(10, 4, 3, 2, 2);
     ndindex!(10, 4, 3, 2, 2, 2);
 }
+
+#[test]
+fn fixed_rank_generic() {
+    use ndarray::{ArrayN, IxN};
+
+    fn first_and_last<const N: usize>(a: &ArrayN<i32, N>) -> (i32, i32)
+    where
+        IxN<N>: Dimension,
+    {
+        let mut last = a.raw_dim();
+        for i in 0..N {
+            last[i] -= 1;
+        }
+        (a.iter().next().cloned().unwrap(), a[last])
+    }
+
+    fn add_axis<const N: usize>(a: ArrayN<i32, N>) -> Array<i32, <IxN<N> as Dimension>::Larger>
+    where
+        IxN<N>: Dimension,
+    {
+        a.insert_axis(Axis(N))
+    }
+
+    let a = Array::from_shape_fn((2, 3), |(i, j)| (i * 3 + j) as i32);
+    assert_eq!(first_and_last(&a), (0, 5));
+    let b = add_axis(a);
+    assert_eq!(b.shape(), &[2, 3, 1]);
+    assert_eq!(first_and_last(&b), (0, 5));
+    assert_eq!(first_and_last(&arr1(&[7, 8])), (7, 8));
+}
@@ -0,0 +1,67 @@
+use ndarray::ffi::{CArrayView, CArrayViewMut};
+use ndarray::prelude::*;
+use ndarray::{arr0, s};
+
+#[test]
+fn ffi_view_roundtrip() {
+    let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    for view in &[
+        a.view(),
+        a.slice(s![.., ..;-1, ..]),
+        a.slice(s![..;-1, 1.., ..;-2]),
+        a.view().reversed_axes(),
+    ] {
+        let c = CArrayView::from(view);
+        assert_eq!(c.ndim(), 3);
+        let back = unsafe { c.as_array_view() };
+        assert_eq!(back, view.view().into_dyn());
+    }
+}
+
+#[test]
+fn ffi_view_raw_parts() {
+    let data = [1, 2, 3, 4, 5, 6];
+    let shape = [2, 3];
+    let strides = [-3, 1];
+    let c = unsafe {
+        CArrayView::from_raw_parts(data[3..].as_ptr(), 2, shape.as_ptr(), strides.as_ptr())
+    };
+    let v = unsafe { c.as_array_view() };
+    assert_eq!(v, array![[4, 5, 6], [1, 2, 3]].into_dyn());
+}
+
+#[test]
+fn ffi_view_zero_dim_and_empty() {
+    let a = arr0(7.);
+    let v = unsafe { CArrayView::from(&a).as_array_view() };
+    assert_eq!(v.shape(), &[] as &[usize]);
+    assert_eq!(v[[]], 7.);
+
+    let c = unsafe {
+        CArrayView::<f64>::from_raw_parts(std::ptr::null(), 0, std::ptr::null(), std::ptr::null())
+    };
+    assert_eq!(c.ndim(), 0);
+
+    let shape = [0, 3];
+    let strides = [3, 1];
+    let c = unsafe {
+        CArrayView::<f64>::from_raw_parts(std::ptr::null(), 2, shape.as_ptr(), strides.as_ptr())
+    };
+    let v = unsafe { c.as_array_view() };
+    assert_eq!(v.shape(), &[0, 3]);
+}
+
+#[test]
+fn ffi_view_mut() {
+    let mut a = Array2::<i32>::zeros((3, 4));
+    {
+        let mut s = a.slice_mut(s![..;-1, ..;2]);
+        let c = CArrayViewMut::from(&mut s);
+        let mut v = unsafe { c.into_array_view_mut() };
+        v[[0, 1]] = 1;
+        v[[2, 0]] = 2;
+    }
+    assert_eq!(a[[2, 2]], 1);
+    assert_eq!(a[[0, 0]], 2);
+    assert_eq!(a.sum(), 3);
+}
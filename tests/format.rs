@@ -72,3 +72,55 @@ fn debug_format() {
  [0, 0, 0, 0]], shape=[3, 4], strides=[4, 1], layout=Cc (0x5), dynamic ndim=2"
     );
 }
+
+#[test]
+fn table_formatting() {
+    use ndarray::TableFormat;
+
+    let a = arr1(&["a|b", "<c>"]);
+    assert_eq!(
+        a.display_as(TableFormat::Markdown).to_string(),
+        "|  |  |\n|---|---|\n| a\\|b | <c> |\n"
+    );
+    assert_eq!(
+        a.display_as(TableFormat::Html).to_string(),
+        "<table>\n<tr><td>a|b</td><td>&lt;c&gt;</td></tr>\n</table>\n"
+    );
+
+    let b = arr2(&[[1.5f64, 2.], [3., 4.], [5., 6.]]);
+    assert_eq!(
+        format!("{:.1}", b.display_as(TableFormat::Latex)),
+        "\\begin{bmatrix}\n1.5 & 2.0 \\\\\n3.0 & 4.0 \\\\\n5.0 & 6.0\n\\end{bmatrix}\n"
+    );
+    assert_eq!(
+        Array2::<f64>::zeros((0, 2)).display_as(TableFormat::Latex).to_string(),
+        "\\begin{bmatrix}\n\\end{bmatrix}\n"
+    );
+}
+
+#[test]
+fn display_options() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f64);
+    assert_eq!(a.display().to_string(), a.to_string());
+    assert_eq!(
+        a.display().threshold(0).edge_items(1).precision(1).to_string(),
+        "\
+[[[0.0, ..., 3.0],
+  ...,
+  [8.0, ..., 11.0]],
+
+ [[12.0, ..., 15.0],
+  ...,
+  [20.0, ..., 23.0]]]"
+    );
+    let a = a.slice(s![0, 0, ..]);
+    assert_eq!(
+        format!("{:#}", a.display().threshold(0).edge_items(0)),
+        "[0, 1, 2, 3]"
+    );
+    assert_eq!(a.display().threshold(0).edge_items(0).to_string(), "[...]");
+    assert_eq!(
+        format!("{:5.1}", a.display().separator("|")),
+        "[  0.0|  1.0|  2.0|  3.0]"
+    );
+}
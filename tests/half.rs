@@ -0,0 +1,28 @@
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use ndarray::prelude::*;
+
+#[test]
+fn half_mean_f32() {
+    let a = Array::linspace(0., 1., 1001).mapv(f16::from_f64);
+    let mean = a.mapv(f64::from).mean().unwrap();
+    assert!((f64::from(a.mean_f32().unwrap()) - mean).abs() < 1e-6);
+    assert_eq!(a.slice(s![..0]).mean_f32(), None);
+
+    // The generic methods accept half precision elements too
+    assert_eq!(a.slice(s![..3;2]).mean(), Some(f16::from_f64(0.001)));
+}
+
+#[test]
+fn half_var_axis_f32() {
+    let a = Array::from_shape_fn((300, 2), |(i, j)| (i * (j + 1)) as f32);
+    let expected = a.var_axis(Axis(0), 1.);
+    let b = a.mapv(bf16::from_f32);
+    let var = b.var_axis_f32(Axis(0), 1.);
+    assert!((&var - &expected).iter().all(|d| d.abs() / expected[1] < 1e-2));
+    assert_eq!(b.t().var_axis_f32(Axis(1), 1.), var);
+
+    let empty = Array2::<f16>::zeros((0, 3));
+    assert!(empty.var_axis_f32(Axis(0), 0.).iter().all(|x| x.is_nan()));
+}
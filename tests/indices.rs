@@ -21,3 +21,94 @@ fn test_ixdyn_index_iterate() {
         assert_eq!(c, a.len());
     }
 }
+
+#[test]
+fn index_counter() {
+    use ndarray::IndexCounter;
+
+    let a = Array::from_shape_fn((2, 3, 4).f(), |(i, j, k)| 100 * i + 10 * j + k);
+    let mut counter = IndexCounter::new(a.raw_dim());
+    let mut n = 0;
+    for (index, &x) in a.indexed_iter() {
+        assert!(!counter.is_done());
+        assert_eq!(counter.index().into_pattern(), index);
+        assert_eq!(unsafe { *a.as_ptr().offset(counter.offset(a.strides())) }, x);
+        assert_eq!(counter.advance(), n + 1 < a.len());
+        n += 1;
+    }
+    assert!(counter.is_done());
+    assert!(!counter.advance());
+    assert_eq!(counter.index(), &Ix3(0, 0, 0));
+
+    // Skip the rest of each row after its first element
+    counter.reset();
+    let mut firsts = Vec::new();
+    while !counter.is_done() {
+        firsts.push(counter.index().clone());
+        counter.advance_axis(Axis(1));
+    }
+    assert_eq!(firsts.len(), 6);
+    assert!(firsts.iter().all(|ix| ix[2] == 0));
+
+    let mut counter = IndexCounter::new(IxDyn(&[2, 0, 3]));
+    assert!(counter.is_done());
+    assert!(!counter.advance());
+    let mut counter = IndexCounter::new(());
+    assert!(!counter.is_done());
+    assert_eq!(counter.offset(&[]), 0);
+    assert!(!counter.advance());
+    assert!(counter.is_done());
+}
+
+#[test]
+fn position_where() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 7 + j * 3 + k) % 6);
+    let mut f = a.clone().reversed_axes();
+    f.assign(&a.t());
+    let mut inverted = a.clone();
+    inverted.invert_axis(Axis(1));
+    let views = [a.view(), a.t(), f.view(), inverted.view(), a.slice(s![.., 1.., ..;2])];
+    for view in &views {
+        let matches: Vec<_> = view
+            .indexed_iter()
+            .filter(|&(_, &x)| x == 4)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(view.position_where(|&x| x == 4), matches.first().cloned());
+        assert_eq!(view.rposition_where(|&x| x == 4), matches.last().cloned());
+        assert_eq!(view.positions_where(3, |&x| x == 4), &matches[..3]);
+        assert_eq!(view.positions_where(usize::MAX, |&x| x == 4), matches);
+        assert_eq!(view.position_where(|&x| x > 5), None);
+        assert_eq!(view.rposition_where(|&x| x > 5), None);
+
+        // The first match in memory order
+        let index = view.position_where_memory_order(|&x| x == 4).unwrap();
+        assert_eq!(view[index], 4);
+        if view.is_standard_layout() || view.t().is_standard_layout() {
+            let first = view.iter().map(|x| x as *const usize).filter(|&p| unsafe { *p } == 4).min();
+            assert_eq!(&view[index] as *const usize, first.unwrap());
+        }
+    }
+}
+
+#[test]
+fn argwhere_and_flatnonzero() {
+    let a = Array::from_shape_fn((2, 3, 4).f(), |(i, j, k)| (i + j * k) % 3);
+    let mask = a.mapv(|x| x != 0);
+    let hits = ndarray::argwhere(&mask);
+    assert_eq!(hits.ncols(), 3);
+    assert_eq!(hits.nrows(), a.flatnonzero().len());
+    let expected: Vec<usize> = a
+        .indexed_iter()
+        .filter(|&(_, &x)| x != 0)
+        .flat_map(|((i, j, k), _)| vec![i, j, k])
+        .collect();
+    assert_eq!(hits.iter().cloned().collect::<Vec<_>>(), expected);
+
+    let flat = a.iter().cloned().collect::<Array1<_>>();
+    assert_eq!(a.flatnonzero(), flat.flatnonzero());
+    assert!(a.flatnonzero().iter().all(|&i| flat[i] != 0));
+
+    assert_eq!(ndarray::argwhere(&arr0(true)), Array2::<usize>::zeros((1, 0)));
+    assert_eq!(ndarray::argwhere(&Array2::<bool>::default((2, 2))).shape(), &[0, 2]);
+}
@@ -7,6 +7,7 @@
 )]
 
 use ndarray::prelude::*;
+use ndarray::{arr1, ChunkTail};
 
 #[test]
 #[cfg(feature = "std")]
@@ -99,3 +100,51 @@ fn chunks_different_size_3() {
     let mut a = Array::<f32, _>::zeros(vec![2, 3]);
     a.exact_chunks_mut(vec![2, 3, 4]);
 }
+
+#[test]
+fn chunks_with_overlap() {
+    let a = Array::from_shape_fn((2, 7), |(i, j)| 10 * i + j);
+    let chunks = a
+        .axis_chunks_with_overlap(Axis(1), 3, 1, ChunkTail::Keep)
+        .collect::<Vec<_>>();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0], a.slice(s![.., 0..3]));
+    assert_eq!(chunks[1], a.slice(s![.., 2..5]));
+    assert_eq!(chunks[2], a.slice(s![.., 4..7]));
+    assert!(chunks.iter().all(|c| c.is_view()));
+
+    // The partial chunk 6..7 is in the previous chunk, so it's not produced
+    let a = Array::from_iter(0..7);
+    let chunks = a.axis_chunks_with_overlap(Axis(0), 4, 3, ChunkTail::Keep);
+    assert_eq!(chunks.count(), 4);
+
+    let chunks = a
+        .axis_chunks_with_overlap(Axis(0), 5, 0, ChunkTail::Keep)
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, vec![arr1(&[0, 1, 2, 3, 4]), arr1(&[5, 6])]);
+    let chunks = a
+        .axis_chunks_with_overlap(Axis(0), 5, 0, ChunkTail::Drop)
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, vec![arr1(&[0, 1, 2, 3, 4])]);
+    let chunks = a
+        .axis_chunks_with_overlap(Axis(0), 5, 0, ChunkTail::Pad(-1))
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, vec![arr1(&[0, 1, 2, 3, 4]), arr1(&[5, 6, -1, -1, -1])]);
+    assert!(chunks[1].is_owned());
+
+    // Shorter than a chunk
+    let chunks = a
+        .axis_chunks_with_overlap(Axis(0), 10, 5, ChunkTail::Pad(0))
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, vec![arr1(&[0, 1, 2, 3, 4, 5, 6, 0, 0, 0])]);
+
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(empty.axis_chunks_with_overlap(Axis(0), 2, 1, ChunkTail::Pad(0)).count(), 0);
+}
+
+#[should_panic]
+#[test]
+fn chunks_with_overlap_too_large() {
+    let a = Array1::<f32>::zeros(8);
+    a.axis_chunks_with_overlap(Axis(0), 2, 2, ChunkTail::Keep);
+}
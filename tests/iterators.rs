@@ -6,7 +6,7 @@
 )]
 
 use ndarray::prelude::*;
-use ndarray::{arr3, aview1, indices, s, Axis, Slice, Zip};
+use ndarray::{arr3, aview1, indices, s, Axis, Order, Slice, Zip};
 
 use itertools::assert_equal;
 use itertools::enumerate;
@@ -952,6 +952,43 @@ fn test_into_iter_sliced() {
     }
 }
 
+#[test]
+fn test_into_iter_partially_consumed() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // The elements that are not iterated are dropped with the iterator,
+    // with and without unreachable elements
+    let drops = Cell::new(0);
+    let a = Array::from_shape_fn((2, 5), |(i, j)| DropCount::new((i * 5 + j) as i32, &drops));
+    let mut iter = a.into_iter();
+    let first = iter.next().unwrap();
+    drop(iter);
+    assert_eq!(drops.get(), 9);
+    drop(first);
+    assert_eq!(drops.get(), 10);
+
+    drops.set(0);
+    let mut a = Array::from_shape_fn((2, 5), |(i, j)| DropCount::new((i * 5 + j) as i32, &drops));
+    a.slice_collapse(s![.., 1..4]);
+    let mut iter = a.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(drops.get(), 10);
+
+    // Zero-sized elements
+    static ZST_DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Zst;
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            ZST_DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    let mut iter = Array::from_shape_fn((2, 3), |_| Zst).into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(ZST_DROPS.load(Ordering::SeqCst), 6);
+}
+
 /// Helper struct that counts its drops Asserts that it's not dropped twice. Also global number of
 /// drops is counted in the cell.
 ///
@@ -982,3 +1019,45 @@ impl Drop for DropCount<'_> {
         self.drops.set(self.drops.get() + 1);
     }
 }
+
+#[test]
+fn iter_memory_order() {
+    let a = Array::from_shape_vec((2, 3, 4).f(), (0..24).collect()).unwrap();
+    assert!(a.iter_memory_order().copied().eq(0..24));
+
+    // Negative strides and a permutation of the axes
+    let mut b = a.clone().permuted_axes([1, 0, 2]);
+    b.invert_axis(Axis(2));
+    b.invert_axis(Axis(0));
+    assert!(b.iter_memory_order().copied().eq(0..24));
+    assert_eq!(b.iter_memory_order().len(), 24);
+
+    // A non-contiguous view is visited in order of increasing address
+    let c = a.slice(s![.., ..;-2, 1..3]);
+    let addresses: Vec<_> = c.iter_memory_order().map(|x| x as *const i32).collect();
+    assert!(addresses.windows(2).all(|w| w[0] < w[1]));
+    let mut sorted: Vec<i32> = c.iter().copied().collect();
+    sorted.sort_unstable();
+    assert!(c.iter_memory_order().copied().eq(sorted));
+
+    let mut d = b.clone();
+    for (i, x) in d.iter_memory_order_mut().enumerate() {
+        *x = i as i32;
+    }
+    assert_eq!(d, b);
+}
+
+#[test]
+fn iter_ptrs() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| i * 100 + j * 10 + k);
+    let v = a.slice(s![..;-1, 1.., ..]);
+    for &order in &[Order::RowMajor, Order::ColumnMajor] {
+        let ptrs = v.iter_ptrs(order);
+        assert_eq!(ptrs.len(), v.len());
+        let logical = if order == Order::RowMajor { v.view() } else { v.t() };
+        let expected: Vec<*const usize> = logical.iter().map(|x| x as *const usize).collect();
+        assert_equal(ptrs, expected);
+    }
+    assert_eq!(arr0(1).iter_ptrs(Order::RowMajor).count(), 1);
+    assert_eq!(Array2::<u8>::zeros((3, 0)).iter_ptrs(Order::ColumnMajor).count(), 0);
+}
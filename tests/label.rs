@@ -0,0 +1,71 @@
+use ndarray::prelude::*;
+use ndarray::{component_sizes, label_connected_components, Connectivity, ShapeBuilder};
+
+/// Label the components by flood fill from each unlabeled element, in
+/// logical order.
+fn flood_fill(mask: &ArrayD<bool>, connectivity: Connectivity) -> (ArrayD<usize>, usize) {
+    let mut labels = ArrayD::zeros(mask.raw_dim());
+    let mut count = 0;
+    let indices: Vec<IxDyn> = ndarray::indices(mask.raw_dim()).into_iter().collect();
+    let neighbors = |a: &IxDyn, b: &IxDyn| {
+        let diffs = a.slice().iter().zip(b.slice()).map(|(&x, &y)| (x as isize - y as isize).abs());
+        match connectivity {
+            Connectivity::Face => diffs.sum::<isize>() == 1,
+            _ => diffs.max() == Some(1),
+        }
+    };
+    for start in &indices {
+        if !mask[start] || labels[start] != 0 {
+            continue;
+        }
+        count += 1;
+        labels[start] = count;
+        let mut stack = vec![start.clone()];
+        while let Some(index) = stack.pop() {
+            for other in &indices {
+                if mask[other] && labels[other] == 0 && neighbors(&index, other) {
+                    labels[other] = count;
+                    stack.push(other.clone());
+                }
+            }
+        }
+    }
+    (labels, count)
+}
+
+#[test]
+fn label_connected_components_matches_flood_fill() {
+    let mut state = 12345u32;
+    let mut random_mask = |shape: &[usize]| {
+        ArrayD::from_shape_simple_fn(IxDyn(shape), || {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            (state >> 16) % 5 < 2
+        })
+    };
+    for shape in &[vec![9, 11], vec![4, 5, 6], vec![17], vec![0, 3]] {
+        let mask = random_mask(shape);
+        for &connectivity in &[Connectivity::Face, Connectivity::Full] {
+            let expected = flood_fill(&mask, connectivity);
+            assert_eq!(label_connected_components(&mask, connectivity), expected);
+            let sizes = component_sizes(&expected.0, expected.1);
+            assert_eq!(sizes.iter().sum::<usize>(), mask.len());
+            assert_eq!(sizes[0], mask.iter().filter(|&&m| !m).count());
+        }
+    }
+}
+
+#[test]
+fn label_connected_components_merges() {
+    // The arms of the U get different provisional labels, which are merged
+    let mask = array![[true, false, true, false, true],
+                      [true, false, true, false, true],
+                      [true, true, true, true, true]];
+    let (labels, count) = label_connected_components(&mask, Connectivity::Face);
+    assert_eq!(count, 1);
+    assert_eq!(labels, mask.mapv(|m| m as usize));
+
+    // A column major mask is labeled in logical order
+    let mask = Array::from_shape_vec((2, 3).f(), vec![false, true, false, false, true, false]).unwrap();
+    let (labels, count) = label_connected_components(&mask, Connectivity::Face);
+    assert_eq!((labels, count), (array![[0, 0, 1], [2, 0, 0]], 2));
+}
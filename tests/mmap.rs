@@ -0,0 +1,74 @@
+#![cfg(feature = "mmap")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use ndarray::mmap::{MmapArray, MmapCowArray, MmapError};
+use ndarray::prelude::*;
+use ndarray::ErrorKind;
+
+/// Write `values` to a temporary file after a header of `header` bytes.
+fn write_temp(name: &str, header: usize, values: &[i32]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("ndarray-test-{}-{}", std::process::id(), name));
+    let mut bytes = vec![0xff; header];
+    for x in values {
+        bytes.extend_from_slice(&x.to_ne_bytes());
+    }
+    fs::write(&path, &bytes).unwrap();
+    path
+}
+
+#[test]
+fn mmap_read_only() {
+    let values = (0..24).collect::<Vec<i32>>();
+    let path = write_temp("read-only", 8, &values);
+    let a = unsafe { MmapArray::<i32, Ix3>::open_mmap(&path, 8, (2, 3, 4)).unwrap() };
+    let expected = Array::from_shape_vec((2, 3, 4), values).unwrap();
+    assert_eq!(a, expected);
+    assert_eq!(a.slice(s![1, .., ..;2]), expected.slice(s![1, .., ..;2]));
+    assert_eq!(a.sum_axis(Axis(2)), expected.sum_axis(Axis(2)));
+
+    // Clones share the map
+    let b = a.clone();
+    assert_eq!(b.as_ptr(), a.as_ptr());
+
+    // The file may be longer than needed
+    let c = unsafe { MmapArray::<i32, Ix2>::open_mmap(&path, 8, (2, 2).f()).unwrap() };
+    assert_eq!(c, array![[0, 2], [1, 3]]);
+    drop((a, b, c));
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_copy_on_write() {
+    let path = write_temp("cow", 0, &[1, 2, 3, 4]);
+    let mut a = unsafe { MmapCowArray::<i32, Ix1>::open_mmap_cow(&path, 0, 4).unwrap() };
+    a[1] = 20;
+    a.slice_mut(s![2..]).map_inplace(|x| *x *= 10);
+    assert_eq!(a, array![1, 20, 30, 40]);
+    drop(a);
+
+    // The file is not modified
+    let b = unsafe { MmapArray::<i32, Ix1>::open_mmap(&path, 0, 4).unwrap() };
+    assert_eq!(b, array![1, 2, 3, 4]);
+    drop(b);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_errors() {
+    let path = write_temp("errors", 0, &[1, 2, 3, 4]);
+    unsafe {
+        match MmapArray::<i32, Ix2>::open_mmap(&path, 0, (3, 2)) {
+            Err(MmapError::Shape(err)) => assert_eq!(err.kind(), ErrorKind::OutOfBounds),
+            other => panic!("unexpected result {:?}", other),
+        }
+        match MmapArray::<i32, Ix1>::open_mmap(&path, 2, 2) {
+            Err(MmapError::Shape(err)) => assert_eq!(err.kind(), ErrorKind::IncompatibleLayout),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+    fs::remove_file(&path).unwrap();
+    let missing = unsafe { MmapArray::<i32, Ix1>::open_mmap(&path, 0, 4) };
+    assert!(matches!(missing, Err(MmapError::Io(_))));
+}
@@ -0,0 +1,137 @@
+use ndarray::prelude::*;
+use ndarray::PadMode;
+
+/// Erode or dilate by checking the neighborhood of each element
+fn naive(a: &ArrayD<bool>, footprint: &ArrayD<bool>, boundary: &PadMode<bool>, erode: bool) -> ArrayD<bool> {
+    let element = |index: &[isize]| {
+        let mut clamped = Vec::new();
+        for (&i, &len) in index.iter().zip(a.shape()) {
+            if 0 <= i && i < len as isize {
+                clamped.push(i as usize);
+            } else {
+                match *boundary {
+                    PadMode::Constant(value) => return value,
+                    PadMode::Edge => clamped.push(if i < 0 { 0 } else { len - 1 }),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        a[&clamped[..]]
+    };
+    ArrayD::from_shape_fn(a.raw_dim(), |x| {
+        let mut taps = footprint.indexed_iter().filter(|&(_, &f)| f).map(|(s, _)| {
+            let y: Vec<isize> = (0..a.ndim())
+                .map(|k| {
+                    let offset = s[k] as isize - (footprint.shape()[k] / 2) as isize;
+                    if erode {
+                        x[k] as isize + offset
+                    } else {
+                        x[k] as isize - offset
+                    }
+                })
+                .collect();
+            element(&y)
+        });
+        if erode {
+            taps.all(|t| t)
+        } else {
+            taps.any(|t| t)
+        }
+    })
+}
+
+#[test]
+fn binary_morphology_matches_naive() {
+    let mut state = 2021u32;
+    let mut random_mask = |shape: &[usize], percent: u32| {
+        ArrayD::from_shape_simple_fn(IxDyn(shape), || {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            (state >> 16) % 100 < percent
+        })
+    };
+    // The last axes of 64 or more elements are eroded in packed words
+    let cases = [
+        (vec![9, 11], vec![3, 3]),
+        (vec![4, 5, 6], vec![2, 3, 1]),
+        (vec![70], vec![5]),
+        (vec![3, 130], vec![3, 4]),
+        (vec![2, 3, 200], vec![1, 2, 67]),
+        (vec![0, 80], vec![3, 3]),
+    ];
+    for (shape, footprint_shape) in &cases {
+        let a = random_mask(shape, 70);
+        let footprint = random_mask(footprint_shape, 60);
+        for boundary in &[PadMode::Constant(false), PadMode::Constant(true), PadMode::Edge] {
+            assert_eq!(
+                a.binary_erode(&footprint, boundary),
+                naive(&a, &footprint, boundary, true),
+                "erode {:?} by {:?}",
+                shape,
+                footprint_shape
+            );
+            assert_eq!(
+                a.binary_dilate(&footprint, boundary),
+                naive(&a, &footprint, boundary, false),
+                "dilate {:?} by {:?}",
+                shape,
+                footprint_shape
+            );
+        }
+    }
+}
+
+#[test]
+fn binary_opening_closing() {
+    let square = Array::from_elem((3, 3), true);
+    let mut a = Array::from_elem((8, 70), false);
+    a.slice_mut(s![1..6, 2..60]).fill(true);
+    // A hole, and a speck outside of the rectangle
+    a[[3, 30]] = false;
+    a[[7, 65]] = true;
+
+    // The speck is removed, and the hole widens into a gap that splits the
+    // rectangle, which the square doesn't fit in
+    let opened = a.binary_opening(&square, &PadMode::Constant(false));
+    let mut expected = Array::from_elem((8, 70), false);
+    expected.slice_mut(s![1..6, 2..60]).fill(true);
+    expected.slice_mut(s![1..6, 30]).fill(false);
+    assert_eq!(opened, expected);
+
+    // The hole is filled, and the speck at the boundary is removed
+    let closed = a.binary_closing(&square, &PadMode::Constant(false));
+    let mut expected = Array::from_elem((8, 70), false);
+    expected.slice_mut(s![1..6, 2..60]).fill(true);
+    assert_eq!(closed, expected);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn distance_transform_edt_matches_brute_force() {
+    let mut state = 818u32;
+    let mut random_mask = |shape: &[usize], percent: u32| {
+        ArrayD::from_shape_simple_fn(IxDyn(shape), || {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            (state >> 16) % 100 < percent
+        })
+    };
+    for (shape, percent) in &[(vec![13, 17], 10), (vec![5, 6, 7], 3), (vec![40], 5), (vec![0, 4], 50)] {
+        let mask = random_mask(shape, *percent);
+        let points: Vec<IxDyn> = ndarray::indices(mask.raw_dim())
+            .into_iter()
+            .filter(|index| mask[index])
+            .collect();
+        let expected = ArrayD::from_shape_fn(mask.raw_dim(), |x| {
+            points
+                .iter()
+                .map(|p| {
+                    let squared: usize = (0..x.ndim()).map(|k| (x[k] as isize - p[k] as isize).pow(2) as usize).sum();
+                    (squared as f64).sqrt()
+                })
+                .fold(f64::INFINITY, f64::min)
+        });
+        assert_eq!(ndarray::distance_transform_edt(&mask), expected, "{:?}", shape);
+    }
+
+    let empty = Array::from_elem((2, 3), false);
+    assert!(ndarray::distance_transform_edt(&empty).iter().all(|d| d.is_infinite()));
+}
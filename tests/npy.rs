@@ -0,0 +1,107 @@
+#![cfg(feature = "npy")]
+
+use ndarray::npy::{read_npy, write_npy, NpyElement, NpyError, NpzReader, NpzWriter};
+use ndarray::prelude::*;
+use ndarray::ShapeBuilder;
+use num_complex::Complex;
+use std::fmt::Debug;
+use std::io::Cursor;
+
+fn roundtrip<A, S, D>(a: &ArrayBase<S, D>) -> ArrayD<A>
+where
+    A: NpyElement + PartialEq + Debug,
+    S: ndarray::Data<Elem = A>,
+    D: Dimension,
+{
+    let mut file = Vec::new();
+    write_npy(&mut file, a).unwrap();
+    // The data starts on a 64 byte boundary
+    assert_eq!((file.len() - a.len() * std::mem::size_of::<A>()) % 64, 0);
+    let b = read_npy(&file[..]).unwrap();
+    assert_eq!(b, a.view().into_dyn());
+    b
+}
+
+#[test]
+fn npy_roundtrip() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 100 + j * 10 + k) as f64);
+    assert!(roundtrip(&a).is_standard_layout());
+    roundtrip(&a.mapv(|x| x as f32));
+    roundtrip(&a.mapv(|x| x as i8 as u8));
+    roundtrip(&a.mapv(|x| -x as i64));
+    roundtrip(&a.mapv(|x| x as u16));
+    roundtrip(&a.mapv(|x| x as i32 % 3 == 0));
+    roundtrip(&a.mapv(|x| Complex::new(x, -x)));
+    roundtrip(&a.slice(s![..;2, 1.., ..;-1]));
+    roundtrip(&arr0(7u32));
+    roundtrip(&Array2::<f64>::zeros((0, 3)));
+}
+
+#[test]
+fn npy_fortran_order() {
+    let a = Array::from_shape_vec((2, 3).f(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let mut file = Vec::new();
+    write_npy(&mut file, &a).unwrap();
+    assert!(String::from_utf8_lossy(&file).contains("'fortran_order': True"));
+    // The elements are stored in memory order
+    assert_eq!(file[file.len() - 24..file.len() - 20], 1i32.to_ne_bytes());
+    assert_eq!(file[file.len() - 20..file.len() - 16], 2i32.to_ne_bytes());
+    let b = read_npy::<_, i32>(&file[..]).unwrap();
+    assert_eq!(b, a.into_dyn());
+    assert!(b.t().is_standard_layout());
+}
+
+#[test]
+fn npy_read_big_endian() {
+    let dict = "{'descr': '>i2', 'fortran_order': True, 'shape': (2, 3), }";
+    let mut file = b"\x93NUMPY\x01\x00".to_vec();
+    file.extend_from_slice(&118u16.to_le_bytes());
+    file.extend_from_slice(dict.as_bytes());
+    file.resize(127, b' ');
+    file.push(b'\n');
+    for x in 0..6i16 {
+        file.extend_from_slice(&x.to_be_bytes());
+    }
+    let a = read_npy::<_, i16>(&file[..]).unwrap();
+    assert_eq!(a, array![[0, 2, 4], [1, 3, 5]].into_dyn());
+}
+
+#[test]
+fn npy_errors() {
+    let mut file = Vec::new();
+    write_npy(&mut file, &array![1., 2., 3.]).unwrap();
+    match read_npy::<_, f32>(&file[..]) {
+        Err(NpyError::DType { expected, found }) => {
+            assert_eq!(&expected[1..], "f4");
+            assert_eq!(&found[1..], "f8");
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    assert!(matches!(read_npy::<_, f64>(&file[..file.len() - 1]), Err(NpyError::Io(_))));
+    assert!(matches!(read_npy::<_, f64>(&file[1..]), Err(NpyError::Format(_))));
+}
+
+#[test]
+fn npz_roundtrip() {
+    for &compressed in &[false, true] {
+        let a = Array::from_shape_fn((10, 20), |(i, j)| (i * j) as f32);
+        let b = array![true, false, true];
+        let mut npz = if compressed {
+            NpzWriter::new_compressed(Cursor::new(Vec::new()))
+        } else {
+            NpzWriter::new(Cursor::new(Vec::new()))
+        };
+        npz.add_array("a", &a).unwrap();
+        npz.add_array("b", &b).unwrap();
+        let file = npz.finish().unwrap();
+
+        let mut npz = NpzReader::new(file).unwrap();
+        assert_eq!(npz.len(), 2);
+        assert_eq!(npz.names().unwrap(), vec!["a", "b"]);
+        assert_eq!(npz.by_name::<f32>("a").unwrap(), a.into_dyn());
+        assert_eq!(npz.by_name::<bool>("b.npy").unwrap(), b.into_dyn());
+        assert_eq!(npz.by_index::<bool>(1).unwrap(), npz.by_name::<bool>("b").unwrap());
+        assert!(matches!(npz.by_name::<f32>("c"), Err(NpyError::MissingArray(_))));
+        assert!(matches!(npz.by_name::<f64>("a"), Err(NpyError::DType { .. })));
+    }
+}
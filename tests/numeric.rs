@@ -7,7 +7,7 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{arr0, arr1, arr2, array, aview1, s, Aggregate, Array, Array1, Array2, Array3, Axis, InterpOrder, PadMode, ShapeBuilder};
 use std::f64;
 
 #[test]
@@ -307,3 +307,508 @@ fn std_axis_empty_axis() {
     assert_eq!(v.shape(), &[2]);
     v.mapv(|x| assert!(x.is_nan()));
 }
+
+#[test]
+fn integral_image_and_box_filter() {
+    let a = Array::from_shape_fn((5, 6, 4), |(i, j, k)| ((i * 7 + j * 5 + k * 3) % 11) as u8 * 20);
+    let sums = a.integral_image::<u32>();
+    assert_eq!(sums[[4, 5, 3]], a.iter().map(|&x| x as u32).sum::<u32>());
+    assert_eq!(sums[[2, 3, 1]], a.slice(ndarray::s![..3, ..4, ..2]).iter().map(|&x| x as u32).sum::<u32>());
+
+    // Compare with the means of the windows
+    for &window in &[(1, 1, 1), (2, 3, 2), (5, 6, 4), (3, 1, 4)] {
+        let means = a.box_filter::<u32, _>(window);
+        let expected = Array::from_iter(
+            a.windows(window).into_iter().map(|w| w.iter().map(|&x| x as u32).sum::<u32>() / w.len() as u32),
+        );
+        assert_eq!(means.len(), expected.len());
+        assert_eq!(means.into_shape(expected.len()).unwrap(), expected);
+    }
+    let means = a.mapv(f64::from).box_filter::<f64, _>((2, 2, 2));
+    assert_eq!(means.shape(), &[4, 5, 3]);
+    assert_abs_diff_eq!(means[[1, 2, 1]], a.slice(ndarray::s![1..3, 2..4, 1..3]).mapv(f64::from).mean().unwrap(), epsilon = 1e-9);
+
+    // Windows longer than the array
+    assert_eq!(a.box_filter::<u32, _>((6, 1, 1)).shape(), &[0, 6, 4]);
+    assert_eq!(arr1(&[-1i32, 3, -5]).box_filter::<i64, _>(2), arr1(&[1, -1]));
+}
+
+#[test]
+#[should_panic]
+fn box_filter_zero_window() {
+    arr1(&[1., 2.]).box_filter::<f64, _>(0);
+}
+
+#[test]
+fn describe() {
+    let a = Array::linspace(0., 10., 11).into_shape((11, 1)).unwrap();
+    let summary = a.describe();
+    assert_eq!(summary.count, 11);
+    assert_abs_diff_eq!(summary.mean, 5.);
+    assert_abs_diff_eq!(summary.std, a.std(1.), epsilon = 1e-12);
+    assert_eq!(summary.min, 0.);
+    assert_abs_diff_eq!(summary.q25, 2.5);
+    assert_abs_diff_eq!(summary.median, 5.);
+    assert_abs_diff_eq!(summary.q75, 7.5);
+    assert_eq!(summary.max, 10.);
+
+    let one = arr1(&[2f32]).describe();
+    assert_eq!((one.count, one.mean, one.median), (1, 2., 2.));
+    assert!(one.std.is_nan());
+
+    let empty = arr1(&[f64::NAN]).describe();
+    assert_eq!(empty.count, 0);
+    assert!(empty.mean.is_nan() && empty.min.is_nan() && empty.max.is_nan());
+}
+
+#[test]
+fn diff_report() {
+    use ndarray::diff_report;
+
+    let a = array![[[1., f64::NAN], [0., 2.]], [[3., 4.], [f64::INFINITY, 5.]]];
+    let mut b = a.clone();
+    let same = diff_report(&a, &b);
+    assert_eq!((same.len, same.count), (8, 0));
+    assert_eq!((same.max_abs_index, same.max_rel_index), (None, None));
+    assert_eq!(same.to_string(), "0 of 8 elements differ");
+
+    b[[0, 0, 1]] = 1.;
+    b[[0, 1, 0]] = 1e-9;
+    b[[1, 0, 1]] = 4.5;
+    b[[1, 1, 0]] = 0.;
+    let report = diff_report(&a, &b);
+    assert_eq!(report.count, 4);
+    assert_eq!(report.max_abs_diff, f64::INFINITY);
+    assert_eq!(report.max_abs_index, Some((1, 1, 0)));
+    assert_eq!(report.max_rel_diff, 1.);
+    assert_eq!(report.max_rel_index, Some((0, 1, 0)));
+    assert_eq!(report.axis_counts, vec![vec![2, 2], vec![2, 2], vec![2, 2]]);
+}
+
+#[test]
+fn round_to_int() {
+    use ndarray::Rounding;
+
+    let a = array![[-2.5, -1.5, -0.5], [0.5, 1.5, 2.49]];
+    let modes = [
+        (Rounding::HalfAwayFromZero, [[-3, -2, -1], [1, 2, 2]]),
+        (Rounding::HalfToEven, [[-2, -2, 0], [0, 2, 2]]),
+        (Rounding::Down, [[-3, -2, -1], [0, 1, 2]]),
+        (Rounding::Up, [[-2, -1, 0], [1, 2, 3]]),
+        (Rounding::TowardZero, [[-2, -1, 0], [0, 1, 2]]),
+    ];
+    for &(rounding, expected) in &modes {
+        let expected = arr2(&expected);
+        assert_eq!(a.round_to_int::<i64>(rounding), Some(expected.clone()));
+        assert_eq!(a.t().round_to_int_saturating::<i64>(rounding), expected.t());
+    }
+
+    let b = array![f64::NEG_INFINITY, -1e30, 127.4, 127.6, f64::INFINITY, f64::NAN];
+    assert_eq!(
+        b.round_to_int_saturating::<i8>(Rounding::HalfAwayFromZero),
+        array![-128, -128, 127, 127, 127, 0]
+    );
+    assert_eq!(array![127.4].round_to_int::<i8>(Rounding::Up), None);
+    assert_eq!(array![127.4].round_to_int::<i8>(Rounding::Down), Some(array![127]));
+    assert_eq!(array![1., f64::NAN].round_to_int::<i64>(Rounding::Down), None);
+}
+
+#[test]
+fn unwrap_phase_axis() {
+    use std::f64::consts::PI;
+
+    let phase = Array::linspace(0., 6. * PI, 25);
+    let wrapped = phase.mapv(|x| (x + PI).rem_euclid(2. * PI) - PI);
+    let a = ndarray::stack![Axis(1), wrapped, -&wrapped];
+    let unwrapped = a.unwrap_phase_axis(Axis(0));
+    assert_abs_diff_eq!(unwrapped, ndarray::stack![Axis(1), phase, -&phase], epsilon = 1e-12);
+
+    // Along the other axis, each row has two elements that differ by less than 2π
+    let rows = a.unwrap_phase_axis(Axis(1));
+    assert_abs_diff_eq!(rows.column(0), wrapped);
+    assert!(rows.rows().into_iter().all(|row| (row[1] - row[0]).abs() <= PI));
+
+    // A difference of exactly π is not changed
+    assert_eq!(array![0., PI].unwrap_phase_axis(Axis(0)), array![0., PI]);
+    assert_eq!(Array1::<f64>::zeros(0).unwrap_phase_axis(Axis(0)).len(), 0);
+}
+
+#[test]
+fn resample_axis() {
+    use ndarray::{BinSpec, Reducer};
+
+    let times = arr1(&[10i64, 11, 15, 17, 18, 30]);
+    let a = arr2(&[[1f64, 2., 3., 4., 5., 6.], [6., 5., 4., 3., 2., 1.]]).reversed_axes();
+    let bins = BinSpec::Width(5);
+    let (starts, sums) = a.resample_axis(Axis(0), &times, bins.clone(), Reducer::Sum);
+    assert_eq!(starts, arr1(&[10, 15, 20, 25, 30]));
+    assert_eq!(sums, arr2(&[[3., 11.], [12., 9.], [0., 0.], [0., 0.], [6., 1.]]));
+    let (_, min) = a.resample_axis(Axis(0), &times, bins.clone(), Reducer::Min);
+    assert_eq!(min.row(1), arr1(&[3., 2.]));
+    let (_, max) = a.resample_axis(Axis(0), &times, bins.clone(), Reducer::Max);
+    assert_eq!(max.row(1), arr1(&[5., 4.]));
+    let (_, first) = a.resample_axis(Axis(0), &times, bins.clone(), Reducer::First);
+    assert_eq!(first.column(0).select(Axis(0), &[0, 1, 4]), arr1(&[1., 3., 6.]));
+    assert!(first[[3, 0]].is_nan());
+    let (_, last) = a.resample_axis(Axis(0), &times, bins, Reducer::Last);
+    assert_eq!(last.row(0), arr1(&[2., 5.]));
+    assert!(last[[2, 0]].is_nan());
+
+    // Samples outside the edges are dropped
+    let (starts, means) =
+        a.resample_axis(Axis(0), &times, BinSpec::Edges(vec![11, 16, 18]), Reducer::Mean);
+    assert_eq!(starts, arr1(&[11, 16]));
+    assert_eq!(means, arr2(&[[2.5, 4.5], [4., 3.]]));
+
+    let empty = Array2::<f64>::zeros((0, 2));
+    let (starts, counts) =
+        empty.resample_axis(Axis(0), &Array1::<f64>::zeros(0), BinSpec::Width(1.), Reducer::Count);
+    assert_eq!(starts.len(), 0);
+    assert_eq!(counts.shape(), &[0, 2]);
+}
+
+#[test]
+#[should_panic]
+fn resample_axis_unsorted() {
+    use ndarray::{BinSpec, Reducer};
+    arr1(&[1., 2.]).resample_axis(Axis(0), &arr1(&[2., 1.]), BinSpec::Width(1.), Reducer::Mean);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_sketch_exact_while_buffered() {
+    use ndarray::QuantileSketch;
+
+    let mut sketch = QuantileSketch::new(10.);
+    assert_eq!(sketch.quantile(0.5), None);
+    sketch.extend(vec![3., 1., f64::NAN, 4., 1., 5.]);
+    assert_eq!(sketch.count(), 5);
+    assert_eq!((sketch.min(), sketch.max()), (Some(1.), Some(5.)));
+    // Sorted: 1 1 3 4 5
+    assert_eq!(sketch.quantile(0.), Some(1.));
+    assert_eq!(sketch.quantile(0.5), Some(3.));
+    assert_eq!(sketch.quantile(0.625), Some(3.5));
+    assert_eq!(sketch.quantile(1.), Some(5.));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_sketch_accuracy_and_merge() {
+    use ndarray::QuantileSketch;
+
+    // A permutation of 0..n, so the quantiles are known
+    let n = 20_000;
+    let a = Array::from_shape_fn(n, |i| ((i * 7919) % n) as f64);
+    let whole = a.quantile_sketch(100.);
+    let mut merged = QuantileSketch::new(100.);
+    for sketch in a.exact_chunks(1000).into_iter().map(|c| c.quantile_sketch(100.)) {
+        merged.merge(&sketch);
+    }
+    for sketch in &[whole, merged] {
+        assert_eq!(sketch.count(), n as u64);
+        assert_eq!(sketch.quantile(0.), Some(0.));
+        assert_eq!(sketch.quantile(1.), Some((n - 1) as f64));
+        for &q in &[0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let exact = q * (n - 1) as f64;
+            let estimate = sketch.quantile(q).unwrap();
+            assert!(
+                (estimate - exact).abs() <= 0.001 * n as f64,
+                "q = {}: estimate {}, exact {}",
+                q,
+                estimate,
+                exact
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_sketch_axis() {
+    let a = Array::from_shape_fn((3, 1000), |(i, j)| (i * 1000 + j) as i64);
+    let sketches = a.quantile_sketch_axis(Axis(1), 50.);
+    let medians = sketches.map(|s| s.quantile(0.5).unwrap());
+    assert_abs_diff_eq!(medians, arr1(&[499.5, 1499.5, 2499.5]), epsilon = 5.);
+}
+
+#[test]
+fn checked_saturating_wrapping_ops() {
+    let a = array![[100u8, 200], [50, 255]];
+    let b = array![[50u8], [5]];
+    let err = a.checked_add(&b).unwrap_err();
+    assert_eq!(err.index(), &[1, 1]);
+    assert_eq!(err.to_string(), "OverflowError: arithmetic overflow at index [1, 1]");
+    assert_eq!(a.checked_sub(&b), Ok(array![[50, 150], [45, 250]]));
+    assert_eq!(a.checked_mul(&arr0(2)).unwrap_err().index(), &[0, 1]);
+    assert_eq!(a.checked_div(&array![1, 0]).unwrap_err().index(), &[0, 1]);
+    assert_eq!(a.saturating_add(&b), array![[150, 250], [55, 255]]);
+    assert_eq!(b.saturating_sub(&a), array![[0, 0], [0, 0]]);
+    assert_eq!(a.saturating_mul(&b), array![[255, 255], [250, 255]]);
+    assert_eq!(a.wrapping_add(&b), array![[150, 250], [55, 4]]);
+    assert_eq!(b.wrapping_sub(&a), array![[206, 106], [211, 6]]);
+    assert_eq!(a.wrapping_mul(&arr0(2)), array![[200, 144], [100, 254]]);
+
+    // The first overflow in logical order, also for a column major array
+    let c = Array::from_shape_vec((2, 2).f(), vec![1i32, i32::MAX, i32::MAX, 1]).unwrap();
+    assert_eq!(c.checked_add(&arr0(1)).unwrap_err().index(), &[0, 1]);
+}
+
+#[test]
+fn all_close() {
+    let a = array![[1., 2.], [f64::INFINITY, 1e-9]];
+    let b = array![[1.001, 2.], [f64::INFINITY, 0.]];
+    assert!(a.all_close(&b, 1e-3, 1e-8));
+    assert!(!a.all_close(&b, 1e-4, 1e-8));
+    assert!(!a.all_close(&b, 1e-3, 0.));
+    assert_eq!(a.first_not_close(&b, 1e-3, 0.), Some(((1, 1), 1e-9, 0.)));
+    assert!(!a.all_close(&array![[1., 2.]], 1., 1.));
+
+    // The tolerance is relative to `other`, and NaN is never close
+    assert!(arr1(&[2.]).all_close(&arr1(&[1.]), 1., 0.));
+    assert!(!arr1(&[1.]).all_close(&arr1(&[2.]), 0.4, 0.));
+    assert!(!arr1(&[f64::NAN]).all_close(&arr1(&[f64::NAN]), 1., 1.));
+
+    // A column major array is checked in logical order
+    let c = Array::from_shape_vec((2, 2).f(), vec![0., 1., 1., 1.]).unwrap();
+    assert_eq!(c.first_not_close(&Array2::zeros((2, 2)), 0., 0.5), Some(((0, 1), 1., 0.)));
+
+    ndarray::assert_all_close!(a, b, 1e-3, 1e-8);
+    let msg = std::panic::catch_unwind(|| ndarray::assert_all_close!(c, Array2::zeros((2, 2)), 0., 0.5))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert!(msg.ends_with("failed: element at (0, 1) is 1.0, expected 0.0"), "{}", msg);
+
+    // Long contiguous arrays in the same and in different layouts, with one
+    // element that is not close
+    let x = Array::from_shape_fn((3, 100), |(i, j)| (i * 100 + j) as f64);
+    let mut y = x.mapv(|v| v * (1. + 1e-7));
+    y[[2, 70]] += 1.;
+    let yf = {
+        let mut yf = Array2::zeros((3, 100).f());
+        yf.assign(&y);
+        yf
+    };
+    for y in &[y.view(), yf.view(), y.slice(s![.., ..;1])] {
+        assert!(!x.all_close(y, 1e-6, 0.));
+        assert!(x.slice(s![..2, ..]).all_close(&y.slice(s![..2, ..]), 1e-6, 0.));
+        assert_eq!(x.all_close_axis(y, Axis(1), 1e-6, 0.), array![true, true, false]);
+        let columns = x.all_close_axis(y, Axis(0), 1e-6, 0.);
+        assert_eq!(columns.iter().filter(|&&c| !c).count(), 1);
+        assert!(!columns[70]);
+    }
+    assert!(x.all_close(&x.t().t(), 0., 0.));
+}
+
+#[test]
+fn rolling_window_stats() {
+    let a = Array::from_shape_fn((7, 3), |(i, j)| ((i * 7 + j * 5) % 11) as i32 - 4);
+    for &window in &[1, 2, 3, 7] {
+        for axis in 0..2 {
+            let axis = Axis(axis);
+            let naive = |f: &dyn Fn(ndarray::ArrayView1<'_, i32>) -> i32| {
+                a.rolling_apply(axis, window, |w| f(w))
+            };
+            assert_eq!(a.rolling_sum(axis, window), naive(&|w| w.sum()));
+            assert_eq!(a.rolling_min(axis, window), naive(&|w| *w.iter().min().unwrap()));
+            assert_eq!(a.rolling_max(axis, window), naive(&|w| *w.iter().max().unwrap()));
+            assert_eq!(a.rolling_sum(axis, window).len_of(axis), (a.len_of(axis) + 1).saturating_sub(window));
+        }
+    }
+    let sums = a.rolling_sum(Axis(0), 3);
+    assert_eq!(sums.row(2), a.slice(s![2..5, ..]).sum_axis(Axis(0)));
+
+    // The windows match axis_windows
+    let windows = a.axis_windows(Axis(1), 2);
+    let firsts = Array::from_iter(windows.into_iter().map(|w| w[[3, 0]]));
+    assert_eq!(a.rolling_apply(Axis(1), 2, |w| w[0]).row(3), firsts);
+
+    // Column major arrays and windows longer than the axis
+    let f = Array::from_shape_vec((2, 4).f(), vec![1., 5., 2., 6., 3., 7., 4., 8.]).unwrap();
+    assert_eq!(f.rolling_mean(Axis(1), 2), array![[1.5, 2.5, 3.5], [5.5, 6.5, 7.5]]);
+    assert_eq!(f.rolling_max(Axis(1), 5).shape(), &[2, 0]);
+}
+
+#[test]
+fn rolling_sum_stays_in_range() {
+    // Only the window sums need to fit in the element type
+    assert_eq!(array![200u8, 50, 200, 50].rolling_sum(Axis(0), 1), array![200u8, 50, 200, 50]);
+    assert_eq!(array![200u8, 50, 200, 50].rolling_sum(Axis(0), 2), array![250u8, 250, 250]);
+    // A large element does not absorb the later windows
+    assert_eq!(array![1e20, 1., 1., 1.].rolling_sum(Axis(0), 1), array![1e20, 1., 1., 1.]);
+}
+
+#[test]
+fn build_pyramid() {
+    let a = Array::from_shape_fn((7, 5, 2), |(i, j, k)| (i * 31 + j * 17 + k * 7) % 23);
+
+    // Compare each level with aggregating the blocks of the array directly
+    let axes = [Axis(0), Axis(1)];
+    for &aggregate in &[Aggregate::Mean, Aggregate::Max, Aggregate::Min] {
+        let pyramid = a.build_pyramid_axes::<usize>(&axes, 4, aggregate);
+        assert_eq!(pyramid.len(), 4);
+        for (level, p) in pyramid.iter().enumerate() {
+            let block = 1 << (level + 1);
+            let expected = Array::from_shape_fn(p.raw_dim(), |(i, j, k)| {
+                let b = a.slice(s![i * block..((i + 1) * block).min(7), j * block..((j + 1) * block).min(5), k]);
+                match aggregate {
+                    Aggregate::Max => *b.iter().max().unwrap(),
+                    Aggregate::Min => *b.iter().min().unwrap(),
+                    _ => b.mapv(|x| x as f64).mean().unwrap() as usize,
+                }
+            });
+            if aggregate == Aggregate::Mean && level > 0 {
+                // Integer means of means are rounded at each level
+                assert_eq!(p.shape(), expected.shape());
+            } else {
+                assert_eq!(p, &expected, "{:?} level {}", aggregate, level);
+            }
+        }
+        assert_eq!(pyramid[3].shape(), &[1, 1, 2]);
+    }
+
+    // Means of odd lengths weigh the partial blocks by their size
+    let pyramid = array![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]].build_pyramid::<f64>(2, Aggregate::Mean);
+    assert_eq!(pyramid[0], array![[3., 4.5], [7.5, 9.]]);
+    assert_eq!(pyramid[1], array![[6.]]);
+
+    // Empty arrays and zero levels
+    assert_eq!(Array2::<f32>::zeros((0, 3)).build_pyramid::<f32>(2, Aggregate::Max)[1].shape(), &[0, 1]);
+    assert!(a.build_pyramid::<usize>(0, Aggregate::Mean).is_empty());
+
+    // The means are summed in the element type of the levels
+    let image = Array2::<u8>::from_elem((4, 4), 200);
+    let pyramid = image.build_pyramid::<u16>(2, Aggregate::Mean);
+    assert_eq!(pyramid[0], Array2::<u16>::from_elem((2, 2), 200));
+    assert_eq!(pyramid[1], array![[200u16]]);
+    let pyramid = image.build_pyramid::<f32>(1, Aggregate::Min);
+    assert_eq!(pyramid[0], Array2::<f32>::from_elem((2, 2), 200.));
+}
+
+#[test]
+fn map_coordinates() {
+    use ndarray::map_coordinates;
+
+    // A linear function is reproduced exactly, also for a strided view
+    let f = |i: f64, j: f64| 3. * i - 2. * j + 1.;
+    let a = Array::from_shape_fn((6, 4), |(i, j)| f(j as f64, i as f64));
+    let a = a.t();
+    let coords = Array::from_shape_fn((3, 5, 2), |(p, q, k)| (0.37 * (p * 5 + q) as f64 + 0.11 * k as f64) % [3., 5.][k]);
+    let linear = map_coordinates(&a, &coords, InterpOrder::Linear, &PadMode::Edge);
+    assert_eq!(linear.shape(), &[3, 5]);
+    for ((p, q), &value) in linear.indexed_iter() {
+        let (i, j) = (coords[[p, q, 0]], coords[[p, q, 1]]);
+        assert!((value - f(i, j)).abs() < 1e-12, "{} at ({}, {})", value, i, j);
+    }
+
+    // Integer coordinates give the elements
+    let points = Array::from_shape_fn((4, 6, 2), |(i, j, k)| [i, j][k] as f64);
+    for &order in &[InterpOrder::Nearest, InterpOrder::Linear] {
+        assert_eq!(map_coordinates(&a, &points, order, &PadMode::Constant(f64::NAN)), a);
+    }
+
+    // Boundary modes
+    let b = array![1., 2., 3.];
+    let points = array![[-1.], [-0.5], [3.], [3.5], [f64::NAN]];
+    let sample = |mode| map_coordinates(&b, &points, InterpOrder::Linear, &mode);
+    let nan_eq = |x: Array1<f64>, y: Array1<f64>| assert!(x.iter().zip(&y).all(|(x, y)| x == y || x.is_nan() && y.is_nan()), "{} != {}", x, y);
+    nan_eq(sample(PadMode::Constant(0.)), array![0., 0.5, 0., 0., f64::NAN]);
+    nan_eq(sample(PadMode::Edge), array![1., 1., 3., 3., f64::NAN]);
+    nan_eq(sample(PadMode::Reflect), array![2., 1.5, 2., 1.5, f64::NAN]);
+    nan_eq(sample(PadMode::Wrap), array![3., 2., 1., 1.5, f64::NAN]);
+    let nearest = map_coordinates(&b, &array![[-0.6], [0.5], [2.4]], InterpOrder::Nearest, &PadMode::Wrap);
+    assert_eq!(nearest, array![3., 2., 3.]);
+}
+
+#[test]
+fn warp_affine() {
+    use ndarray::{map_coordinates, warp_affine};
+
+    // An image with three channels, stored channel first
+    let img = Array::from_shape_fn((3, 5, 4), |(c, i, j)| ((i * 7 + j * 3 + c * 5) % 11) as f64);
+    let img = img.view().permuted_axes([1, 2, 0]);
+    let (sin, cos) = 0.3f64.sin_cos();
+    let matrix = array![[cos, -sin, 1.2], [sin, cos, -0.7], [0., 0., 1.]];
+    for &order in &[InterpOrder::Nearest, InterpOrder::Linear] {
+        for mode in vec![PadMode::Constant(-1.), PadMode::Edge, PadMode::Reflect, PadMode::Wrap] {
+            let warped = warp_affine(&img, &matrix, (6, 3), order, &mode);
+            assert_eq!(warped.shape(), &[6, 3, 3]);
+            let coords = Array::from_shape_fn((6, 3, 2), |(i, j, k)| {
+                matrix.row(k).dot(&array![i as f64, j as f64, 1.])
+            });
+            for c in 0..3 {
+                let expected = map_coordinates(&img.index_axis(Axis(2), c), &coords, order, &mode);
+                let actual = warped.index_axis(Axis(2), c);
+                assert!(
+                    actual.iter().zip(&expected).all(|(a, b)| (a - b).abs() < 1e-12),
+                    "{:?} {:?} channel {}:\n{}\n{}", order, mode, c, actual, expected
+                );
+            }
+        }
+    }
+
+    // An empty image with a constant boundary
+    let empty = Array2::<f32>::zeros((0, 3));
+    let identity = array![[1., 0., 0.], [0., 1., 0.]];
+    assert_eq!(warp_affine(&empty, &identity, (2, 2), InterpOrder::Linear, &PadMode::Constant(5.)), Array2::from_elem((2, 2), 5.));
+}
+
+#[test]
+fn count_distinct_axis() {
+    let a = array![[[1, 2], [1, 1]], [[3, 3], [3, 2]]];
+    assert_eq!(a.count_distinct_axis(Axis(2)), array![[2, 1], [1, 2]]);
+    assert_eq!(a.count_distinct_axis(Axis(0)), array![[2, 2], [2, 2]]);
+    assert_eq!(a.t().count_distinct_axis(Axis(0)), a.count_distinct_axis(Axis(2)).t());
+
+    let empty = Array2::<i32>::zeros((3, 0));
+    assert_eq!(empty.count_distinct_axis(Axis(1)), array![0, 0, 0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn entropy_axis() {
+    let p = array![[0.25, 0.25, 0.25, 0.25], [0.5, 0.5, 0., 0.], [1., 0., 0., 0.]];
+    assert_abs_diff_eq!(p.entropy_axis(Axis(1), 2., false), array![2., 1., 0.], epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        p.entropy_axis(Axis(1), f64::consts::E, false),
+        array![4f64.ln(), 2f64.ln(), 0.],
+        epsilon = 1e-12
+    );
+
+    // Counts need normalization
+    let counts = &p * 12.;
+    assert_abs_diff_eq!(counts.entropy_axis(Axis(1), 2., true), array![2., 1., 0.], epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        counts.t().entropy_axis(Axis(0), 2., true),
+        counts.entropy_axis(Axis(1), 2., true),
+        epsilon = 1e-12
+    );
+    assert!(array![[0f64, 0.]].entropy_axis(Axis(1), 2., true)[0].is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn histogram2d() {
+    let x = array![0., 1., 2., 3., 4., 4., f64::NAN];
+    let y = array![10., 10., 10., 20., 20., 30., 10.];
+    let (counts, x_edges, y_edges) = ndarray::histogram2d(&x, &y, (4, 2), None);
+    assert_eq!(x_edges, array![0., 1., 2., 3., 4.]);
+    assert_eq!(y_edges, array![10., 20., 30.]);
+    assert_eq!(counts, array![[1., 0.], [1., 0.], [1., 0.], [0., 3.]]);
+    assert_eq!(counts.sum(), 6.);
+
+    // Weights, on views with strides
+    let w = array![1., 2., 3., 4., 5., 6., 7., 8.];
+    let (sums, _, _) = ndarray::histogram2d(
+        &x.slice(s![..;2]),
+        &y.slice(s![..;2]),
+        (2, 1),
+        Some(w.slice(s![..;2])),
+    );
+    assert_eq!(sums, array![[1.], [8.]]);
+
+    // A constant coordinate is binned around its value
+    let (counts, x_edges, _) = ndarray::histogram2d(&array![2., 2.], &array![0., 1.], (1, 2), None);
+    assert_eq!(x_edges, array![1.5, 2.5]);
+    assert_eq!(counts, array![[1., 1.]]);
+}
@@ -145,6 +145,68 @@ fn scalar_operations() {
     }
 }
 
+#[test]
+fn scalar_operations_newtype() {
+    use ndarray::ScalarOperand;
+    use std::ops::{Add, AddAssign, Mul};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Meters(f64);
+
+    impl Add for Meters {
+        type Output = Meters;
+        fn add(self, rhs: Meters) -> Meters {
+            Meters(self.0 + rhs.0)
+        }
+    }
+
+    impl ScalarOperand for Meters {}
+
+    impl AddAssign for Meters {
+        fn add_assign(&mut self, rhs: Meters) {
+            self.0 += rhs.0;
+        }
+    }
+
+    // A scalar of another type than the elements
+    impl Mul<f64> for Meters {
+        type Output = Meters;
+        fn mul(self, rhs: f64) -> Meters {
+            Meters(self.0 * rhs)
+        }
+    }
+
+    let mut a = arr1(&[Meters(0.), Meters(1.)]);
+    a += Meters(1.);
+    let a = &a * 3.;
+    assert_eq!(a, arr1(&[Meters(3.), Meters(6.)]));
+    let b = &a + Meters(1.);
+    assert_eq!(b, arr1(&[Meters(4.), Meters(7.)]));
+    assert_eq!(a + Meters(0.5), arr1(&[Meters(3.5), Meters(6.5)]));
+}
+
+#[test]
+fn scalar_newtype_macro() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Seconds(f32);
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Count(i32);
+
+    ndarray::scalar_newtype!(Seconds(f32), Count(i32));
+
+    let a = arr1(&[Seconds(1.), Seconds(2.), Seconds(4.)]);
+    assert_eq!(&a * 2., arr1(&[Seconds(2.), Seconds(4.), Seconds(8.)]));
+    assert_eq!(&a / 2. + &a, arr1(&[Seconds(1.5), Seconds(3.), Seconds(6.)]));
+    assert_eq!(-a.clone() + Seconds(4.), arr1(&[Seconds(3.), Seconds(2.), Seconds(0.)]));
+    assert_eq!(a.mapv(|t| 0.5 * t), &a * 0.5);
+
+    let mut c = Array::from_elem((2, 2), Count(3));
+    c -= Count(1);
+    c = c * 5 / 2;
+    c += &arr1(&[Count(1), Count(2)]);
+    assert_eq!(c, arr2(&[[Count(6), Count(7)], [Count(6), Count(7)]]));
+}
+
 fn reference_dot<'a, V1, V2>(a: V1, b: V2) -> f32
 where
     V1: AsArray<'a, f32>,
@@ -408,6 +470,25 @@ fn mat_mul() {
     assert_eq!(ab, af.dot(&bf));
 }
 
+#[test]
+fn fixed_mat_mul() {
+    use ndarray::linalg::{fixed_mat_mul, fixed_mat_vec_mul};
+
+    let a = [[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]];
+    let b = [[1., 0.], [0., 2.], [-1., 1.], [0.5, 0.]];
+    let c = fixed_mat_mul(&a, &b);
+    assert_eq!(aview2(&c), aview2(&a).dot(&aview2(&b)));
+    let x = [1., -1., 2., 0.];
+    assert_eq!(aview1(&fixed_mat_vec_mul(&a, &x)), aview2(&a).dot(&aview1(&x)));
+
+    // zero inner and outer dimensions
+    let e: [[i32; 0]; 2] = [[], []];
+    let f: [[i32; 3]; 0] = [];
+    assert_eq!(fixed_mat_mul(&e, &f), [[0; 3]; 2]);
+    assert_eq!(fixed_mat_mul(&f, &[[1; 2]; 3]), [[0; 2]; 0]);
+    assert_eq!(fixed_mat_vec_mul(&e, &[]), [0, 0]);
+}
+
 // Check that matrix multiplication of contiguous matrices returns a
 // matrix with the same order
 #[test]
@@ -820,3 +901,97 @@ fn vec_mat_mul() {
         }
     }
 }
+
+#[test]
+fn elementwise_comparisons() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    let b = a.mapv(|x| (x * 5.) % 11.);
+    assert_eq!(a.eq_elem(&b), a.mapv(|x| x == 0.));
+    assert_eq!(a.ne_elem(&b), a.eq_elem(&b).mapv(|x| !x));
+    assert_eq!(a.lt(&b), ndarray::Zip::from(&a).and(&b).map_collect(|x, y| x < y));
+    assert_eq!(a.le(&b), ndarray::Zip::from(&a).and(&b).map_collect(|x, y| x <= y));
+    assert_eq!(a.gt(&b), ndarray::Zip::from(&a).and(&b).map_collect(|x, y| x > y));
+    assert_eq!(a.ge(&b), ndarray::Zip::from(&a).and(&b).map_collect(|x, y| x >= y));
+
+    // Broadcasting both ways, and with a dynamic dimension
+    let col = arr2(&[[1.], [5.], [9.]]);
+    let row = arr1(&[0., 1., 2., 3.]);
+    let c = col.gt(&row);
+    assert_eq!(c.shape(), &[3, 4]);
+    assert_eq!(c.row(0), arr1(&[true, false, false, false]));
+    assert_eq!(row.lt(&col), c);
+    let d = a.view().into_dyn().ge(&arr0(6.));
+    assert_eq!(d.ndim(), 2);
+    assert_eq!(d.iter().filter(|&&x| x).count(), 6);
+
+    // Boolean reductions
+    assert!(c.any() && !c.all());
+    assert!(Array2::<bool>::from_elem((0, 3), false).all());
+    assert!(!Array2::<bool>::from_elem((0, 3), true).any());
+    assert_eq!(c.any_axis(Axis(0)), arr1(&[true, true, true, true]));
+    assert_eq!(c.all_axis(Axis(0)), arr1(&[true, false, false, false]));
+    assert_eq!(c.all_axis(Axis(1)), arr1(&[false, true, true]));
+    assert_eq!(c.slice(s![..2, ..]).any_axis(Axis(1)), arr1(&[true, true]));
+    assert_eq!(Array2::<bool>::from_elem((0, 3), false).all_axis(Axis(0)), arr1(&[true; 3]));
+}
+
+#[test]
+#[should_panic]
+fn elementwise_comparison_incompatible() {
+    arr1(&[1, 2]).eq_elem(&arr1(&[1, 2, 3]));
+}
+
+#[test]
+fn ops_into() {
+    use ndarray::{add_into, binary_op_into, div_into, mul_into, sub_into};
+
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f32 + 1.);
+    let b = arr1(&[1., 2., 4., 8.]);
+    let mut out = Array2::zeros((3, 4).f());
+    add_into(&a, &b, &mut out);
+    assert_eq!(out, &a + &b);
+    sub_into(&b, &a, &mut out);
+    assert_eq!(out, &b - &a);
+    mul_into(&a.slice(s![..;-1, ..]), &arr0(2.), &mut out);
+    assert_eq!(out, a.slice(s![..;-1, ..]).mapv(|x| x * 2.));
+    div_into(&a, &a, &mut out.view_mut());
+    assert_eq!(out, Array2::ones((3, 4)));
+
+    // Broadcast both inputs, and a different output element type
+    let mut mask = Array2::from_elem((2, 3), false);
+    binary_op_into(|x: &i32, y: &i32| x < y, &arr2(&[[1], [5]]), &arr1(&[0, 3, 6]), &mut mask);
+    assert_eq!(mask, arr2(&[[false, true, true], [false, false, true]]));
+}
+
+#[test]
+#[should_panic]
+fn ops_into_incompatible() {
+    ndarray::add_into(&arr1(&[1, 2]), &arr0(1), &mut Array1::zeros(3));
+}
+
+#[test]
+fn multi_dot() {
+    use ndarray::linalg::multi_dot;
+
+    let a = range_mat64(4, 30);
+    let b = range_mat64(30, 2);
+    let c = range_mat64(2, 25);
+    let d = range_mat64(25, 3);
+    let expected = a.dot(&b).dot(&c).dot(&d);
+    assert_abs_diff_eq!(multi_dot(&[&a, &b, &c, &d]), expected, epsilon = 1e-6);
+    assert_eq!(multi_dot(&[&a]), a);
+
+    // Transposed views
+    let (at, bt) = (a.t(), b.t());
+    assert_abs_diff_eq!(
+        multi_dot(&[&bt, &at, &a.view(), &b.view()]),
+        b.t().dot(&a.t()).dot(&a).dot(&b),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+#[should_panic]
+fn multi_dot_incompatible() {
+    ndarray::linalg::multi_dot(&[&Array2::<f64>::zeros((2, 3)), &Array2::zeros((2, 3))]);
+}
@@ -35,6 +35,26 @@ fn test_axis_iter_mut() {
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
 
+#[test]
+fn test_par_axis_iter() {
+    let mut a = Array2::<f64>::zeros((M, N));
+    a.par_axis_iter_mut(Axis(0))
+        .enumerate()
+        .for_each(|(i, mut v)| v.fill(i as _));
+    let s: f64 = a.par_axis_iter(Axis(0)).map(|x| x.sum()).sum();
+    assert_eq!(s, a.sum());
+    assert_eq!(a.par_axis_iter(Axis(1)).len(), N);
+
+    a.par_axis_chunks_iter_mut(Axis(0), CHUNK_SIZE)
+        .for_each(|mut v| v.mapv_inplace(|x| x + 1.));
+    let sums = a.par_axis_chunks_iter(Axis(0), CHUNK_SIZE)
+        .map(|x| x.sum())
+        .collect::<Vec<_>>();
+    assert_eq!(sums.len(), N_CHUNKS);
+    assert_eq!(sums.iter().sum::<f64>(), a.sum());
+    assert_eq!(a[[M - 1, 0]], M as f64);
+}
+
 #[test]
 fn test_regular_iter() {
     let mut a = Array2::<f64>::zeros((M, N));
@@ -86,3 +106,126 @@ fn test_axis_chunks_iter_mut() {
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_for_each_mut() {
+    let mut a = Array2::<f64>::zeros((M, N));
+    a.par_for_each_mut(|x| *x += 1.);
+    assert_eq!(a.sum(), (M * N) as f64);
+
+    // Expensive closure, non-contiguous view
+    let mut b = Array3::<u64>::zeros((7, 50, 9));
+    b.slice_mut(s![.., ..;-3, 1..]).par_for_each_mut(|x| {
+        *x = (0..1000).fold(*x, |acc, i| acc ^ i) + 1;
+    });
+    assert_eq!(b.iter().filter(|&&x| x != 0).count(), 7 * 17 * 8);
+
+    let mut c = Array1::<f64>::zeros(0);
+    c.par_for_each_mut(|x| *x += 1.);
+}
+
+#[test]
+fn test_par_sum_deterministic() {
+    // Serial computation with the same reduction tree
+    fn tree_sum(a: ArrayView2<'_, f64>) -> f64 {
+        if a.len() <= 4096 {
+            return a.sum();
+        }
+        let axis = if a.nrows() > 1 { Axis(0) } else { Axis(1) };
+        let (l, r) = a.split_at(axis, a.len_of(axis) / 2);
+        tree_sum(l) + tree_sum(r)
+    }
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * N + j) as f64).sin() * 1e10 + 0.1);
+    let sum = a.par_sum_deterministic();
+    assert_eq!(sum.to_bits(), tree_sum(a.view()).to_bits());
+    for _ in 0..10 {
+        assert_eq!(a.par_sum_deterministic().to_bits(), sum.to_bits());
+    }
+    let row = a.slice(s![..1, ..]);
+    assert_eq!(row.par_sum_deterministic(), row.sum());
+
+    let max = a.par_reduce_deterministic(|| f64::NEG_INFINITY, f64::max);
+    assert_eq!(max, a.iter().cloned().fold(f64::NEG_INFINITY, f64::max));
+    assert_eq!(Array2::<f64>::zeros((0, 3)).par_reduce_deterministic(|| 1., |a, b| a * b), 1.);
+}
+
+#[test]
+fn test_par_process_chunks() {
+    let a = Array::from_shape_fn((M, 3), |(i, j)| (i * 3 + j) as u64);
+    let sum = a.par_process_chunks(Axis(0), CHUNK_SIZE, || (), |_, chunk| chunk.sum(), |x, y| x + y);
+    assert_eq!(sum, Some(a.sum()));
+    // Non-commutative reduction: the chunks are combined in order
+    let firsts = a.par_process_chunks(Axis(0), CHUNK_SIZE, Vec::new, |scratch, chunk| {
+        scratch.clear();
+        scratch.extend(chunk.iter().cloned());
+        vec![scratch[0]]
+    }, |mut x, y| {
+        x.extend(y);
+        x
+    });
+    let expected = (0..N_CHUNKS).map(|i| (i * CHUNK_SIZE * 3) as u64).collect::<Vec<_>>();
+    assert_eq!(firsts, Some(expected.clone()));
+
+    let chunks = a.axis_chunks_iter(Axis(0), CHUNK_SIZE).map(|c| c.to_owned());
+    let firsts = ndarray::parallel::par_process_chunks(chunks, || (), |_, c| vec![c[[0, 0]]], |mut x, y| {
+        x.extend(y);
+        x
+    });
+    assert_eq!(firsts, Some(expected));
+    let none = ndarray::parallel::par_process_chunks(Vec::<u8>::new(), || (), |_, x| x, |x, _| x);
+    assert_eq!(none, None);
+}
+
+#[test]
+fn test_par_axis_reductions() {
+    let a = Array::linspace(0., 1., M * 8)
+        .mapv(f64::sin)
+        .into_shape((M / 16, 16, 8))
+        .unwrap();
+    for axis in 0..a.ndim() {
+        let axis = Axis(axis);
+        assert_eq!(a.par_sum_axis(axis), a.sum_axis(axis));
+        assert_eq!(a.par_mean_axis(axis), a.mean_axis(axis));
+        assert_eq!(a.par_var_axis(axis, 1.), a.var_axis(axis, 1.));
+        assert_eq!(a.par_std_axis(axis, 0.), a.std_axis(axis, 0.));
+    }
+    let t = a.index_axis(Axis(2), 3).reversed_axes();
+    assert_eq!(t.par_sum_axis(Axis(1)), t.sum_axis(Axis(1)));
+    assert_eq!(t.par_sum_axis(Axis(0)), t.sum_axis(Axis(0)));
+
+    let empty = Array2::<f64>::zeros((0, M));
+    assert_eq!(empty.par_sum_axis(Axis(0)), Array1::zeros(M));
+    assert_eq!(empty.par_mean_axis(Axis(0)), None);
+}
+
+#[test]
+fn test_par_sort_axis() {
+    let a = Array::from_shape_fn((M, 8), |(i, j)| ((i * 31 + j * 17) % 101) as i32);
+    for &axis in &[Axis(0), Axis(1)] {
+        let mut expected = a.clone();
+        expected.sort_axis(axis);
+        let mut b = a.clone();
+        b.par_sort_axis_by(axis, |x, y| x.cmp(y));
+        assert_eq!(b, expected);
+        let mut c = a.t().to_owned();
+        c.par_sort_unstable_axis_by(Axis(1 - axis.index()), |x, y| x.cmp(y));
+        assert_eq!(c, expected.t());
+    }
+
+    // A single lane, contiguous or not, is sorted with a parallel sort; the
+    // stable sort keeps equal elements in order
+    let pairs = Array::from_shape_fn((1, M), |(_, j)| ((j * 7919) % 50, j));
+    let mut sorted = pairs.clone();
+    sorted.par_sort_axis_by(Axis(1), |x, y| x.0.cmp(&y.0));
+    let row = sorted.row(0);
+    assert!(row.iter().zip(row.iter().skip(1)).all(|(x, y)| x.0 < y.0 || (x.0 == y.0 && x.1 < y.1)));
+    let mut strided = pairs.t().to_owned();
+    strided.par_sort_axis_by(Axis(0), |x, y| x.0.cmp(&y.0));
+    assert_eq!(strided.t(), sorted);
+    let mut reversed = pairs.clone();
+    reversed.invert_axis(Axis(1));
+    reversed.par_sort_unstable_axis_by(Axis(1), |x, y| x.cmp(y));
+    let mut expected = pairs.clone();
+    expected.sort_axis(Axis(1));
+    assert_eq!(reversed, expected);
+}
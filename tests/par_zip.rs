@@ -140,3 +140,18 @@ fn test_zip_assign_into() {
 
     assert_abs_diff_eq!(a, &b + &c, epsilon = 1e-6);
 }
+
+#[test]
+fn test_zip_for_each_init() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i * N + j) as f32);
+    let mut b = Array::<f32, _>::zeros((M, N));
+
+    Zip::from(&a).and(&mut b).par_for_each_init(
+        || vec![0.; 4],
+        |scratch, &x, y| {
+            scratch[0] = x;
+            *y = scratch[0] * 2.;
+        },
+    );
+    assert_eq!(b, &a * 2.);
+}
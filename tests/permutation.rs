@@ -0,0 +1,48 @@
+use ndarray::prelude::*;
+use ndarray::Permutation;
+
+#[test]
+fn sort_unsort_roundtrip() {
+    let keys = array![3., 1., 2., 1., 0.];
+    let p = Permutation::from_sort_of(&keys);
+    // Stable: the two equal keys keep their order
+    assert_eq!(p.indices(), &[4, 1, 3, 2, 0]);
+    assert_eq!(keys.permute_axis(Axis(0), &p), array![0., 1., 1., 2., 3.]);
+
+    let a = Array::from_shape_fn((3, 5, 2), |(i, j, k)| i * 100 + j * 10 + k);
+    let sorted = a.permute_axis(Axis(1), &p);
+    assert_eq!(sorted.index_axis(Axis(1), 0), a.index_axis(Axis(1), 4));
+    assert_eq!(sorted.permute_axis(Axis(1), &p.inverse()), a);
+
+    assert!(p.then(&p.inverse()).is_identity());
+    assert!(p.inverse().then(&p).is_identity());
+    assert_eq!(p.inverse().inverse(), p);
+    assert!(Permutation::identity(0).is_empty());
+    assert_eq!(Permutation::identity(4).len(), 4);
+    assert_eq!(Permutation::from_indices(vec![1, 0]).unwrap().into_indices(), vec![1, 0]);
+    assert_eq!(Permutation::from_indices(vec![0, 0]), None);
+}
+
+#[test]
+#[should_panic]
+fn permute_axis_wrong_length() {
+    let a = Array2::<f32>::zeros((3, 4));
+    a.permute_axis(Axis(0), &Permutation::identity(4));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_permutation() {
+    use ndarray::rand::rngs::mock::StepRng;
+
+    let mut rng = StepRng::new(1, 0x9e37_79b9_7f4a_7c15);
+    let p = Permutation::random(100, &mut rng);
+    let mut sorted = p.clone().into_indices();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+    assert!(!p.is_identity());
+
+    // Deterministic for the same generator state
+    let mut rng = StepRng::new(1, 0x9e37_79b9_7f4a_7c15);
+    assert_eq!(Permutation::random(100, &mut rng), p);
+}
@@ -0,0 +1,53 @@
+use ndarray::{array, Array2, ErrorKind, RaggedArray};
+
+#[test]
+fn ragged_rows() {
+    let mut ragged = RaggedArray::from_rows(vec![vec![1., 2.], vec![], vec![3., 4., 5.]]);
+    assert_eq!(ragged.nrows(), 3);
+    assert_eq!(ragged.len(), 5);
+    assert_eq!(ragged.offsets(), &[0, 2, 2, 5]);
+    assert_eq!(ragged.max_row_len(), 3);
+    assert_eq!(ragged.row_len(1), 0);
+    assert_eq!(ragged.rows().len(), 3);
+    assert_eq!(ragged.rows().rev().next().unwrap(), array![3., 4., 5.]);
+
+    ragged.row_mut(0)[1] = 20.;
+    ragged.values_mut().mapv_inplace(|x| x * 2.);
+    assert_eq!(ragged.values(), array![2., 40., 6., 8., 10.]);
+
+    let lengths = ragged.map(|&x| x as i32);
+    assert_eq!(lengths.row(2), array![6, 8, 10]);
+}
+
+#[test]
+fn ragged_parts() {
+    let ragged = RaggedArray::from_parts(vec![1, 2, 3], vec![0, 1, 3]).unwrap();
+    assert_eq!(ragged.row(1), array![2, 3]);
+    assert_eq!(ragged.clone().into_parts(), (vec![1, 2, 3], vec![0, 1, 3]));
+
+    for offsets in vec![vec![], vec![1, 3], vec![0, 2, 1, 3], vec![0, 2]] {
+        let err = RaggedArray::from_parts(vec![1, 2, 3], offsets).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    }
+    let empty = RaggedArray::<f32>::new();
+    assert_eq!(empty.nrows(), 0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_dense(0.).0.shape(), &[0, 0]);
+}
+
+#[test]
+fn ragged_dense() {
+    let ragged = RaggedArray::from_rows(vec![vec![1, 2], vec![3], vec![]]);
+    let (dense, mask) = ragged.to_dense(-1);
+    assert_eq!(dense, array![[1, 2], [3, -1], [-1, -1]]);
+    assert_eq!(mask, array![[true, true], [true, false], [false, false]]);
+    let lengths = mask.map_axis(ndarray::Axis(1), |row| row.iter().filter(|&&m| m).count());
+    assert_eq!(RaggedArray::from_dense(&dense, lengths.as_slice().unwrap()), ragged);
+    assert_eq!(RaggedArray::from_dense(&dense.t(), &[1, 0]).row(0), array![1]);
+}
+
+#[test]
+#[should_panic]
+fn ragged_from_dense_too_long() {
+    RaggedArray::from_dense(&Array2::<f32>::zeros((1, 2)), &[3]);
+}
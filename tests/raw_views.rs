@@ -96,3 +96,28 @@ fn raw_view_negative_strides() {
     let data: [u16; 2] = [0x0011, 0x2233];
     misaligned_deref(&data);
 }
+
+#[test]
+fn raw_view_from_shape_strides_ptr() {
+    let data = [0, 1, 2, 3, 4, 5];
+    // Rows in reverse order, each element repeated along the last axis
+    let view = unsafe {
+        let ptr = data.as_ptr().add(3);
+        RawArrayView::from_shape_strides_ptr((2, 3, 2), &[-3, 1, 0], ptr).deref_into_view()
+    };
+    assert_eq!(
+        view,
+        array![[[3, 3], [4, 4], [5, 5]], [[0, 0], [1, 1], [2, 2]]]
+    );
+}
+
+#[test]
+fn raw_view_mut_from_shape_strides_ptr() {
+    let mut data = [0; 6];
+    let mut view = unsafe {
+        let ptr = data.as_mut_ptr().add(5);
+        RawArrayViewMut::from_shape_strides_ptr((2, 3), &[-1, -2], ptr).deref_into_view_mut()
+    };
+    view.assign(&array![[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(data, [6, 3, 5, 2, 4, 1]);
+}
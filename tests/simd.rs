@@ -0,0 +1,152 @@
+#![cfg(feature = "simd")]
+
+use ndarray::prelude::*;
+
+#[test]
+fn simd_sum_product() {
+    // Lengths around the vector and chunk sizes
+    for n in 0..40 {
+        let a = Array::from_shape_fn(n, |i| i as f64 - 10.);
+        assert_eq!(a.simd_sum(), a.sum());
+        let b = a.mapv(|x| (x / 8.).exp() as f32);
+        assert!((b.simd_product() - b.product()).abs() <= 1e-5 * b.product().abs());
+        let c = Array::from_shape_fn(n, |i| i as i32 - 10);
+        assert_eq!(c.simd_sum(), c.sum());
+        assert_eq!(c.slice(s![..n.min(12)]).simd_product(), c.slice(s![..n.min(12)]).product());
+    }
+    assert_eq!(Array1::<f32>::zeros(0).simd_product(), 1.);
+    assert_eq!(arr0(3).simd_sum(), 3);
+    assert_eq!(arr1(&[i32::MAX, 1]).simd_sum(), i32::MIN);
+}
+
+#[test]
+fn simd_min_max() {
+    for n in 1..40 {
+        let a = Array::from_shape_fn(n, |i| ((i * 7) % 13) as f32 - 6.);
+        let min = a.fold(f32::INFINITY, |m, &x| m.min(x));
+        let max = a.fold(f32::NEG_INFINITY, |m, &x| m.max(x));
+        assert_eq!(a.simd_min(), Some(min));
+        assert_eq!(a.simd_max(), Some(max));
+        let c = a.mapv(|x| x as i32);
+        assert_eq!(c.simd_min(), Some(min as i32));
+        assert_eq!(c.simd_max(), Some(max as i32));
+    }
+    assert_eq!(Array1::<f64>::zeros(0).simd_min(), None);
+    assert_eq!(Array1::<i32>::zeros(0).simd_max(), None);
+}
+
+#[test]
+fn simd_min_max_nan() {
+    let mut a = Array1::from_elem(37, f64::NAN);
+    assert_eq!(a.simd_min(), None);
+    assert_eq!(a.simd_max(), None);
+    a[20] = 1.;
+    a[5] = -2.;
+    assert_eq!(a.simd_min(), Some(-2.));
+    assert_eq!(a.simd_max(), Some(1.));
+    a[30] = f64::INFINITY;
+    a[31] = f64::NEG_INFINITY;
+    assert_eq!(a.simd_min(), Some(f64::NEG_INFINITY));
+    assert_eq!(a.simd_max(), Some(f64::INFINITY));
+    assert_eq!(array![f32::NAN, f32::INFINITY].simd_min(), Some(f32::INFINITY));
+}
+
+#[test]
+fn simd_strided() {
+    let a = Array::from_shape_fn((9, 14, 3), |(i, j, k)| (i * 100 + j * 10 + k) as f64);
+    let views = [
+        a.view(),
+        a.slice(s![.., ..;2, ..]),
+        a.slice(s![..;-1, 1.., ..;2]),
+        a.view().permuted_axes([2, 0, 1]),
+        a.slice(s![.., .., 1..2]),
+    ];
+    for v in &views {
+        assert_eq!(v.simd_sum(), v.sum());
+        assert_eq!(v.simd_min(), v.iter().cloned().reduce(f64::min));
+        assert_eq!(v.simd_max(), v.iter().cloned().reduce(f64::max));
+    }
+    let f = a.t().to_owned();
+    assert_eq!(f.simd_sum(), a.sum());
+    assert_eq!(a.slice(s![.., 3, ..]).simd_min(), Some(30.));
+}
+
+#[test]
+fn simd_pack_ops() {
+    use ndarray::simd::{Pack, PACK_LEN};
+
+    let a = Pack([1., -2., 3., f64::NAN, 5., -6., 7., 8.]);
+    let b = Pack([2., 2., -1., 0., f64::NAN, 4., 7., -8.]);
+    for i in 0..PACK_LEN {
+        let (x, y) = (a.0[i], b.0[i]);
+        assert_eq!((a + b).0[i].to_bits(), (x + y).to_bits());
+        assert_eq!((a - b).0[i].to_bits(), (x - y).to_bits());
+        assert_eq!((a * b).0[i].to_bits(), (x * y).to_bits());
+        assert_eq!((a / b).0[i].to_bits(), (x / y).to_bits());
+    }
+    // NaN in the second operand keeps the first one
+    assert_eq!(a.min(b).0[4], 5.);
+    assert_eq!(a.max(b).0[4], 5.);
+    assert!(a.min(b).0[3].is_nan());
+    assert_eq!(a.min(b).0[..3], [1., -2., -1.]);
+    assert_eq!(a.max(b).0[5..], [4., 7., 8.]);
+
+    let c = Pack([1, -2, 3, i32::MAX, 5, -6, 7, 8]);
+    let d = Pack::splat(3);
+    assert_eq!((c + d).0, [4, 1, 6, i32::MIN + 2, 8, -3, 10, 11]);
+    assert_eq!((c * d).0[..3], [3, -6, 9]);
+    assert_eq!((c / d).0, [0, 0, 1, i32::MAX / 3, 1, -2, 2, 2]);
+    assert_eq!(c.min(d).0, [1, -2, 3, 3, 3, -6, 3, 3]);
+    assert_eq!((c - d).max(Pack::splat(0)).0, [0, 0, 0, i32::MAX - 3, 2, 0, 4, 5]);
+}
+
+#[test]
+fn simd_fold() {
+    use ndarray::simd::Pack;
+
+    let a = Array::from_shape_fn((9, 14, 3), |(i, j, k)| (i * 100 + j * 10 + k) as f64 - 400.);
+    let views = [a.view(), a.slice(s![.., ..;2, ..]), a.slice(s![..;-1, 1.., ..;2]), a.view().permuted_axes([2, 0, 1])];
+    for v in &views {
+        let sum_of_squares = v.simd_fold(0., |acc, x| acc + x * x, |x, y| x + y);
+        assert_eq!(sum_of_squares, v.fold(0., |acc, &x| acc + x * x));
+        let max_abs = v.simd_fold(0., |acc, x| acc.max(x.max(Pack::splat(0.) - x)), f64::max);
+        assert_eq!(max_abs, v.fold(0f64, |acc, &x| acc.max(x.abs())));
+    }
+    // Lengths around the pack size; the lanes without elements keep `init`
+    for n in 0..20 {
+        let b = Array::from_shape_fn(n, |i| i as i32 + 1);
+        assert_eq!(b.simd_fold(0, |acc, x| acc + x * Pack::splat(2) + Pack::splat(1), |x, y| x + y),
+                   (n * n + 2 * n) as i32);
+        assert_eq!(b.simd_fold(i32::MIN, |acc, x| acc.max(x), i32::max), b.iter().cloned().max().unwrap_or(i32::MIN));
+    }
+}
+
+#[test]
+fn simd_zip_for_each() {
+    use ndarray::simd::Pack;
+    use ndarray::Zip;
+
+    for n in 0..20 {
+        let x = Array::from_shape_fn(n, |i| i as f32);
+        let z = Array::from_shape_fn(n, |i| (i % 3) as f32);
+        let mut y = Array::ones(n);
+        Zip::from(&mut y).and(&x).simd_for_each(|y, x| *y = Pack::splat(2.) * x + *y);
+        assert_eq!(y, &x * 2. + 1.);
+        Zip::from(&mut y).and(&x).and(&z).simd_for_each(|y, x, z| *y = *y - x * z);
+        assert_eq!(y, &x * 2. + 1. - &x * &z);
+        Zip::from(&mut y).simd_for_each(|y| *y = y.min(Pack::splat(10.)));
+        assert_eq!(y, (&x * 2. + 1. - &x * &z).mapv(|v| v.min(10.)));
+    }
+
+    // Strided arrays and arrays with different memory layouts
+    let a = Array::from_shape_fn((6, 11), |(i, j)| (i * 11 + j) as i32);
+    let mut b = Array::zeros((11, 6)).reversed_axes();
+    Zip::from(&mut b).and(&a).simd_for_each(|b, a| *b = a * Pack::splat(3));
+    assert_eq!(b, &a * 3);
+    let mut c = Array::zeros((6, 22));
+    Zip::from(c.slice_mut(s![.., ..;2])).and(&a).and(&b).simd_for_each(|c, a, b| *c = b - a);
+    assert_eq!(c.slice(s![.., ..;2]), &a * 2);
+    assert_eq!(c.slice(s![.., 1..;2]).sum(), 0);
+    Zip::from(&mut c).simd_for_each(|c| *c = *c / Pack::splat(2));
+    assert_eq!(c.slice(s![.., ..;2]), a);
+}
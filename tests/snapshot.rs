@@ -0,0 +1,146 @@
+#![cfg(feature = "std")]
+
+use ndarray::io::snapshot::{self, AxisAppender, SnapshotElement, SnapshotError};
+use ndarray::prelude::*;
+use ndarray::ShapeBuilder;
+use num_complex::Complex;
+use std::fmt::Debug;
+use std::fs::{self, OpenOptions};
+use std::io::{Cursor, Write};
+
+fn roundtrip<A, S, D>(a: &ArrayBase<S, D>) -> ArrayD<A>
+where
+    A: SnapshotElement + PartialEq + Debug,
+    S: ndarray::Data<Elem = A>,
+    D: Dimension,
+{
+    let mut file = Vec::new();
+    snapshot::save(&mut file, a).unwrap();
+    assert_eq!(file.len(), 20 + 8 * a.ndim() + a.len() * std::mem::size_of::<A>());
+    let b = snapshot::load(&file[..]).unwrap();
+    assert_eq!(b, a.view().into_dyn());
+    b
+}
+
+#[test]
+fn snapshot_roundtrip() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 100 + j * 10 + k) as f64);
+    assert!(roundtrip(&a).is_standard_layout());
+    roundtrip(&a.mapv(|x| x as f32));
+    roundtrip(&a.mapv(|x| x as i16 as u8));
+    roundtrip(&a.mapv(|x| -x as i64));
+    roundtrip(&a.mapv(|x| x as u128));
+    roundtrip(&a.mapv(|x| x as i32 % 2 == 0));
+    roundtrip(&a.mapv(|x| Complex::new(x, -x)));
+
+    // Fortran layout is kept, other layouts are written in standard order
+    let f = Array::from_shape_vec((3, 4).f(), (0..12).collect()).unwrap();
+    assert!(roundtrip(&f).t().is_standard_layout());
+    assert!(roundtrip(&a.slice(s![.., ..;2, 1..])).is_standard_layout());
+
+    roundtrip(&arr0(7u16));
+    roundtrip(&Array3::<f64>::zeros((2, 0, 3)));
+}
+
+#[test]
+fn snapshot_errors() {
+    let a = array![[1, 2], [3, 4]];
+    let mut file = Vec::new();
+    snapshot::save(&mut file, &a).unwrap();
+
+    match snapshot::load::<_, u32>(&file[..]) {
+        Err(SnapshotError::DType { expected, found }) => {
+            assert_eq!((expected, &found[..]), ("u32", "i32"));
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    assert!(matches!(snapshot::load::<_, i32>(&file[..file.len() - 1]), Err(SnapshotError::Io(_))));
+    assert!(matches!(snapshot::load::<_, i32>(&file[1..]), Err(SnapshotError::Format(_))));
+
+    // A later minor version with an extra header field can be read
+    let mut newer = file.clone();
+    newer[9] = 1;
+    newer[10] += 3;
+    newer.splice(20 + 16..20 + 16, [0xff; 3].iter().copied());
+    assert_eq!(snapshot::load::<_, i32>(&newer[..]).unwrap(), a.clone().into_dyn());
+
+    // A later major version can't
+    let mut newer = file.clone();
+    newer[8] = 2;
+    assert!(matches!(
+        snapshot::load::<_, i32>(&newer[..]),
+        Err(SnapshotError::Version { major: 2, minor: 0 })
+    ));
+}
+
+#[test]
+fn axis_appender() {
+    let a = Array::from_shape_fn((5, 2, 3), |(i, j, k)| (i * 100 + j * 10 + k) as f32);
+
+    // Along the first axis, with a prefix before the snapshot
+    let mut file = Cursor::new(b"prefix".to_vec());
+    file.set_position(6);
+    let mut appender = AxisAppender::new(file, &[7, 2, 3], Axis(0)).unwrap();
+    assert!(appender.is_empty());
+    appender.append(&a.slice(s![..2, .., ..])).unwrap();
+    appender.flush().unwrap();
+    appender.append(&a.slice(s![2.., .., ..])).unwrap();
+    assert_eq!(appender.shape(), &[5, 2, 3]);
+    let file = appender.finish().unwrap().into_inner();
+    assert_eq!(&file[..6], b"prefix");
+    assert_eq!(snapshot::load::<_, f32>(&file[6..]).unwrap(), a.clone().into_dyn());
+
+    // Along the last axis
+    let mut appender = AxisAppender::new(Cursor::new(Vec::new()), &[5, 2, 0], Axis(2)).unwrap();
+    for j in 0..3 {
+        appender.append(&a.slice(s![.., .., j..j + 1])).unwrap();
+    }
+    let file = appender.finish().unwrap().into_inner();
+    let b = snapshot::load::<_, f32>(&file[..]).unwrap();
+    assert_eq!(b, a.clone().into_dyn());
+    assert!(b.t().is_standard_layout());
+
+    // The other axes must match
+    let mut appender = AxisAppender::new(Cursor::new(Vec::new()), &[0, 2, 3], Axis(0)).unwrap();
+    match appender.append(&a.slice(s![.., .., ..2])) {
+        Err(SnapshotError::Shape(err)) => assert_eq!(err.shapes(), Some((&[5, 2, 2][..], &[0, 2, 3][..]))),
+        res => panic!("unexpected result {:?}", res.map(|_| ())),
+    }
+    assert!(appender.append(&a.slice(s![.., .., 0])).is_err());
+    assert!(appender.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn axis_appender_without_axes() {
+    let _ = AxisAppender::<_, f32>::new(Cursor::new(Vec::new()), &[], Axis(0));
+}
+
+#[test]
+fn axis_appender_resume() {
+    let path = std::env::temp_dir().join(format!("ndarray-test-{}-appender", std::process::id()));
+    let open = || OpenOptions::new().read(true).write(true).open(&path).unwrap();
+    let a = Array::from_shape_fn((4, 3), |(i, j)| (i * 10 + j) as i64);
+
+    // An interrupted snapshot, whose header says one row, with two rows and
+    // an incomplete row at the end
+    let mut appender = AxisAppender::new(fs::File::create(&path).unwrap(), &[0, 3], Axis(0)).unwrap();
+    appender.append(&a.slice(s![..1, ..])).unwrap();
+    appender.flush().unwrap();
+    appender.append(&a.slice(s![1..2, ..])).unwrap();
+    drop(appender);
+    OpenOptions::new().append(true).open(&path).unwrap().write_all(&[1, 2, 3]).unwrap();
+    let b = snapshot::load::<_, i64>(fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(b, a.slice(s![..1, ..]).into_dyn());
+
+    let mut appender = AxisAppender::<_, i64>::resume(open()).unwrap();
+    assert_eq!(appender.shape(), &[2, 3]);
+    appender.append(&a.slice(s![2.., ..])).unwrap();
+    drop(appender.finish().unwrap());
+    let b = snapshot::load::<_, i64>(fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(b, a.clone().into_dyn());
+
+    // The element type is checked
+    assert!(matches!(AxisAppender::<_, f64>::resume(open()), Err(SnapshotError::DType { .. })));
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,147 @@
+#![cfg(all(feature = "linalg", feature = "approx"))]
+
+use approx::assert_abs_diff_eq;
+use ndarray::linalg::solve::LinalgError;
+use ndarray::prelude::*;
+
+fn test_matrix(n: usize) -> Array2<f64> {
+    Array::from_shape_fn((n, n), |(i, j)| {
+        if i == j && i != 0 {
+            n as f64 + 1.
+        } else {
+            ((i * 7 + j * 3) % 5) as f64 - 2.
+        }
+    })
+}
+
+#[test]
+fn lu_factors() {
+    let a = test_matrix(5);
+    let lu = a.lu().unwrap();
+    let pa = a.select(Axis(0), lu.permutation());
+    assert_abs_diff_eq!(lu.l().dot(&lu.u()), pa, epsilon = 1e-12);
+    assert!(!lu.is_singular());
+
+    // A zero in the top left corner requires a row swap
+    let lu = array![[0., 1.], [1., 0.]].lu().unwrap();
+    assert_eq!(lu.permutation(), &[1, 0]);
+    assert_eq!(lu.det(), -1.);
+}
+
+#[test]
+fn solve_vector_and_matrix() {
+    let a = test_matrix(6);
+    let x = Array::linspace(-1., 1., 6);
+    let b = a.dot(&x);
+    assert_abs_diff_eq!(a.solve(&b).unwrap(), x, epsilon = 1e-12);
+
+    let xs = Array::from_shape_fn((6, 3), |(i, j)| (i + 2 * j) as f64);
+    let bs = a.dot(&xs);
+    assert_abs_diff_eq!(a.solve(&bs).unwrap(), xs, epsilon = 1e-12);
+    // Right hand side in Fortran order
+    let bs_f = bs.t().to_owned();
+    assert_abs_diff_eq!(a.solve(&bs_f.t()).unwrap(), xs, epsilon = 1e-12);
+}
+
+#[test]
+fn inverse_and_determinant() {
+    let a = test_matrix(4);
+    let inv = a.inv().unwrap();
+    assert_abs_diff_eq!(a.dot(&inv), Array2::eye(4), epsilon = 1e-12);
+
+    let a = array![[0., 2., 1.], [1., 1., 0.], [3., 0., 1.]];
+    assert_abs_diff_eq!(a.det().unwrap(), -5., epsilon = 1e-12);
+    assert_abs_diff_eq!(a.t().det().unwrap(), -5., epsilon = 1e-12);
+    assert_eq!(Array2::<f32>::zeros((0, 0)).det().unwrap(), 1.);
+    assert_abs_diff_eq!(array![[2f32]].inv().unwrap(), array![[0.5]]);
+}
+
+#[test]
+fn singular_and_shape_errors() {
+    let a = array![[1., 2.], [2., 4.]];
+    assert_eq!(a.det().unwrap(), 0.);
+    assert!(a.lu().unwrap().is_singular());
+    assert_eq!(a.inv(), Err(LinalgError::Singular));
+    assert_eq!(a.solve(&array![1., 1.]), Err(LinalgError::Singular));
+
+    let b = Array2::<f64>::zeros((2, 3));
+    assert_eq!(b.det(), Err(LinalgError::NotSquare { rows: 2, cols: 3 }));
+    assert!(matches!(
+        test_matrix(3).solve(&array![1., 2.]),
+        Err(LinalgError::Shape(_))
+    ));
+}
+
+fn spd_matrix(n: usize) -> Array2<f64> {
+    let a = test_matrix(n);
+    a.t().dot(&a) + Array2::<f64>::eye(n)
+}
+
+#[test]
+fn eigh_reconstructs() {
+    let a = spd_matrix(6);
+    let (values, vectors) = a.eigh().unwrap();
+    assert!(values.windows(2).into_iter().all(|w| w[0] <= w[1]));
+    assert_abs_diff_eq!(vectors.t().dot(&vectors), Array2::eye(6), epsilon = 1e-12);
+    let back = (&vectors * &values).dot(&vectors.t());
+    assert_abs_diff_eq!(back, a, epsilon = 1e-9);
+
+    // A diagonal matrix is already decomposed
+    let (values, _) = array![[3., 0.], [0., -1.]].eigh().unwrap();
+    assert_eq!(values, array![-1., 3.]);
+}
+
+#[test]
+fn symmetric_matrix_functions() {
+    let a = spd_matrix(5);
+    let root = a.sqrtm().unwrap();
+    assert_abs_diff_eq!(root, root.t(), epsilon = 1e-12);
+    assert_abs_diff_eq!(root.dot(&root), a, epsilon = 1e-9);
+
+    let log = a.logm_symmetric().unwrap();
+    assert_abs_diff_eq!(log.expm_symmetric().unwrap(), a, epsilon = 1e-8);
+
+    // Semidefinite: the square root exists, the logarithm doesn't
+    let singular = array![[1., 1.], [1., 1.]];
+    let root = singular.sqrtm().unwrap();
+    assert_abs_diff_eq!(root.dot(&root), singular, epsilon = 1e-12);
+    assert_eq!(singular.logm_symmetric(), Err(LinalgError::NotPositiveDefinite));
+    assert_eq!(array![[0., 1.], [1., 0.]].sqrtm(), Err(LinalgError::NotPositiveDefinite));
+
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).eigh().map(|_| ()),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn matrix_norms() {
+    let a = array![[1., -2., 0.], [3., 4., -5.]];
+    assert_abs_diff_eq!(a.norm_fro(), 55f64.sqrt(), epsilon = 1e-12);
+    assert_eq!(a.norm_1(), 6.);
+    assert_eq!(a.norm_inf(), 12.);
+    assert_eq!(a.t().norm_1(), 12.);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).norm_inf(), 0.);
+}
+
+#[test]
+fn cond_estimate() {
+    for n in 1..8 {
+        let a = test_matrix(n);
+        let exact = a.norm_1() * a.inv().unwrap().norm_1();
+        let estimate = a.cond().unwrap();
+        assert!(estimate <= exact * (1. + 1e-12), "{} > {}", estimate, exact);
+        assert!(estimate >= exact / 3., "{} < {} / 3", estimate, exact);
+    }
+
+    // Hilbert matrices are badly conditioned
+    let hilbert = Array::from_shape_fn((6, 6), |(i, j)| 1. / (i + j + 1) as f64);
+    let exact = hilbert.norm_1() * hilbert.inv().unwrap().norm_1();
+    assert_abs_diff_eq!(hilbert.cond().unwrap() / exact, 1., epsilon = 1e-3);
+
+    assert_eq!(array![[1., 2.], [2., 4.]].cond(), Ok(f64::INFINITY));
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).cond(),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
@@ -1,4 +1,7 @@
-use ndarray::{arr2, arr3, aview1, aview2, concatenate, stack, Array2, Axis, ErrorKind, Ix1};
+use ndarray::{
+    arr1, arr2, arr3, aview1, aview2, concatenate, interleave, s, stack, Array, Array2, Axis,
+    ErrorKind, Ix1, ShapeError,
+};
 
 #[test]
 fn concatenating() {
@@ -51,3 +54,112 @@ fn stacking() {
     let res: Result<Array2<f64>, _> = ndarray::stack::<_, Ix1>(Axis(0), &[]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
 }
+
+#[test]
+fn concatenating_checked() {
+    use ndarray::{concatenate_checked, ConcatError};
+
+    let a = arr2(&[[2., 2.], [3., 3.]]);
+    let b = arr2(&[[1., 1.]]);
+    let res = concatenate_checked(Axis(0), &[a.view(), b.view(), a.view()]).unwrap();
+    assert_eq!(res, concatenate(Axis(0), &[a.view(), b.view(), a.view()]).unwrap());
+    let res = concatenate_checked(Axis(1), &[a.view(), a.t()]).unwrap();
+    assert_eq!(res, arr2(&[[2., 2., 2., 3.], [3., 3., 2., 3.]]));
+
+    let err = concatenate_checked(Axis(1), &[a.view(), b.view(), a.view(), b.view()]).unwrap_err();
+    assert_eq!(
+        err,
+        ConcatError::ShapeMismatch { expected: vec![2, 2], mismatches: vec![(1, vec![1, 2]), (3, vec![1, 2])] }
+    );
+    assert_eq!(
+        concatenate_checked(Axis(2), &[a.view()]),
+        Err(ConcatError::AxisOutOfBounds { axis: 2, ndim: 2 })
+    );
+    let empty: Result<Array2<f64>, _> = concatenate_checked(Axis(0), &[]);
+    assert_eq!(empty, Err(ConcatError::Empty));
+
+    // Dynamic dimension arrays with different numbers of dimensions
+    let c = a.clone().into_dyn();
+    let d = aview1(&[1., 2.]).into_dyn();
+    let err = concatenate_checked(Axis(0), &[c.view(), d.view()]).unwrap_err();
+    assert_eq!(err, ConcatError::ShapeMismatch { expected: vec![2, 2], mismatches: vec![(1, vec![2])] });
+}
+
+#[test]
+fn concatenating_fixed() {
+    use ndarray::concatenate_fixed;
+
+    let a = [[1, 2, 3], [4, 5, 6]];
+    let b = [[7, 8, 9]];
+    let res = concatenate_fixed(&[&a, &b, &a[..1]]);
+    assert_eq!(res, arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9], [1, 2, 3]]));
+    let empty = concatenate_fixed::<f32, 4>(&[]);
+    assert_eq!(empty.shape(), &[0, 4]);
+    let no_columns = concatenate_fixed::<f32, 0>(&[&[[]; 3]]);
+    assert_eq!(no_columns.shape(), &[3, 0]);
+}
+
+#[test]
+fn concatenating_and_stacking_iter() {
+    use ndarray::{concatenate_iter, stack_iter};
+
+    let a = arr2(&[[2., 2.], [3., 3.]]);
+    let b = arr2(&[[4., 4.]]);
+    let views = vec![a.view(), b.view(), a.view()];
+    let res = concatenate_iter(Axis(0), views.iter().cloned()).unwrap();
+    assert_eq!(res, concatenate(Axis(0), &views).unwrap());
+    let res = concatenate_iter(Axis(1), vec![a.view(), a.t()]).unwrap();
+    assert_eq!(res, arr2(&[[2., 2., 2., 3.], [3., 3., 2., 3.]]));
+
+    let err = concatenate_iter(Axis(1), vec![a.view(), b.view()]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = concatenate_iter(Axis(2), vec![a.view()]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let err = concatenate_iter(Axis(0), Vec::<ndarray::ArrayView2<f64>>::new()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+    let res = stack_iter(Axis(1), a.rows()).unwrap();
+    assert_eq!(res, a.t());
+    let res = stack_iter(Axis(2), vec![a.view(), a.view()]).unwrap();
+    assert_eq!(res, stack(Axis(2), &[a.view(), a.view()]).unwrap());
+    let err = stack_iter(Axis(0), vec![a.view(), b.view()]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = stack_iter(Axis(3), vec![a.view()]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn interleave_and_deinterleave() {
+    let a = Array::from_shape_fn((2, 3, 2), |(i, j, k)| (i * 6 + j * 2 + k) as i32);
+    let b = &a + 100;
+    let c = (&a + 200).reversed_axes().as_standard_layout().reversed_axes().to_owned();
+    let res = interleave(Axis(1), &[a.view(), b.view(), c.view()]).unwrap();
+    assert_eq!(res.shape(), &[2, 9, 2]);
+    assert_eq!(res.slice(s![.., 0..;3, ..]), a);
+    assert_eq!(res.slice(s![.., 1..;3, ..]), b);
+    assert_eq!(res.slice(s![.., 2..;3, ..]), c);
+    assert_eq!(res.deinterleave(Axis(1), 3), vec![a.view(), b.view(), c.view()]);
+
+    // Interleaving along the last axis is stacking along a new last axis
+    let flat = interleave(Axis(2), &[a.view(), b.view()]).unwrap();
+    let stacked = stack(Axis(3), &[a.view(), b.view()]).unwrap();
+    assert_eq!(flat, Array::from_shape_vec((2, 3, 4), stacked.iter().cloned().collect()).unwrap());
+
+    assert_eq!(
+        interleave::<i32, Ix1>(Axis(0), &[]),
+        Err(ShapeError::from_kind(ErrorKind::Unsupported))
+    );
+    assert_eq!(
+        interleave(Axis(3), &[a.view()]),
+        Err(ShapeError::from_kind(ErrorKind::OutOfBounds))
+    );
+    assert_eq!(
+        interleave(Axis(0), &[a.view(), a.slice(s![.., 1.., ..])]),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+    );
+
+    // More parts than elements
+    let short = arr1(&[1, 2]);
+    let parts = short.deinterleave(Axis(0), 3);
+    assert_eq!(parts, vec![arr1(&[1]), arr1(&[2]), arr1(&[])]);
+}
@@ -1,5 +1,5 @@
 use ndarray::prelude::*;
-use ndarray::Zip;
+use ndarray::{Slice, Zip};
 
 #[test]
 fn cell_view() {
@@ -14,3 +14,85 @@ fn cell_view() {
     }
     assert_eq!(a, answer);
 }
+
+#[test]
+fn axis_tags() {
+    use ndarray::axis_tags;
+
+    struct Batch;
+    struct Time;
+    struct Channel;
+    axis_tags!(struct Video[Batch, Time, Channel,]);
+    axis_tags!(pub(crate) struct Frames[Time, Channel]);
+
+    let mut a = Array::from_shape_fn((2, 3, 4), |(b, t, c)| (b * 100 + t * 10 + c) as f64).into_tagged::<Video>();
+    assert_eq!(a.axis::<Channel>(), Axis(2));
+    assert_eq!(a.len_of_tag::<Time>(), 3);
+    assert_eq!(a.sum_axis_tag::<Time>(), a.sum_axis(Axis(1)));
+    assert_eq!(a.mean_axis_tag::<Batch>(), a.mean_axis(Axis(0)));
+    assert_eq!(a.index_axis_tag::<Channel>(1), a.index_axis(Axis(2), 1));
+
+    a.index_axis_tag_mut::<Batch>(1).fill(0.);
+    a.view_mut()[[0, 0, 0]] = -1.;
+    let frames = a.index_axis_tag::<Batch>(0).into_tagged::<Frames>();
+    assert_eq!(frames.axis::<Time>(), Axis(0));
+    assert_eq!(frames.sum_axis_tag::<Channel>(), array![5., 46., 86.]);
+    assert_eq!(a.into_inner().index_axis(Axis(0), 1).sum(), 0.);
+
+    // Dynamic dimensions are checked when tagged
+    let d = ArrayD::<f64>::zeros(IxDyn(&[3, 4]));
+    assert_eq!(d.tagged::<Frames>().len_of_tag::<Channel>(), 4);
+    let result = std::panic::catch_unwind(|| d.tagged::<Video>().ndim());
+    assert!(result.is_err());
+}
+
+#[test]
+fn axis_names() {
+    let a = ArrayD::from_shape_fn(IxDyn(&[2, 3, 4]), |ix| (ix[0] * 100 + ix[1] * 10 + ix[2]) as i32)
+        .with_axis_names(vec!["batch", "time", "feature"]);
+    assert_eq!(a.axis_by_name("feature"), Some(Axis(2)));
+    assert_eq!(a.axis_by_name("channel"), None);
+    assert_eq!(a.len_of_name("time"), 3);
+
+    let mean = a.mean_axis_by_name("batch").unwrap();
+    assert_eq!(mean.names(), ["time", "feature"]);
+    assert_eq!(*mean, a.mean_axis(Axis(0)).unwrap());
+
+    // Indexing and slicing keep the names consistent
+    let frame = a.index_axis_by_name("time", 2);
+    assert_eq!(frame.names(), ["batch", "feature"]);
+    assert_eq!(frame.sum_axis_by_name("feature").into_inner(), a.index_axis(Axis(1), 2).sum_axis(Axis(1)));
+    let sliced = a.slice_axis_by_name("feature", Slice::new(0, None, 2));
+    assert_eq!(sliced.shape(), &[2, 3, 2]);
+    assert_eq!(sliced[[1, 2, 1]], 122);
+
+    let p = a.view().permuted_axes_by_name(&["time", "feature", "batch"]);
+    assert_eq!(p.shape(), &[3, 4, 2]);
+    assert_eq!(p[[2, 1, 1]], 121);
+    assert_eq!(p.t().names(), ["batch", "feature", "time"]);
+    assert_eq!(p.sum_axis_by_name("batch").into_inner(), a.sum_axis(Axis(0)));
+
+    let mut a = a;
+    a.view_mut()[[0, 0, 0]] = 7;
+    assert_eq!(a.into_inner()[[0, 0, 0]], 7);
+}
+
+#[test]
+fn axis_names_from_strings() {
+    let names = vec![String::from("x"), String::from("y")];
+    let a = ArrayD::<f32>::zeros(IxDyn(&[2, 3])).with_axis_names(&names);
+    assert_eq!(a.names(), &names[..]);
+    assert_eq!(a.len_of_name("y"), 3);
+}
+
+#[test]
+#[should_panic]
+fn axis_names_missing() {
+    ArrayD::<f32>::zeros(IxDyn(&[2])).with_axis_names(&["x"]).len_of_name("y");
+}
+
+#[test]
+#[should_panic]
+fn axis_names_repeated() {
+    ArrayD::<f32>::zeros(IxDyn(&[2, 2])).with_axis_names(&["x", "x"]);
+}
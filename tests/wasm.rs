@@ -0,0 +1,43 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use js_sys::{Float32Array, Float64Array, Int32Array, Uint8Array};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use ndarray::prelude::*;
+
+#[wasm_bindgen_test]
+fn to_typed_array_1d() {
+    let a = array![1f32, 2., 3.];
+    let typed: Float32Array = a.to_typed_array();
+    assert_eq!(typed.to_vec(), vec![1., 2., 3.]);
+    assert_eq!(Array1::from_typed_array(&typed), a);
+}
+
+#[wasm_bindgen_test]
+fn to_typed_array_logical_order() {
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let typed: Int32Array = a.t().to_typed_array();
+    assert_eq!(typed.to_vec(), vec![1, 4, 2, 5, 3, 6]);
+
+    let b = Array::from_shape_typed_array((3, 2), &typed).unwrap();
+    assert_eq!(b, a.t());
+    assert!(Array::<i32, _>::from_shape_typed_array((4, 2), &typed).is_err());
+}
+
+#[wasm_bindgen_test]
+fn typed_array_view() {
+    let a = Array::linspace(0., 1., 5);
+    let view: Float64Array = unsafe { a.typed_array_view() }.unwrap();
+    assert_eq!(view.length(), 5);
+    assert_eq!(view.get_index(4), 1.);
+    assert!(unsafe { a.slice(s![..;2]).typed_array_view() }.is_none());
+}
+
+#[wasm_bindgen_test]
+fn from_typed_array_copies() {
+    let typed = Uint8Array::from(&[1u8, 2, 3][..]);
+    let mut a = Array1::<u8>::from_typed_array(&typed);
+    a[0] = 10;
+    assert_eq!(typed.get_index(0), 1);
+    assert_eq!(a, array![10, 2, 3]);
+}
@@ -152,3 +152,86 @@ fn test_window_neg_stride() {
         answer.iter()
     );
 }
+
+#[test]
+fn windows_with_stride() {
+    let a = Array::from_iter(0..24).into_shape((4, 6)).unwrap();
+
+    // Strided windows are a subset of the unit stride windows
+    let all = a.windows((2, 3)).into_iter().collect::<Vec<_>>();
+    let all = Array::from_shape_vec((3, 4), all).unwrap();
+    itertools::assert_equal(
+        a.windows_with_stride((2, 3), (2, 3)),
+        all.slice(s![..;2, ..;3]).iter().cloned(),
+    );
+    itertools::assert_equal(
+        a.windows_with_stride((2, 3), (1, 1)),
+        a.windows((2, 3)),
+    );
+    // A stride larger than the window skips elements
+    itertools::assert_equal(
+        a.windows_with_stride((1, 1), (3, 4)),
+        vec![aview2(&[[0]]), aview2(&[[4]]), aview2(&[[18]]), aview2(&[[22]])],
+    );
+    // Negative strides of the array
+    itertools::assert_equal(
+        a.slice(s![.., ..;-1]).windows_with_stride((4, 2), (1, 2)),
+        vec![a.slice(s![.., 4..;-1]), a.slice(s![.., 2..4;-1]), a.slice(s![.., 0..2;-1])],
+    );
+    // Windows that don't fit
+    assert_eq!(a.windows_with_stride((5, 1), (1, 1)).into_iter().count(), 0);
+
+    // As a producer
+    let mut sums = Array::zeros((2, 2));
+    Zip::from(&mut sums)
+        .and(a.windows_with_stride((2, 3), (2, 3)))
+        .for_each(|s, w| *s = w.sum());
+    assert_eq!(sums, arr2(&[[24, 42], [96, 114]]));
+}
+
+#[test]
+#[should_panic]
+fn windows_with_stride_zero_stride() {
+    let a = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    a.windows_with_stride((1, 1), (1, 0));
+}
+
+#[test]
+fn axis_windows() {
+    let a = Array::from_iter(0..24).into_shape((2, 4, 3)).unwrap();
+    let windows = a.axis_windows(Axis(1), 3);
+    itertools::assert_equal(
+        windows.clone(),
+        vec![a.slice(s![.., 0..3, ..]), a.slice(s![.., 1..4, ..])],
+    );
+    // Split as a producer, in parallel or in Zip
+    let mut firsts = Array1::zeros(2);
+    Zip::from(&mut firsts).and(windows).for_each(|f, w| *f = w[[0, 0, 0]]);
+    assert_eq!(firsts, arr1(&[0, 3]));
+
+    let b = a.slice(s![.., ..;-1, ..]);
+    itertools::assert_equal(
+        b.axis_windows(Axis(1), 2),
+        vec![b.slice(s![.., 0..2, ..]), b.slice(s![.., 1..3, ..]), b.slice(s![.., 2..4, ..])],
+    );
+    assert_eq!(a.axis_windows(Axis(0), 3).into_iter().count(), 0);
+    assert_eq!(a.axis_windows(Axis(2), 1).into_iter().count(), 3);
+}
+
+#[test]
+fn axis_windows_empty() {
+    let a = Array2::<i32>::zeros((5, 0));
+    assert_eq!(a.axis_windows(Axis(0), 2).into_iter().count(), 0);
+    assert_eq!(a.axis_windows(Axis(1), 1).into_iter().count(), 0);
+    let b = Array2::<i32>::zeros((0, 5));
+    assert_eq!(b.axis_windows(Axis(0), 1).into_iter().count(), 0);
+    assert_eq!(b.axis_windows(Axis(1), 2).into_iter().count(), 0);
+    Zip::from(b.axis_windows(Axis(1), 2)).for_each(|_| panic!("no windows"));
+}
+
+#[test]
+#[should_panic]
+fn axis_windows_zero_size() {
+    let a = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    a.axis_windows(Axis(0), 0);
+}
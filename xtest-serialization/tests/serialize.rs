@@ -109,6 +109,24 @@ fn serial_wrong_count_serde() {
     assert!(arr.is_err());
 }
 
+#[test]
+fn serial_view_logical_order_serde() {
+    let a = arr2(&[[1., 2., 3.], [4., 5., 6.]]);
+    // Elements are serialized in logical order regardless of memory layout
+    let serial = serde_json::to_string(&a.t()).unwrap();
+    assert_eq!(serial, r#"{"v":1,"dim":[3,2],"data":[1.0,4.0,2.0,5.0,3.0,6.0]}"#);
+    let res = serde_json::from_str::<ArrayD<f64>>(&serial);
+    assert_eq!(res.unwrap(), a.t().into_dyn());
+
+    // wrong number of dimensions
+    let text = r##"{"v":1,"dim":[6],"data":[1,2,3,4,5,6]}"##;
+    assert!(serde_json::from_str::<ArcArray2<f64>>(text).is_err());
+    // the error mentions the shape
+    let text = r##"{"v":1,"dim":[2,2],"data":[1,2,3]}"##;
+    let err = serde_json::from_str::<ArcArray2<f64>>(text).unwrap_err();
+    assert!(err.to_string().contains("[2, 2]"), "{}", err);
+}
+
 #[test]
 fn serial_many_dim_serde_msgpack() {
     {